@@ -0,0 +1,551 @@
+// Bindings for the 2.2.x headers of the qiskit c api, used when the "bindgen" feature is disabled.
+// Transcribed from the declarations of the 2.2.3 qiskit.h, not yet generated by bindgen: replace
+// them with the output of `make bindings`, which `make check_bindings` compares them against in CI.
+
+pub const QISKIT_VERSION_MAJOR: u32 = 2;
+pub const QISKIT_VERSION_MINOR: u32 = 2;
+pub const QISKIT_VERSION_PATCH: u32 = 3;
+pub type QkExitCode = ::std::os::raw::c_uint;
+pub const QkExitCode_QkExitCode_Success: QkExitCode = 0;
+pub const QkExitCode_QkExitCode_CInputError: QkExitCode = 100;
+pub const QkExitCode_QkExitCode_NullPointerError: QkExitCode = 101;
+pub const QkExitCode_QkExitCode_AlignmentError: QkExitCode = 102;
+pub const QkExitCode_QkExitCode_IndexError: QkExitCode = 103;
+pub const QkExitCode_QkExitCode_ArithmeticError: QkExitCode = 200;
+pub const QkExitCode_QkExitCode_MismatchedQubits: QkExitCode = 201;
+pub const QkExitCode_QkExitCode_ExpectedUnitary: QkExitCode = 300;
+pub const QkExitCode_QkExitCode_TargetError: QkExitCode = 400;
+pub const QkExitCode_QkExitCode_TargetInstAlreadyExists: QkExitCode = 401;
+pub const QkExitCode_QkExitCode_TargetQargMismatch: QkExitCode = 402;
+pub const QkExitCode_QkExitCode_TargetInvalidQargsKey: QkExitCode = 403;
+pub const QkExitCode_QkExitCode_TargetInvalidInstKey: QkExitCode = 404;
+pub const QkGate_QkGate_GlobalPhase: QkGate = 0;
+pub const QkGate_QkGate_H: QkGate = 1;
+pub const QkGate_QkGate_I: QkGate = 2;
+pub const QkGate_QkGate_X: QkGate = 3;
+pub const QkGate_QkGate_Y: QkGate = 4;
+pub const QkGate_QkGate_Z: QkGate = 5;
+pub const QkGate_QkGate_Phase: QkGate = 6;
+pub const QkGate_QkGate_R: QkGate = 7;
+pub const QkGate_QkGate_RX: QkGate = 8;
+pub const QkGate_QkGate_RY: QkGate = 9;
+pub const QkGate_QkGate_RZ: QkGate = 10;
+pub const QkGate_QkGate_S: QkGate = 11;
+pub const QkGate_QkGate_Sdg: QkGate = 12;
+pub const QkGate_QkGate_SX: QkGate = 13;
+pub const QkGate_QkGate_SXdg: QkGate = 14;
+pub const QkGate_QkGate_T: QkGate = 15;
+pub const QkGate_QkGate_Tdg: QkGate = 16;
+pub const QkGate_QkGate_U: QkGate = 17;
+pub const QkGate_QkGate_U1: QkGate = 18;
+pub const QkGate_QkGate_U2: QkGate = 19;
+pub const QkGate_QkGate_U3: QkGate = 20;
+pub const QkGate_QkGate_CH: QkGate = 21;
+pub const QkGate_QkGate_CX: QkGate = 22;
+pub const QkGate_QkGate_CY: QkGate = 23;
+pub const QkGate_QkGate_CZ: QkGate = 24;
+pub const QkGate_QkGate_DCX: QkGate = 25;
+pub const QkGate_QkGate_ECR: QkGate = 26;
+pub const QkGate_QkGate_Swap: QkGate = 27;
+pub const QkGate_QkGate_ISwap: QkGate = 28;
+pub const QkGate_QkGate_CPhase: QkGate = 29;
+pub const QkGate_QkGate_CRX: QkGate = 30;
+pub const QkGate_QkGate_CRY: QkGate = 31;
+pub const QkGate_QkGate_CRZ: QkGate = 32;
+pub const QkGate_QkGate_CS: QkGate = 33;
+pub const QkGate_QkGate_CSdg: QkGate = 34;
+pub const QkGate_QkGate_CSX: QkGate = 35;
+pub const QkGate_QkGate_CU: QkGate = 36;
+pub const QkGate_QkGate_CU1: QkGate = 37;
+pub const QkGate_QkGate_CU3: QkGate = 38;
+pub const QkGate_QkGate_RXX: QkGate = 39;
+pub const QkGate_QkGate_RYY: QkGate = 40;
+pub const QkGate_QkGate_RZZ: QkGate = 41;
+pub const QkGate_QkGate_RZX: QkGate = 42;
+pub const QkGate_QkGate_XXMinusYY: QkGate = 43;
+pub const QkGate_QkGate_XXPlusYY: QkGate = 44;
+pub const QkGate_QkGate_CCX: QkGate = 45;
+pub const QkGate_QkGate_CCZ: QkGate = 46;
+pub const QkGate_QkGate_CSwap: QkGate = 47;
+pub const QkGate_QkGate_RCCX: QkGate = 48;
+pub const QkGate_QkGate_C3X: QkGate = 49;
+pub const QkGate_QkGate_C3SX: QkGate = 50;
+pub const QkGate_QkGate_RC3X: QkGate = 51;
+pub type QkGate = u8;
+pub const QkBitTerm_QkBitTerm_X: QkBitTerm = 2;
+pub const QkBitTerm_QkBitTerm_Plus: QkBitTerm = 10;
+pub const QkBitTerm_QkBitTerm_Minus: QkBitTerm = 6;
+pub const QkBitTerm_QkBitTerm_Y: QkBitTerm = 3;
+pub const QkBitTerm_QkBitTerm_Right: QkBitTerm = 11;
+pub const QkBitTerm_QkBitTerm_Left: QkBitTerm = 7;
+pub const QkBitTerm_QkBitTerm_Z: QkBitTerm = 1;
+pub const QkBitTerm_QkBitTerm_Zero: QkBitTerm = 9;
+pub const QkBitTerm_QkBitTerm_One: QkBitTerm = 5;
+pub type QkBitTerm = u8;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkCircuit {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkClassicalRegister {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkElidePermutationsResult {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkObs {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkParam {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkQuantumRegister {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkTarget {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkTargetEntry {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkVF2LayoutResult {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkComplex64 {
+    pub re: f64,
+    pub im: f64,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkCircuitInstruction {
+    pub name: *mut ::std::os::raw::c_char,
+    pub qubits: *mut u32,
+    pub clbits: *mut u32,
+    pub params: *mut f64,
+    pub num_qubits: u32,
+    pub num_clbits: u32,
+    pub num_params: u32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkOpCount {
+    pub name: *const ::std::os::raw::c_char,
+    pub count: usize,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkOpCounts {
+    pub data: *mut QkOpCount,
+    pub len: usize,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkObsTerm {
+    pub coeff: QkComplex64,
+    pub len: usize,
+    pub bit_terms: *mut QkBitTerm,
+    pub indices: *mut u32,
+    pub num_qubits: u32,
+}
+unsafe extern "C" {
+    pub fn qk_circuit_new(num_qubits: u32, num_clbits: u32) -> *mut QkCircuit;
+}
+unsafe extern "C" {
+    pub fn qk_circuit_free(circuit: *mut QkCircuit);
+}
+unsafe extern "C" {
+    pub fn qk_circuit_copy(circuit: *const QkCircuit) -> *mut QkCircuit;
+}
+unsafe extern "C" {
+    pub fn qk_circuit_num_qubits(circuit: *const QkCircuit) -> u32;
+}
+unsafe extern "C" {
+    pub fn qk_circuit_num_clbits(circuit: *const QkCircuit) -> u32;
+}
+unsafe extern "C" {
+    pub fn qk_circuit_num_instructions(circuit: *const QkCircuit) -> usize;
+}
+unsafe extern "C" {
+    pub fn qk_gate_num_qubits(gate: QkGate) -> u32;
+}
+unsafe extern "C" {
+    pub fn qk_gate_num_params(gate: QkGate) -> u32;
+}
+unsafe extern "C" {
+    pub fn qk_circuit_gate(
+        circuit: *mut QkCircuit,
+        gate: QkGate,
+        qubits: *const u32,
+        params: *const f64,
+    ) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_circuit_measure(circuit: *mut QkCircuit, qubit: u32, clbit: u32) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_circuit_reset(circuit: *mut QkCircuit, qubit: u32) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_circuit_barrier(
+        circuit: *mut QkCircuit,
+        qubits: *const u32,
+        num_qubits: u32,
+    ) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_circuit_unitary(
+        circuit: *mut QkCircuit,
+        matrix: *const QkComplex64,
+        qubits: *const u32,
+        num_qubits: u32,
+        check_input: bool,
+    ) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_circuit_get_instruction(
+        circuit: *const QkCircuit,
+        index: usize,
+        instruction: *mut QkCircuitInstruction,
+    );
+}
+unsafe extern "C" {
+    pub fn qk_circuit_instruction_clear(inst: *mut QkCircuitInstruction);
+}
+unsafe extern "C" {
+    pub fn qk_circuit_count_ops(circuit: *const QkCircuit) -> QkOpCounts;
+}
+unsafe extern "C" {
+    pub fn qk_opcounts_clear(op_counts: *mut QkOpCounts);
+}
+unsafe extern "C" {
+    pub fn qk_quantum_register_new(
+        num_qubits: u32,
+        name: *const ::std::os::raw::c_char,
+    ) -> *mut QkQuantumRegister;
+}
+unsafe extern "C" {
+    pub fn qk_quantum_register_free(reg: *mut QkQuantumRegister);
+}
+unsafe extern "C" {
+    pub fn qk_classical_register_new(
+        num_clbits: u32,
+        name: *const ::std::os::raw::c_char,
+    ) -> *mut QkClassicalRegister;
+}
+unsafe extern "C" {
+    pub fn qk_classical_register_free(reg: *mut QkClassicalRegister);
+}
+unsafe extern "C" {
+    pub fn qk_circuit_add_quantum_register(circuit: *mut QkCircuit, reg: *const QkQuantumRegister);
+}
+unsafe extern "C" {
+    pub fn qk_circuit_add_classical_register(
+        circuit: *mut QkCircuit,
+        reg: *const QkClassicalRegister,
+    );
+}
+unsafe extern "C" {
+    pub fn qk_str_free(string: *mut ::std::os::raw::c_char);
+}
+unsafe extern "C" {
+    pub fn qk_obs_zero(num_qubits: u32) -> *mut QkObs;
+}
+unsafe extern "C" {
+    pub fn qk_obs_identity(num_qubits: u32) -> *mut QkObs;
+}
+unsafe extern "C" {
+    pub fn qk_obs_new(
+        num_qubits: u32,
+        num_terms: u64,
+        num_bits: u64,
+        coeffs: *mut QkComplex64,
+        bit_terms: *mut QkBitTerm,
+        indices: *mut u32,
+        boundaries: *mut usize,
+    ) -> *mut QkObs;
+}
+unsafe extern "C" {
+    pub fn qk_obs_free(obs: *mut QkObs);
+}
+unsafe extern "C" {
+    pub fn qk_obs_copy(obs: *const QkObs) -> *mut QkObs;
+}
+unsafe extern "C" {
+    pub fn qk_obs_add_term(obs: *mut QkObs, cterm: *const QkObsTerm) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_obs_num_terms(obs: *const QkObs) -> usize;
+}
+unsafe extern "C" {
+    pub fn qk_obs_num_qubits(obs: *const QkObs) -> u32;
+}
+unsafe extern "C" {
+    pub fn qk_obs_len(obs: *const QkObs) -> usize;
+}
+unsafe extern "C" {
+    pub fn qk_obs_coeffs(obs: *mut QkObs) -> *mut QkComplex64;
+}
+unsafe extern "C" {
+    pub fn qk_obs_bit_terms(obs: *mut QkObs) -> *mut QkBitTerm;
+}
+unsafe extern "C" {
+    pub fn qk_obs_indices(obs: *mut QkObs) -> *mut u32;
+}
+unsafe extern "C" {
+    pub fn qk_obs_boundaries(obs: *mut QkObs) -> *mut usize;
+}
+unsafe extern "C" {
+    pub fn qk_obs_equal(obs: *const QkObs, other: *const QkObs) -> bool;
+}
+unsafe extern "C" {
+    pub fn qk_obs_str(obs: *const QkObs) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn qk_param_new_symbol(name: *const ::std::os::raw::c_char) -> *mut QkParam;
+}
+unsafe extern "C" {
+    pub fn qk_param_zero() -> *mut QkParam;
+}
+unsafe extern "C" {
+    pub fn qk_param_from_double(value: f64) -> *mut QkParam;
+}
+unsafe extern "C" {
+    pub fn qk_param_copy(param: *const QkParam) -> *mut QkParam;
+}
+unsafe extern "C" {
+    pub fn qk_param_free(param: *mut QkParam);
+}
+unsafe extern "C" {
+    pub fn qk_param_str(param: *const QkParam) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn qk_param_equal(lhs: *const QkParam, rhs: *const QkParam) -> bool;
+}
+unsafe extern "C" {
+    pub fn qk_param_as_real(param: *const QkParam) -> f64;
+}
+unsafe extern "C" {
+    pub fn qk_param_bind(
+        out: *mut QkParam,
+        param: *const QkParam,
+        keys: *const *const QkParam,
+        values: *const f64,
+        num: usize,
+    ) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_param_add(out: *mut QkParam, lhs: *const QkParam, rhs: *const QkParam) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_param_sub(out: *mut QkParam, lhs: *const QkParam, rhs: *const QkParam) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_param_mul(out: *mut QkParam, lhs: *const QkParam, rhs: *const QkParam) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_param_div(out: *mut QkParam, lhs: *const QkParam, rhs: *const QkParam) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_param_pow(out: *mut QkParam, lhs: *const QkParam, rhs: *const QkParam) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_param_sin(out: *mut QkParam, src: *const QkParam) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_param_cos(out: *mut QkParam, src: *const QkParam) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_param_tan(out: *mut QkParam, src: *const QkParam) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_param_asin(out: *mut QkParam, src: *const QkParam) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_param_acos(out: *mut QkParam, src: *const QkParam) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_param_atan(out: *mut QkParam, src: *const QkParam) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_param_log(out: *mut QkParam, src: *const QkParam) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_param_exp(out: *mut QkParam, src: *const QkParam) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_param_abs(out: *mut QkParam, src: *const QkParam) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_param_neg(out: *mut QkParam, src: *const QkParam) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_target_new(num_qubits: u32) -> *mut QkTarget;
+}
+unsafe extern "C" {
+    pub fn qk_target_free(target: *mut QkTarget);
+}
+unsafe extern "C" {
+    pub fn qk_target_copy(target: *const QkTarget) -> *mut QkTarget;
+}
+unsafe extern "C" {
+    pub fn qk_target_num_qubits(target: *const QkTarget) -> u32;
+}
+unsafe extern "C" {
+    pub fn qk_target_dt(target: *const QkTarget) -> f64;
+}
+unsafe extern "C" {
+    pub fn qk_target_set_dt(target: *mut QkTarget, dt: f64) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_target_num_instructions(target: *const QkTarget) -> usize;
+}
+unsafe extern "C" {
+    pub fn qk_target_add_instruction(
+        target: *mut QkTarget,
+        target_entry: *mut QkTargetEntry,
+    ) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_target_update_property(
+        target: *mut QkTarget,
+        instruction: QkGate,
+        qargs: *mut u32,
+        num_qargs: u32,
+        duration: f64,
+        error: f64,
+    ) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_target_entry_new(operation: QkGate) -> *mut QkTargetEntry;
+}
+unsafe extern "C" {
+    pub fn qk_target_entry_new_measure() -> *mut QkTargetEntry;
+}
+unsafe extern "C" {
+    pub fn qk_target_entry_new_reset() -> *mut QkTargetEntry;
+}
+unsafe extern "C" {
+    pub fn qk_target_entry_num_properties(entry: *const QkTargetEntry) -> usize;
+}
+unsafe extern "C" {
+    pub fn qk_target_entry_add_property(
+        entry: *mut QkTargetEntry,
+        qargs: *mut u32,
+        num_qargs: u32,
+        duration: f64,
+        error: f64,
+    ) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_target_entry_free(entry: *mut QkTargetEntry);
+}
+unsafe extern "C" {
+    pub fn qk_transpiler_pass_standalone_vf2_layout(
+        circuit: *const QkCircuit,
+        target: *const QkTarget,
+        strict_direction: bool,
+        call_limit: i64,
+        time_limit: f64,
+        max_trials: i64,
+    ) -> *mut QkVF2LayoutResult;
+}
+unsafe extern "C" {
+    pub fn qk_vf2_layout_result_has_match(result: *const QkVF2LayoutResult) -> bool;
+}
+unsafe extern "C" {
+    pub fn qk_vf2_layout_result_map_virtual_qubit(
+        result: *const QkVF2LayoutResult,
+        qubit: u32,
+    ) -> u32;
+}
+unsafe extern "C" {
+    pub fn qk_vf2_layout_result_free(result: *mut QkVF2LayoutResult);
+}
+unsafe extern "C" {
+    pub fn qk_transpiler_pass_standalone_elide_permutations(
+        circuit: *const QkCircuit,
+    ) -> *mut QkElidePermutationsResult;
+}
+unsafe extern "C" {
+    pub fn qk_elide_permutations_result_elided_gates(
+        result: *const QkElidePermutationsResult,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn qk_elide_permutations_result_permutation(
+        result: *const QkElidePermutationsResult,
+    ) -> *mut usize;
+}
+unsafe extern "C" {
+    pub fn qk_elide_permutations_result_circuit(
+        result: *const QkElidePermutationsResult,
+    ) -> *const QkCircuit;
+}
+unsafe extern "C" {
+    pub fn qk_elide_permutations_result_free(result: *mut QkElidePermutationsResult);
+}
+unsafe extern "C" {
+    pub fn qk_transpiler_pass_standalone_remove_identity_equivalent(
+        circuit: *mut QkCircuit,
+        target: *const QkTarget,
+        approximation_degree: f64,
+    ) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_transpiler_pass_standalone_commutative_cancellation(
+        circuit: *mut QkCircuit,
+        target: *const QkTarget,
+        approximation_degree: f64,
+    ) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_transpiler_pass_standalone_optimize_1q_sequences(
+        circuit: *mut QkCircuit,
+        target: *const QkTarget,
+    ) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_transpiler_pass_standalone_basis_translator(
+        circuit: *mut QkCircuit,
+        target: *const QkTarget,
+        min_qubits: usize,
+    ) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_transpiler_pass_standalone_gate_direction(
+        circuit: *mut QkCircuit,
+        target: *const QkTarget,
+    ) -> QkExitCode;
+}
+unsafe extern "C" {
+    pub fn qk_transpiler_pass_standalone_check_gate_direction(
+        circuit: *const QkCircuit,
+        target: *const QkTarget,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn qk_transpiler_pass_standalone_consolidate_blocks(
+        circuit: *mut QkCircuit,
+        target: *const QkTarget,
+        approximation_degree: f64,
+        force_consolidate: bool,
+    ) -> QkExitCode;
+}
@@ -0,0 +1,1751 @@
+// Bindings for the 2.2.x headers of the qiskit c api, used with the "runtime-linking" feature when the "bindgen" feature is disabled.
+// Transcribed from the declarations of the 2.2.3 qiskit.h, not yet generated by bindgen: replace
+// them with the output of `make bindings`, which `make check_bindings` compares them against in CI.
+
+pub const QISKIT_VERSION_MAJOR: u32 = 2;
+pub const QISKIT_VERSION_MINOR: u32 = 2;
+pub const QISKIT_VERSION_PATCH: u32 = 3;
+pub type QkExitCode = ::std::os::raw::c_uint;
+pub const QkExitCode_QkExitCode_Success: QkExitCode = 0;
+pub const QkExitCode_QkExitCode_CInputError: QkExitCode = 100;
+pub const QkExitCode_QkExitCode_NullPointerError: QkExitCode = 101;
+pub const QkExitCode_QkExitCode_AlignmentError: QkExitCode = 102;
+pub const QkExitCode_QkExitCode_IndexError: QkExitCode = 103;
+pub const QkExitCode_QkExitCode_ArithmeticError: QkExitCode = 200;
+pub const QkExitCode_QkExitCode_MismatchedQubits: QkExitCode = 201;
+pub const QkExitCode_QkExitCode_ExpectedUnitary: QkExitCode = 300;
+pub const QkExitCode_QkExitCode_TargetError: QkExitCode = 400;
+pub const QkExitCode_QkExitCode_TargetInstAlreadyExists: QkExitCode = 401;
+pub const QkExitCode_QkExitCode_TargetQargMismatch: QkExitCode = 402;
+pub const QkExitCode_QkExitCode_TargetInvalidQargsKey: QkExitCode = 403;
+pub const QkExitCode_QkExitCode_TargetInvalidInstKey: QkExitCode = 404;
+pub const QkGate_QkGate_GlobalPhase: QkGate = 0;
+pub const QkGate_QkGate_H: QkGate = 1;
+pub const QkGate_QkGate_I: QkGate = 2;
+pub const QkGate_QkGate_X: QkGate = 3;
+pub const QkGate_QkGate_Y: QkGate = 4;
+pub const QkGate_QkGate_Z: QkGate = 5;
+pub const QkGate_QkGate_Phase: QkGate = 6;
+pub const QkGate_QkGate_R: QkGate = 7;
+pub const QkGate_QkGate_RX: QkGate = 8;
+pub const QkGate_QkGate_RY: QkGate = 9;
+pub const QkGate_QkGate_RZ: QkGate = 10;
+pub const QkGate_QkGate_S: QkGate = 11;
+pub const QkGate_QkGate_Sdg: QkGate = 12;
+pub const QkGate_QkGate_SX: QkGate = 13;
+pub const QkGate_QkGate_SXdg: QkGate = 14;
+pub const QkGate_QkGate_T: QkGate = 15;
+pub const QkGate_QkGate_Tdg: QkGate = 16;
+pub const QkGate_QkGate_U: QkGate = 17;
+pub const QkGate_QkGate_U1: QkGate = 18;
+pub const QkGate_QkGate_U2: QkGate = 19;
+pub const QkGate_QkGate_U3: QkGate = 20;
+pub const QkGate_QkGate_CH: QkGate = 21;
+pub const QkGate_QkGate_CX: QkGate = 22;
+pub const QkGate_QkGate_CY: QkGate = 23;
+pub const QkGate_QkGate_CZ: QkGate = 24;
+pub const QkGate_QkGate_DCX: QkGate = 25;
+pub const QkGate_QkGate_ECR: QkGate = 26;
+pub const QkGate_QkGate_Swap: QkGate = 27;
+pub const QkGate_QkGate_ISwap: QkGate = 28;
+pub const QkGate_QkGate_CPhase: QkGate = 29;
+pub const QkGate_QkGate_CRX: QkGate = 30;
+pub const QkGate_QkGate_CRY: QkGate = 31;
+pub const QkGate_QkGate_CRZ: QkGate = 32;
+pub const QkGate_QkGate_CS: QkGate = 33;
+pub const QkGate_QkGate_CSdg: QkGate = 34;
+pub const QkGate_QkGate_CSX: QkGate = 35;
+pub const QkGate_QkGate_CU: QkGate = 36;
+pub const QkGate_QkGate_CU1: QkGate = 37;
+pub const QkGate_QkGate_CU3: QkGate = 38;
+pub const QkGate_QkGate_RXX: QkGate = 39;
+pub const QkGate_QkGate_RYY: QkGate = 40;
+pub const QkGate_QkGate_RZZ: QkGate = 41;
+pub const QkGate_QkGate_RZX: QkGate = 42;
+pub const QkGate_QkGate_XXMinusYY: QkGate = 43;
+pub const QkGate_QkGate_XXPlusYY: QkGate = 44;
+pub const QkGate_QkGate_CCX: QkGate = 45;
+pub const QkGate_QkGate_CCZ: QkGate = 46;
+pub const QkGate_QkGate_CSwap: QkGate = 47;
+pub const QkGate_QkGate_RCCX: QkGate = 48;
+pub const QkGate_QkGate_C3X: QkGate = 49;
+pub const QkGate_QkGate_C3SX: QkGate = 50;
+pub const QkGate_QkGate_RC3X: QkGate = 51;
+pub type QkGate = u8;
+pub const QkBitTerm_QkBitTerm_X: QkBitTerm = 2;
+pub const QkBitTerm_QkBitTerm_Plus: QkBitTerm = 10;
+pub const QkBitTerm_QkBitTerm_Minus: QkBitTerm = 6;
+pub const QkBitTerm_QkBitTerm_Y: QkBitTerm = 3;
+pub const QkBitTerm_QkBitTerm_Right: QkBitTerm = 11;
+pub const QkBitTerm_QkBitTerm_Left: QkBitTerm = 7;
+pub const QkBitTerm_QkBitTerm_Z: QkBitTerm = 1;
+pub const QkBitTerm_QkBitTerm_Zero: QkBitTerm = 9;
+pub const QkBitTerm_QkBitTerm_One: QkBitTerm = 5;
+pub type QkBitTerm = u8;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkCircuit {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkClassicalRegister {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkElidePermutationsResult {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkObs {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkParam {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkQuantumRegister {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkTarget {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkTargetEntry {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkVF2LayoutResult {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkComplex64 {
+    pub re: f64,
+    pub im: f64,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkCircuitInstruction {
+    pub name: *mut ::std::os::raw::c_char,
+    pub qubits: *mut u32,
+    pub clbits: *mut u32,
+    pub params: *mut f64,
+    pub num_qubits: u32,
+    pub num_clbits: u32,
+    pub num_params: u32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkOpCount {
+    pub name: *const ::std::os::raw::c_char,
+    pub count: usize,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkOpCounts {
+    pub data: *mut QkOpCount,
+    pub len: usize,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QkObsTerm {
+    pub coeff: QkComplex64,
+    pub len: usize,
+    pub bit_terms: *mut QkBitTerm,
+    pub indices: *mut u32,
+    pub num_qubits: u32,
+}
+pub struct Qiskit {
+    __library: ::libloading::Library,
+    pub qk_circuit_new: Result<
+        unsafe extern "C" fn(num_qubits: u32, num_clbits: u32) -> *mut QkCircuit,
+        ::libloading::Error,
+    >,
+    pub qk_circuit_free: Result<unsafe extern "C" fn(circuit: *mut QkCircuit), ::libloading::Error>,
+    pub qk_circuit_copy: Result<
+        unsafe extern "C" fn(circuit: *const QkCircuit) -> *mut QkCircuit,
+        ::libloading::Error,
+    >,
+    pub qk_circuit_num_qubits:
+        Result<unsafe extern "C" fn(circuit: *const QkCircuit) -> u32, ::libloading::Error>,
+    pub qk_circuit_num_clbits:
+        Result<unsafe extern "C" fn(circuit: *const QkCircuit) -> u32, ::libloading::Error>,
+    pub qk_circuit_num_instructions:
+        Result<unsafe extern "C" fn(circuit: *const QkCircuit) -> usize, ::libloading::Error>,
+    pub qk_gate_num_qubits: Result<unsafe extern "C" fn(gate: QkGate) -> u32, ::libloading::Error>,
+    pub qk_gate_num_params: Result<unsafe extern "C" fn(gate: QkGate) -> u32, ::libloading::Error>,
+    pub qk_circuit_gate: Result<
+        unsafe extern "C" fn(
+            circuit: *mut QkCircuit,
+            gate: QkGate,
+            qubits: *const u32,
+            params: *const f64,
+        ) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_circuit_measure: Result<
+        unsafe extern "C" fn(circuit: *mut QkCircuit, qubit: u32, clbit: u32) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_circuit_reset: Result<
+        unsafe extern "C" fn(circuit: *mut QkCircuit, qubit: u32) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_circuit_barrier: Result<
+        unsafe extern "C" fn(
+            circuit: *mut QkCircuit,
+            qubits: *const u32,
+            num_qubits: u32,
+        ) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_circuit_unitary: Result<
+        unsafe extern "C" fn(
+            circuit: *mut QkCircuit,
+            matrix: *const QkComplex64,
+            qubits: *const u32,
+            num_qubits: u32,
+            check_input: bool,
+        ) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_circuit_get_instruction: Result<
+        unsafe extern "C" fn(
+            circuit: *const QkCircuit,
+            index: usize,
+            instruction: *mut QkCircuitInstruction,
+        ),
+        ::libloading::Error,
+    >,
+    pub qk_circuit_instruction_clear:
+        Result<unsafe extern "C" fn(inst: *mut QkCircuitInstruction), ::libloading::Error>,
+    pub qk_circuit_count_ops:
+        Result<unsafe extern "C" fn(circuit: *const QkCircuit) -> QkOpCounts, ::libloading::Error>,
+    pub qk_opcounts_clear:
+        Result<unsafe extern "C" fn(op_counts: *mut QkOpCounts), ::libloading::Error>,
+    pub qk_quantum_register_new: Result<
+        unsafe extern "C" fn(
+            num_qubits: u32,
+            name: *const ::std::os::raw::c_char,
+        ) -> *mut QkQuantumRegister,
+        ::libloading::Error,
+    >,
+    pub qk_quantum_register_free:
+        Result<unsafe extern "C" fn(reg: *mut QkQuantumRegister), ::libloading::Error>,
+    pub qk_classical_register_new: Result<
+        unsafe extern "C" fn(
+            num_clbits: u32,
+            name: *const ::std::os::raw::c_char,
+        ) -> *mut QkClassicalRegister,
+        ::libloading::Error,
+    >,
+    pub qk_classical_register_free:
+        Result<unsafe extern "C" fn(reg: *mut QkClassicalRegister), ::libloading::Error>,
+    pub qk_circuit_add_quantum_register: Result<
+        unsafe extern "C" fn(circuit: *mut QkCircuit, reg: *const QkQuantumRegister),
+        ::libloading::Error,
+    >,
+    pub qk_circuit_add_classical_register: Result<
+        unsafe extern "C" fn(circuit: *mut QkCircuit, reg: *const QkClassicalRegister),
+        ::libloading::Error,
+    >,
+    pub qk_str_free:
+        Result<unsafe extern "C" fn(string: *mut ::std::os::raw::c_char), ::libloading::Error>,
+    pub qk_obs_zero:
+        Result<unsafe extern "C" fn(num_qubits: u32) -> *mut QkObs, ::libloading::Error>,
+    pub qk_obs_identity:
+        Result<unsafe extern "C" fn(num_qubits: u32) -> *mut QkObs, ::libloading::Error>,
+    pub qk_obs_new: Result<
+        unsafe extern "C" fn(
+            num_qubits: u32,
+            num_terms: u64,
+            num_bits: u64,
+            coeffs: *mut QkComplex64,
+            bit_terms: *mut QkBitTerm,
+            indices: *mut u32,
+            boundaries: *mut usize,
+        ) -> *mut QkObs,
+        ::libloading::Error,
+    >,
+    pub qk_obs_free: Result<unsafe extern "C" fn(obs: *mut QkObs), ::libloading::Error>,
+    pub qk_obs_copy:
+        Result<unsafe extern "C" fn(obs: *const QkObs) -> *mut QkObs, ::libloading::Error>,
+    pub qk_obs_add_term: Result<
+        unsafe extern "C" fn(obs: *mut QkObs, cterm: *const QkObsTerm) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_obs_num_terms:
+        Result<unsafe extern "C" fn(obs: *const QkObs) -> usize, ::libloading::Error>,
+    pub qk_obs_num_qubits:
+        Result<unsafe extern "C" fn(obs: *const QkObs) -> u32, ::libloading::Error>,
+    pub qk_obs_len: Result<unsafe extern "C" fn(obs: *const QkObs) -> usize, ::libloading::Error>,
+    pub qk_obs_coeffs:
+        Result<unsafe extern "C" fn(obs: *mut QkObs) -> *mut QkComplex64, ::libloading::Error>,
+    pub qk_obs_bit_terms:
+        Result<unsafe extern "C" fn(obs: *mut QkObs) -> *mut QkBitTerm, ::libloading::Error>,
+    pub qk_obs_indices:
+        Result<unsafe extern "C" fn(obs: *mut QkObs) -> *mut u32, ::libloading::Error>,
+    pub qk_obs_boundaries:
+        Result<unsafe extern "C" fn(obs: *mut QkObs) -> *mut usize, ::libloading::Error>,
+    pub qk_obs_equal: Result<
+        unsafe extern "C" fn(obs: *const QkObs, other: *const QkObs) -> bool,
+        ::libloading::Error,
+    >,
+    pub qk_obs_str: Result<
+        unsafe extern "C" fn(obs: *const QkObs) -> *mut ::std::os::raw::c_char,
+        ::libloading::Error,
+    >,
+    pub qk_param_new_symbol: Result<
+        unsafe extern "C" fn(name: *const ::std::os::raw::c_char) -> *mut QkParam,
+        ::libloading::Error,
+    >,
+    pub qk_param_zero: Result<unsafe extern "C" fn() -> *mut QkParam, ::libloading::Error>,
+    pub qk_param_from_double:
+        Result<unsafe extern "C" fn(value: f64) -> *mut QkParam, ::libloading::Error>,
+    pub qk_param_copy:
+        Result<unsafe extern "C" fn(param: *const QkParam) -> *mut QkParam, ::libloading::Error>,
+    pub qk_param_free: Result<unsafe extern "C" fn(param: *mut QkParam), ::libloading::Error>,
+    pub qk_param_str: Result<
+        unsafe extern "C" fn(param: *const QkParam) -> *mut ::std::os::raw::c_char,
+        ::libloading::Error,
+    >,
+    pub qk_param_equal: Result<
+        unsafe extern "C" fn(lhs: *const QkParam, rhs: *const QkParam) -> bool,
+        ::libloading::Error,
+    >,
+    pub qk_param_as_real:
+        Result<unsafe extern "C" fn(param: *const QkParam) -> f64, ::libloading::Error>,
+    pub qk_param_bind: Result<
+        unsafe extern "C" fn(
+            out: *mut QkParam,
+            param: *const QkParam,
+            keys: *const *const QkParam,
+            values: *const f64,
+            num: usize,
+        ) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_param_add: Result<
+        unsafe extern "C" fn(
+            out: *mut QkParam,
+            lhs: *const QkParam,
+            rhs: *const QkParam,
+        ) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_param_sub: Result<
+        unsafe extern "C" fn(
+            out: *mut QkParam,
+            lhs: *const QkParam,
+            rhs: *const QkParam,
+        ) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_param_mul: Result<
+        unsafe extern "C" fn(
+            out: *mut QkParam,
+            lhs: *const QkParam,
+            rhs: *const QkParam,
+        ) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_param_div: Result<
+        unsafe extern "C" fn(
+            out: *mut QkParam,
+            lhs: *const QkParam,
+            rhs: *const QkParam,
+        ) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_param_pow: Result<
+        unsafe extern "C" fn(
+            out: *mut QkParam,
+            lhs: *const QkParam,
+            rhs: *const QkParam,
+        ) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_param_sin: Result<
+        unsafe extern "C" fn(out: *mut QkParam, src: *const QkParam) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_param_cos: Result<
+        unsafe extern "C" fn(out: *mut QkParam, src: *const QkParam) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_param_tan: Result<
+        unsafe extern "C" fn(out: *mut QkParam, src: *const QkParam) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_param_asin: Result<
+        unsafe extern "C" fn(out: *mut QkParam, src: *const QkParam) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_param_acos: Result<
+        unsafe extern "C" fn(out: *mut QkParam, src: *const QkParam) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_param_atan: Result<
+        unsafe extern "C" fn(out: *mut QkParam, src: *const QkParam) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_param_log: Result<
+        unsafe extern "C" fn(out: *mut QkParam, src: *const QkParam) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_param_exp: Result<
+        unsafe extern "C" fn(out: *mut QkParam, src: *const QkParam) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_param_abs: Result<
+        unsafe extern "C" fn(out: *mut QkParam, src: *const QkParam) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_param_neg: Result<
+        unsafe extern "C" fn(out: *mut QkParam, src: *const QkParam) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_target_new:
+        Result<unsafe extern "C" fn(num_qubits: u32) -> *mut QkTarget, ::libloading::Error>,
+    pub qk_target_free: Result<unsafe extern "C" fn(target: *mut QkTarget), ::libloading::Error>,
+    pub qk_target_copy:
+        Result<unsafe extern "C" fn(target: *const QkTarget) -> *mut QkTarget, ::libloading::Error>,
+    pub qk_target_num_qubits:
+        Result<unsafe extern "C" fn(target: *const QkTarget) -> u32, ::libloading::Error>,
+    pub qk_target_dt:
+        Result<unsafe extern "C" fn(target: *const QkTarget) -> f64, ::libloading::Error>,
+    pub qk_target_set_dt: Result<
+        unsafe extern "C" fn(target: *mut QkTarget, dt: f64) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_target_num_instructions:
+        Result<unsafe extern "C" fn(target: *const QkTarget) -> usize, ::libloading::Error>,
+    pub qk_target_add_instruction: Result<
+        unsafe extern "C" fn(target: *mut QkTarget, target_entry: *mut QkTargetEntry) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_target_update_property: Result<
+        unsafe extern "C" fn(
+            target: *mut QkTarget,
+            instruction: QkGate,
+            qargs: *mut u32,
+            num_qargs: u32,
+            duration: f64,
+            error: f64,
+        ) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_target_entry_new:
+        Result<unsafe extern "C" fn(operation: QkGate) -> *mut QkTargetEntry, ::libloading::Error>,
+    pub qk_target_entry_new_measure:
+        Result<unsafe extern "C" fn() -> *mut QkTargetEntry, ::libloading::Error>,
+    pub qk_target_entry_new_reset:
+        Result<unsafe extern "C" fn() -> *mut QkTargetEntry, ::libloading::Error>,
+    pub qk_target_entry_num_properties:
+        Result<unsafe extern "C" fn(entry: *const QkTargetEntry) -> usize, ::libloading::Error>,
+    pub qk_target_entry_add_property: Result<
+        unsafe extern "C" fn(
+            entry: *mut QkTargetEntry,
+            qargs: *mut u32,
+            num_qargs: u32,
+            duration: f64,
+            error: f64,
+        ) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_target_entry_free:
+        Result<unsafe extern "C" fn(entry: *mut QkTargetEntry), ::libloading::Error>,
+    pub qk_transpiler_pass_standalone_vf2_layout: Result<
+        unsafe extern "C" fn(
+            circuit: *const QkCircuit,
+            target: *const QkTarget,
+            strict_direction: bool,
+            call_limit: i64,
+            time_limit: f64,
+            max_trials: i64,
+        ) -> *mut QkVF2LayoutResult,
+        ::libloading::Error,
+    >,
+    pub qk_vf2_layout_result_has_match:
+        Result<unsafe extern "C" fn(result: *const QkVF2LayoutResult) -> bool, ::libloading::Error>,
+    pub qk_vf2_layout_result_map_virtual_qubit: Result<
+        unsafe extern "C" fn(result: *const QkVF2LayoutResult, qubit: u32) -> u32,
+        ::libloading::Error,
+    >,
+    pub qk_vf2_layout_result_free:
+        Result<unsafe extern "C" fn(result: *mut QkVF2LayoutResult), ::libloading::Error>,
+    pub qk_transpiler_pass_standalone_elide_permutations: Result<
+        unsafe extern "C" fn(circuit: *const QkCircuit) -> *mut QkElidePermutationsResult,
+        ::libloading::Error,
+    >,
+    pub qk_elide_permutations_result_elided_gates: Result<
+        unsafe extern "C" fn(result: *const QkElidePermutationsResult) -> bool,
+        ::libloading::Error,
+    >,
+    pub qk_elide_permutations_result_permutation: Result<
+        unsafe extern "C" fn(result: *const QkElidePermutationsResult) -> *mut usize,
+        ::libloading::Error,
+    >,
+    pub qk_elide_permutations_result_circuit: Result<
+        unsafe extern "C" fn(result: *const QkElidePermutationsResult) -> *const QkCircuit,
+        ::libloading::Error,
+    >,
+    pub qk_elide_permutations_result_free:
+        Result<unsafe extern "C" fn(result: *mut QkElidePermutationsResult), ::libloading::Error>,
+    pub qk_transpiler_pass_standalone_remove_identity_equivalent: Result<
+        unsafe extern "C" fn(
+            circuit: *mut QkCircuit,
+            target: *const QkTarget,
+            approximation_degree: f64,
+        ) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_transpiler_pass_standalone_commutative_cancellation: Result<
+        unsafe extern "C" fn(
+            circuit: *mut QkCircuit,
+            target: *const QkTarget,
+            approximation_degree: f64,
+        ) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_transpiler_pass_standalone_optimize_1q_sequences: Result<
+        unsafe extern "C" fn(circuit: *mut QkCircuit, target: *const QkTarget) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_transpiler_pass_standalone_basis_translator: Result<
+        unsafe extern "C" fn(
+            circuit: *mut QkCircuit,
+            target: *const QkTarget,
+            min_qubits: usize,
+        ) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_transpiler_pass_standalone_gate_direction: Result<
+        unsafe extern "C" fn(circuit: *mut QkCircuit, target: *const QkTarget) -> QkExitCode,
+        ::libloading::Error,
+    >,
+    pub qk_transpiler_pass_standalone_check_gate_direction: Result<
+        unsafe extern "C" fn(circuit: *const QkCircuit, target: *const QkTarget) -> bool,
+        ::libloading::Error,
+    >,
+    pub qk_transpiler_pass_standalone_consolidate_blocks: Result<
+        unsafe extern "C" fn(
+            circuit: *mut QkCircuit,
+            target: *const QkTarget,
+            approximation_degree: f64,
+            force_consolidate: bool,
+        ) -> QkExitCode,
+        ::libloading::Error,
+    >,
+}
+impl Qiskit {
+    pub unsafe fn new<P>(path: P) -> Result<Self, ::libloading::Error>
+    where
+        P: AsRef<::std::ffi::OsStr>,
+    {
+        let library = unsafe { ::libloading::Library::new(path) }?;
+        unsafe { Self::from_library(library) }
+    }
+    pub unsafe fn from_library<L>(library: L) -> Result<Self, ::libloading::Error>
+    where
+        L: Into<::libloading::Library>,
+    {
+        let __library = library.into();
+        let qk_circuit_new = unsafe { __library.get(b"qk_circuit_new\0") }.map(|sym| *sym);
+        let qk_circuit_free = unsafe { __library.get(b"qk_circuit_free\0") }.map(|sym| *sym);
+        let qk_circuit_copy = unsafe { __library.get(b"qk_circuit_copy\0") }.map(|sym| *sym);
+        let qk_circuit_num_qubits =
+            unsafe { __library.get(b"qk_circuit_num_qubits\0") }.map(|sym| *sym);
+        let qk_circuit_num_clbits =
+            unsafe { __library.get(b"qk_circuit_num_clbits\0") }.map(|sym| *sym);
+        let qk_circuit_num_instructions =
+            unsafe { __library.get(b"qk_circuit_num_instructions\0") }.map(|sym| *sym);
+        let qk_gate_num_qubits = unsafe { __library.get(b"qk_gate_num_qubits\0") }.map(|sym| *sym);
+        let qk_gate_num_params = unsafe { __library.get(b"qk_gate_num_params\0") }.map(|sym| *sym);
+        let qk_circuit_gate = unsafe { __library.get(b"qk_circuit_gate\0") }.map(|sym| *sym);
+        let qk_circuit_measure = unsafe { __library.get(b"qk_circuit_measure\0") }.map(|sym| *sym);
+        let qk_circuit_reset = unsafe { __library.get(b"qk_circuit_reset\0") }.map(|sym| *sym);
+        let qk_circuit_barrier = unsafe { __library.get(b"qk_circuit_barrier\0") }.map(|sym| *sym);
+        let qk_circuit_unitary = unsafe { __library.get(b"qk_circuit_unitary\0") }.map(|sym| *sym);
+        let qk_circuit_get_instruction =
+            unsafe { __library.get(b"qk_circuit_get_instruction\0") }.map(|sym| *sym);
+        let qk_circuit_instruction_clear =
+            unsafe { __library.get(b"qk_circuit_instruction_clear\0") }.map(|sym| *sym);
+        let qk_circuit_count_ops =
+            unsafe { __library.get(b"qk_circuit_count_ops\0") }.map(|sym| *sym);
+        let qk_opcounts_clear = unsafe { __library.get(b"qk_opcounts_clear\0") }.map(|sym| *sym);
+        let qk_quantum_register_new =
+            unsafe { __library.get(b"qk_quantum_register_new\0") }.map(|sym| *sym);
+        let qk_quantum_register_free =
+            unsafe { __library.get(b"qk_quantum_register_free\0") }.map(|sym| *sym);
+        let qk_classical_register_new =
+            unsafe { __library.get(b"qk_classical_register_new\0") }.map(|sym| *sym);
+        let qk_classical_register_free =
+            unsafe { __library.get(b"qk_classical_register_free\0") }.map(|sym| *sym);
+        let qk_circuit_add_quantum_register =
+            unsafe { __library.get(b"qk_circuit_add_quantum_register\0") }.map(|sym| *sym);
+        let qk_circuit_add_classical_register =
+            unsafe { __library.get(b"qk_circuit_add_classical_register\0") }.map(|sym| *sym);
+        let qk_str_free = unsafe { __library.get(b"qk_str_free\0") }.map(|sym| *sym);
+        let qk_obs_zero = unsafe { __library.get(b"qk_obs_zero\0") }.map(|sym| *sym);
+        let qk_obs_identity = unsafe { __library.get(b"qk_obs_identity\0") }.map(|sym| *sym);
+        let qk_obs_new = unsafe { __library.get(b"qk_obs_new\0") }.map(|sym| *sym);
+        let qk_obs_free = unsafe { __library.get(b"qk_obs_free\0") }.map(|sym| *sym);
+        let qk_obs_copy = unsafe { __library.get(b"qk_obs_copy\0") }.map(|sym| *sym);
+        let qk_obs_add_term = unsafe { __library.get(b"qk_obs_add_term\0") }.map(|sym| *sym);
+        let qk_obs_num_terms = unsafe { __library.get(b"qk_obs_num_terms\0") }.map(|sym| *sym);
+        let qk_obs_num_qubits = unsafe { __library.get(b"qk_obs_num_qubits\0") }.map(|sym| *sym);
+        let qk_obs_len = unsafe { __library.get(b"qk_obs_len\0") }.map(|sym| *sym);
+        let qk_obs_coeffs = unsafe { __library.get(b"qk_obs_coeffs\0") }.map(|sym| *sym);
+        let qk_obs_bit_terms = unsafe { __library.get(b"qk_obs_bit_terms\0") }.map(|sym| *sym);
+        let qk_obs_indices = unsafe { __library.get(b"qk_obs_indices\0") }.map(|sym| *sym);
+        let qk_obs_boundaries = unsafe { __library.get(b"qk_obs_boundaries\0") }.map(|sym| *sym);
+        let qk_obs_equal = unsafe { __library.get(b"qk_obs_equal\0") }.map(|sym| *sym);
+        let qk_obs_str = unsafe { __library.get(b"qk_obs_str\0") }.map(|sym| *sym);
+        let qk_param_new_symbol =
+            unsafe { __library.get(b"qk_param_new_symbol\0") }.map(|sym| *sym);
+        let qk_param_zero = unsafe { __library.get(b"qk_param_zero\0") }.map(|sym| *sym);
+        let qk_param_from_double =
+            unsafe { __library.get(b"qk_param_from_double\0") }.map(|sym| *sym);
+        let qk_param_copy = unsafe { __library.get(b"qk_param_copy\0") }.map(|sym| *sym);
+        let qk_param_free = unsafe { __library.get(b"qk_param_free\0") }.map(|sym| *sym);
+        let qk_param_str = unsafe { __library.get(b"qk_param_str\0") }.map(|sym| *sym);
+        let qk_param_equal = unsafe { __library.get(b"qk_param_equal\0") }.map(|sym| *sym);
+        let qk_param_as_real = unsafe { __library.get(b"qk_param_as_real\0") }.map(|sym| *sym);
+        let qk_param_bind = unsafe { __library.get(b"qk_param_bind\0") }.map(|sym| *sym);
+        let qk_param_add = unsafe { __library.get(b"qk_param_add\0") }.map(|sym| *sym);
+        let qk_param_sub = unsafe { __library.get(b"qk_param_sub\0") }.map(|sym| *sym);
+        let qk_param_mul = unsafe { __library.get(b"qk_param_mul\0") }.map(|sym| *sym);
+        let qk_param_div = unsafe { __library.get(b"qk_param_div\0") }.map(|sym| *sym);
+        let qk_param_pow = unsafe { __library.get(b"qk_param_pow\0") }.map(|sym| *sym);
+        let qk_param_sin = unsafe { __library.get(b"qk_param_sin\0") }.map(|sym| *sym);
+        let qk_param_cos = unsafe { __library.get(b"qk_param_cos\0") }.map(|sym| *sym);
+        let qk_param_tan = unsafe { __library.get(b"qk_param_tan\0") }.map(|sym| *sym);
+        let qk_param_asin = unsafe { __library.get(b"qk_param_asin\0") }.map(|sym| *sym);
+        let qk_param_acos = unsafe { __library.get(b"qk_param_acos\0") }.map(|sym| *sym);
+        let qk_param_atan = unsafe { __library.get(b"qk_param_atan\0") }.map(|sym| *sym);
+        let qk_param_log = unsafe { __library.get(b"qk_param_log\0") }.map(|sym| *sym);
+        let qk_param_exp = unsafe { __library.get(b"qk_param_exp\0") }.map(|sym| *sym);
+        let qk_param_abs = unsafe { __library.get(b"qk_param_abs\0") }.map(|sym| *sym);
+        let qk_param_neg = unsafe { __library.get(b"qk_param_neg\0") }.map(|sym| *sym);
+        let qk_target_new = unsafe { __library.get(b"qk_target_new\0") }.map(|sym| *sym);
+        let qk_target_free = unsafe { __library.get(b"qk_target_free\0") }.map(|sym| *sym);
+        let qk_target_copy = unsafe { __library.get(b"qk_target_copy\0") }.map(|sym| *sym);
+        let qk_target_num_qubits =
+            unsafe { __library.get(b"qk_target_num_qubits\0") }.map(|sym| *sym);
+        let qk_target_dt = unsafe { __library.get(b"qk_target_dt\0") }.map(|sym| *sym);
+        let qk_target_set_dt = unsafe { __library.get(b"qk_target_set_dt\0") }.map(|sym| *sym);
+        let qk_target_num_instructions =
+            unsafe { __library.get(b"qk_target_num_instructions\0") }.map(|sym| *sym);
+        let qk_target_add_instruction =
+            unsafe { __library.get(b"qk_target_add_instruction\0") }.map(|sym| *sym);
+        let qk_target_update_property =
+            unsafe { __library.get(b"qk_target_update_property\0") }.map(|sym| *sym);
+        let qk_target_entry_new =
+            unsafe { __library.get(b"qk_target_entry_new\0") }.map(|sym| *sym);
+        let qk_target_entry_new_measure =
+            unsafe { __library.get(b"qk_target_entry_new_measure\0") }.map(|sym| *sym);
+        let qk_target_entry_new_reset =
+            unsafe { __library.get(b"qk_target_entry_new_reset\0") }.map(|sym| *sym);
+        let qk_target_entry_num_properties =
+            unsafe { __library.get(b"qk_target_entry_num_properties\0") }.map(|sym| *sym);
+        let qk_target_entry_add_property =
+            unsafe { __library.get(b"qk_target_entry_add_property\0") }.map(|sym| *sym);
+        let qk_target_entry_free =
+            unsafe { __library.get(b"qk_target_entry_free\0") }.map(|sym| *sym);
+        let qk_transpiler_pass_standalone_vf2_layout =
+            unsafe { __library.get(b"qk_transpiler_pass_standalone_vf2_layout\0") }.map(|sym| *sym);
+        let qk_vf2_layout_result_has_match =
+            unsafe { __library.get(b"qk_vf2_layout_result_has_match\0") }.map(|sym| *sym);
+        let qk_vf2_layout_result_map_virtual_qubit =
+            unsafe { __library.get(b"qk_vf2_layout_result_map_virtual_qubit\0") }.map(|sym| *sym);
+        let qk_vf2_layout_result_free =
+            unsafe { __library.get(b"qk_vf2_layout_result_free\0") }.map(|sym| *sym);
+        let qk_transpiler_pass_standalone_elide_permutations =
+            unsafe { __library.get(b"qk_transpiler_pass_standalone_elide_permutations\0") }
+                .map(|sym| *sym);
+        let qk_elide_permutations_result_elided_gates =
+            unsafe { __library.get(b"qk_elide_permutations_result_elided_gates\0") }
+                .map(|sym| *sym);
+        let qk_elide_permutations_result_permutation =
+            unsafe { __library.get(b"qk_elide_permutations_result_permutation\0") }.map(|sym| *sym);
+        let qk_elide_permutations_result_circuit =
+            unsafe { __library.get(b"qk_elide_permutations_result_circuit\0") }.map(|sym| *sym);
+        let qk_elide_permutations_result_free =
+            unsafe { __library.get(b"qk_elide_permutations_result_free\0") }.map(|sym| *sym);
+        let qk_transpiler_pass_standalone_remove_identity_equivalent =
+            unsafe { __library.get(b"qk_transpiler_pass_standalone_remove_identity_equivalent\0") }
+                .map(|sym| *sym);
+        let qk_transpiler_pass_standalone_commutative_cancellation =
+            unsafe { __library.get(b"qk_transpiler_pass_standalone_commutative_cancellation\0") }
+                .map(|sym| *sym);
+        let qk_transpiler_pass_standalone_optimize_1q_sequences =
+            unsafe { __library.get(b"qk_transpiler_pass_standalone_optimize_1q_sequences\0") }
+                .map(|sym| *sym);
+        let qk_transpiler_pass_standalone_basis_translator =
+            unsafe { __library.get(b"qk_transpiler_pass_standalone_basis_translator\0") }
+                .map(|sym| *sym);
+        let qk_transpiler_pass_standalone_gate_direction =
+            unsafe { __library.get(b"qk_transpiler_pass_standalone_gate_direction\0") }
+                .map(|sym| *sym);
+        let qk_transpiler_pass_standalone_check_gate_direction =
+            unsafe { __library.get(b"qk_transpiler_pass_standalone_check_gate_direction\0") }
+                .map(|sym| *sym);
+        let qk_transpiler_pass_standalone_consolidate_blocks =
+            unsafe { __library.get(b"qk_transpiler_pass_standalone_consolidate_blocks\0") }
+                .map(|sym| *sym);
+        Ok(Qiskit {
+            __library,
+            qk_circuit_new,
+            qk_circuit_free,
+            qk_circuit_copy,
+            qk_circuit_num_qubits,
+            qk_circuit_num_clbits,
+            qk_circuit_num_instructions,
+            qk_gate_num_qubits,
+            qk_gate_num_params,
+            qk_circuit_gate,
+            qk_circuit_measure,
+            qk_circuit_reset,
+            qk_circuit_barrier,
+            qk_circuit_unitary,
+            qk_circuit_get_instruction,
+            qk_circuit_instruction_clear,
+            qk_circuit_count_ops,
+            qk_opcounts_clear,
+            qk_quantum_register_new,
+            qk_quantum_register_free,
+            qk_classical_register_new,
+            qk_classical_register_free,
+            qk_circuit_add_quantum_register,
+            qk_circuit_add_classical_register,
+            qk_str_free,
+            qk_obs_zero,
+            qk_obs_identity,
+            qk_obs_new,
+            qk_obs_free,
+            qk_obs_copy,
+            qk_obs_add_term,
+            qk_obs_num_terms,
+            qk_obs_num_qubits,
+            qk_obs_len,
+            qk_obs_coeffs,
+            qk_obs_bit_terms,
+            qk_obs_indices,
+            qk_obs_boundaries,
+            qk_obs_equal,
+            qk_obs_str,
+            qk_param_new_symbol,
+            qk_param_zero,
+            qk_param_from_double,
+            qk_param_copy,
+            qk_param_free,
+            qk_param_str,
+            qk_param_equal,
+            qk_param_as_real,
+            qk_param_bind,
+            qk_param_add,
+            qk_param_sub,
+            qk_param_mul,
+            qk_param_div,
+            qk_param_pow,
+            qk_param_sin,
+            qk_param_cos,
+            qk_param_tan,
+            qk_param_asin,
+            qk_param_acos,
+            qk_param_atan,
+            qk_param_log,
+            qk_param_exp,
+            qk_param_abs,
+            qk_param_neg,
+            qk_target_new,
+            qk_target_free,
+            qk_target_copy,
+            qk_target_num_qubits,
+            qk_target_dt,
+            qk_target_set_dt,
+            qk_target_num_instructions,
+            qk_target_add_instruction,
+            qk_target_update_property,
+            qk_target_entry_new,
+            qk_target_entry_new_measure,
+            qk_target_entry_new_reset,
+            qk_target_entry_num_properties,
+            qk_target_entry_add_property,
+            qk_target_entry_free,
+            qk_transpiler_pass_standalone_vf2_layout,
+            qk_vf2_layout_result_has_match,
+            qk_vf2_layout_result_map_virtual_qubit,
+            qk_vf2_layout_result_free,
+            qk_transpiler_pass_standalone_elide_permutations,
+            qk_elide_permutations_result_elided_gates,
+            qk_elide_permutations_result_permutation,
+            qk_elide_permutations_result_circuit,
+            qk_elide_permutations_result_free,
+            qk_transpiler_pass_standalone_remove_identity_equivalent,
+            qk_transpiler_pass_standalone_commutative_cancellation,
+            qk_transpiler_pass_standalone_optimize_1q_sequences,
+            qk_transpiler_pass_standalone_basis_translator,
+            qk_transpiler_pass_standalone_gate_direction,
+            qk_transpiler_pass_standalone_check_gate_direction,
+            qk_transpiler_pass_standalone_consolidate_blocks,
+        })
+    }
+    pub unsafe fn qk_circuit_new(&self, num_qubits: u32, num_clbits: u32) -> *mut QkCircuit {
+        unsafe {
+            (self
+                .qk_circuit_new
+                .as_ref()
+                .expect("Expected function, got error."))(num_qubits, num_clbits)
+        }
+    }
+    pub unsafe fn qk_circuit_free(&self, circuit: *mut QkCircuit) {
+        unsafe {
+            (self
+                .qk_circuit_free
+                .as_ref()
+                .expect("Expected function, got error."))(circuit)
+        }
+    }
+    pub unsafe fn qk_circuit_copy(&self, circuit: *const QkCircuit) -> *mut QkCircuit {
+        unsafe {
+            (self
+                .qk_circuit_copy
+                .as_ref()
+                .expect("Expected function, got error."))(circuit)
+        }
+    }
+    pub unsafe fn qk_circuit_num_qubits(&self, circuit: *const QkCircuit) -> u32 {
+        unsafe {
+            (self
+                .qk_circuit_num_qubits
+                .as_ref()
+                .expect("Expected function, got error."))(circuit)
+        }
+    }
+    pub unsafe fn qk_circuit_num_clbits(&self, circuit: *const QkCircuit) -> u32 {
+        unsafe {
+            (self
+                .qk_circuit_num_clbits
+                .as_ref()
+                .expect("Expected function, got error."))(circuit)
+        }
+    }
+    pub unsafe fn qk_circuit_num_instructions(&self, circuit: *const QkCircuit) -> usize {
+        unsafe {
+            (self
+                .qk_circuit_num_instructions
+                .as_ref()
+                .expect("Expected function, got error."))(circuit)
+        }
+    }
+    pub unsafe fn qk_gate_num_qubits(&self, gate: QkGate) -> u32 {
+        unsafe {
+            (self
+                .qk_gate_num_qubits
+                .as_ref()
+                .expect("Expected function, got error."))(gate)
+        }
+    }
+    pub unsafe fn qk_gate_num_params(&self, gate: QkGate) -> u32 {
+        unsafe {
+            (self
+                .qk_gate_num_params
+                .as_ref()
+                .expect("Expected function, got error."))(gate)
+        }
+    }
+    pub unsafe fn qk_circuit_gate(
+        &self,
+        circuit: *mut QkCircuit,
+        gate: QkGate,
+        qubits: *const u32,
+        params: *const f64,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_circuit_gate
+                .as_ref()
+                .expect("Expected function, got error."))(circuit, gate, qubits, params)
+        }
+    }
+    pub unsafe fn qk_circuit_measure(
+        &self,
+        circuit: *mut QkCircuit,
+        qubit: u32,
+        clbit: u32,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_circuit_measure
+                .as_ref()
+                .expect("Expected function, got error."))(circuit, qubit, clbit)
+        }
+    }
+    pub unsafe fn qk_circuit_reset(&self, circuit: *mut QkCircuit, qubit: u32) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_circuit_reset
+                .as_ref()
+                .expect("Expected function, got error."))(circuit, qubit)
+        }
+    }
+    pub unsafe fn qk_circuit_barrier(
+        &self,
+        circuit: *mut QkCircuit,
+        qubits: *const u32,
+        num_qubits: u32,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_circuit_barrier
+                .as_ref()
+                .expect("Expected function, got error."))(circuit, qubits, num_qubits)
+        }
+    }
+    pub unsafe fn qk_circuit_unitary(
+        &self,
+        circuit: *mut QkCircuit,
+        matrix: *const QkComplex64,
+        qubits: *const u32,
+        num_qubits: u32,
+        check_input: bool,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_circuit_unitary
+                .as_ref()
+                .expect("Expected function, got error."))(
+                circuit,
+                matrix,
+                qubits,
+                num_qubits,
+                check_input,
+            )
+        }
+    }
+    pub unsafe fn qk_circuit_get_instruction(
+        &self,
+        circuit: *const QkCircuit,
+        index: usize,
+        instruction: *mut QkCircuitInstruction,
+    ) {
+        unsafe {
+            (self
+                .qk_circuit_get_instruction
+                .as_ref()
+                .expect("Expected function, got error."))(circuit, index, instruction)
+        }
+    }
+    pub unsafe fn qk_circuit_instruction_clear(&self, inst: *mut QkCircuitInstruction) {
+        unsafe {
+            (self
+                .qk_circuit_instruction_clear
+                .as_ref()
+                .expect("Expected function, got error."))(inst)
+        }
+    }
+    pub unsafe fn qk_circuit_count_ops(&self, circuit: *const QkCircuit) -> QkOpCounts {
+        unsafe {
+            (self
+                .qk_circuit_count_ops
+                .as_ref()
+                .expect("Expected function, got error."))(circuit)
+        }
+    }
+    pub unsafe fn qk_opcounts_clear(&self, op_counts: *mut QkOpCounts) {
+        unsafe {
+            (self
+                .qk_opcounts_clear
+                .as_ref()
+                .expect("Expected function, got error."))(op_counts)
+        }
+    }
+    pub unsafe fn qk_quantum_register_new(
+        &self,
+        num_qubits: u32,
+        name: *const ::std::os::raw::c_char,
+    ) -> *mut QkQuantumRegister {
+        unsafe {
+            (self
+                .qk_quantum_register_new
+                .as_ref()
+                .expect("Expected function, got error."))(num_qubits, name)
+        }
+    }
+    pub unsafe fn qk_quantum_register_free(&self, reg: *mut QkQuantumRegister) {
+        unsafe {
+            (self
+                .qk_quantum_register_free
+                .as_ref()
+                .expect("Expected function, got error."))(reg)
+        }
+    }
+    pub unsafe fn qk_classical_register_new(
+        &self,
+        num_clbits: u32,
+        name: *const ::std::os::raw::c_char,
+    ) -> *mut QkClassicalRegister {
+        unsafe {
+            (self
+                .qk_classical_register_new
+                .as_ref()
+                .expect("Expected function, got error."))(num_clbits, name)
+        }
+    }
+    pub unsafe fn qk_classical_register_free(&self, reg: *mut QkClassicalRegister) {
+        unsafe {
+            (self
+                .qk_classical_register_free
+                .as_ref()
+                .expect("Expected function, got error."))(reg)
+        }
+    }
+    pub unsafe fn qk_circuit_add_quantum_register(
+        &self,
+        circuit: *mut QkCircuit,
+        reg: *const QkQuantumRegister,
+    ) {
+        unsafe {
+            (self
+                .qk_circuit_add_quantum_register
+                .as_ref()
+                .expect("Expected function, got error."))(circuit, reg)
+        }
+    }
+    pub unsafe fn qk_circuit_add_classical_register(
+        &self,
+        circuit: *mut QkCircuit,
+        reg: *const QkClassicalRegister,
+    ) {
+        unsafe {
+            (self
+                .qk_circuit_add_classical_register
+                .as_ref()
+                .expect("Expected function, got error."))(circuit, reg)
+        }
+    }
+    pub unsafe fn qk_str_free(&self, string: *mut ::std::os::raw::c_char) {
+        unsafe {
+            (self
+                .qk_str_free
+                .as_ref()
+                .expect("Expected function, got error."))(string)
+        }
+    }
+    pub unsafe fn qk_obs_zero(&self, num_qubits: u32) -> *mut QkObs {
+        unsafe {
+            (self
+                .qk_obs_zero
+                .as_ref()
+                .expect("Expected function, got error."))(num_qubits)
+        }
+    }
+    pub unsafe fn qk_obs_identity(&self, num_qubits: u32) -> *mut QkObs {
+        unsafe {
+            (self
+                .qk_obs_identity
+                .as_ref()
+                .expect("Expected function, got error."))(num_qubits)
+        }
+    }
+    pub unsafe fn qk_obs_new(
+        &self,
+        num_qubits: u32,
+        num_terms: u64,
+        num_bits: u64,
+        coeffs: *mut QkComplex64,
+        bit_terms: *mut QkBitTerm,
+        indices: *mut u32,
+        boundaries: *mut usize,
+    ) -> *mut QkObs {
+        unsafe {
+            (self
+                .qk_obs_new
+                .as_ref()
+                .expect("Expected function, got error."))(
+                num_qubits, num_terms, num_bits, coeffs, bit_terms, indices, boundaries,
+            )
+        }
+    }
+    pub unsafe fn qk_obs_free(&self, obs: *mut QkObs) {
+        unsafe {
+            (self
+                .qk_obs_free
+                .as_ref()
+                .expect("Expected function, got error."))(obs)
+        }
+    }
+    pub unsafe fn qk_obs_copy(&self, obs: *const QkObs) -> *mut QkObs {
+        unsafe {
+            (self
+                .qk_obs_copy
+                .as_ref()
+                .expect("Expected function, got error."))(obs)
+        }
+    }
+    pub unsafe fn qk_obs_add_term(&self, obs: *mut QkObs, cterm: *const QkObsTerm) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_obs_add_term
+                .as_ref()
+                .expect("Expected function, got error."))(obs, cterm)
+        }
+    }
+    pub unsafe fn qk_obs_num_terms(&self, obs: *const QkObs) -> usize {
+        unsafe {
+            (self
+                .qk_obs_num_terms
+                .as_ref()
+                .expect("Expected function, got error."))(obs)
+        }
+    }
+    pub unsafe fn qk_obs_num_qubits(&self, obs: *const QkObs) -> u32 {
+        unsafe {
+            (self
+                .qk_obs_num_qubits
+                .as_ref()
+                .expect("Expected function, got error."))(obs)
+        }
+    }
+    pub unsafe fn qk_obs_len(&self, obs: *const QkObs) -> usize {
+        unsafe {
+            (self
+                .qk_obs_len
+                .as_ref()
+                .expect("Expected function, got error."))(obs)
+        }
+    }
+    pub unsafe fn qk_obs_coeffs(&self, obs: *mut QkObs) -> *mut QkComplex64 {
+        unsafe {
+            (self
+                .qk_obs_coeffs
+                .as_ref()
+                .expect("Expected function, got error."))(obs)
+        }
+    }
+    pub unsafe fn qk_obs_bit_terms(&self, obs: *mut QkObs) -> *mut QkBitTerm {
+        unsafe {
+            (self
+                .qk_obs_bit_terms
+                .as_ref()
+                .expect("Expected function, got error."))(obs)
+        }
+    }
+    pub unsafe fn qk_obs_indices(&self, obs: *mut QkObs) -> *mut u32 {
+        unsafe {
+            (self
+                .qk_obs_indices
+                .as_ref()
+                .expect("Expected function, got error."))(obs)
+        }
+    }
+    pub unsafe fn qk_obs_boundaries(&self, obs: *mut QkObs) -> *mut usize {
+        unsafe {
+            (self
+                .qk_obs_boundaries
+                .as_ref()
+                .expect("Expected function, got error."))(obs)
+        }
+    }
+    pub unsafe fn qk_obs_equal(&self, obs: *const QkObs, other: *const QkObs) -> bool {
+        unsafe {
+            (self
+                .qk_obs_equal
+                .as_ref()
+                .expect("Expected function, got error."))(obs, other)
+        }
+    }
+    pub unsafe fn qk_obs_str(&self, obs: *const QkObs) -> *mut ::std::os::raw::c_char {
+        unsafe {
+            (self
+                .qk_obs_str
+                .as_ref()
+                .expect("Expected function, got error."))(obs)
+        }
+    }
+    pub unsafe fn qk_param_new_symbol(&self, name: *const ::std::os::raw::c_char) -> *mut QkParam {
+        unsafe {
+            (self
+                .qk_param_new_symbol
+                .as_ref()
+                .expect("Expected function, got error."))(name)
+        }
+    }
+    pub unsafe fn qk_param_zero(&self) -> *mut QkParam {
+        unsafe {
+            (self
+                .qk_param_zero
+                .as_ref()
+                .expect("Expected function, got error."))()
+        }
+    }
+    pub unsafe fn qk_param_from_double(&self, value: f64) -> *mut QkParam {
+        unsafe {
+            (self
+                .qk_param_from_double
+                .as_ref()
+                .expect("Expected function, got error."))(value)
+        }
+    }
+    pub unsafe fn qk_param_copy(&self, param: *const QkParam) -> *mut QkParam {
+        unsafe {
+            (self
+                .qk_param_copy
+                .as_ref()
+                .expect("Expected function, got error."))(param)
+        }
+    }
+    pub unsafe fn qk_param_free(&self, param: *mut QkParam) {
+        unsafe {
+            (self
+                .qk_param_free
+                .as_ref()
+                .expect("Expected function, got error."))(param)
+        }
+    }
+    pub unsafe fn qk_param_str(&self, param: *const QkParam) -> *mut ::std::os::raw::c_char {
+        unsafe {
+            (self
+                .qk_param_str
+                .as_ref()
+                .expect("Expected function, got error."))(param)
+        }
+    }
+    pub unsafe fn qk_param_equal(&self, lhs: *const QkParam, rhs: *const QkParam) -> bool {
+        unsafe {
+            (self
+                .qk_param_equal
+                .as_ref()
+                .expect("Expected function, got error."))(lhs, rhs)
+        }
+    }
+    pub unsafe fn qk_param_as_real(&self, param: *const QkParam) -> f64 {
+        unsafe {
+            (self
+                .qk_param_as_real
+                .as_ref()
+                .expect("Expected function, got error."))(param)
+        }
+    }
+    pub unsafe fn qk_param_bind(
+        &self,
+        out: *mut QkParam,
+        param: *const QkParam,
+        keys: *const *const QkParam,
+        values: *const f64,
+        num: usize,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_param_bind
+                .as_ref()
+                .expect("Expected function, got error."))(out, param, keys, values, num)
+        }
+    }
+    pub unsafe fn qk_param_add(
+        &self,
+        out: *mut QkParam,
+        lhs: *const QkParam,
+        rhs: *const QkParam,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_param_add
+                .as_ref()
+                .expect("Expected function, got error."))(out, lhs, rhs)
+        }
+    }
+    pub unsafe fn qk_param_sub(
+        &self,
+        out: *mut QkParam,
+        lhs: *const QkParam,
+        rhs: *const QkParam,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_param_sub
+                .as_ref()
+                .expect("Expected function, got error."))(out, lhs, rhs)
+        }
+    }
+    pub unsafe fn qk_param_mul(
+        &self,
+        out: *mut QkParam,
+        lhs: *const QkParam,
+        rhs: *const QkParam,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_param_mul
+                .as_ref()
+                .expect("Expected function, got error."))(out, lhs, rhs)
+        }
+    }
+    pub unsafe fn qk_param_div(
+        &self,
+        out: *mut QkParam,
+        lhs: *const QkParam,
+        rhs: *const QkParam,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_param_div
+                .as_ref()
+                .expect("Expected function, got error."))(out, lhs, rhs)
+        }
+    }
+    pub unsafe fn qk_param_pow(
+        &self,
+        out: *mut QkParam,
+        lhs: *const QkParam,
+        rhs: *const QkParam,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_param_pow
+                .as_ref()
+                .expect("Expected function, got error."))(out, lhs, rhs)
+        }
+    }
+    pub unsafe fn qk_param_sin(&self, out: *mut QkParam, src: *const QkParam) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_param_sin
+                .as_ref()
+                .expect("Expected function, got error."))(out, src)
+        }
+    }
+    pub unsafe fn qk_param_cos(&self, out: *mut QkParam, src: *const QkParam) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_param_cos
+                .as_ref()
+                .expect("Expected function, got error."))(out, src)
+        }
+    }
+    pub unsafe fn qk_param_tan(&self, out: *mut QkParam, src: *const QkParam) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_param_tan
+                .as_ref()
+                .expect("Expected function, got error."))(out, src)
+        }
+    }
+    pub unsafe fn qk_param_asin(&self, out: *mut QkParam, src: *const QkParam) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_param_asin
+                .as_ref()
+                .expect("Expected function, got error."))(out, src)
+        }
+    }
+    pub unsafe fn qk_param_acos(&self, out: *mut QkParam, src: *const QkParam) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_param_acos
+                .as_ref()
+                .expect("Expected function, got error."))(out, src)
+        }
+    }
+    pub unsafe fn qk_param_atan(&self, out: *mut QkParam, src: *const QkParam) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_param_atan
+                .as_ref()
+                .expect("Expected function, got error."))(out, src)
+        }
+    }
+    pub unsafe fn qk_param_log(&self, out: *mut QkParam, src: *const QkParam) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_param_log
+                .as_ref()
+                .expect("Expected function, got error."))(out, src)
+        }
+    }
+    pub unsafe fn qk_param_exp(&self, out: *mut QkParam, src: *const QkParam) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_param_exp
+                .as_ref()
+                .expect("Expected function, got error."))(out, src)
+        }
+    }
+    pub unsafe fn qk_param_abs(&self, out: *mut QkParam, src: *const QkParam) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_param_abs
+                .as_ref()
+                .expect("Expected function, got error."))(out, src)
+        }
+    }
+    pub unsafe fn qk_param_neg(&self, out: *mut QkParam, src: *const QkParam) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_param_neg
+                .as_ref()
+                .expect("Expected function, got error."))(out, src)
+        }
+    }
+    pub unsafe fn qk_target_new(&self, num_qubits: u32) -> *mut QkTarget {
+        unsafe {
+            (self
+                .qk_target_new
+                .as_ref()
+                .expect("Expected function, got error."))(num_qubits)
+        }
+    }
+    pub unsafe fn qk_target_free(&self, target: *mut QkTarget) {
+        unsafe {
+            (self
+                .qk_target_free
+                .as_ref()
+                .expect("Expected function, got error."))(target)
+        }
+    }
+    pub unsafe fn qk_target_copy(&self, target: *const QkTarget) -> *mut QkTarget {
+        unsafe {
+            (self
+                .qk_target_copy
+                .as_ref()
+                .expect("Expected function, got error."))(target)
+        }
+    }
+    pub unsafe fn qk_target_num_qubits(&self, target: *const QkTarget) -> u32 {
+        unsafe {
+            (self
+                .qk_target_num_qubits
+                .as_ref()
+                .expect("Expected function, got error."))(target)
+        }
+    }
+    pub unsafe fn qk_target_dt(&self, target: *const QkTarget) -> f64 {
+        unsafe {
+            (self
+                .qk_target_dt
+                .as_ref()
+                .expect("Expected function, got error."))(target)
+        }
+    }
+    pub unsafe fn qk_target_set_dt(&self, target: *mut QkTarget, dt: f64) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_target_set_dt
+                .as_ref()
+                .expect("Expected function, got error."))(target, dt)
+        }
+    }
+    pub unsafe fn qk_target_num_instructions(&self, target: *const QkTarget) -> usize {
+        unsafe {
+            (self
+                .qk_target_num_instructions
+                .as_ref()
+                .expect("Expected function, got error."))(target)
+        }
+    }
+    pub unsafe fn qk_target_add_instruction(
+        &self,
+        target: *mut QkTarget,
+        target_entry: *mut QkTargetEntry,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_target_add_instruction
+                .as_ref()
+                .expect("Expected function, got error."))(target, target_entry)
+        }
+    }
+    pub unsafe fn qk_target_update_property(
+        &self,
+        target: *mut QkTarget,
+        instruction: QkGate,
+        qargs: *mut u32,
+        num_qargs: u32,
+        duration: f64,
+        error: f64,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_target_update_property
+                .as_ref()
+                .expect("Expected function, got error."))(
+                target,
+                instruction,
+                qargs,
+                num_qargs,
+                duration,
+                error,
+            )
+        }
+    }
+    pub unsafe fn qk_target_entry_new(&self, operation: QkGate) -> *mut QkTargetEntry {
+        unsafe {
+            (self
+                .qk_target_entry_new
+                .as_ref()
+                .expect("Expected function, got error."))(operation)
+        }
+    }
+    pub unsafe fn qk_target_entry_new_measure(&self) -> *mut QkTargetEntry {
+        unsafe {
+            (self
+                .qk_target_entry_new_measure
+                .as_ref()
+                .expect("Expected function, got error."))()
+        }
+    }
+    pub unsafe fn qk_target_entry_new_reset(&self) -> *mut QkTargetEntry {
+        unsafe {
+            (self
+                .qk_target_entry_new_reset
+                .as_ref()
+                .expect("Expected function, got error."))()
+        }
+    }
+    pub unsafe fn qk_target_entry_num_properties(&self, entry: *const QkTargetEntry) -> usize {
+        unsafe {
+            (self
+                .qk_target_entry_num_properties
+                .as_ref()
+                .expect("Expected function, got error."))(entry)
+        }
+    }
+    pub unsafe fn qk_target_entry_add_property(
+        &self,
+        entry: *mut QkTargetEntry,
+        qargs: *mut u32,
+        num_qargs: u32,
+        duration: f64,
+        error: f64,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_target_entry_add_property
+                .as_ref()
+                .expect("Expected function, got error."))(
+                entry, qargs, num_qargs, duration, error
+            )
+        }
+    }
+    pub unsafe fn qk_target_entry_free(&self, entry: *mut QkTargetEntry) {
+        unsafe {
+            (self
+                .qk_target_entry_free
+                .as_ref()
+                .expect("Expected function, got error."))(entry)
+        }
+    }
+    pub unsafe fn qk_transpiler_pass_standalone_vf2_layout(
+        &self,
+        circuit: *const QkCircuit,
+        target: *const QkTarget,
+        strict_direction: bool,
+        call_limit: i64,
+        time_limit: f64,
+        max_trials: i64,
+    ) -> *mut QkVF2LayoutResult {
+        unsafe {
+            (self
+                .qk_transpiler_pass_standalone_vf2_layout
+                .as_ref()
+                .expect("Expected function, got error."))(
+                circuit,
+                target,
+                strict_direction,
+                call_limit,
+                time_limit,
+                max_trials,
+            )
+        }
+    }
+    pub unsafe fn qk_vf2_layout_result_has_match(&self, result: *const QkVF2LayoutResult) -> bool {
+        unsafe {
+            (self
+                .qk_vf2_layout_result_has_match
+                .as_ref()
+                .expect("Expected function, got error."))(result)
+        }
+    }
+    pub unsafe fn qk_vf2_layout_result_map_virtual_qubit(
+        &self,
+        result: *const QkVF2LayoutResult,
+        qubit: u32,
+    ) -> u32 {
+        unsafe {
+            (self
+                .qk_vf2_layout_result_map_virtual_qubit
+                .as_ref()
+                .expect("Expected function, got error."))(result, qubit)
+        }
+    }
+    pub unsafe fn qk_vf2_layout_result_free(&self, result: *mut QkVF2LayoutResult) {
+        unsafe {
+            (self
+                .qk_vf2_layout_result_free
+                .as_ref()
+                .expect("Expected function, got error."))(result)
+        }
+    }
+    pub unsafe fn qk_transpiler_pass_standalone_elide_permutations(
+        &self,
+        circuit: *const QkCircuit,
+    ) -> *mut QkElidePermutationsResult {
+        unsafe {
+            (self
+                .qk_transpiler_pass_standalone_elide_permutations
+                .as_ref()
+                .expect("Expected function, got error."))(circuit)
+        }
+    }
+    pub unsafe fn qk_elide_permutations_result_elided_gates(
+        &self,
+        result: *const QkElidePermutationsResult,
+    ) -> bool {
+        unsafe {
+            (self
+                .qk_elide_permutations_result_elided_gates
+                .as_ref()
+                .expect("Expected function, got error."))(result)
+        }
+    }
+    pub unsafe fn qk_elide_permutations_result_permutation(
+        &self,
+        result: *const QkElidePermutationsResult,
+    ) -> *mut usize {
+        unsafe {
+            (self
+                .qk_elide_permutations_result_permutation
+                .as_ref()
+                .expect("Expected function, got error."))(result)
+        }
+    }
+    pub unsafe fn qk_elide_permutations_result_circuit(
+        &self,
+        result: *const QkElidePermutationsResult,
+    ) -> *const QkCircuit {
+        unsafe {
+            (self
+                .qk_elide_permutations_result_circuit
+                .as_ref()
+                .expect("Expected function, got error."))(result)
+        }
+    }
+    pub unsafe fn qk_elide_permutations_result_free(&self, result: *mut QkElidePermutationsResult) {
+        unsafe {
+            (self
+                .qk_elide_permutations_result_free
+                .as_ref()
+                .expect("Expected function, got error."))(result)
+        }
+    }
+    pub unsafe fn qk_transpiler_pass_standalone_remove_identity_equivalent(
+        &self,
+        circuit: *mut QkCircuit,
+        target: *const QkTarget,
+        approximation_degree: f64,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_transpiler_pass_standalone_remove_identity_equivalent
+                .as_ref()
+                .expect("Expected function, got error."))(
+                circuit, target, approximation_degree
+            )
+        }
+    }
+    pub unsafe fn qk_transpiler_pass_standalone_commutative_cancellation(
+        &self,
+        circuit: *mut QkCircuit,
+        target: *const QkTarget,
+        approximation_degree: f64,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_transpiler_pass_standalone_commutative_cancellation
+                .as_ref()
+                .expect("Expected function, got error."))(
+                circuit, target, approximation_degree
+            )
+        }
+    }
+    pub unsafe fn qk_transpiler_pass_standalone_optimize_1q_sequences(
+        &self,
+        circuit: *mut QkCircuit,
+        target: *const QkTarget,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_transpiler_pass_standalone_optimize_1q_sequences
+                .as_ref()
+                .expect("Expected function, got error."))(circuit, target)
+        }
+    }
+    pub unsafe fn qk_transpiler_pass_standalone_basis_translator(
+        &self,
+        circuit: *mut QkCircuit,
+        target: *const QkTarget,
+        min_qubits: usize,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_transpiler_pass_standalone_basis_translator
+                .as_ref()
+                .expect("Expected function, got error."))(circuit, target, min_qubits)
+        }
+    }
+    pub unsafe fn qk_transpiler_pass_standalone_gate_direction(
+        &self,
+        circuit: *mut QkCircuit,
+        target: *const QkTarget,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_transpiler_pass_standalone_gate_direction
+                .as_ref()
+                .expect("Expected function, got error."))(circuit, target)
+        }
+    }
+    pub unsafe fn qk_transpiler_pass_standalone_check_gate_direction(
+        &self,
+        circuit: *const QkCircuit,
+        target: *const QkTarget,
+    ) -> bool {
+        unsafe {
+            (self
+                .qk_transpiler_pass_standalone_check_gate_direction
+                .as_ref()
+                .expect("Expected function, got error."))(circuit, target)
+        }
+    }
+    pub unsafe fn qk_transpiler_pass_standalone_consolidate_blocks(
+        &self,
+        circuit: *mut QkCircuit,
+        target: *const QkTarget,
+        approximation_degree: f64,
+        force_consolidate: bool,
+    ) -> QkExitCode {
+        unsafe {
+            (self
+                .qk_transpiler_pass_standalone_consolidate_blocks
+                .as_ref()
+                .expect("Expected function, got error."))(
+                circuit,
+                target,
+                approximation_degree,
+                force_consolidate,
+            )
+        }
+    }
+}
@@ -17,37 +17,62 @@ use std::process::Command;
 enum InstallMethod {
     Clone,
     Path(String),
+    Source(String),
+    System,
 }
 
+#[cfg(feature = "bindgen")]
 #[derive(Debug)]
 struct CargoCallbacks;
 
+#[cfg(feature = "bindgen")]
 impl bindgen::callbacks::ParseCallbacks for CargoCallbacks {
     fn process_comment(&self, comment: &str) -> Option<String> {
         Some(format!("````ignore\n{}\n````", comment))
     }
 }
 
-// There are two installation methods:
-// - Clone (no path specified): Automatically clones and builds the qiskit c api from source
+// There are four installation methods:
+// - Clone: Automatically clones and builds the qiskit c api from source
 //     Set envvar export QISKIT_CEXT_INSTALL_METHOD="clone" to use the clone method. WARNING, cloning and building from
-//     source is very slow.
+//     source is very slow. Requires the "clone" feature (enabled by default).
 // - Path (Manually specified path): Uses qiskit c api binary or source from a path
 //     export QISKIT_CEXT_INSTALL_METHOD="path"
 //     export QISKIT_CEXT_PATH="path/to/qiskit-cext-dir"
+// - Source (Local source tree or tarball): Builds the qiskit c api from a local source directory or
+//     a source tarball (.tar, .tar.gz, .tgz), without touching the network. The Rust dependencies of
+//     qiskit must already be available to cargo, e.g. vendored or in the local registry cache.
+//     export QISKIT_CEXT_INSTALL_METHOD="source"
+//     export QISKIT_CEXT_PATH="path/to/qiskit-2.2.3.tar.gz"
+// - System (System wide installation): Finds the headers and library with pkg-config, falling back to
+//     the standard prefixes (/usr/local, /usr, ...). Either directory can be overridden.
+//     export QISKIT_CEXT_INSTALL_METHOD="system"
+//     export QISKIT_CEXT_INCLUDE_DIR="path/to/include"  # optional
+//     export QISKIT_CEXT_LIB_DIR="path/to/lib"  # optional
+//
+// When QISKIT_CEXT_INSTALL_METHOD is unset, the clone method is used if the "clone" feature is
+// enabled and the system method otherwise.
+//
+// Whatever the method, the headers must match the major and minor version of this crate.
+//
+// With the "static" feature, the static library libqiskit.a is linked instead of the shared library,
+// so the resulting binaries do not need libqiskit at runtime.
 fn check_installation_method() -> InstallMethod {
     let qiskit_cext_path = env::var("QISKIT_CEXT_PATH");
     match env::var("QISKIT_CEXT_INSTALL_METHOD") {
         Ok(val) => match val.as_str() {
             "path" => InstallMethod::Path(qiskit_cext_path.expect("QISKIT_CEXT_PATH is unset")),
+            "source" => InstallMethod::Source(qiskit_cext_path.expect("QISKIT_CEXT_PATH is unset")),
+            "system" => InstallMethod::System,
             "clone" => InstallMethod::Clone,
             _ => panic!(
-                "\"{}\" is not a valid input to QISKIT_CEXT_INSTALL_METHOD, please specify one of the following options: (\"path\", \"clone\")",
+                "\"{}\" is not a valid input to QISKIT_CEXT_INSTALL_METHOD, please specify one of the following options: (\"path\", \"source\", \"system\", \"clone\")",
                 val
             ),
         },
         Err(e) => match e {
-            env::VarError::NotPresent => InstallMethod::Clone,
+            env::VarError::NotPresent if cfg!(feature = "clone") => InstallMethod::Clone,
+            env::VarError::NotPresent => InstallMethod::System,
             env::VarError::NotUnicode(_) => {
                 panic!("Envvar QISKIT_CEXT_INSTALL_METHOD is not unicode")
             }
@@ -55,6 +80,7 @@ fn check_installation_method() -> InstallMethod {
     }
 }
 
+#[cfg(feature = "clone")]
 fn clone_qiskit(source_path: &Path) {
     let url = "https://github.com/Qiskit/qiskit.git";
     match git2::Repository::clone(url, source_path) {
@@ -91,51 +117,200 @@ fn clone_qiskit(source_path: &Path) {
     }
 }
 
-fn build_qiskit(source_path: &Path) {
-    let _ = Command::new("make")
+fn build_qiskit(source_path: &Path, offline: bool) {
+    let mut command = Command::new("make");
+    command
         .current_dir(source_path)
         .env("CARGO_BUILD_TARGET", env::var("TARGET").unwrap())
         .arg(format!(
             "C_CARGO_TARGET_DIR=target/{}/release",
             env::var("TARGET").unwrap()
         ))
-        .arg("c")
+        .arg("c");
+    if offline {
+        command.env("CARGO_NET_OFFLINE", "true");
+    }
+    let status = command
         .status()
         .expect("Dynamically linked library generation failed");
+    if !status.success() {
+        panic!("Building the qiskit c api failed: {status}");
+    }
 }
 
-fn build_qiskit_from_source() {
-    let out_dir = std::env::var("OUT_DIR").unwrap();
-    let source_path = Path::new(&out_dir).join("qiskit_c_lib");
-    let source_path = source_path.as_path();
-
-    clone_qiskit(source_path);
+// Unpack a source tarball into `dest`. Release tarballs contain a single top level directory,
+// which is stripped so that `dest` becomes the root of the source tree.
+fn unpack_qiskit(tarball: &Path, dest: &Path) {
+    if dest.exists() {
+        std::fs::remove_dir_all(dest).expect("Couldn't clear the previous qiskit source tree");
+    }
+    std::fs::create_dir_all(dest).expect("Couldn't create the qiskit source directory");
+    let status = Command::new("tar")
+        .arg("-xf")
+        .arg(tarball)
+        .arg("-C")
+        .arg(dest)
+        .arg("--strip-components=1")
+        .status()
+        .expect("Failed to run tar");
+    if !status.success() {
+        panic!("Unpacking {:?} failed: {status}", tarball);
+    }
+}
 
-    match source_path.try_exists() {
-        Ok(b) => match b {
-            true => {}
-            false => panic!("Qiskit source path does not exist"),
-        },
-        Err(e) => panic!("{e:?}"),
+fn link_qiskit(lib_dir: &str) {
+    if cfg!(feature = "runtime-linking") {
+        // Nothing is linked, but remember where the library was found as the default to load.
+        println!(
+            "cargo:rustc-env=QISKIT_SYS_DEFAULT_LIBRARY={}/{}qiskit{}",
+            lib_dir,
+            env::consts::DLL_PREFIX,
+            env::consts::DLL_SUFFIX
+        );
+        return;
+    }
+    println!("cargo:rustc-link-search={}", lib_dir);
+    if cfg!(feature = "static") {
+        let archive = Path::new(lib_dir).join("libqiskit.a");
+        if !archive.exists() {
+            panic!(
+                "The \"static\" feature is enabled but {:?} does not exist",
+                archive
+            );
+        }
+        println!("cargo:rustc-link-lib=static=qiskit");
+    } else {
+        println!("cargo:rustc-env=LD_LIBRARY_PATH={}", lib_dir);
+        println!("cargo:rustc-env=DYLD_LIBRARY_PATH={}", lib_dir);
+        println!("cargo:rustc-link-lib=qiskit");
     }
+}
 
-    let repo_dir_str: &str = source_path.to_str().unwrap();
+// Read the value of a `#define NAME value` line from a header.
+fn header_define(header: &str, name: &str) -> Option<u32> {
+    header.lines().find_map(|line| {
+        let mut tokens = line.split_whitespace();
+        match (tokens.next(), tokens.next(), tokens.next()) {
+            (Some("#define"), Some(n), Some(value)) if n == name => value.parse().ok(),
+            _ => None,
+        }
+    })
+}
+
+// The version of the qiskit c api headers in `include_dir`, if the headers define it.
+fn header_version(include_dir: &str) -> Option<(u32, u32, u32)> {
+    ["qiskit.h", "qiskit/version.h"].iter().find_map(|file| {
+        let header = std::fs::read_to_string(Path::new(include_dir).join(file)).ok()?;
+        Some((
+            header_define(&header, "QISKIT_VERSION_MAJOR")?,
+            header_define(&header, "QISKIT_VERSION_MINOR")?,
+            header_define(&header, "QISKIT_VERSION_PATCH")?,
+        ))
+    })
+}
 
-    build_qiskit(source_path);
+// The major and minor version of the qiskit c api this crate is built for.
+fn crate_abi() -> (u32, u32) {
+    let (major, minor, _) = parse_version(env!("CARGO_PKG_VERSION")).unwrap();
+    (major, minor)
+}
 
-    println!(
-        "cargo:rustc-env=LD_LIBRARY_PATH={}/dist/c/lib",
-        repo_dir_str
-    );
-    println!("cargo:rustc-link-search={}/dist/c/lib", repo_dir_str);
-    println!("cargo:rustc-link-lib=qiskit");
+// Record the version of the headers the bindings were generated from, and of the library when it
+// is known, for the version constants of the crate.
+fn write_version(header: (u32, u32, u32), library: Option<(u32, u32, u32)>) {
+    let (abi_major, abi_minor) = crate_abi();
+    let library = match library {
+        Some((major, minor, patch)) => format!("Some(({}, {}, {}))", major, minor, patch),
+        None => "None".to_string(),
+    };
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+    std::fs::write(
+        out_path.join("version.rs"),
+        format!(
+            "/// The version of the qiskit c api headers the bindings were generated from, as\n\
+             /// `(major, minor, patch)`.\n\
+             pub const HEADER_VERSION: (u32, u32, u32) = ({}, {}, {});\n\
+             /// The version of the qiskit c api installation found at build time, if it is known.\n\
+             pub const LIBRARY_VERSION: Option<(u32, u32, u32)> = {};\n\
+             /// The major and minor version of the qiskit c api this crate supports.\n\
+             pub const ABI_VERSION: (u32, u32) = ({}, {});\n",
+            header.0, header.1, header.2, library, abi_major, abi_minor
+        ),
+    )
+    .expect("Couldn't write the version!");
+}
+
+fn check_version(what: &str, (major, minor, patch): (u32, u32, u32)) {
+    let (expected_major, expected_minor) = crate_abi();
+    if (major, minor) != (expected_major, expected_minor) {
+        panic!(
+            "{} is version {}.{}.{}, but qiskit-sys {} requires version {}.{}.x",
+            what,
+            major,
+            minor,
+            patch,
+            env!("CARGO_PKG_VERSION"),
+            expected_major,
+            expected_minor
+        );
+    }
+}
+
+// Parse a "major.minor.patch" version string.
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = version.split('.').map(|part| part.parse::<u32>().ok());
+    Some((parts.next()??, parts.next()??, parts.next()??))
+}
+
+// Check that the headers exist and match the ABI of this crate (the same major and minor version)
+// before handing them to bindgen, whose errors are hard to read.
+fn check_headers(include_dir: &str) {
+    let header = Path::new(include_dir).join("qiskit.h");
+    if !header.exists() {
+        panic!(
+            "Couldn't find the qiskit c api header {:?}, set QISKIT_CEXT_INCLUDE_DIR to the directory containing qiskit.h",
+            header
+        );
+    }
+    match header_version(include_dir) {
+        Some(version) => check_version(
+            &format!("The qiskit c api headers in {}", include_dir),
+            version,
+        ),
+        None => {
+            let (major, minor) = crate_abi();
+            println!(
+                "cargo::warning=Couldn't determine the version of the qiskit c api headers in {}, expected {}.{}.x",
+                include_dir, major, minor
+            )
+        }
+    }
+}
 
-    let bindings = bindgen::Builder::default()
-        .header(format!("{}/dist/c/include/qiskit.h", repo_dir_str))
-        .header(format!("{}/dist/c/include/qiskit/complex.h", repo_dir_str))
-        .parse_callbacks(Box::new(CargoCallbacks))
-        .generate()
-        .expect("Unable to generate bindings");
+#[cfg(feature = "bindgen")]
+fn write_bindings(include_dir: Option<&str>, library_version: Option<(u32, u32, u32)>) {
+    let include_dir = include_dir.unwrap_or_else(|| {
+        panic!("Couldn't find the qiskit c api headers, set QISKIT_CEXT_INCLUDE_DIR to the directory containing qiskit.h or disable the \"bindgen\" feature")
+    });
+    check_headers(include_dir);
+    // Headers without version macros have already been warned about, assume they match the crate.
+    let version = header_version(include_dir)
+        .unwrap_or_else(|| parse_version(env!("CARGO_PKG_VERSION")).unwrap());
+    write_version(version, library_version.or(Some(version)));
+    let mut builder = bindgen::Builder::default()
+        .header(format!("{}/qiskit.h", include_dir))
+        .header(format!("{}/qiskit/complex.h", include_dir))
+        .clang_arg(format!("-I{}", include_dir))
+        .parse_callbacks(Box::new(CargoCallbacks));
+    if cfg!(feature = "runtime-linking") {
+        // Load the functions into a `Qiskit` table with libloading instead of declaring them.
+        // Functions missing from the loaded library only fail when they are called.
+        builder = builder
+            .dynamic_library_name("Qiskit")
+            .dynamic_link_require_all(false)
+            .wrap_unsafe_ops(true);
+    }
+    let bindings = builder.generate().expect("Unable to generate bindings");
 
     let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
     bindings
@@ -143,58 +318,229 @@ fn build_qiskit_from_source() {
         .expect("Couldn't write bindings!");
 }
 
-fn build_qiskit_from_path(qiskit_path_str: String) {
-    let qiskit_path = Path::new(&qiskit_path_str);
+// Pre-generated bindings in the bindings directory, with the version of the headers they were
+// generated from, for link time and for runtime linking.
+#[cfg(not(feature = "bindgen"))]
+const PREGENERATED_BINDINGS: [((u32, u32, u32), &str, &str); 1] =
+    [((2, 2, 3), "qiskit_2_2.rs", "qiskit_2_2_dynamic.rs")];
+
+// Without the "bindgen" feature the headers are optional, but when they are available they are
+// still checked against the version of the pre-generated bindings.
+#[cfg(not(feature = "bindgen"))]
+fn write_bindings(include_dir: Option<&str>, library_version: Option<(u32, u32, u32)>) {
+    if let Some(include_dir) = include_dir {
+        check_headers(include_dir);
+    }
+    let (major, minor) = crate_abi();
+    let (version, file, dynamic_file) = PREGENERATED_BINDINGS
+        .iter()
+        .find(|((found_major, found_minor, _), _, _)| {
+            (*found_major, *found_minor) == (major, minor)
+        })
+        .unwrap_or_else(|| {
+            panic!(
+                "There are no pre-generated bindings for qiskit {}.{}, enable the \"bindgen\" feature",
+                major, minor
+            )
+        });
+    let file = match cfg!(feature = "runtime-linking") {
+        true => dynamic_file,
+        false => file,
+    };
+    let source = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("bindings")
+        .join(file);
+    println!("cargo:rerun-if-changed={}", source.display());
+    write_version(
+        *version,
+        library_version.or_else(|| include_dir.and_then(header_version)),
+    );
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+    std::fs::copy(&source, out_path.join("bindings.rs")).expect("Couldn't write bindings!");
+}
 
-    match qiskit_path.try_exists() {
+// Link and generate bindings for a qiskit c api installed in the `dist/c` layout of a qiskit
+// source tree.
+fn use_dist(qiskit_path_str: &str) {
+    link_qiskit(&format!("{}/dist/c/lib", qiskit_path_str));
+    write_bindings(Some(&format!("{}/dist/c/include", qiskit_path_str)), None);
+}
+
+fn check_exists(path: &Path, what: &str) {
+    match path.try_exists() {
         Ok(b) => match b {
             true => {}
-            false => panic!("Qiskit path does not exist"),
+            false => panic!("{} does not exist", what),
         },
         Err(e) => panic!("{e:?}"),
     }
+}
 
-    println!(
-        "cargo:rustc-env=LD_LIBRARY_PATH={}/dist/c/lib",
-        qiskit_path_str
-    );
-    println!(
-        "cargo:rustc-env=DYLD_LIBRARY_PATH={}/dist/c/lib",
-        qiskit_path_str
+#[cfg(feature = "clone")]
+fn build_qiskit_from_clone() {
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    let source_path = Path::new(&out_dir).join("qiskit_c_lib");
+    let source_path = source_path.as_path();
+
+    clone_qiskit(source_path);
+    check_exists(source_path, "Qiskit source path");
+
+    let repo_dir_str: &str = source_path.to_str().unwrap();
+
+    build_qiskit(source_path, false);
+    use_dist(repo_dir_str);
+}
+
+#[cfg(not(feature = "clone"))]
+fn build_qiskit_from_clone() {
+    panic!(
+        "QISKIT_CEXT_INSTALL_METHOD=\"clone\" requires the \"clone\" feature of qiskit-sys, enable it or set QISKIT_CEXT_INSTALL_METHOD to \"path\", \"source\" or \"system\" instead"
     );
-    println!("cargo:rustc-link-search={}/dist/c/lib", qiskit_path_str);
-    println!("cargo:rustc-link-lib=qiskit");
-
-    let bindings = bindgen::Builder::default()
-        .header(format!("{}/dist/c/include/qiskit.h", qiskit_path_str))
-        .header(format!(
-            "{}/dist/c/include/qiskit/complex.h",
-            qiskit_path_str
-        ))
-        .parse_callbacks(Box::new(CargoCallbacks))
-        .generate()
-        .expect("Unable to generate bindings");
+}
 
-    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
-    bindings
-        .write_to_file(out_path.join("bindings.rs"))
-        .expect("Couldn't write bindings!");
+fn build_qiskit_from_source(qiskit_source_str: String) {
+    let qiskit_source = Path::new(&qiskit_source_str);
+    check_exists(qiskit_source, "Qiskit source path");
+
+    // A source directory is built in place, a tarball is unpacked into OUT_DIR first.
+    let source_path = match qiskit_source.is_dir() {
+        true => qiskit_source.to_path_buf(),
+        false => {
+            println!("cargo:rerun-if-changed={}", qiskit_source_str);
+            let out_dir = std::env::var("OUT_DIR").unwrap();
+            let source_path = Path::new(&out_dir).join("qiskit_c_lib");
+            unpack_qiskit(qiskit_source, &source_path);
+            source_path
+        }
+    };
+
+    let source_dir_str: &str = source_path.to_str().unwrap();
+
+    build_qiskit(&source_path, true);
+    use_dist(source_dir_str);
+}
+
+fn build_qiskit_from_path(qiskit_path_str: String) {
+    let qiskit_path = Path::new(&qiskit_path_str);
+    check_exists(qiskit_path, "Qiskit path");
+
+    use_dist(&qiskit_path_str);
+}
+
+// Standard installation prefixes searched by the system method when pkg-config can't find qiskit.
+const SYSTEM_PREFIXES: [&str; 4] = ["/usr/local", "/usr", "/opt/homebrew", "/opt/local"];
+
+fn find_system_include_dir() -> Option<String> {
+    SYSTEM_PREFIXES
+        .iter()
+        .map(|prefix| format!("{}/include", prefix))
+        .find(|dir| Path::new(dir).join("qiskit.h").exists())
+}
+
+fn find_system_lib_dir() -> Option<String> {
+    let target = env::var("TARGET").unwrap();
+    // Debian style multiarch directories use the target triple without the vendor.
+    let multiarch = target.replacen("-unknown", "", 1);
+    let library = match cfg!(feature = "static") {
+        true => "libqiskit.a".to_string(),
+        false => format!(
+            "{}qiskit{}",
+            env::consts::DLL_PREFIX,
+            env::consts::DLL_SUFFIX
+        ),
+    };
+    SYSTEM_PREFIXES
+        .iter()
+        .flat_map(|prefix| {
+            [
+                format!("{}/lib", prefix),
+                format!("{}/lib64", prefix),
+                format!("{}/lib/{}", prefix, multiarch),
+            ]
+        })
+        .find(|dir| Path::new(dir).join(&library).exists())
+}
+
+fn build_qiskit_from_system() {
+    let mut include_dir = env::var("QISKIT_CEXT_INCLUDE_DIR").ok();
+    let mut lib_dir = env::var("QISKIT_CEXT_LIB_DIR").ok();
+    let mut library_version = None;
+
+    if include_dir.is_none() || lib_dir.is_none() {
+        // Only ask pkg-config for the paths, linking is handled by link_qiskit.
+        match pkg_config::Config::new()
+            .cargo_metadata(false)
+            .statik(cfg!(feature = "static"))
+            .probe("qiskit")
+        {
+            Ok(library) => {
+                library_version = parse_version(&library.version);
+                if let Some(version) = library_version {
+                    check_version("The qiskit library found by pkg-config", version);
+                }
+                include_dir = include_dir.or_else(|| {
+                    library
+                        .include_paths
+                        .iter()
+                        .find(|dir| dir.join("qiskit.h").exists())
+                        .map(|dir| dir.to_str().unwrap().to_string())
+                });
+                lib_dir = lib_dir.or_else(|| {
+                    library
+                        .link_paths
+                        .first()
+                        .map(|dir| dir.to_str().unwrap().to_string())
+                });
+            }
+            Err(_) => println!(
+                "cargo::warning=pkg-config couldn't find qiskit, searching the standard prefixes"
+            ),
+        }
+    }
+
+    let include_dir = include_dir.or_else(find_system_include_dir);
+    let lib_dir = lib_dir.or_else(find_system_lib_dir).unwrap_or_else(|| {
+        panic!("Couldn't find libqiskit, set QISKIT_CEXT_LIB_DIR to the directory containing it")
+    });
+
+    link_qiskit(&lib_dir);
+    write_bindings(include_dir.as_deref(), library_version);
 }
 
 fn main() {
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo::rerun-if-env-changed=QISKIT_CEXT_INSTALL_METHOD");
     println!("cargo::rerun-if-env-changed=QISKIT_CEXT_PATH");
+    println!("cargo::rerun-if-env-changed=QISKIT_CEXT_INCLUDE_DIR");
+    println!("cargo::rerun-if-env-changed=QISKIT_CEXT_LIB_DIR");
+
+    if cfg!(all(feature = "static", feature = "runtime-linking")) {
+        panic!("The \"static\" and \"runtime-linking\" features can't be enabled together");
+    }
+
+    // With runtime linking and pre-generated bindings nothing from the qiskit c api is needed
+    // at build time.
+    if cfg!(all(feature = "runtime-linking", not(feature = "bindgen"))) {
+        write_bindings(None, None);
+        return;
+    }
 
     let install_method = check_installation_method();
 
     match install_method {
         InstallMethod::Clone => {
             println!("cargo::warning=Cloning and building from source is very slow");
-            build_qiskit_from_source();
+            build_qiskit_from_clone();
         }
         InstallMethod::Path(path) => {
             build_qiskit_from_path(path);
         }
+        InstallMethod::Source(path) => {
+            build_qiskit_from_source(path);
+        }
+        InstallMethod::System => {
+            build_qiskit_from_system();
+        }
     };
 }
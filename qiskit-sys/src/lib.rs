@@ -12,8 +12,69 @@
 
 #![allow(non_upper_case_globals)]
 #![allow(non_camel_case_types)]
+#![cfg_attr(
+    feature = "runtime-linking",
+    allow(clippy::missing_safety_doc, clippy::too_many_arguments)
+)]
 
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+include!(concat!(env!("OUT_DIR"), "/version.rs"));
+
+#[cfg(feature = "runtime-linking")]
+mod runtime;
+#[cfg(feature = "runtime-linking")]
+pub use runtime::{LoadError, library, library_version, load, try_library};
+
+/// Call a function of the qiskit c api, or take a pointer to it when no
+/// arguments are given.
+///
+/// This is the linked function of the same name, or with the
+/// "runtime-linking" feature the function loaded into [library], so code
+/// using it works the same either way.
+///
+/// ```ignore
+/// let qc = unsafe { qiskit_sys::qk!(qk_circuit_new(2, 0)) };
+/// let free: unsafe extern "C" fn(*mut QkCircuit) = qiskit_sys::qk!(qk_circuit_free);
+/// ```
+#[cfg(not(feature = "runtime-linking"))]
+#[macro_export]
+macro_rules! qk {
+    ($function:ident($($arg:expr),* $(,)?)) => {
+        $crate::$function($($arg),*)
+    };
+    ($function:ident) => {
+        $crate::$function
+    };
+}
+
+/// Call a function of the qiskit c api, or take a pointer to it when no
+/// arguments are given.
+///
+/// This is the linked function of the same name, or with the
+/// "runtime-linking" feature the function loaded into [library], so code
+/// using it works the same either way.
+///
+/// ```ignore
+/// let qc = unsafe { qiskit_sys::qk!(qk_circuit_new(2, 0)) };
+/// let free: unsafe extern "C" fn(*mut QkCircuit) = qiskit_sys::qk!(qk_circuit_free);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the loaded library doesn't export the function.
+#[cfg(feature = "runtime-linking")]
+#[macro_export]
+macro_rules! qk {
+    ($function:ident($($arg:expr),* $(,)?)) => {
+        ($crate::qk!($function))($($arg),*)
+    };
+    ($function:ident) => {
+        *$crate::library()
+            .$function
+            .as_ref()
+            .expect(concat!("libqiskit doesn't export ", stringify!($function)))
+    };
+}
 
 #[cfg(test)]
 mod tests {
@@ -22,15 +83,15 @@ mod tests {
     #[test]
     fn test_empty_circuit() {
         unsafe {
-            let qc = qk_circuit_new(0, 0);
-            assert_eq!(0, qk_circuit_num_qubits(qc));
-            assert_eq!(0, qk_circuit_num_clbits(qc));
-            assert_eq!(0, qk_circuit_num_instructions(qc));
-            let mut op_counts = qk_circuit_count_ops(qc);
+            let qc = qk!(qk_circuit_new(0, 0));
+            assert_eq!(0, qk!(qk_circuit_num_qubits(qc)));
+            assert_eq!(0, qk!(qk_circuit_num_clbits(qc)));
+            assert_eq!(0, qk!(qk_circuit_num_instructions(qc)));
+            let mut op_counts = qk!(qk_circuit_count_ops(qc));
             assert_eq!(0, op_counts.len);
 
-            qk_circuit_free(qc);
-            qk_opcounts_clear(&mut op_counts);
+            qk!(qk_circuit_free(qc));
+            qk!(qk_opcounts_clear(&mut op_counts));
         }
     }
 }
@@ -0,0 +1,206 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! Loading libqiskit at runtime, with the "runtime-linking" feature.
+
+use crate::Qiskit;
+use std::ffi::{OsStr, OsString};
+use std::fmt;
+use std::path::Path;
+use std::sync::OnceLock;
+
+static LIBRARY: OnceLock<Result<Loaded, LoadError>> = OnceLock::new();
+
+struct Loaded {
+    library: Qiskit,
+    version: Option<(u32, u32, u32)>,
+}
+
+/// An error loading libqiskit at runtime.
+#[derive(Debug)]
+pub enum LoadError {
+    /// A library was already loaded, either by an earlier call to [load] or
+    /// by the first use of the bindings.
+    AlreadyLoaded,
+    /// The library couldn't be opened.
+    Library(libloading::Error),
+    /// The library is a version of the qiskit c api that doesn't match the
+    /// major and minor version of the bindings, see [library_version].
+    IncompatibleVersion((u32, u32, u32)),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::AlreadyLoaded => write!(f, "libqiskit is already loaded"),
+            LoadError::Library(e) => write!(f, "couldn't load libqiskit: {e}"),
+            LoadError::IncompatibleVersion((major, minor, patch)) => {
+                let (abi_major, abi_minor) = crate::ABI_VERSION;
+                write!(
+                    f,
+                    "libqiskit {major}.{minor}.{patch} doesn't match the qiskit {abi_major}.{abi_minor}.x c api of the bindings"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::Library(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+// Open the library at `path` and check its version.
+unsafe fn open(path: &OsStr) -> Result<Loaded, LoadError> {
+    let version = installation_version(Path::new(path));
+    if let Some(version) = version {
+        let (major, minor, _) = version;
+        if (major, minor) != crate::ABI_VERSION {
+            return Err(LoadError::IncompatibleVersion(version));
+        }
+    }
+    let library = unsafe { Qiskit::new(path) }.map_err(LoadError::Library)?;
+    Ok(Loaded { library, version })
+}
+
+/// Load libqiskit from `path`.
+///
+/// This has to be called before any other function of the bindings is used,
+/// otherwise the default library is loaded on first use, see [library].
+/// Functions the library doesn't export aren't an error here, calling them
+/// through [qk](crate::qk) panics instead.
+///
+/// A library whose version is known, see [library_version], and doesn't
+/// match the major and minor version of the bindings isn't loaded.
+///
+/// # Safety
+///
+/// Loading a library runs its initialization routines, and the library must
+/// be a build of the qiskit c api matching these bindings.
+pub unsafe fn load<P: AsRef<OsStr>>(path: P) -> Result<(), LoadError> {
+    if LIBRARY.get().is_some() {
+        return Err(LoadError::AlreadyLoaded);
+    }
+    let library = unsafe { open(path.as_ref()) }?;
+    LIBRARY
+        .set(Ok(library))
+        .map_err(|_| LoadError::AlreadyLoaded)
+}
+
+// The library to load when `load` wasn't called: $QISKIT_LIBRARY_PATH, the library found at
+// build time, or libqiskit from the default search path.
+fn default_library() -> OsString {
+    std::env::var_os("QISKIT_LIBRARY_PATH")
+        .or_else(|| option_env!("QISKIT_SYS_DEFAULT_LIBRARY").map(OsString::from))
+        .unwrap_or_else(|| libloading::library_filename("qiskit"))
+}
+
+// The value of `#define name <value>` in a header.
+fn header_define(header: &str, name: &str) -> Option<u32> {
+    header.lines().find_map(|line| {
+        let mut tokens = line.split_whitespace();
+        match (tokens.next(), tokens.next(), tokens.next()) {
+            (Some("#define"), Some(n), Some(value)) if n == name => value.parse().ok(),
+            _ => None,
+        }
+    })
+}
+
+// The qiskit 2.2 c api has no function returning its version, so it is read from the version
+// macros of the headers installed next to the library, in `lib/../include` as laid out by
+// `make c` and by system installations.
+fn installation_version(library: &Path) -> Option<(u32, u32, u32)> {
+    let include_dir = library.parent()?.parent()?.join("include");
+    ["qiskit.h", "qiskit/version.h"].iter().find_map(|file| {
+        let header = std::fs::read_to_string(include_dir.join(file)).ok()?;
+        Some((
+            header_define(&header, "QISKIT_VERSION_MAJOR")?,
+            header_define(&header, "QISKIT_VERSION_MINOR")?,
+            header_define(&header, "QISKIT_VERSION_PATCH")?,
+        ))
+    })
+}
+
+/// The loaded library, or the error loading it.
+///
+/// If [load] wasn't called, the library at `$QISKIT_LIBRARY_PATH`, the
+/// library found at build time, or `libqiskit` from the default search path
+/// is loaded, once: a failure is returned again by later calls.
+pub fn try_library() -> Result<&'static Qiskit, &'static LoadError> {
+    LIBRARY
+        .get_or_init(|| unsafe { open(&default_library()) })
+        .as_ref()
+        .map(|loaded| &loaded.library)
+}
+
+/// The loaded library.
+///
+/// This is [try_library] for the [qk](crate::qk) macro, so every function
+/// of the bindings panics when the library can't be loaded.
+///
+/// # Panics
+///
+/// Panics if no library was loaded and the default library can't be loaded.
+pub fn library() -> &'static Qiskit {
+    match try_library() {
+        Ok(library) => library,
+        Err(e) => panic!(
+            "{e} (default library {:?}). Load it with qiskit_rs::init or set QISKIT_LIBRARY_PATH to its path",
+            default_library()
+        ),
+    }
+}
+
+/// The version of the loaded library, if it is known.
+///
+/// The version is read from the headers installed next to the library, in
+/// `../include` relative to its directory. It isn't known when the library
+/// was found on the default search path or installed without its headers.
+///
+/// # Panics
+///
+/// Panics like [library] if no library was loaded and the default library
+/// can't be loaded.
+pub fn library_version() -> Option<(u32, u32, u32)> {
+    library();
+    LIBRARY.get()?.as_ref().ok()?.version
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_incompatible_version() {
+        let prefix = std::env::temp_dir().join(format!("qiskit-sys-{}", std::process::id()));
+        std::fs::create_dir_all(prefix.join("include/qiskit")).unwrap();
+        std::fs::write(
+            prefix.join("include/qiskit/version.h"),
+            "#define QISKIT_VERSION_MAJOR 2\n#define QISKIT_VERSION_MINOR 1\n#define QISKIT_VERSION_PATCH 0\n",
+        )
+        .unwrap();
+        let library = prefix
+            .join("lib")
+            .join(libloading::library_filename("qiskit"));
+        assert_eq!(installation_version(&library), Some((2, 1, 0)));
+        let result = unsafe { open(library.as_os_str()) };
+        std::fs::remove_dir_all(&prefix).unwrap();
+        assert!(matches!(
+            result,
+            Err(LoadError::IncompatibleVersion((2, 1, 0)))
+        ));
+    }
+}
@@ -0,0 +1,165 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A double precision complex number.
+///
+/// This has the same layout as the C API's `QkComplex64` and is used for
+/// coefficients and amplitudes throughout qiskit-rs.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Complex64 {
+    /// The real part.
+    pub re: f64,
+    /// The imaginary part.
+    pub im: f64,
+}
+
+impl Complex64 {
+    /// The additive identity.
+    pub const ZERO: Complex64 = Complex64 { re: 0.0, im: 0.0 };
+    /// The multiplicative identity.
+    pub const ONE: Complex64 = Complex64 { re: 1.0, im: 0.0 };
+    /// The imaginary unit.
+    pub const I: Complex64 = Complex64 { re: 0.0, im: 1.0 };
+
+    /// Create a new complex number from its real and imaginary parts.
+    pub const fn new(re: f64, im: f64) -> Complex64 {
+        Complex64 { re, im }
+    }
+
+    /// Create a complex number from polar coordinates.
+    pub fn from_polar(r: f64, theta: f64) -> Complex64 {
+        Complex64::new(r * theta.cos(), r * theta.sin())
+    }
+
+    /// Return `exp(i * theta)`.
+    pub fn cis(theta: f64) -> Complex64 {
+        Complex64::from_polar(1.0, theta)
+    }
+
+    /// Return the complex conjugate.
+    pub fn conj(self) -> Complex64 {
+        Complex64::new(self.re, -self.im)
+    }
+
+    /// Return the squared modulus.
+    pub fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Return the modulus.
+    pub fn norm(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    /// Return the argument.
+    pub fn arg(self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    /// Multiply by a real scalar.
+    pub fn scale(self, factor: f64) -> Complex64 {
+        Complex64::new(self.re * factor, self.im * factor)
+    }
+
+    pub(crate) fn to_qk(self) -> qiskit_sys::QkComplex64 {
+        qiskit_sys::QkComplex64 {
+            re: self.re,
+            im: self.im,
+        }
+    }
+
+    pub(crate) fn from_qk(value: qiskit_sys::QkComplex64) -> Complex64 {
+        Complex64::new(value.re, value.im)
+    }
+}
+
+impl From<f64> for Complex64 {
+    fn from(re: f64) -> Complex64 {
+        Complex64::new(re, 0.0)
+    }
+}
+
+impl Add for Complex64 {
+    type Output = Complex64;
+
+    fn add(self, rhs: Complex64) -> Complex64 {
+        Complex64::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl AddAssign for Complex64 {
+    fn add_assign(&mut self, rhs: Complex64) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Complex64 {
+    type Output = Complex64;
+
+    fn sub(self, rhs: Complex64) -> Complex64 {
+        Complex64::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl SubAssign for Complex64 {
+    fn sub_assign(&mut self, rhs: Complex64) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for Complex64 {
+    type Output = Complex64;
+
+    fn mul(self, rhs: Complex64) -> Complex64 {
+        Complex64::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl MulAssign for Complex64 {
+    fn mul_assign(&mut self, rhs: Complex64) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for Complex64 {
+    type Output = Complex64;
+
+    fn div(self, rhs: Complex64) -> Complex64 {
+        let denom = rhs.norm_sqr();
+        (self * rhs.conj()).scale(1.0 / denom)
+    }
+}
+
+impl Neg for Complex64 {
+    type Output = Complex64;
+
+    fn neg(self) -> Complex64 {
+        Complex64::new(-self.re, -self.im)
+    }
+}
+
+impl fmt::Display for Complex64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im < 0.0 {
+            write!(f, "{}-{}j", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}j", self.re, self.im)
+        }
+    }
+}
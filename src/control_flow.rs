@@ -0,0 +1,309 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! Classical control flow.
+//!
+//! Control-flow operations are added with the builder methods on
+//! [`QuantumCircuit`], such as [`QuantumCircuit::if_test`] and
+//! [`QuantumCircuit::for_loop`]. Each body is built in a circuit with the
+//! same qubits and classical bits as the outer circuit. The operations show
+//! up in [`QuantumCircuit::instructions`] under their Qiskit names, and the
+//! full operation is available from
+//! [`CircuitInstruction::control_flow`](crate::qiskit::CircuitInstruction::control_flow).
+//!
+//! # Example
+//!
+//! ```
+//! use qiskit_rs::QuantumCircuit;
+//! use qiskit_rs::control_flow::Condition;
+//!
+//! // Repeat until the measurement of qubit 0 gives 0.
+//! let mut qc = QuantumCircuit::new(1, 1);
+//! qc.h(0);
+//! qc.measure(0, 0);
+//! qc.while_loop(Condition::bit(0, true), |body| {
+//!     body.h(0);
+//!     body.measure(0, 0);
+//! });
+//! assert_eq!(qc.instructions().last().unwrap().name, "while_loop");
+//! ```
+
+use crate::parameter::Parameter;
+use crate::qiskit::{QiskitError, QuantumCircuit};
+use std::collections::HashMap;
+
+/// A condition on the value of one or more classical bits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Condition {
+    clbits: Vec<u32>,
+    value: u64,
+}
+
+impl Condition {
+    /// A condition that a single classical bit has the given value.
+    pub fn bit(clbit: u32, value: bool) -> Condition {
+        Condition {
+            clbits: vec![clbit],
+            value: value as u64,
+        }
+    }
+
+    /// A condition that classical bits, read as an integer with `clbits[0]`
+    /// as the least significant bit, are equal to `value`.
+    ///
+    /// This is how conditions on a classical register are expressed; the
+    /// indices of a register's bits are returned by
+    /// [`QuantumCircuit::add_classical_register`].
+    pub fn register(clbits: &[u32], value: u64) -> Condition {
+        Condition {
+            clbits: clbits.to_vec(),
+            value,
+        }
+    }
+
+    /// Return the classical bits the condition reads.
+    pub fn clbits(&self) -> &[u32] {
+        &self.clbits
+    }
+
+    /// Return the value the classical bits are compared with.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    // Whether the value can be represented by the condition's bits.
+    fn is_valid(&self) -> bool {
+        !self.clbits.is_empty() && fits(self.value, self.clbits.len())
+    }
+}
+
+fn fits(value: u64, num_bits: usize) -> bool {
+    num_bits >= 64 || value >> num_bits == 0
+}
+
+/// A value matched by a case of a switch statement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CaseValue {
+    /// Match the target when it has this value.
+    Value(u64),
+    /// Match any value not matched by another case.
+    Default,
+}
+
+/// A classical control-flow operation in a [`QuantumCircuit`].
+#[derive(Clone, Debug)]
+pub enum ControlFlow {
+    /// Run `true_body` if the condition holds, and `false_body` if there is
+    /// one otherwise.
+    IfElse {
+        /// The condition that selects the branch.
+        condition: Condition,
+        /// The body run when the condition holds.
+        true_body: QuantumCircuit,
+        /// The body run when the condition does not hold.
+        false_body: Option<QuantumCircuit>,
+    },
+    /// Run the body for as long as the condition holds.
+    WhileLoop {
+        /// The condition checked before every iteration.
+        condition: Condition,
+        /// The loop body.
+        body: QuantumCircuit,
+    },
+    /// Run the body once for every value in `indexset`.
+    ForLoop {
+        /// The values the loop parameter takes.
+        indexset: Vec<i64>,
+        /// The parameter bound to the current value in the body.
+        loop_parameter: Parameter,
+        /// The loop body.
+        body: QuantumCircuit,
+    },
+    /// Run the body of the first case matching the value of the target.
+    Switch {
+        /// The classical bits read as an integer, least significant first.
+        target: Vec<u32>,
+        /// The values each body is run for.
+        cases: Vec<(Vec<CaseValue>, QuantumCircuit)>,
+    },
+}
+
+impl ControlFlow {
+    /// Return the name of the operation, as used by Qiskit.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ControlFlow::IfElse { .. } => "if_else",
+            ControlFlow::WhileLoop { .. } => "while_loop",
+            ControlFlow::ForLoop { .. } => "for_loop",
+            ControlFlow::Switch { .. } => "switch_case",
+        }
+    }
+
+    /// Return the bodies of the operation.
+    pub fn blocks(&self) -> Vec<&QuantumCircuit> {
+        match self {
+            ControlFlow::IfElse {
+                true_body,
+                false_body,
+                ..
+            } => std::iter::once(true_body).chain(false_body).collect(),
+            ControlFlow::WhileLoop { body, .. } | ControlFlow::ForLoop { body, .. } => vec![body],
+            ControlFlow::Switch { cases, .. } => cases.iter().map(|(_, body)| body).collect(),
+        }
+    }
+
+    /// Return the classical bits the operation reads to decide what to run.
+    pub fn condition_clbits(&self) -> &[u32] {
+        match self {
+            ControlFlow::IfElse { condition, .. } | ControlFlow::WhileLoop { condition, .. } => {
+                condition.clbits()
+            }
+            ControlFlow::ForLoop { .. } => &[],
+            ControlFlow::Switch { target, .. } => target,
+        }
+    }
+
+    // Check the operation against the width of the circuit it is added to.
+    pub(crate) fn validate(&self, num_qubits: u32, num_clbits: u32) -> QiskitError {
+        if self.condition_clbits().iter().any(|c| *c >= num_clbits) {
+            return QiskitError::IndexError;
+        }
+        for block in self.blocks() {
+            if block.num_qubits() != num_qubits {
+                return QiskitError::MismatchedQubits;
+            }
+            if block.num_clbits() != num_clbits {
+                return QiskitError::MismatchedClbits;
+            }
+        }
+        let valid = match self {
+            ControlFlow::IfElse { condition, .. } | ControlFlow::WhileLoop { condition, .. } => {
+                condition.is_valid()
+            }
+            ControlFlow::ForLoop { .. } => true,
+            ControlFlow::Switch { target, cases } => {
+                let values: Vec<CaseValue> = cases
+                    .iter()
+                    .flat_map(|(values, _)| values.iter().copied())
+                    .collect();
+                !target.is_empty()
+                    && values.iter().enumerate().all(|(i, value)| {
+                        !values[..i].contains(value)
+                            && match value {
+                                CaseValue::Value(value) => fits(*value, target.len()),
+                                CaseValue::Default => true,
+                            }
+                    })
+            }
+        };
+        if !valid {
+            return QiskitError::CInputError;
+        }
+        QiskitError::Success
+    }
+
+    // Move the operation into a circuit of the given width, mapping qubit
+    // `i` to `qubits[i]` and clbit `j` to `clbits[j]` and binding the
+    // parameters in `values`.
+    pub(crate) fn remap(
+        &self,
+        num_qubits: u32,
+        num_clbits: u32,
+        qubits: &[u32],
+        clbits: &[u32],
+        values: &HashMap<Parameter, f64>,
+    ) -> Result<ControlFlow, QiskitError> {
+        let block = |body: &QuantumCircuit| -> Result<QuantumCircuit, QiskitError> {
+            let mut out = QuantumCircuit::new(num_qubits, num_clbits);
+            out.append_bound(body, qubits, clbits, values)?;
+            Ok(out)
+        };
+        let condition = |condition: &Condition| Condition {
+            clbits: condition
+                .clbits
+                .iter()
+                .map(|c| clbits[*c as usize])
+                .collect(),
+            value: condition.value,
+        };
+        Ok(match self {
+            ControlFlow::IfElse {
+                condition: cond,
+                true_body,
+                false_body,
+            } => ControlFlow::IfElse {
+                condition: condition(cond),
+                true_body: block(true_body)?,
+                false_body: false_body.as_ref().map(block).transpose()?,
+            },
+            ControlFlow::WhileLoop {
+                condition: cond,
+                body,
+            } => ControlFlow::WhileLoop {
+                condition: condition(cond),
+                body: block(body)?,
+            },
+            ControlFlow::ForLoop {
+                indexset,
+                loop_parameter,
+                body,
+            } => ControlFlow::ForLoop {
+                indexset: indexset.clone(),
+                loop_parameter: loop_parameter.clone(),
+                body: block(body)?,
+            },
+            ControlFlow::Switch { target, cases } => ControlFlow::Switch {
+                target: target.iter().map(|c| clbits[*c as usize]).collect(),
+                cases: cases
+                    .iter()
+                    .map(|(values, body)| Ok((values.clone(), block(body)?)))
+                    .collect::<Result<_, QiskitError>>()?,
+            },
+        })
+    }
+}
+
+/// The cases of a switch statement, filled in by the closure passed to
+/// [`QuantumCircuit::switch`].
+pub struct SwitchCases {
+    num_qubits: u32,
+    num_clbits: u32,
+    pub(crate) cases: Vec<(Vec<CaseValue>, QuantumCircuit)>,
+}
+
+impl SwitchCases {
+    pub(crate) fn new(num_qubits: u32, num_clbits: u32) -> SwitchCases {
+        SwitchCases {
+            num_qubits,
+            num_clbits,
+            cases: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, values: Vec<CaseValue>, body: impl FnOnce(&mut QuantumCircuit)) {
+        let mut block = QuantumCircuit::new(self.num_qubits, self.num_clbits);
+        body(&mut block);
+        self.cases.push((values, block));
+    }
+
+    /// Add a case that runs `body` when the target has any of `values`.
+    pub fn case(&mut self, values: &[u64], body: impl FnOnce(&mut QuantumCircuit)) -> &mut Self {
+        self.push(values.iter().map(|v| CaseValue::Value(*v)).collect(), body);
+        self
+    }
+
+    /// Add a case that runs `body` when no other case matches.
+    pub fn default(&mut self, body: impl FnOnce(&mut QuantumCircuit)) -> &mut Self {
+        self.push(vec![CaseValue::Default], body);
+        self
+    }
+}
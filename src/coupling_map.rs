@@ -0,0 +1,334 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! The connectivity of a device's qubits.
+//!
+//! A [`CouplingMap`] is a directed graph with an edge from qubit `a` to qubit
+//! `b` when a two-qubit gate can act on `(a, b)`. Distances, paths and
+//! neighbours ignore the direction of the edges, as routing can flip gates.
+//!
+//! # Example
+//!
+//! ```
+//! use qiskit_rs::{CouplingMap, StandardGate};
+//!
+//! let ring = CouplingMap::ring(6);
+//! assert_eq!(ring.distance(0, 4), Some(2));
+//! assert_eq!(ring.shortest_path(0, 4), Some(vec![0, 5, 4]));
+//! assert_eq!(ring.neighbors(0), [1, 5]);
+//!
+//! let target = ring
+//!     .to_target(StandardGate::CZ, &[StandardGate::RZ, StandardGate::SX])
+//!     .unwrap();
+//! assert!(target.instruction_supported(StandardGate::CZ, &[5, 0]));
+//! ```
+
+use crate::qiskit::{QiskitError, StandardGate};
+use crate::target::{InstructionProperties, Target};
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+/// The directed graph of the pairs of qubits two-qubit gates can act on.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CouplingMap {
+    edges: Vec<[u32; 2]>,
+    // The same edges, to look them up.
+    edge_set: HashSet<[u32; 2]>,
+    // The qubits each qubit is coupled to in either direction.
+    adjacency: Vec<BTreeSet<u32>>,
+}
+
+impl CouplingMap {
+    /// Create a coupling map of `num_qubits` qubits with no edges.
+    pub fn new(num_qubits: u32) -> CouplingMap {
+        CouplingMap {
+            edges: Vec::new(),
+            edge_set: HashSet::new(),
+            adjacency: vec![BTreeSet::new(); num_qubits as usize],
+        }
+    }
+
+    /// Create a coupling map from a list of directed edges, with as many
+    /// qubits as the largest qubit they use.
+    pub fn from_edges(edges: impl IntoIterator<Item = [u32; 2]>) -> CouplingMap {
+        let mut map = CouplingMap::default();
+        for [a, b] in edges {
+            map.add_edge(a, b);
+        }
+        map
+    }
+
+    /// Create a line of `num_qubits` qubits coupled in both directions.
+    pub fn line(num_qubits: u32) -> CouplingMap {
+        let mut map = CouplingMap::new(num_qubits);
+        for q in 1..num_qubits {
+            map.add_bidirectional_edge(q - 1, q);
+        }
+        map
+    }
+
+    /// Create a ring of `num_qubits` qubits coupled in both directions.
+    pub fn ring(num_qubits: u32) -> CouplingMap {
+        let mut map = CouplingMap::line(num_qubits);
+        if num_qubits > 2 {
+            map.add_bidirectional_edge(num_qubits - 1, 0);
+        }
+        map
+    }
+
+    /// Create a grid of `rows` by `columns` qubits coupled in both
+    /// directions, with qubit `row * columns + column` at each position.
+    pub fn grid(rows: u32, columns: u32) -> CouplingMap {
+        let mut map = CouplingMap::new(rows * columns);
+        for row in 0..rows {
+            for column in 0..columns {
+                let q = row * columns + column;
+                if column + 1 < columns {
+                    map.add_bidirectional_edge(q, q + 1);
+                }
+                if row + 1 < rows {
+                    map.add_bidirectional_edge(q, q + columns);
+                }
+            }
+        }
+        map
+    }
+
+    /// Create a heavy-hex lattice of code distance `distance`, coupled in
+    /// both directions.
+    ///
+    /// The lattice has `distance` rows of `2 * distance - 1` qubits, labelled
+    /// row by row, with the qubits bridging each row to the next labelled
+    /// after it. Like Qiskit's, it has `(5d² - 2d - 1) / 2` qubits for an odd
+    /// distance `d` and no qubit has more than three neighbours.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `distance` is even.
+    pub fn heavy_hex(distance: u32) -> CouplingMap {
+        assert!(distance % 2 == 1, "heavy-hex distance must be odd");
+        let row_len = 2 * distance - 1;
+        let mut map = CouplingMap::default();
+        let mut next = 0;
+        let mut previous_row: Option<u32> = None;
+        let mut bridges: Vec<(u32, u32)> = Vec::new();
+        for row in 0..distance {
+            let start = next;
+            for q in start..start + row_len {
+                map.add_qubit();
+                if q > start {
+                    map.add_bidirectional_edge(q - 1, q);
+                }
+            }
+            next += row_len;
+            if let Some(previous) = previous_row {
+                for (bridge, position) in bridges.drain(..) {
+                    map.add_bidirectional_edge(previous + position, bridge);
+                    map.add_bidirectional_edge(bridge, start + position);
+                }
+            }
+            previous_row = Some(start);
+            if row + 1 < distance {
+                // Bridges sit under every fourth qubit, offset by two on odd
+                // rows, which then need one more at the end to keep the
+                // lattice's qubit count.
+                let mut positions: Vec<u32> = (2 * (row % 2)..row_len).step_by(4).collect();
+                if row % 2 == 1 {
+                    positions.push(row_len - 1);
+                }
+                for position in positions {
+                    map.add_qubit();
+                    bridges.push((next, position));
+                    next += 1;
+                }
+            }
+        }
+        map
+    }
+
+    /// Return the number of qubits.
+    pub fn num_qubits(&self) -> u32 {
+        self.adjacency.len() as u32
+    }
+
+    /// Return the directed edges in the order they were added.
+    pub fn edges(&self) -> &[[u32; 2]] {
+        &self.edges
+    }
+
+    /// Add a qubit with no edges, returning its index.
+    pub fn add_qubit(&mut self) -> u32 {
+        self.adjacency.push(BTreeSet::new());
+        self.num_qubits() - 1
+    }
+
+    /// Add a directed edge from `a` to `b`, adding qubits up to the larger of
+    /// them if needed. Adding an edge twice has no effect.
+    pub fn add_edge(&mut self, a: u32, b: u32) {
+        let needed = a.max(b) as usize + 1;
+        if self.adjacency.len() < needed {
+            self.adjacency.resize(needed, BTreeSet::new());
+        }
+        if self.edge_set.insert([a, b]) {
+            self.edges.push([a, b]);
+            self.adjacency[a as usize].insert(b);
+            self.adjacency[b as usize].insert(a);
+        }
+    }
+
+    fn add_bidirectional_edge(&mut self, a: u32, b: u32) {
+        self.add_edge(a, b);
+        self.add_edge(b, a);
+    }
+
+    /// Return whether there is an edge from `a` to `b`.
+    pub fn has_edge(&self, a: u32, b: u32) -> bool {
+        self.edge_set.contains(&[a, b])
+    }
+
+    /// Return the qubits coupled to `qubit` in either direction, in
+    /// increasing order.
+    pub fn neighbors(&self, qubit: u32) -> Vec<u32> {
+        self.adjacency
+            .get(qubit as usize)
+            .map(|neighbors| neighbors.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    // The previous qubit on a shortest path from `start` to each qubit, with
+    // `start` its own previous qubit and `None` for unreachable qubits.
+    fn bfs(&self, start: u32) -> Vec<Option<u32>> {
+        let mut previous = vec![None; self.adjacency.len()];
+        if (start as usize) < previous.len() {
+            previous[start as usize] = Some(start);
+            let mut queue = VecDeque::from([start]);
+            while let Some(q) = queue.pop_front() {
+                for &neighbor in &self.adjacency[q as usize] {
+                    if previous[neighbor as usize].is_none() {
+                        previous[neighbor as usize] = Some(q);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+        previous
+    }
+
+    /// Return a shortest path from `a` to `b` including both ends, or `None`
+    /// if `b` can't be reached from `a`.
+    pub fn shortest_path(&self, a: u32, b: u32) -> Option<Vec<u32>> {
+        let previous = self.bfs(a);
+        previous.get(b as usize).copied().flatten()?;
+        let mut path = vec![b];
+        let mut q = b;
+        while q != a {
+            q = previous[q as usize]?;
+            path.push(q);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Return the number of edges on a shortest path from `a` to `b`, or
+    /// `None` if `b` can't be reached from `a`.
+    pub fn distance(&self, a: u32, b: u32) -> Option<u32> {
+        self.shortest_path(a, b).map(|path| path.len() as u32 - 1)
+    }
+
+    /// Return whether every qubit can be reached from every other, ignoring
+    /// the direction of the edges.
+    pub fn is_connected(&self) -> bool {
+        self.bfs(0).iter().all(Option::is_some)
+    }
+
+    /// Return the coupling map of the given qubits and the edges between
+    /// them, with `qubits[i]` relabelled as qubit `i`.
+    ///
+    /// Returns [`QiskitError::IndexError`] if a qubit is out of range and
+    /// [`QiskitError::CInputError`] if a qubit is given twice.
+    pub fn subgraph(&self, qubits: &[u32]) -> Result<CouplingMap, QiskitError> {
+        let mut index = vec![None; self.adjacency.len()];
+        for (i, q) in qubits.iter().enumerate() {
+            match index.get_mut(*q as usize) {
+                None => return Err(QiskitError::IndexError),
+                Some(Some(_)) => return Err(QiskitError::CInputError),
+                Some(slot) => *slot = Some(i as u32),
+            }
+        }
+        let mut map = CouplingMap::new(qubits.len() as u32);
+        for [a, b] in &self.edges {
+            if let (Some(a), Some(b)) = (index[*a as usize], index[*b as usize]) {
+                map.add_edge(a, b);
+            }
+        }
+        Ok(map)
+    }
+
+    /// Like [`subgraph`](CouplingMap::subgraph), but also returns
+    /// [`QiskitError::CInputError`] if the qubits aren't connected among
+    /// themselves, so circuits laid out on them can be routed.
+    pub fn reduce(&self, qubits: &[u32]) -> Result<CouplingMap, QiskitError> {
+        let map = self.subgraph(qubits)?;
+        if map.is_connected() {
+            Ok(map)
+        } else {
+            Err(QiskitError::CInputError)
+        }
+    }
+
+    /// Create a target with `two_qubit_gate` on every edge and each of
+    /// `single_qubit_gates` on every qubit, all ideal.
+    ///
+    /// Returns the error of the C API if the gates don't act on the
+    /// expected number of qubits.
+    pub fn to_target(
+        &self,
+        two_qubit_gate: StandardGate,
+        single_qubit_gates: &[StandardGate],
+    ) -> Result<Target, QiskitError> {
+        let mut target = Target::new(self.num_qubits());
+        for gate in single_qubit_gates {
+            target
+                .add_instruction(
+                    *gate,
+                    (0..self.num_qubits()).map(|q| ([q], InstructionProperties::default())),
+                )
+                .into_result()?;
+        }
+        if !self.edges.is_empty() {
+            target
+                .add_instruction(
+                    two_qubit_gate,
+                    self.edges
+                        .iter()
+                        .map(|edge| (*edge, InstructionProperties::default())),
+                )
+                .into_result()?;
+        }
+        Ok(target)
+    }
+}
+
+impl From<&Target> for CouplingMap {
+    /// The coupling map of the qubits the target's two-qubit operations act
+    /// on. Global operations add no edges.
+    fn from(target: &Target) -> CouplingMap {
+        let mut map = CouplingMap::new(target.num_qubits());
+        for operation in target.operations() {
+            for qargs in target.qargs(operation).unwrap_or_default() {
+                if let [a, b] = qargs {
+                    map.add_edge(*a, *b);
+                }
+            }
+        }
+        map
+    }
+}
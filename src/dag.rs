@@ -0,0 +1,580 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! The DAG representation of circuits.
+//!
+//! A [`DagCircuit`] has an input and an output node for every qubit and
+//! classical bit, and an operation node for every instruction. The nodes on
+//! each wire are connected in order, so two operations are connected when
+//! one directly follows the other on a shared wire. Nodes are identified by
+//! their `u32` index, which stays the same for the life of the DAG.
+//!
+//! # Example
+//!
+//! ```
+//! use qiskit_rs::QuantumCircuit;
+//! use qiskit_rs::dag::DagCircuit;
+//!
+//! let mut qc = QuantumCircuit::new(3, 0);
+//! qc.h(0);
+//! qc.cx(0, 1);
+//! qc.x(2);
+//! qc.cx(1, 2);
+//!
+//! let dag = DagCircuit::from_circuit(&qc).unwrap();
+//! assert_eq!(dag.layers().len(), 3);
+//! let names: Vec<String> = dag
+//!     .longest_path()
+//!     .into_iter()
+//!     .map(|node| dag.instruction(node).unwrap().name.to_string())
+//!     .collect();
+//! assert_eq!(names, ["h", "cx", "cx"]);
+//! ```
+
+use crate::parameter::{ParameterExpression, ParameterValue};
+use crate::qiskit::{
+    CircuitInstruction, ClassicalRegister, QiskitError, QuantumCircuit, QuantumRegister, Registers,
+    qk_to_qiskit_error,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+/// A qubit or classical bit of a [`DagCircuit`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Wire {
+    /// The qubit with the given index
+    Qubit(u32),
+    /// The classical bit with the given index
+    Clbit(u32),
+}
+
+/// The kind of a node in a [`DagCircuit`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DagNode {
+    /// An instruction
+    Operation,
+    /// The start of a wire
+    In(Wire),
+    /// The end of a wire
+    Out(Wire),
+}
+
+/// A circuit as a directed acyclic graph of its instructions.
+pub struct DagCircuit {
+    dag: *mut qiskit_sys::QkDag,
+    // Gate parameters that are not bound to a value, keyed by node index and
+    // parameter position. The C DAG holds NaN in their place.
+    symbolic_params: BTreeMap<(u32, usize), ParameterExpression>,
+    // The indices of all nodes, which the C API does not check.
+    nodes: HashSet<u32>,
+    // The names and bit ranges of the registers, which the C API does not
+    // expose, for the conversion back to a circuit.
+    qregs: Registers,
+    cregs: Registers,
+}
+
+impl DagCircuit {
+    /// Create a DAG with no instructions.
+    ///
+    /// # Panics
+    ///
+    /// With the `runtime-linking` feature, panics if libqiskit wasn't loaded
+    /// with `qiskit_rs::init` and the default library can't be loaded.
+    pub fn new(num_qubits: u32, num_clbits: u32) -> DagCircuit {
+        let dag = unsafe { qiskit_sys::qk!(qk_dag_new()) };
+        let mut qregs = Vec::new();
+        let mut cregs = Vec::new();
+        if num_qubits > 0 {
+            let qreg = QuantumRegister::new(num_qubits, "q");
+            unsafe { qiskit_sys::qk!(qk_dag_add_quantum_register(dag, qreg.register)) };
+            qregs.push((qreg.name().to_string(), 0..num_qubits));
+        }
+        if num_clbits > 0 {
+            let creg = ClassicalRegister::new(num_clbits, "c");
+            unsafe { qiskit_sys::qk!(qk_dag_add_classical_register(dag, creg.register)) };
+            cregs.push((creg.name().to_string(), 0..num_clbits));
+        }
+        DagCircuit::from_qk(dag, qregs, cregs)
+    }
+
+    // Wrap a C DAG without symbolic parameters.
+    fn from_qk(dag: *mut qiskit_sys::QkDag, qregs: Registers, cregs: Registers) -> DagCircuit {
+        let mut out = DagCircuit {
+            dag,
+            symbolic_params: BTreeMap::new(),
+            nodes: HashSet::new(),
+            qregs,
+            cregs,
+        };
+        let wires: Vec<Wire> = (0..out.num_qubits())
+            .map(Wire::Qubit)
+            .chain((0..out.num_clbits()).map(Wire::Clbit))
+            .collect();
+        for wire in wires {
+            out.nodes.insert(out.in_node(wire));
+            out.nodes.insert(out.out_node(wire));
+        }
+        out.nodes.extend(out.op_nodes());
+        out
+    }
+
+    /// Convert a circuit into a DAG, keeping its registers.
+    ///
+    /// Returns [`QiskitError::UnsupportedInstruction`] for circuits with
+    /// control flow.
+    pub fn from_circuit(circuit: &QuantumCircuit) -> Result<DagCircuit, QiskitError> {
+        let qc = circuit.as_qk_symbolic()?;
+        let (qregs, cregs) = circuit.registers();
+        let mut dag = DagCircuit::from_qk(
+            unsafe { qiskit_sys::qk!(qk_circuit_to_dag(qc)) },
+            qregs.clone(),
+            cregs.clone(),
+        );
+        // Find the node of every instruction by walking its wires in the
+        // order of the instructions: it is the next node on each of them.
+        // Operations without wires, like global phases, aren't connected to
+        // anything and keep their order among themselves.
+        let mut wireless = dag
+            .op_nodes()
+            .into_iter()
+            .filter(|node| dag.wires(*node).is_empty());
+        let mut cursors: HashMap<Wire, u32> = HashMap::new();
+        let mut nodes = Vec::with_capacity(circuit.num_instructions());
+        for inst in circuit.instructions() {
+            let wires = inst_wires(&inst);
+            let node = match wires.first() {
+                Some(wire) => {
+                    let cursor = cursors.get(wire).copied();
+                    let cursor = cursor.unwrap_or_else(|| dag.in_node(*wire));
+                    dag.successor_on_wire(cursor, *wire)
+                }
+                None => wireless.next(),
+            };
+            let node = node.expect("the DAG has a node for every instruction");
+            for wire in wires {
+                cursors.insert(wire, node);
+            }
+            nodes.push((node, inst.param_values()));
+        }
+        for (node, params) in nodes {
+            for (i, param) in params.into_iter().enumerate() {
+                if let ParameterValue::Expression(expr) = param {
+                    dag.symbolic_params.insert((node, i), expr);
+                }
+            }
+        }
+        Ok(dag)
+    }
+
+    /// Convert the DAG back into a circuit, with the instructions in
+    /// topological order and the registers of the DAG.
+    pub fn to_circuit(&self) -> QuantumCircuit {
+        let qc = unsafe { qiskit_sys::qk!(qk_dag_to_circuit(self.dag)) };
+        let mut symbolic_params = BTreeMap::new();
+        for (index, node) in self.op_nodes().into_iter().enumerate() {
+            for ((_, i), expr) in self.symbolic_params.range((node, 0)..(node + 1, 0)) {
+                symbolic_params.insert((index, *i), expr.clone());
+            }
+        }
+        unsafe {
+            QuantumCircuit::from_qk(qc, symbolic_params, self.qregs.clone(), self.cregs.clone())
+        }
+    }
+
+    /// Return the number of qubits in the DAG.
+    pub fn num_qubits(&self) -> u32 {
+        unsafe { qiskit_sys::qk!(qk_dag_num_qubits(self.dag)) }
+    }
+    /// Return the number of classical bits in the DAG.
+    pub fn num_clbits(&self) -> u32 {
+        unsafe { qiskit_sys::qk!(qk_dag_num_clbits(self.dag)) }
+    }
+    /// Return the number of operation nodes in the DAG.
+    pub fn num_op_nodes(&self) -> usize {
+        unsafe { qiskit_sys::qk!(qk_dag_num_op_nodes(self.dag)) }
+    }
+
+    /// Return the kind of a node, or `None` if it isn't in the DAG.
+    pub fn node(&self, node: u32) -> Option<DagNode> {
+        if !self.nodes.contains(&node) {
+            return None;
+        }
+        use qiskit_sys::{
+            QkDagNodeType_QkDagNodeType_ClbitIn as CLBIT_IN,
+            QkDagNodeType_QkDagNodeType_ClbitOut as CLBIT_OUT,
+            QkDagNodeType_QkDagNodeType_QubitIn as QUBIT_IN,
+            QkDagNodeType_QkDagNodeType_QubitOut as QUBIT_OUT,
+        };
+        let index = || unsafe { qiskit_sys::qk!(qk_dag_wire_node_value(self.dag, node)) };
+        Some(
+            match unsafe { qiskit_sys::qk!(qk_dag_node_type(self.dag, node)) } {
+                QUBIT_IN => DagNode::In(Wire::Qubit(index())),
+                QUBIT_OUT => DagNode::Out(Wire::Qubit(index())),
+                CLBIT_IN => DagNode::In(Wire::Clbit(index())),
+                CLBIT_OUT => DagNode::Out(Wire::Clbit(index())),
+                _ => DagNode::Operation,
+            },
+        )
+    }
+
+    /// Return the input node of a wire.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the wire isn't in the DAG.
+    pub fn in_node(&self, wire: Wire) -> u32 {
+        self.check_wire(wire);
+        match wire {
+            Wire::Qubit(qubit) => unsafe { qiskit_sys::qk!(qk_dag_qubit_in_node(self.dag, qubit)) },
+            Wire::Clbit(clbit) => unsafe { qiskit_sys::qk!(qk_dag_clbit_in_node(self.dag, clbit)) },
+        }
+    }
+    /// Return the output node of a wire.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the wire isn't in the DAG.
+    pub fn out_node(&self, wire: Wire) -> u32 {
+        self.check_wire(wire);
+        match wire {
+            Wire::Qubit(qubit) => unsafe {
+                qiskit_sys::qk!(qk_dag_qubit_out_node(self.dag, qubit))
+            },
+            Wire::Clbit(clbit) => unsafe {
+                qiskit_sys::qk!(qk_dag_clbit_out_node(self.dag, clbit))
+            },
+        }
+    }
+
+    /// Return the operation nodes in topological order.
+    ///
+    /// For a DAG converted from a circuit, this is the order of the
+    /// circuit's instructions.
+    pub fn op_nodes(&self) -> Vec<u32> {
+        let mut order = vec![0; self.num_op_nodes()];
+        unsafe { qiskit_sys::qk!(qk_dag_topological_op_nodes(self.dag, order.as_mut_ptr())) };
+        order
+    }
+
+    /// Return the instruction of an operation node, or `None` if the node
+    /// isn't an operation node.
+    pub fn instruction(&self, node: u32) -> Option<CircuitInstruction<'_>> {
+        match self.node(node) {
+            Some(DagNode::Operation) => Some(self.op_instruction(node)),
+            _ => None,
+        }
+    }
+
+    // The instruction of a node that is known to be an operation node.
+    fn op_instruction(&self, node: u32) -> CircuitInstruction<'_> {
+        let mut inst = CircuitInstruction::empty_qk();
+        unsafe { qiskit_sys::qk!(qk_dag_get_instruction(self.dag, node, &mut inst)) };
+        let symbolic = self
+            .symbolic_params
+            .range((node, 0)..(node + 1, 0))
+            .map(|((_, i), expr)| (*i, expr))
+            .collect();
+        unsafe { CircuitInstruction::from_qk(inst, symbolic) }
+    }
+
+    /// Return the nodes with an edge into `node`, including input nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node isn't in the DAG.
+    pub fn predecessors(&self, node: u32) -> Vec<u32> {
+        self.neighbors(qiskit_sys::qk!(qk_dag_predecessors), node)
+    }
+    /// Return the nodes with an edge from `node`, including output nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node isn't in the DAG.
+    pub fn successors(&self, node: u32) -> Vec<u32> {
+        self.neighbors(qiskit_sys::qk!(qk_dag_successors), node)
+    }
+
+    fn neighbors(
+        &self,
+        query: unsafe extern "C" fn(*const qiskit_sys::QkDag, u32) -> qiskit_sys::QkDagNeighbors,
+        node: u32,
+    ) -> Vec<u32> {
+        self.check_node(node);
+        unsafe {
+            let mut neighbors = query(self.dag, node);
+            let out = match neighbors.num_neighbors {
+                0 => Vec::new(),
+                len => std::slice::from_raw_parts(neighbors.neighbors, len).to_vec(),
+            };
+            qiskit_sys::qk!(qk_dag_neighbors_clear(&mut neighbors));
+            out
+        }
+    }
+
+    /// Return the operation nodes on a wire, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the wire isn't in the DAG.
+    pub fn nodes_on_wire(&self, wire: Wire) -> Vec<u32> {
+        let out = self.out_node(wire);
+        let mut nodes = Vec::new();
+        let mut node = self.in_node(wire);
+        while let Some(next) = self.successor_on_wire(node, wire) {
+            if next == out {
+                break;
+            }
+            nodes.push(next);
+            node = next;
+        }
+        nodes
+    }
+
+    /// Return the node before `node` on `wire`, which is the input node if
+    /// `node` is the first operation on the wire. Returns `None` if `node`
+    /// doesn't act on `wire` or is its input node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node or the wire isn't in the DAG.
+    pub fn predecessor_on_wire(&self, node: u32, wire: Wire) -> Option<u32> {
+        self.check_wire(wire);
+        if !self.wires(node).contains(&wire) {
+            return None;
+        }
+        self.adjacent_on_wire(self.on_wire(self.predecessors(node), wire), false)
+    }
+    /// Return the node after `node` on `wire`, which is the output node if
+    /// `node` is the last operation on the wire. Returns `None` if `node`
+    /// doesn't act on `wire` or is its output node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node or the wire isn't in the DAG.
+    pub fn successor_on_wire(&self, node: u32, wire: Wire) -> Option<u32> {
+        self.check_wire(wire);
+        if !self.wires(node).contains(&wire) {
+            return None;
+        }
+        self.adjacent_on_wire(self.on_wire(self.successors(node), wire), true)
+    }
+
+    // Of the neighbors of a node that are on a wire, the one next to it on
+    // the wire. A neighbor connected through another wire of the node can be
+    // on the wire too, further along it, so the one next to it comes first
+    // in topological order among successors and last among predecessors.
+    fn adjacent_on_wire(&self, neighbors: Vec<u32>, first: bool) -> Option<u32> {
+        if neighbors.len() < 2 {
+            return neighbors.first().copied();
+        }
+        let order = self.op_nodes();
+        let position = |node: &u32| order.iter().position(|n| n == node);
+        match first {
+            true => neighbors.into_iter().min_by_key(position),
+            false => neighbors.into_iter().max_by_key(position),
+        }
+    }
+
+    // The distinct nodes of `nodes` that are on `wire`.
+    fn on_wire(&self, mut nodes: Vec<u32>, wire: Wire) -> Vec<u32> {
+        nodes.sort_unstable();
+        nodes.dedup();
+        nodes.retain(|node| self.wires(*node).contains(&wire));
+        nodes
+    }
+
+    // The wires a node is on.
+    fn wires(&self, node: u32) -> Vec<Wire> {
+        match self.node(node) {
+            Some(DagNode::Operation) => inst_wires(&self.op_instruction(node)),
+            Some(DagNode::In(wire)) | Some(DagNode::Out(wire)) => vec![wire],
+            None => Vec::new(),
+        }
+    }
+
+    /// Return the operation nodes that only follow input nodes, in
+    /// topological order.
+    pub fn front_layer(&self) -> Vec<u32> {
+        self.op_nodes()
+            .into_iter()
+            .filter(|node| !self.has_op_predecessor(*node))
+            .collect()
+    }
+
+    fn has_op_predecessor(&self, node: u32) -> bool {
+        self.predecessors(node)
+            .into_iter()
+            .any(|pred| self.node(pred) == Some(DagNode::Operation))
+    }
+
+    /// Return the operation nodes grouped into layers that can run in
+    /// parallel.
+    ///
+    /// Every node is in the layer after the last layer of its predecessors,
+    /// so the number of layers is the depth of the circuit.
+    pub fn layers(&self) -> Vec<Vec<u32>> {
+        let mut layers: Vec<Vec<u32>> = Vec::new();
+        for (node, layer) in self.op_depths() {
+            if layer == layers.len() {
+                layers.push(Vec::new());
+            }
+            layers[layer].push(node);
+        }
+        layers
+    }
+
+    /// Return the operation nodes of a longest path through the DAG, in
+    /// order.
+    pub fn longest_path(&self) -> Vec<u32> {
+        let depths = self.op_depths();
+        let depth: HashMap<u32, usize> = depths.iter().copied().collect();
+        let Some(last) = depths.iter().map(|(_, layer)| *layer).max() else {
+            return Vec::new();
+        };
+        let mut node = depths.iter().find(|(_, layer)| *layer == last).unwrap().0;
+        let mut path = vec![node];
+        while depth[&node] > 0 {
+            node = self
+                .predecessors(node)
+                .into_iter()
+                .filter(|pred| depth.get(pred) == Some(&(depth[&node] - 1)))
+                .min()
+                .expect("a node past the first layer has a predecessor in the layer before");
+            path.push(node);
+        }
+        path.reverse();
+        path
+    }
+
+    // The layer of every operation node, in topological order.
+    fn op_depths(&self) -> Vec<(u32, usize)> {
+        let mut depth: HashMap<u32, usize> = HashMap::new();
+        let mut out = Vec::new();
+        for node in self.op_nodes() {
+            let layer = self
+                .predecessors(node)
+                .iter()
+                .filter_map(|pred| depth.get(pred).map(|layer| layer + 1))
+                .max()
+                .unwrap_or(0);
+            depth.insert(node, layer);
+            out.push((node, layer));
+        }
+        out
+    }
+
+    /// Replace an operation node with the operations of another DAG.
+    ///
+    /// Qubit `i` of `replacement` is mapped to the `i`-th qubit of the node,
+    /// and likewise for classical bits. Returns the new nodes, in the
+    /// topological order of `replacement`. Returns
+    /// [`QiskitError::IndexError`] if `node` isn't an operation node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::QuantumCircuit;
+    /// use qiskit_rs::dag::DagCircuit;
+    ///
+    /// let mut qc = QuantumCircuit::new(2, 0);
+    /// qc.cx(0, 1);
+    /// let mut dag = DagCircuit::from_circuit(&qc).unwrap();
+    ///
+    /// let mut cx = QuantumCircuit::new(2, 0);
+    /// cx.h(1);
+    /// cx.cz(0, 1);
+    /// cx.h(1);
+    /// let cx = DagCircuit::from_circuit(&cx).unwrap();
+    ///
+    /// let node = dag.op_nodes()[0];
+    /// let new_nodes = dag.substitute_node(node, &cx).unwrap();
+    /// assert_eq!(new_nodes.len(), 3);
+    /// assert_eq!(dag.num_op_nodes(), 3);
+    /// ```
+    pub fn substitute_node(
+        &mut self,
+        node: u32,
+        replacement: &DagCircuit,
+    ) -> Result<Vec<u32>, QiskitError> {
+        let Some(inst) = self.instruction(node) else {
+            return Err(QiskitError::IndexError);
+        };
+        if inst.qubits.len() != replacement.num_qubits() as usize {
+            return Err(QiskitError::MismatchedQubits);
+        }
+        if inst.clbits.len() != replacement.num_clbits() as usize {
+            return Err(QiskitError::MismatchedClbits);
+        }
+        drop(inst);
+        let old_nodes = replacement.op_nodes();
+        let mut new_nodes = vec![0; old_nodes.len()];
+        qk_to_qiskit_error(unsafe {
+            qiskit_sys::qk!(qk_dag_substitute_node_with_dag(
+                self.dag,
+                node,
+                replacement.dag,
+                new_nodes.as_mut_ptr(),
+            ))
+        })
+        .into_result()?;
+        self.nodes.remove(&node);
+        self.symbolic_params.retain(|(n, _), _| *n != node);
+        for (old, new) in old_nodes.iter().zip(&new_nodes) {
+            self.nodes.insert(*new);
+            for ((_, i), expr) in replacement.symbolic_params.range((*old, 0)..(*old + 1, 0)) {
+                self.symbolic_params.insert((*new, *i), expr.clone());
+            }
+        }
+        Ok(new_nodes)
+    }
+
+    fn check_node(&self, node: u32) {
+        assert!(self.nodes.contains(&node), "node {node} is not in the DAG");
+    }
+
+    fn check_wire(&self, wire: Wire) {
+        let exists = match wire {
+            Wire::Qubit(qubit) => qubit < self.num_qubits(),
+            Wire::Clbit(clbit) => clbit < self.num_clbits(),
+        };
+        assert!(exists, "{wire:?} is not in the DAG");
+    }
+}
+
+// The wires an instruction acts on, qubits first.
+fn inst_wires(inst: &CircuitInstruction) -> Vec<Wire> {
+    let qubits = inst.qubits.iter().map(|q| Wire::Qubit(*q));
+    qubits
+        .chain(inst.clbits.iter().map(|c| Wire::Clbit(*c)))
+        .collect()
+}
+
+impl fmt::Debug for DagCircuit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nodes: Vec<_> = self
+            .op_nodes()
+            .into_iter()
+            .map(|node| (node, self.op_instruction(node)))
+            .collect();
+        f.debug_struct("DagCircuit")
+            .field("num_qubits", &self.num_qubits())
+            .field("num_clbits", &self.num_clbits())
+            .field("op_nodes", &nodes)
+            .finish()
+    }
+}
+
+impl Drop for DagCircuit {
+    fn drop(&mut self) {
+        unsafe { qiskit_sys::qk!(qk_dag_free(self.dag)) };
+    }
+}
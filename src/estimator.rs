@@ -0,0 +1,327 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! Estimating expectation values of observables from measurement counts.
+//!
+//! An [`Estimator`] splits an observable into groups of qubit-wise
+//! commuting terms, which can all be measured at once after rotating each
+//! qubit into a shared basis. It builds one measurement circuit per group
+//! and combines the counts measured for each of them into an expectation
+//! value with its standard error.
+//!
+//! # Example
+//!
+//! ```
+//! use qiskit_rs::estimator::Estimator;
+//! use qiskit_rs::{Complex64, QuantumCircuit, SparseObservable};
+//! use std::collections::HashMap;
+//!
+//! let mut bell = QuantumCircuit::new(2, 0);
+//! bell.h(0);
+//! bell.cx(0, 1);
+//! let obs = SparseObservable::from_sparse_list(
+//!     &[("ZZ", &[0, 1], Complex64::ONE), ("XX", &[0, 1], Complex64::ONE)],
+//!     2,
+//! )
+//! .unwrap();
+//!
+//! let estimator = Estimator::new(&bell, &obs).unwrap();
+//! assert_eq!(estimator.num_groups(), 2);
+//! // A Bell state only ever gives equal bits, in both bases.
+//! let counts: Vec<HashMap<String, u64>> = (0..2)
+//!     .map(|_| HashMap::from([("00".to_string(), 510), ("11".to_string(), 490)]))
+//!     .collect();
+//! let estimate = estimator.estimate(&counts).unwrap();
+//! assert_eq!(estimate.value, 2.0);
+//! assert_eq!(estimate.std_error, 0.0);
+//! ```
+
+use crate::qiskit::{QiskitError, QuantumCircuit};
+use crate::sparse_observable::{BitTerm, SparseObservable, SparseTerm};
+use std::collections::HashMap;
+
+/// The Pauli basis a qubit is measured in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MeasurementBasis {
+    /// The X basis, measured after a Hadamard gate.
+    X,
+    /// The Y basis, measured after `sdg` and Hadamard gates.
+    Y,
+    /// The computational basis.
+    Z,
+}
+
+impl MeasurementBasis {
+    /// Return the basis a bit term is diagonal in.
+    pub fn of(bit_term: BitTerm) -> MeasurementBasis {
+        match bit_term {
+            BitTerm::X | BitTerm::Plus | BitTerm::Minus => MeasurementBasis::X,
+            BitTerm::Y | BitTerm::Right | BitTerm::Left => MeasurementBasis::Y,
+            BitTerm::Z | BitTerm::Zero | BitTerm::One => MeasurementBasis::Z,
+        }
+    }
+}
+
+// The eigenvalue of a bit term for a measured bit in its basis.
+fn eigenvalue(bit_term: BitTerm, bit: bool) -> f64 {
+    match bit_term {
+        BitTerm::X | BitTerm::Y | BitTerm::Z => {
+            if bit {
+                -1.0
+            } else {
+                1.0
+            }
+        }
+        BitTerm::Plus | BitTerm::Right | BitTerm::Zero => f64::from(u8::from(!bit)),
+        BitTerm::Minus | BitTerm::Left | BitTerm::One => f64::from(u8::from(bit)),
+    }
+}
+
+/// An estimate of an expectation value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Estimate {
+    /// The estimated expectation value
+    pub value: f64,
+    /// The standard error of the estimate
+    pub std_error: f64,
+}
+
+/// A set of qubit-wise commuting terms measured with a single circuit.
+#[derive(Clone, Debug)]
+pub struct MeasurementGroup {
+    circuit: QuantumCircuit,
+    bases: Vec<(u32, MeasurementBasis)>,
+    terms: Vec<SparseTerm>,
+    clbit_offset: u32,
+}
+
+impl MeasurementGroup {
+    /// Return the circuit to run, which measures the qubits of
+    /// [`bases`](MeasurementGroup::bases) in order into the classical bits
+    /// after those of the original circuit.
+    pub fn circuit(&self) -> &QuantumCircuit {
+        &self.circuit
+    }
+
+    /// Return the measured qubits and the basis each is measured in.
+    pub fn bases(&self) -> &[(u32, MeasurementBasis)] {
+        &self.bases
+    }
+
+    /// Return the terms of the observable measured by the group.
+    pub fn terms(&self) -> &[SparseTerm] {
+        &self.terms
+    }
+
+    /// Estimate the expectation value of the group's terms from the counts
+    /// of its circuit.
+    ///
+    /// Counts are keyed by bitstrings with the last classical bit first, as
+    /// Qiskit prints them; spaces between registers are ignored. Returns
+    /// [`QiskitError::CInputError`] for empty counts or bitstrings that
+    /// don't match the circuit's classical bits.
+    pub fn estimate(&self, counts: &HashMap<String, u64>) -> Result<Estimate, QiskitError> {
+        let num_clbits = self.circuit.num_clbits() as usize;
+        // The position in `bases` of each qubit of each term.
+        let positions: Vec<Vec<usize>> = self
+            .terms
+            .iter()
+            .map(|term| {
+                term.indices
+                    .iter()
+                    .map(|q| self.bases.iter().position(|(b, _)| b == q).unwrap())
+                    .collect()
+            })
+            .collect();
+        let mut samples = Vec::with_capacity(counts.len());
+        for (bitstring, count) in counts {
+            let bits: Vec<bool> = bitstring
+                .chars()
+                .filter(|c| *c != ' ')
+                .rev()
+                .map(|c| match c {
+                    '0' => Ok(false),
+                    '1' => Ok(true),
+                    _ => Err(QiskitError::CInputError),
+                })
+                .collect::<Result<_, _>>()?;
+            if bits.len() != num_clbits {
+                return Err(QiskitError::CInputError);
+            }
+            let measured = &bits[self.clbit_offset as usize..];
+            let value: f64 = self
+                .terms
+                .iter()
+                .zip(&positions)
+                .map(|(term, positions)| {
+                    term.bit_terms
+                        .iter()
+                        .zip(positions)
+                        .map(|(bit_term, position)| eigenvalue(*bit_term, measured[*position]))
+                        .product::<f64>()
+                        * term.coeff.re
+                })
+                .sum();
+            samples.push((value, *count as f64));
+        }
+        let shots: f64 = samples.iter().map(|(_, count)| count).sum();
+        if shots == 0.0 {
+            return Err(QiskitError::CInputError);
+        }
+        let mean = samples
+            .iter()
+            .map(|(value, count)| value * count)
+            .sum::<f64>()
+            / shots;
+        let variance = samples
+            .iter()
+            .map(|(value, count)| (value - mean).powi(2) * count)
+            .sum::<f64>()
+            / shots;
+        Ok(Estimate {
+            value: mean,
+            std_error: (variance / shots).sqrt(),
+        })
+    }
+}
+
+/// The measurement circuits for the expectation value of an observable in
+/// the state a circuit prepares.
+#[derive(Clone, Debug)]
+pub struct Estimator {
+    groups: Vec<MeasurementGroup>,
+    // The coefficient of the identity terms, which need no measurement.
+    constant: f64,
+}
+
+impl Estimator {
+    /// Group the terms of `observable` and build a measurement circuit for
+    /// each group, starting from `circuit`.
+    ///
+    /// Terms are grouped as by [`SparseObservable::group_commuting`] with
+    /// qubit-wise commutation. Returns [`QiskitError::MismatchedQubits`]
+    /// if the observable and circuit have different numbers of qubits and
+    /// [`QiskitError::CInputError`] if the observable has complex
+    /// coefficients.
+    pub fn new(
+        circuit: &QuantumCircuit,
+        observable: &SparseObservable,
+    ) -> Result<Estimator, QiskitError> {
+        if observable.num_qubits() != circuit.num_qubits() {
+            return Err(QiskitError::MismatchedQubits);
+        }
+        let mut constant = 0.0;
+        let mut measured = SparseObservable::zero(observable.num_qubits());
+        for term in observable.terms() {
+            if term.coeff.im != 0.0 {
+                return Err(QiskitError::CInputError);
+            }
+            if term.indices.is_empty() {
+                constant += term.coeff.re;
+            } else {
+                measured.add_term(&term).into_result()?;
+            }
+        }
+        let groups = measured
+            .group_commuting(true)
+            .into_iter()
+            .map(|group| {
+                let terms: Vec<SparseTerm> = group.terms().collect();
+                let mut bases: Vec<(u32, MeasurementBasis)> = terms
+                    .iter()
+                    .flat_map(|term| {
+                        term.indices
+                            .iter()
+                            .zip(&term.bit_terms)
+                            .map(|(q, bit_term)| (*q, MeasurementBasis::of(*bit_term)))
+                    })
+                    .collect();
+                bases.sort();
+                bases.dedup();
+                measurement_group(circuit, bases, terms)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Estimator { groups, constant })
+    }
+
+    /// Return the number of measurement groups.
+    pub fn num_groups(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Return the measurement groups in the order their counts are expected.
+    pub fn groups(&self) -> &[MeasurementGroup] {
+        &self.groups
+    }
+
+    /// Return the measurement circuit of each group.
+    pub fn circuits(&self) -> Vec<&QuantumCircuit> {
+        self.groups.iter().map(MeasurementGroup::circuit).collect()
+    }
+
+    /// Combine the counts of each group's circuit into an estimate of the
+    /// expectation value.
+    ///
+    /// The groups are measured independently, so their variances add.
+    /// Returns [`QiskitError::CInputError`] if there isn't one set of counts
+    /// per group or any of them is invalid.
+    pub fn estimate(&self, counts: &[HashMap<String, u64>]) -> Result<Estimate, QiskitError> {
+        if counts.len() != self.groups.len() {
+            return Err(QiskitError::CInputError);
+        }
+        let mut estimate = Estimate {
+            value: self.constant,
+            std_error: 0.0,
+        };
+        let mut variance = 0.0;
+        for (group, counts) in self.groups.iter().zip(counts) {
+            let group_estimate = group.estimate(counts)?;
+            estimate.value += group_estimate.value;
+            variance += group_estimate.std_error.powi(2);
+        }
+        estimate.std_error = variance.sqrt();
+        Ok(estimate)
+    }
+}
+
+fn measurement_group(
+    circuit: &QuantumCircuit,
+    bases: Vec<(u32, MeasurementBasis)>,
+    terms: Vec<SparseTerm>,
+) -> Result<MeasurementGroup, QiskitError> {
+    let clbit_offset = circuit.num_clbits();
+    let mut qc = QuantumCircuit::new(circuit.num_qubits(), clbit_offset + bases.len() as u32);
+    let qubits: Vec<u32> = (0..circuit.num_qubits()).collect();
+    let clbits: Vec<u32> = (0..clbit_offset).collect();
+    qc.compose(circuit, &qubits, &clbits).into_result()?;
+    for (q, basis) in &bases {
+        match basis {
+            MeasurementBasis::X => {
+                qc.h(*q);
+            }
+            MeasurementBasis::Y => {
+                qc.sdg(*q);
+                qc.h(*q);
+            }
+            MeasurementBasis::Z => {}
+        }
+    }
+    for (clbit, (q, _)) in (clbit_offset..).zip(&bases) {
+        qc.measure(*q, clbit);
+    }
+    Ok(MeasurementGroup {
+        circuit: qc,
+        bases,
+        terms,
+        clbit_offset,
+    })
+}
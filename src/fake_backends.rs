@@ -0,0 +1,207 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! Synthetic device targets for testing and benchmarking.
+//!
+//! Each function builds a [`Target`] modelled on a kind of device, with gate
+//! errors and durations drawn around typical values from a seed. The same
+//! seed always gives the same target, so results built on them are
+//! reproducible without access to real devices.
+//!
+//! # Example
+//!
+//! ```
+//! use qiskit_rs::fake_backends;
+//! use qiskit_rs::transpiler::{BasisTranslator, PassManager};
+//! use qiskit_rs::QuantumCircuit;
+//!
+//! let target = fake_backends::line_5q(1234);
+//! let mut qc = QuantumCircuit::new(5, 0);
+//! qc.h(0);
+//! qc.cx(0, 1);
+//!
+//! let mut pm = PassManager::new();
+//! pm.append(BasisTranslator::default());
+//! pm.run(&mut qc, &target).unwrap();
+//! assert!(qc.instructions().all(|inst| ["rz", "sx", "x", "cx"].contains(&inst.name)));
+//! ```
+
+use crate::coupling_map::CouplingMap;
+use crate::qiskit::StandardGate;
+use crate::rng::Rng;
+use crate::target::{InstructionProperties, Target, TargetOperation};
+
+// The couplings of IBM's 27-qubit Falcon devices.
+const FALCON_EDGES: [[u32; 2]; 28] = [
+    [0, 1],
+    [1, 2],
+    [1, 4],
+    [2, 3],
+    [3, 5],
+    [4, 7],
+    [5, 8],
+    [6, 7],
+    [7, 10],
+    [8, 9],
+    [8, 11],
+    [10, 12],
+    [11, 14],
+    [12, 13],
+    [12, 15],
+    [13, 14],
+    [14, 16],
+    [15, 18],
+    [16, 19],
+    [17, 18],
+    [18, 21],
+    [19, 20],
+    [19, 22],
+    [21, 23],
+    [22, 25],
+    [23, 24],
+    [24, 25],
+    [25, 26],
+];
+
+// The sample time of IBM's devices, in seconds.
+const IBM_DT: f64 = 2.0 / 9.0 * 1e-9;
+
+impl Rng {
+    // Properties within a factor of two of the given ones.
+    fn properties(&mut self, duration: f64, error: f64) -> InstructionProperties {
+        let mut spread = |value: f64| value * 2f64.powf(2.0 * self.next_f64() - 1.0);
+        InstructionProperties::new(spread(duration), spread(error))
+    }
+}
+
+// The instructions of the fake backends are built to be valid, so a failure to add one is a
+// bug here.
+fn add_properties<Q: AsRef<[u32]>>(
+    target: &mut Target,
+    operation: impl Into<TargetOperation>,
+    properties: impl IntoIterator<Item = (Q, InstructionProperties)>,
+) {
+    target
+        .add_instruction(operation, properties)
+        .into_result()
+        .expect("the instructions of a fake backend are valid");
+}
+
+fn add_instruction(
+    target: &mut Target,
+    rng: &mut Rng,
+    operation: impl Into<TargetOperation>,
+    qargs: impl IntoIterator<Item = Vec<u32>>,
+    duration: f64,
+    error: f64,
+) {
+    let properties: Vec<_> = qargs
+        .into_iter()
+        .map(|qargs| (qargs, rng.properties(duration, error)))
+        .collect();
+    add_properties(target, operation, properties);
+}
+
+// Virtual rz gates, which take no time and have no error.
+fn add_rz(target: &mut Target) {
+    let ideal = InstructionProperties::new(0.0, 0.0);
+    let properties: Vec<_> = (0..target.num_qubits()).map(|q| ([q], ideal)).collect();
+    add_properties(target, StandardGate::RZ, properties);
+}
+
+fn qubits(num_qubits: u32) -> impl Iterator<Item = Vec<u32>> {
+    (0..num_qubits).map(|q| vec![q])
+}
+
+fn superconducting(map: &CouplingMap, two_qubit_gate: StandardGate, seed: u64) -> Target {
+    let mut rng = Rng(seed);
+    let mut target = Target::new(map.num_qubits());
+    target.set_dt(IBM_DT);
+    add_rz(&mut target);
+    for gate in [StandardGate::SX, StandardGate::X] {
+        add_instruction(
+            &mut target,
+            &mut rng,
+            gate,
+            qubits(map.num_qubits()),
+            3.55e-8,
+            3e-4,
+        );
+    }
+    let edges = map.edges().iter().map(|edge| edge.to_vec());
+    add_instruction(&mut target, &mut rng, two_qubit_gate, edges, 5e-7, 8e-3);
+    add_instruction(
+        &mut target,
+        &mut rng,
+        TargetOperation::Measure,
+        qubits(map.num_qubits()),
+        1.5e-6,
+        1.5e-2,
+    );
+    target
+}
+
+/// A 5-qubit line with `cx` in both directions between neighbours and `rz`,
+/// `sx` and `x` on every qubit.
+pub fn line_5q(seed: u64) -> Target {
+    superconducting(&CouplingMap::line(5), StandardGate::CX, seed)
+}
+
+/// A 27-qubit heavy-hex device like IBM's Falcon processors, with `ecr` in
+/// one direction on each coupling and `rz`, `sx` and `x` on every qubit.
+pub fn heavy_hex_27q(seed: u64) -> Target {
+    superconducting(
+        &CouplingMap::from_edges(FALCON_EDGES),
+        StandardGate::ECR,
+        seed,
+    )
+}
+
+/// A trapped-ion device of `num_qubits` qubits, with `rxx` between every
+/// pair of qubits in both directions, and `rz` and `rx` on every qubit.
+///
+/// Its gates are much slower but more accurate than those of the
+/// superconducting devices.
+pub fn ion_trap(num_qubits: u32, seed: u64) -> Target {
+    let mut rng = Rng(seed);
+    let mut target = Target::new(num_qubits);
+    add_rz(&mut target);
+    add_instruction(
+        &mut target,
+        &mut rng,
+        StandardGate::RX,
+        qubits(num_qubits),
+        1e-5,
+        5e-4,
+    );
+    // Both directions of a pair share their properties.
+    let mut rxx = Vec::new();
+    for a in 0..num_qubits {
+        for b in a + 1..num_qubits {
+            let properties = rng.properties(2e-4, 4e-3);
+            rxx.push((vec![a, b], properties));
+            rxx.push((vec![b, a], properties));
+        }
+    }
+    if !rxx.is_empty() {
+        add_properties(&mut target, StandardGate::RXX, rxx);
+    }
+    add_instruction(
+        &mut target,
+        &mut rng,
+        TargetOperation::Measure,
+        qubits(num_qubits),
+        1e-4,
+        3e-3,
+    );
+    target
+}
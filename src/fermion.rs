@@ -0,0 +1,483 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! Second-quantized fermionic operators and their mappings to qubits.
+//!
+//! A [`FermionicOp`] is a sum of products of creation and annihilation
+//! operators on a number of fermionic modes. A [`QubitMapper`] turns it into
+//! a [`SparseObservable`] on one qubit per mode, which can then be evolved
+//! or measured like any other observable.
+//!
+//! # Example
+//!
+//! ```
+//! use qiskit_rs::fermion::{FermionicOp, QubitMapper};
+//! use qiskit_rs::{BitTerm, Complex64};
+//!
+//! // The number operator of mode 1.
+//! let number = FermionicOp::from_sparse_list(&[("+-", &[1, 1], Complex64::ONE)], 2).unwrap();
+//! let obs = QubitMapper::JordanWigner.map(&number);
+//! // (I - Z_1) / 2
+//! let terms: Vec<_> = obs.terms().collect();
+//! assert_eq!(terms.len(), 2);
+//! assert_eq!(terms[1].bit_terms, [BitTerm::Z]);
+//! assert_eq!(terms[1].coeff, Complex64::new(-0.5, 0.0));
+//! ```
+
+use crate::complex::Complex64;
+use crate::qiskit::QiskitError;
+use crate::sparse_observable::{BitTerm, SparseObservable, SparseTerm};
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashMap;
+
+/// A creation or annihilation operator in a term of a [`FermionicOp`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LadderOp {
+    /// The creation operator `a†`.
+    Create,
+    /// The annihilation operator `a`.
+    Annihilate,
+}
+
+impl LadderOp {
+    /// Return the single character label of the operator, `+` or `-`.
+    pub fn label(self) -> char {
+        match self {
+            LadderOp::Create => '+',
+            LadderOp::Annihilate => '-',
+        }
+    }
+
+    /// Parse an operator from its single character label.
+    pub fn from_label(label: char) -> Option<LadderOp> {
+        match label {
+            '+' => Some(LadderOp::Create),
+            '-' => Some(LadderOp::Annihilate),
+            _ => None,
+        }
+    }
+
+    fn adjoint(self) -> LadderOp {
+        match self {
+            LadderOp::Create => LadderOp::Annihilate,
+            LadderOp::Annihilate => LadderOp::Create,
+        }
+    }
+}
+
+/// A single term of a [`FermionicOp`], the product of its operators from
+/// left to right.
+///
+/// `ops[i]` acts on mode `indices[i]`, and a term without operators is the
+/// identity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FermionicTerm {
+    /// The coefficient of the term.
+    pub coeff: Complex64,
+    /// The ladder operators, leftmost first.
+    pub ops: Vec<LadderOp>,
+    /// The modes each of the operators acts on.
+    pub indices: Vec<u32>,
+}
+
+/// A fermionic operator, stored term by term.
+///
+/// # Example
+///
+/// ```
+/// use qiskit_rs::Complex64;
+/// use qiskit_rs::fermion::FermionicOp;
+///
+/// // a_0 a†_0 = 1 - a†_0 a_0
+/// let op = FermionicOp::from_sparse_list(&[("-+", &[0, 0], Complex64::ONE)], 1).unwrap();
+/// let expected = FermionicOp::from_sparse_list(
+///     &[("+-", &[0, 0], -Complex64::ONE), ("", &[], Complex64::ONE)],
+///     1,
+/// )
+/// .unwrap();
+/// assert_eq!(op.normal_order(), expected);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct FermionicOp {
+    num_modes: u32,
+    terms: Vec<FermionicTerm>,
+}
+
+impl FermionicOp {
+    /// Create the zero operator on `num_modes` modes.
+    pub fn zero(num_modes: u32) -> FermionicOp {
+        FermionicOp {
+            num_modes,
+            terms: Vec::new(),
+        }
+    }
+
+    /// Create the identity operator on `num_modes` modes.
+    pub fn identity(num_modes: u32) -> FermionicOp {
+        FermionicOp {
+            num_modes,
+            terms: vec![FermionicTerm {
+                coeff: Complex64::ONE,
+                ops: Vec::new(),
+                indices: Vec::new(),
+            }],
+        }
+    }
+
+    /// Create an operator from a list of `(label, indices, coeff)` triples.
+    ///
+    /// Each character of `label` is the [`LadderOp`] label of the matching
+    /// entry in `indices`, so `("+-", &[2, 0], coeff)` is `a†_2 a_0`.
+    pub fn from_sparse_list(
+        terms: &[(&str, &[u32], Complex64)],
+        num_modes: u32,
+    ) -> Result<FermionicOp, QiskitError> {
+        let mut out = FermionicOp::zero(num_modes);
+        for (label, indices, coeff) in terms {
+            let ops = label
+                .chars()
+                .map(LadderOp::from_label)
+                .collect::<Option<Vec<_>>>()
+                .ok_or(QiskitError::CInputError)?;
+            let term = FermionicTerm {
+                coeff: *coeff,
+                ops,
+                indices: indices.to_vec(),
+            };
+            out.add_term(&term).into_result()?;
+        }
+        Ok(out)
+    }
+
+    /// Add a single term to the operator.
+    ///
+    /// Unlike the terms of an observable, operators may repeat a mode and
+    /// keep the order they are given in.
+    pub fn add_term(&mut self, term: &FermionicTerm) -> QiskitError {
+        if term.ops.len() != term.indices.len() {
+            return QiskitError::MismatchedQubits;
+        }
+        if term.indices.iter().any(|index| *index >= self.num_modes) {
+            return QiskitError::IndexError;
+        }
+        self.terms.push(term.clone());
+        QiskitError::Success
+    }
+
+    /// Return the number of modes the operator acts on.
+    pub fn num_modes(&self) -> u32 {
+        self.num_modes
+    }
+
+    /// Return the number of terms in the operator.
+    pub fn num_terms(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Return the terms of the operator.
+    pub fn terms(&self) -> &[FermionicTerm] {
+        &self.terms
+    }
+
+    /// Return the hermitian conjugate of the operator, which reverses each
+    /// term, swaps creation and annihilation and conjugates the
+    /// coefficients.
+    pub fn adjoint(&self) -> FermionicOp {
+        let terms = self
+            .terms
+            .iter()
+            .map(|term| FermionicTerm {
+                coeff: term.coeff.conj(),
+                ops: term.ops.iter().rev().map(|op| op.adjoint()).collect(),
+                indices: term.indices.iter().rev().copied().collect(),
+            })
+            .collect();
+        FermionicOp {
+            num_modes: self.num_modes,
+            terms,
+        }
+    }
+
+    /// Return the operator with like terms summed and the terms with a
+    /// coefficient of magnitude at most `tol` removed.
+    ///
+    /// Terms are only alike when their operators are in the same order, so
+    /// call [`FermionicOp::normal_order`] to combine every equal term.
+    pub fn simplify(&self, tol: f64) -> FermionicOp {
+        let mut terms: Vec<FermionicTerm> = Vec::new();
+        let mut positions: HashMap<(Vec<LadderOp>, Vec<u32>), usize> = HashMap::new();
+        for term in &self.terms {
+            let key = (term.ops.clone(), term.indices.clone());
+            match positions.get(&key) {
+                Some(&position) => terms[position].coeff += term.coeff,
+                None => {
+                    positions.insert(key, terms.len());
+                    terms.push(term.clone());
+                }
+            }
+        }
+        terms.retain(|term| term.coeff.norm() > tol);
+        FermionicOp {
+            num_modes: self.num_modes,
+            terms,
+        }
+    }
+
+    /// Return the equal operator in normal order.
+    ///
+    /// Every term is rewritten with the anticommutation relations so that
+    /// its creation operators come before its annihilation operators, each
+    /// in descending order of mode. Terms applying the same operator twice
+    /// vanish, and like terms are then summed, dropping those that cancel.
+    pub fn normal_order(&self) -> FermionicOp {
+        // Creation operators first, then higher modes first.
+        let key = |(op, index): (LadderOp, u32)| (op == LadderOp::Annihilate, Reverse(index));
+        let mut pending: Vec<(Complex64, Vec<(LadderOp, u32)>)> = self
+            .terms
+            .iter()
+            .map(|term| {
+                let ops = term.ops.iter().copied().zip(term.indices.iter().copied());
+                (term.coeff, ops.collect())
+            })
+            .collect();
+        pending.reverse();
+        let mut terms = Vec::new();
+        'terms: while let Some((mut coeff, mut ops)) = pending.pop() {
+            let mut k = 0;
+            while k + 1 < ops.len() {
+                match key(ops[k]).cmp(&key(ops[k + 1])) {
+                    Ordering::Less => k += 1,
+                    Ordering::Equal => continue 'terms,
+                    Ordering::Greater => {
+                        // a_p a†_p = 1 - a†_p a_p
+                        if ops[k].1 == ops[k + 1].1 {
+                            let mut contracted = ops.clone();
+                            contracted.drain(k..k + 2);
+                            pending.push((coeff, contracted));
+                        }
+                        ops.swap(k, k + 1);
+                        coeff = -coeff;
+                        k = k.saturating_sub(1);
+                    }
+                }
+            }
+            let (ops, indices) = ops.into_iter().unzip();
+            terms.push(FermionicTerm {
+                coeff,
+                ops,
+                indices,
+            });
+        }
+        FermionicOp {
+            num_modes: self.num_modes,
+            terms,
+        }
+        .simplify(0.0)
+    }
+}
+
+/// A mapping of fermionic modes onto qubits, one qubit per mode.
+///
+/// Each mapping represents the Majorana operators `a_j + a†_j` and
+/// `i (a†_j - a_j)` of mode `j` as Pauli strings, so the spectrum of an
+/// operator is the same under all of them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QubitMapper {
+    /// Qubit `j` holds the occupation of mode `j`, which is `|1>` when
+    /// occupied, and operators carry a string of `Z` over the lower modes
+    /// for their sign.
+    JordanWigner,
+    /// Qubit `j` holds the parity of modes `0` to `j`, so operators carry a
+    /// string of `X` over the higher qubits instead.
+    Parity,
+    /// Qubits hold the parities of the modes in a binary tree, so both the
+    /// occupations and the signs take a logarithmic number of qubits.
+    BravyiKitaev,
+}
+
+impl QubitMapper {
+    /// Map a fermionic operator onto an observable.
+    ///
+    /// Like Pauli terms are summed, and terms whose coefficients cancel
+    /// exactly are removed.
+    pub fn map(self, op: &FermionicOp) -> SparseObservable {
+        let n = op.num_modes() as usize;
+        let majoranas: Vec<[PauliString; 2]> = (0..n).map(|j| self.majoranas(j, n)).collect();
+        let half = Complex64::new(0.5, 0.0);
+        let mut paulis: Vec<PauliString> = Vec::new();
+        let mut positions: HashMap<Vec<Option<BitTerm>>, usize> = HashMap::new();
+        for term in op.terms() {
+            // a = (c + i d) / 2 and a† = (c - i d) / 2
+            let mut products = vec![PauliString {
+                phase: term.coeff,
+                paulis: vec![None; n],
+            }];
+            for (ladder, index) in term.ops.iter().zip(&term.indices) {
+                let [c, d] = &majoranas[*index as usize];
+                let d_phase = match ladder {
+                    LadderOp::Create => -Complex64::I * half,
+                    LadderOp::Annihilate => Complex64::I * half,
+                };
+                products = products
+                    .iter()
+                    .flat_map(|product| {
+                        [
+                            product.multiply(c).scale(half),
+                            product.multiply(d).scale(d_phase),
+                        ]
+                    })
+                    .collect();
+            }
+            for product in products {
+                match positions.get(&product.paulis) {
+                    Some(&position) => paulis[position].phase += product.phase,
+                    None => {
+                        positions.insert(product.paulis.clone(), paulis.len());
+                        paulis.push(product);
+                    }
+                }
+            }
+        }
+        let mut out = SparseObservable::zero(op.num_modes());
+        for pauli in paulis {
+            if pauli.phase == Complex64::ZERO {
+                continue;
+            }
+            let (indices, bit_terms) = (0..)
+                .zip(&pauli.paulis)
+                .filter_map(|(q, bit_term)| bit_term.map(|bit_term| (q, bit_term)))
+                .unzip();
+            let term = SparseTerm {
+                coeff: pauli.phase,
+                bit_terms,
+                indices,
+            };
+            let result = out.add_term(&term);
+            debug_assert_eq!(result, QiskitError::Success);
+        }
+        out
+    }
+
+    // The Majorana operators `a_j + a†_j` and `i (a†_j - a_j)` of mode `j` of
+    // `n`, as `X_U X_j Z_P` and `X_U Y_j Z_R`. The qubits `U` also store mode
+    // `j`, the qubits `P` give the parity of the modes below it, and `R` is
+    // `P` without the qubits whose parities qubit `j` already includes.
+    fn majoranas(self, j: usize, n: usize) -> [PauliString; 2] {
+        let (update, parity, remainder): (Vec<usize>, Vec<usize>, Vec<usize>) = match self {
+            QubitMapper::JordanWigner => (Vec::new(), (0..j).collect(), (0..j).collect()),
+            QubitMapper::Parity => (
+                (j + 1..n).collect(),
+                j.checked_sub(1).into_iter().collect(),
+                Vec::new(),
+            ),
+            QubitMapper::BravyiKitaev => fenwick_sets(j, n),
+        };
+        let string = |pauli: BitTerm, z: &[usize]| {
+            let mut paulis = vec![None; n];
+            for q in &update {
+                paulis[*q] = Some(BitTerm::X);
+            }
+            for q in z {
+                paulis[*q] = Some(BitTerm::Z);
+            }
+            paulis[j] = Some(pauli);
+            PauliString {
+                phase: Complex64::ONE,
+                paulis,
+            }
+        };
+        [string(BitTerm::X, &parity), string(BitTerm::Y, &remainder)]
+    }
+}
+
+// The update, parity and remainder sets of mode `j` of `n` in the
+// Bravyi-Kitaev encoding, where qubit `k` holds the parity of the modes
+// below `k + 1` in the binary indexed tree of the modes.
+fn fenwick_sets(j: usize, n: usize) -> (Vec<usize>, Vec<usize>, Vec<usize>) {
+    let lowbit = |i: usize| i & i.wrapping_neg();
+    // The tree is indexed from 1, so node `j + 1` is qubit `j`.
+    let node = j + 1;
+    let mut update = Vec::new();
+    let mut i = node + lowbit(node);
+    while i <= n {
+        update.push(i - 1);
+        i += lowbit(i);
+    }
+    let mut parity = Vec::new();
+    let mut i = j;
+    while i > 0 {
+        parity.push(i - 1);
+        i -= lowbit(i);
+    }
+    // The children of the node, whose parities it combines with mode `j`.
+    let mut flip = Vec::new();
+    let mut i = j;
+    while i > node - lowbit(node) {
+        flip.push(i - 1);
+        i -= lowbit(i);
+    }
+    let remainder = parity
+        .iter()
+        .copied()
+        .filter(|q| !flip.contains(q))
+        .collect();
+    (update, parity, remainder)
+}
+
+// A Pauli string with a phase, with `None` for the identity on a qubit.
+struct PauliString {
+    phase: Complex64,
+    paulis: Vec<Option<BitTerm>>,
+}
+
+impl PauliString {
+    fn scale(mut self, factor: Complex64) -> PauliString {
+        self.phase *= factor;
+        self
+    }
+
+    fn multiply(&self, other: &PauliString) -> PauliString {
+        let mut phase = self.phase * other.phase;
+        let paulis = self
+            .paulis
+            .iter()
+            .zip(&other.paulis)
+            .map(|(a, b)| {
+                let (factor, pauli) = multiply_paulis(*a, *b);
+                phase *= factor;
+                pauli
+            })
+            .collect();
+        PauliString { phase, paulis }
+    }
+}
+
+// The product of two single-qubit Paulis, as a phase and a Pauli.
+fn multiply_paulis(a: Option<BitTerm>, b: Option<BitTerm>) -> (Complex64, Option<BitTerm>) {
+    let (a, b) = match (a, b) {
+        (None, pauli) | (pauli, None) => return (Complex64::ONE, pauli),
+        (Some(a), Some(b)) if a == b => return (Complex64::ONE, None),
+        (Some(a), Some(b)) => (a, b),
+    };
+    // XY = iZ, YZ = iX and ZX = iY, and the reverse products take -i.
+    let (pauli, cyclic) = match (a, b) {
+        (BitTerm::X, BitTerm::Y) => (BitTerm::Z, true),
+        (BitTerm::Y, BitTerm::X) => (BitTerm::Z, false),
+        (BitTerm::Y, BitTerm::Z) => (BitTerm::X, true),
+        (BitTerm::Z, BitTerm::Y) => (BitTerm::X, false),
+        (BitTerm::Z, BitTerm::X) => (BitTerm::Y, true),
+        (BitTerm::X, BitTerm::Z) => (BitTerm::Y, false),
+        _ => unreachable!("the Majorana operators only hold Paulis"),
+    };
+    let phase = if cyclic { Complex64::I } else { -Complex64::I };
+    (phase, Some(pauli))
+}
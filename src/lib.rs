@@ -63,12 +63,178 @@
 //! export QISKIT_CEXT_PATH="<path/to/qiskit-cext-dir>"
 //! ```
 //!
+//! #### Source
+//!
+//! Builds the qiskit c api from a local source directory or tarball without
+//! network access. The Rust dependencies of qiskit must already be available
+//! to cargo, for example vendored.
+//!
+//! ```bash
+//! export QISKIT_CEXT_INSTALL_METHOD="source"
+//! export QISKIT_CEXT_PATH="<path/to/qiskit-2.2.3.tar.gz>"
+//! ```
+//!
+//! #### System
+//!
+//! Uses a system wide installation of the qiskit c api, found with pkg-config
+//! or in the standard prefixes. The header and library directories can be
+//! set separately.
+//!
+//! ```bash
+//! export QISKIT_CEXT_INSTALL_METHOD="system"
+//! export QISKIT_CEXT_INCLUDE_DIR="<path/to/include>"  # optional
+//! export QISKIT_CEXT_LIB_DIR="<path/to/lib>"  # optional
+//! ```
+//!
+//! The headers must be from a 2.2.x release of the qiskit c api.
+//!
+//! The clone method needs the `clone` feature, which is enabled by default.
+//! Disable default features to build without `git2`.
+//!
+//! #### Static linking
+//!
+//! With the `static` feature, `libqiskit.a` is linked from
+//! `$QISKIT_CEXT_PATH/dist/c/lib` instead of the shared library, so binaries
+//! don't need `libqiskit` at runtime.
+//!
+//! ```bash
+//! cargo build --features static
+//! ```
+//!
+//! #### Runtime linking
+//!
+//! With the `runtime-linking` feature, libqiskit isn't linked but loaded at
+//! runtime with `qiskit_rs::init`, so one binary can use different installations and
+//! reports a missing library as an error. Combined with the pre-generated
+//! bindings, no qiskit installation is needed at build time.
+//!
+//! If `qiskit_rs::init` isn't called, the default library is loaded the first
+//! time a circuit, observable, parameter or target is created, and a library
+//! that can't be loaded there is a panic. Calling `init` first reports it as
+//! a `LoadError` instead.
+//!
+//! ```bash
+//! cargo build --no-default-features --features runtime-linking
+//! ```
+//!
+//! #### Pre-generated bindings
+//!
+//! By default the bindings are generated from the qiskit c api headers at
+//! build time, which needs libclang. Disabling the `bindgen` feature uses the
+//! bindings checked into `qiskit-sys/bindings` instead. If the headers are
+//! available, they are still checked against the version of those bindings.
+//!
+//! ```bash
+//! cargo build --no-default-features --features clone
+//! ```
+//!
+//! ## Python interop
+//!
+//! With the `python` feature, [`QuantumCircuit`] and [`SparseObservable`]
+//! convert to and from the `qiskit` Python package's `QuantumCircuit` and
+//! `SparseObservable` through [pyo3](https://pyo3.rs), so a `#[pyfunction]` can
+//! take or return them directly. Standard gates, measurements, resets and
+//! barriers are converted, with registers, parameter expressions and parameter
+//! vectors; other Python gates are replaced by their definition, and control
+//! flow is rejected. Reading expressions from Python needs sympy. The pyo3 version in use is re-exported as
+//! `qiskit_rs::pyo3`.
+//!
+//! ## Backend json
+//!
+//! With the `json` feature, a [`Target`] can be loaded from the json
+//! configuration and properties of an IBM backend with
+//! `Target::from_backend_json` and written back with `Target::to_json`.
+//!
+//! ## DAG
+//!
+//! With the `dag` feature, circuits convert to and from a `DagCircuit`
+//! wrapping the C API's DAG. The `qk_dag_*` functions aren't part of the
+//! pre-generated 2.2 bindings, so the feature generates the bindings with
+//! the `bindgen` feature, from headers that must declare them.
+//!
 //! ## License
 //!
 //! [Apache License 2.0](https://github.com/Qiskit/qiskit/blob/main/LICENSE.txt)
 
 #![warn(missing_docs)]
+/// Complex numbers used for coefficients and amplitudes
+pub mod complex;
+/// Classical control flow in circuits
+pub mod control_flow;
+/// The connectivity of device qubits
+pub mod coupling_map;
+/// Circuits as directed acyclic graphs
+#[cfg(feature = "dag")]
+pub mod dag;
+/// Expectation values of observables from measurement counts
+pub mod estimator;
+/// Synthetic device targets for testing
+pub mod fake_backends;
+/// Fermionic operators and their mappings to qubits
+pub mod fermion;
+/// Builders for commonly used circuits
+pub mod library;
+/// Hamiltonians of lattice models and optimization problems
+pub mod models;
+/// Symbolic parameters for gate angles
+pub mod parameter;
+#[cfg(feature = "python")]
+mod python;
 /// The main qiskit-rs module
 pub mod qiskit;
+mod rng;
+/// Observables over Pauli and projector bases
+pub mod sparse_observable;
+/// Descriptions of the devices circuits are compiled for
+pub mod target;
+/// Transpiler passes and pass managers
+pub mod transpiler;
+/// Versions of the qiskit c api qiskit-rs is built for
+pub mod version;
+/// Circuit drawers
+pub mod visualization;
+
+pub use complex::Complex64;
+pub use coupling_map::CouplingMap;
+#[cfg(feature = "dag")]
+pub use dag::DagCircuit;
+pub use parameter::{Angle, Parameter, ParameterExpression, ParameterValue, ParameterVector};
+pub use qiskit::{ClassicalRegister, QiskitError, QuantumCircuit, QuantumRegister, StandardGate};
+pub use sparse_observable::{BitTerm, SparseObservable, SparseTerm};
+pub use target::Target;
+pub use version::{HEADER_VERSION, Version, version};
+
+#[cfg(feature = "python")]
+pub use pyo3;
+#[cfg(feature = "runtime-linking")]
+pub use qiskit_sys::LoadError;
 
-pub use qiskit::{ClassicalRegister, QiskitError, QuantumCircuit, QuantumRegister};
+/// Load libqiskit from `path`.
+///
+/// Only available with the `runtime-linking` feature. Call this before using
+/// any other part of the library; if it isn't called, the library at
+/// `$QISKIT_LIBRARY_PATH` (or the one found at build time) is loaded on first
+/// use.
+///
+/// The version of the library is read from the headers installed next to it,
+/// in `../include`, and a library whose major or minor version differs from
+/// [`HEADER_VERSION`] isn't loaded. The loaded version is then returned by
+/// [`version`].
+///
+/// # Safety
+///
+/// Loading a library runs its initialization routines, so `path` must be a
+/// trusted library. It must also be a build of the qiskit c api with the
+/// version of these bindings: its functions are called with the signatures
+/// of the bindings, and a mismatch is undefined behavior. When the library
+/// is installed without its headers its version can't be checked.
+///
+/// ```no_run
+/// // SAFETY: /opt/qiskit holds a build of the qiskit c api these bindings are for.
+/// unsafe { qiskit_rs::init("/opt/qiskit/lib/libqiskit.so") }.expect("libqiskit is missing");
+/// let qc = qiskit_rs::QuantumCircuit::new(2, 2);
+/// ```
+#[cfg(feature = "runtime-linking")]
+pub unsafe fn init(path: impl AsRef<std::path::Path>) -> Result<(), LoadError> {
+    unsafe { qiskit_sys::load(path.as_ref()) }
+}
@@ -0,0 +1,540 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! Builders for commonly used circuits.
+//!
+//! Each builder returns a fresh [`QuantumCircuit`] without classical bits,
+//! laid out the same way as the matching circuit in Qiskit's Python circuit
+//! library.
+
+use crate::parameter::{Angle, Parameter, ParameterVector};
+use crate::qiskit::{QiskitError, QuantumCircuit};
+use crate::rng::Rng;
+use crate::sparse_observable::{BitTerm, SparseObservable};
+use std::f64::consts::PI;
+
+/// The pattern of two-qubit entangling gates in a layer of a variational form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Entanglement {
+    /// Every qubit is entangled with every other qubit.
+    Full,
+    /// Qubit `i` is entangled with qubit `i + 1`.
+    Linear,
+    /// As [`Entanglement::Linear`], but starting from the last pair.
+    ReverseLinear,
+    /// As [`Entanglement::Linear`], with an extra gate from the last qubit to
+    /// the first.
+    Circular,
+    /// The even pairs `(0, 1), (2, 3), ...` followed by the odd pairs
+    /// `(1, 2), (3, 4), ...`.
+    Pairwise,
+}
+
+impl Entanglement {
+    /// Return the `(control, target)` pairs of one entangling layer.
+    pub fn pairs(self, num_qubits: u32) -> Vec<(u32, u32)> {
+        let n = num_qubits;
+        match self {
+            Entanglement::Full => (0..n)
+                .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+                .collect(),
+            Entanglement::Linear => (1..n).map(|i| (i - 1, i)).collect(),
+            Entanglement::ReverseLinear => (1..n).rev().map(|i| (i - 1, i)).collect(),
+            Entanglement::Circular => {
+                let mut pairs = Vec::new();
+                if n > 2 {
+                    pairs.push((n - 1, 0));
+                }
+                pairs.extend((1..n).map(|i| (i - 1, i)));
+                pairs
+            }
+            Entanglement::Pairwise => (1..n)
+                .step_by(2)
+                .chain((2..n).step_by(2))
+                .map(|i| (i - 1, i))
+                .collect(),
+        }
+    }
+}
+
+/// Build a quantum Fourier transform on `num_qubits` qubits.
+///
+/// Controlled-phase rotations are dropped for the `approximation_degree`
+/// smallest angles, and the output qubit order is reversed with swaps at the
+/// end, as in Qiskit's `QFTGate` synthesis. With `inverse` set, the inverse
+/// transform is built instead.
+///
+/// # Example
+///
+/// ```
+/// use qiskit_rs::library::qft;
+///
+/// let qc = qft(4, false, 0);
+/// assert_eq!(qc.num_instructions(), 12);
+/// ```
+pub fn qft(num_qubits: u32, inverse: bool, approximation_degree: u32) -> QuantumCircuit {
+    let n = num_qubits;
+    let mut qc = QuantumCircuit::new(n, 0);
+    // The qubit of each Hadamard layer together with the (qubit, angle) pairs
+    // of its controlled-phase rotations, in forward order.
+    let layers: Vec<(u32, Vec<(u32, f64)>)> = (0..n)
+        .rev()
+        .map(|j| {
+            let num_entanglements =
+                j.saturating_sub(approximation_degree.saturating_sub(n - j - 1));
+            let rotations = (j - num_entanglements..j)
+                .rev()
+                .map(|k| (k, PI * 2f64.powi(k as i32 - j as i32)))
+                .collect();
+            (j, rotations)
+        })
+        .collect();
+    if inverse {
+        for i in 0..n / 2 {
+            qc.swap(i, n - i - 1);
+        }
+        for (j, rotations) in layers.iter().rev() {
+            for (k, angle) in rotations.iter().rev() {
+                qc.cp(-angle, *j, *k);
+            }
+            qc.h(*j);
+        }
+    } else {
+        for (j, rotations) in &layers {
+            qc.h(*j);
+            for (k, angle) in rotations {
+                qc.cp(*angle, *j, *k);
+            }
+        }
+        for i in 0..n / 2 {
+            qc.swap(i, n - i - 1);
+        }
+    }
+    qc
+}
+
+/// Build a circuit preparing the GHZ state `(|0...0> + |1...1>) / sqrt(2)`.
+pub fn ghz(num_qubits: u32) -> QuantumCircuit {
+    let mut qc = QuantumCircuit::new(num_qubits, 0);
+    if num_qubits == 0 {
+        return qc;
+    }
+    qc.h(0);
+    for i in 1..num_qubits {
+        qc.cx(i - 1, i);
+    }
+    qc
+}
+
+/// Build a circuit preparing the W state, the equal superposition of all
+/// basis states with exactly one qubit set.
+pub fn w_state(num_qubits: u32) -> QuantumCircuit {
+    let mut qc = QuantumCircuit::new(num_qubits, 0);
+    if num_qubits == 0 {
+        return qc;
+    }
+    qc.x(0);
+    for k in 1..num_qubits {
+        // Leave 1 / (n - k + 1) of the remaining weight on qubit k - 1 and
+        // move the rest on to qubit k.
+        let theta = 2.0 * (1.0 / f64::from(num_qubits - k + 1)).sqrt().acos();
+        qc.cry(theta, k - 1, k);
+        qc.cx(k, k - 1);
+    }
+    qc
+}
+
+/// Build the Grover operator for an oracle that flips the phase of the good
+/// states.
+///
+/// The operator is the oracle followed by the reflection about the uniform
+/// superposition, with the same global phase as Qiskit's `grover_operator`.
+/// Applying it `k` times to the uniform superposition amplifies the
+/// amplitude of the good states.
+///
+/// Returns [`QiskitError::UnsupportedInstruction`] if the oracle contains
+/// instructions that cannot be copied, such as measurements.
+pub fn grover_operator(oracle: &QuantumCircuit) -> Result<QuantumCircuit, QiskitError> {
+    let n = oracle.num_qubits();
+    let mut qc = QuantumCircuit::new(n, 0);
+    if oracle.instructions().any(|inst| inst.name == "measure") {
+        return Err(QiskitError::UnsupportedInstruction);
+    }
+    let qubits: Vec<u32> = (0..n).collect();
+    qc.append_mapped(oracle, &qubits, &[])?;
+    for q in 0..n {
+        qc.h(q);
+    }
+    for q in 0..n {
+        qc.x(q);
+    }
+    if let Some((last, rest)) = qubits.split_last() {
+        qc.h(*last);
+        qc.mcx(rest, *last).into_result()?;
+        qc.h(*last);
+    }
+    for q in 0..n {
+        qc.x(q);
+    }
+    for q in 0..n {
+        qc.h(q);
+    }
+    qc.global_phase(PI);
+    Ok(qc)
+}
+
+/// The product formula approximating the evolution under an observable whose
+/// terms don't commute.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EvolutionSynthesis {
+    /// The first-order Lie-Trotter formula, which evolves under each term
+    /// in turn.
+    LieTrotter {
+        /// The number of equal time steps
+        reps: usize,
+    },
+    /// The Suzuki-Trotter formula of an even order.
+    ///
+    /// Order 2 evolves under each term for half a step, forwards and then
+    /// backwards; higher orders combine steps of the order below.
+    SuzukiTrotter {
+        /// The order of the formula
+        order: u32,
+        /// The number of equal time steps
+        reps: usize,
+    },
+    /// The randomized QDrift formula, which evolves under terms sampled with
+    /// probability proportional to the magnitude of their coefficients.
+    ///
+    /// It samples `ceil(2 * λ² * time² * reps)` terms, where `λ` is the sum
+    /// of those magnitudes.
+    QDrift {
+        /// The factor scaling the number of samples
+        reps: usize,
+        /// The seed of the sampling, which gives the same circuit every time
+        seed: u64,
+    },
+}
+
+/// Build a circuit approximating `exp(-i * time * obs)` with a product
+/// formula.
+///
+/// Projector bit terms are expanded into Paulis first, and identity terms
+/// become a global phase. Each Pauli term is applied as `rx`, `ry`, `rz`,
+/// `rxx`, `ryy` or `rzz` when it is one or two equal Paulis, and otherwise
+/// rotated into the Z basis with `h` and `sdg` gates and reduced onto a
+/// single `rz` with a `cx` ladder.
+///
+/// Returns [`QiskitError::CInputError`] if the observable has complex
+/// coefficients, or the synthesis has no reps or an order that isn't even
+/// and positive.
+///
+/// # Example
+///
+/// ```
+/// use qiskit_rs::library::{EvolutionSynthesis, pauli_evolution};
+/// use qiskit_rs::{Complex64, SparseObservable};
+///
+/// let field = Complex64::new(0.5, 0.0);
+/// let ising = SparseObservable::from_sparse_list(
+///     &[("ZZ", &[0, 1], Complex64::ONE), ("X", &[0], field), ("X", &[1], field)],
+///     2,
+/// )
+/// .unwrap();
+/// let synthesis = EvolutionSynthesis::LieTrotter { reps: 4 };
+/// let qc = pauli_evolution(&ising, 1.0, synthesis).unwrap();
+/// assert_eq!(qc.num_instructions(), 12);
+/// ```
+pub fn pauli_evolution(
+    obs: &SparseObservable,
+    time: f64,
+    synthesis: EvolutionSynthesis,
+) -> Result<QuantumCircuit, QiskitError> {
+    let mut phase = 0.0;
+    let mut terms = Vec::new();
+    for term in obs.as_paulis().terms() {
+        if term.coeff.im != 0.0 {
+            return Err(QiskitError::CInputError);
+        }
+        if term.indices.is_empty() {
+            phase -= time * term.coeff.re;
+        } else {
+            terms.push(term);
+        }
+    }
+    // The index of each term evolved under and the time it's evolved for,
+    // in order.
+    let slices = match synthesis {
+        EvolutionSynthesis::LieTrotter { reps } if reps > 0 => {
+            let step = time / reps as f64;
+            (0..terms.len())
+                .map(|j| (j, step))
+                .collect::<Vec<_>>()
+                .repeat(reps)
+        }
+        EvolutionSynthesis::SuzukiTrotter { order, reps }
+            if reps > 0 && order > 0 && order % 2 == 0 =>
+        {
+            suzuki_trotter(order, terms.len(), time / reps as f64).repeat(reps)
+        }
+        EvolutionSynthesis::QDrift { reps, seed } if reps > 0 => {
+            let coeffs: Vec<f64> = terms.iter().map(|term| term.coeff.re).collect();
+            qdrift(&coeffs, time, reps, seed)
+        }
+        _ => return Err(QiskitError::CInputError),
+    };
+    let mut qc = QuantumCircuit::new(obs.num_qubits(), 0);
+    if phase != 0.0 {
+        qc.global_phase(phase).into_result()?;
+    }
+    for (j, step) in slices {
+        let term = &terms[j];
+        let theta = 2.0 * step * term.coeff.re;
+        append_pauli_rotation(&mut qc, &term.bit_terms, &term.indices, theta)?;
+    }
+    Ok(qc)
+}
+
+// One step of the Suzuki-Trotter formula of an even order over `num_terms`
+// terms, as (term, time) slices.
+fn suzuki_trotter(order: u32, num_terms: usize, time: f64) -> Vec<(usize, f64)> {
+    if order == 2 {
+        let Some(last) = num_terms.checked_sub(1) else {
+            return Vec::new();
+        };
+        let halves: Vec<(usize, f64)> = (0..last).map(|j| (j, time / 2.0)).collect();
+        return halves
+            .iter()
+            .copied()
+            .chain([(last, time)])
+            .chain(halves.iter().rev().copied())
+            .collect();
+    }
+    let u = 1.0 / (4.0 - 4f64.powf(1.0 / f64::from(order - 1)));
+    let outer = suzuki_trotter(order - 2, num_terms, u * time);
+    let inner = suzuki_trotter(order - 2, num_terms, (1.0 - 4.0 * u) * time);
+    [&outer[..], &outer, &inner, &outer, &outer].concat()
+}
+
+// The sampled (term, time) slices of the QDrift formula. Each sample evolves
+// under its term's sign for `λ * time / num_samples`, so its time is scaled
+// by the magnitude of the coefficient.
+fn qdrift(coeffs: &[f64], time: f64, reps: usize, seed: u64) -> Vec<(usize, f64)> {
+    let lambda: f64 = coeffs.iter().map(|coeff| coeff.abs()).sum();
+    let num_samples = (2.0 * lambda.powi(2) * time.powi(2) * reps as f64).ceil() as usize;
+    let mut rng = Rng(seed);
+    (0..num_samples)
+        .map(|_| {
+            let mut remaining = rng.next_f64() * lambda;
+            let j = coeffs
+                .iter()
+                .position(|coeff| {
+                    remaining -= coeff.abs();
+                    remaining < 0.0
+                })
+                // Rounding can leave a sliver of weight past the last term.
+                .unwrap_or_else(|| coeffs.iter().rposition(|coeff| *coeff != 0.0).unwrap());
+            (j, lambda * time / (num_samples as f64 * coeffs[j].abs()))
+        })
+        .collect()
+}
+
+/// Build a QAOA ansatz for a cost observable.
+///
+/// The circuit starts in the uniform superposition and then alternates
+/// `reps` times between evolving under the cost observable and under the
+/// transverse-field mixer. Layer `i` evolves under the cost observable for
+/// the time `γ[i]` and applies `rx(2 * β[i])` to every qubit, so sorted by
+/// name the circuit's parameters are the mixer angles `β` followed by the
+/// cost angles `γ`.
+///
+/// The cost observable must have real coefficients and only Pauli bit
+/// terms; otherwise [`QiskitError::CInputError`] is returned.
+///
+/// # Example
+///
+/// ```
+/// use qiskit_rs::library::qaoa_ansatz;
+/// use qiskit_rs::{Complex64, SparseObservable};
+/// use std::collections::HashMap;
+///
+/// let cost = SparseObservable::from_sparse_list(&[("ZZ", &[0, 1], Complex64::ONE)], 2).unwrap();
+/// let qc = qaoa_ansatz(&cost, 2).unwrap();
+/// assert_eq!(qc.num_parameters(), 4);
+///
+/// let values: HashMap<_, _> = qc.parameters().into_iter().zip([0.1, 0.2, 0.3, 0.4]).collect();
+/// let bound = qc.assign_parameters(&values).unwrap();
+/// assert_eq!(bound.num_parameters(), 0);
+/// ```
+pub fn qaoa_ansatz(cost: &SparseObservable, reps: usize) -> Result<QuantumCircuit, QiskitError> {
+    let betas = ParameterVector::new("β", reps);
+    let gammas = ParameterVector::new("γ", reps);
+    let n = cost.num_qubits();
+    let mut qc = QuantumCircuit::new(n, 0);
+    for q in 0..n {
+        qc.h(q);
+    }
+    for (beta, gamma) in betas.iter().zip(&gammas) {
+        append_evolution(&mut qc, cost, gamma)?;
+        for q in 0..n {
+            qc.rx(2.0 * beta, q);
+        }
+    }
+    Ok(qc)
+}
+
+/// Build the hardware-efficient `EfficientSU2` ansatz.
+///
+/// Each of the `reps + 1` rotation layers applies `ry` and then `rz` to
+/// every qubit, separated by `cx` gates in the given entanglement pattern.
+/// The angles are the `2 * num_qubits * (reps + 1)` elements of a
+/// parameter vector `θ`, used layer by layer, with all the `ry` angles of a
+/// layer before its `rz` angles.
+///
+/// # Example
+///
+/// ```
+/// use qiskit_rs::library::{Entanglement, efficient_su2};
+///
+/// let qc = efficient_su2(3, 2, Entanglement::Linear);
+/// assert_eq!(qc.num_parameters(), 18);
+/// assert_eq!(qc.parameters()[0].name(), "θ[0]");
+/// ```
+pub fn efficient_su2(num_qubits: u32, reps: usize, entanglement: Entanglement) -> QuantumCircuit {
+    two_local(
+        num_qubits,
+        reps,
+        entanglement,
+        &[QuantumCircuit::ry, QuantumCircuit::rz],
+    )
+}
+
+/// Build the `RealAmplitudes` ansatz, whose states have only real
+/// amplitudes.
+///
+/// Each of the `reps + 1` rotation layers applies `ry` to every qubit,
+/// separated by `cx` gates in the given entanglement pattern. The angles
+/// are the `num_qubits * (reps + 1)` elements of a parameter vector `θ`,
+/// used layer by layer.
+pub fn real_amplitudes(num_qubits: u32, reps: usize, entanglement: Entanglement) -> QuantumCircuit {
+    two_local(num_qubits, reps, entanglement, &[QuantumCircuit::ry])
+}
+
+type Rotation = fn(&mut QuantumCircuit, Parameter, u32) -> QiskitError;
+
+fn two_local(
+    num_qubits: u32,
+    reps: usize,
+    entanglement: Entanglement,
+    rotations: &[Rotation],
+) -> QuantumCircuit {
+    let n = num_qubits as usize;
+    let theta = ParameterVector::new("θ", rotations.len() * n * (reps + 1));
+    let mut qc = QuantumCircuit::new(num_qubits, 0);
+    let pairs = entanglement.pairs(num_qubits);
+    let mut angles = theta.iter();
+    for rep in 0..=reps {
+        if rep > 0 {
+            for (control, target) in &pairs {
+                qc.cx(*control, *target);
+            }
+        }
+        for rotation in rotations {
+            for (q, angle) in (0..num_qubits).zip(angles.by_ref()) {
+                rotation(&mut qc, angle.clone(), q);
+            }
+        }
+    }
+    qc
+}
+
+/// Append a single Lie-Trotter step of `exp(-i * time * obs)`, which is exact
+/// when the terms of the observable commute.
+fn append_evolution(
+    qc: &mut QuantumCircuit,
+    obs: &SparseObservable,
+    time: impl Angle,
+) -> Result<(), QiskitError> {
+    let time = time.to_value();
+    for term in obs.terms() {
+        if term.coeff.im != 0.0 {
+            return Err(QiskitError::CInputError);
+        }
+        let theta = &time * (2.0 * term.coeff.re);
+        if term.indices.is_empty() {
+            qc.global_phase(-&theta / 2.0).into_result()?;
+        } else {
+            append_pauli_rotation(qc, &term.bit_terms, &term.indices, theta)?;
+        }
+    }
+    Ok(())
+}
+
+/// Append `exp(-i * theta / 2 * P)` for the Pauli string `P`.
+///
+/// Strings of one or two equal Paulis map onto the native rotation gates;
+/// everything else is rotated into the Z basis and reduced onto the last
+/// qubit with a `cx` ladder.
+pub(crate) fn append_pauli_rotation(
+    qc: &mut QuantumCircuit,
+    bit_terms: &[BitTerm],
+    indices: &[u32],
+    theta: impl Angle,
+) -> Result<(), QiskitError> {
+    let theta = theta.to_value();
+    if bit_terms.iter().any(|bit| !bit.is_pauli()) {
+        return Err(QiskitError::CInputError);
+    }
+    let retval = match (bit_terms, indices) {
+        ([BitTerm::X], [q]) => qc.rx(&theta, *q),
+        ([BitTerm::Y], [q]) => qc.ry(&theta, *q),
+        ([BitTerm::Z], [q]) => qc.rz(&theta, *q),
+        ([BitTerm::X, BitTerm::X], [q0, q1]) => qc.rxx(&theta, *q0, *q1),
+        ([BitTerm::Y, BitTerm::Y], [q0, q1]) => qc.ryy(&theta, *q0, *q1),
+        ([BitTerm::Z, BitTerm::Z], [q0, q1]) => qc.rzz(&theta, *q0, *q1),
+        _ => {
+            for (bit, q) in bit_terms.iter().zip(indices) {
+                match bit {
+                    BitTerm::X => qc.h(*q),
+                    BitTerm::Y => {
+                        qc.sdg(*q);
+                        qc.h(*q)
+                    }
+                    _ => QiskitError::Success,
+                }
+                .into_result()?;
+            }
+            for pair in indices.windows(2) {
+                qc.cx(pair[0], pair[1]).into_result()?;
+            }
+            if let Some(last) = indices.last() {
+                qc.rz(&theta, *last).into_result()?;
+            }
+            for pair in indices.windows(2).rev() {
+                qc.cx(pair[0], pair[1]).into_result()?;
+            }
+            for (bit, q) in bit_terms.iter().zip(indices) {
+                match bit {
+                    BitTerm::X => qc.h(*q),
+                    BitTerm::Y => {
+                        qc.h(*q);
+                        qc.s(*q)
+                    }
+                    _ => QiskitError::Success,
+                }
+                .into_result()?;
+            }
+            QiskitError::Success
+        }
+    };
+    retval.into_result()
+}
@@ -0,0 +1,191 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! Hamiltonians of standard lattice models and optimization problems.
+//!
+//! Each builder places a model on the edges of a [`CouplingMap`], so the
+//! same lattice can be used for the Hamiltonian and the device it runs on.
+//! Edges in both directions count once, and an edge list can be turned into
+//! a graph with [`CouplingMap::from_edges`]. The observables have real
+//! coefficients and only Pauli bit terms, so they can be passed straight to
+//! [`pauli_evolution`](crate::library::pauli_evolution) or
+//! [`qaoa_ansatz`](crate::library::qaoa_ansatz).
+//!
+//! # Example
+//!
+//! ```
+//! use qiskit_rs::CouplingMap;
+//! use qiskit_rs::library::{EvolutionSynthesis, pauli_evolution};
+//! use qiskit_rs::models::transverse_field_ising;
+//!
+//! let chain = CouplingMap::line(4);
+//! let ising = transverse_field_ising(&chain, -1.0, 0.5);
+//! assert_eq!(ising.num_terms(), 3 + 4);
+//!
+//! let synthesis = EvolutionSynthesis::SuzukiTrotter { order: 2, reps: 5 };
+//! let qc = pauli_evolution(&ising, 1.0, synthesis).unwrap();
+//! assert_eq!(qc.num_qubits(), 4);
+//! ```
+
+use crate::complex::Complex64;
+use crate::coupling_map::CouplingMap;
+use crate::qiskit::QiskitError;
+use crate::sparse_observable::{BitTerm, SparseObservable, SparseTerm};
+use std::collections::BTreeSet;
+
+// The edges of the graph as undirected pairs `[a, b]` with `a < b`, in the
+// order they first appear, without self-loops.
+fn undirected_edges(graph: &CouplingMap) -> Vec<[u32; 2]> {
+    let mut seen = BTreeSet::new();
+    graph
+        .edges()
+        .iter()
+        .map(|[a, b]| [*a.min(b), *a.max(b)])
+        .filter(|[a, b]| a != b && seen.insert([*a, *b]))
+        .collect()
+}
+
+// Add a term on distinct qubits of the observable, which the C API always
+// accepts, unless its coefficient is zero.
+fn add_term(obs: &mut SparseObservable, bit_terms: &[BitTerm], indices: &[u32], coeff: f64) {
+    if coeff == 0.0 {
+        return;
+    }
+    let term = SparseTerm {
+        coeff: Complex64::new(coeff, 0.0),
+        bit_terms: bit_terms.to_vec(),
+        indices: indices.to_vec(),
+    };
+    let result = obs.add_term(&term);
+    debug_assert_eq!(result, QiskitError::Success);
+}
+
+/// Build the transverse-field Ising model
+/// `coupling * Σ Z_a Z_b + field * Σ X_q`, with a `ZZ` term on every edge of
+/// the graph and an `X` term on every qubit.
+///
+/// Terms with a zero coefficient are left out.
+pub fn transverse_field_ising(graph: &CouplingMap, coupling: f64, field: f64) -> SparseObservable {
+    let mut obs = SparseObservable::zero(graph.num_qubits());
+    for edge in undirected_edges(graph) {
+        add_term(&mut obs, &[BitTerm::Z, BitTerm::Z], &edge, coupling);
+    }
+    for q in 0..graph.num_qubits() {
+        add_term(&mut obs, &[BitTerm::X], &[q], field);
+    }
+    obs
+}
+
+/// Build the Heisenberg XXZ model
+/// `coupling * Σ (X_a X_b + Y_a Y_b + anisotropy * Z_a Z_b) + field * Σ Z_q`
+/// on the edges of the graph.
+///
+/// An `anisotropy` of 1 gives the isotropic Heisenberg model and 0 the XY
+/// model. Terms with a zero coefficient are left out.
+pub fn heisenberg_xxz(
+    graph: &CouplingMap,
+    coupling: f64,
+    anisotropy: f64,
+    field: f64,
+) -> SparseObservable {
+    let mut obs = SparseObservable::zero(graph.num_qubits());
+    for edge in undirected_edges(graph) {
+        add_term(&mut obs, &[BitTerm::X, BitTerm::X], &edge, coupling);
+        add_term(&mut obs, &[BitTerm::Y, BitTerm::Y], &edge, coupling);
+        add_term(
+            &mut obs,
+            &[BitTerm::Z, BitTerm::Z],
+            &edge,
+            coupling * anisotropy,
+        );
+    }
+    for q in 0..graph.num_qubits() {
+        add_term(&mut obs, &[BitTerm::Z], &[q], field);
+    }
+    obs
+}
+
+/// Build the Fermi-Hubbard model on the sites of the graph, mapped to qubits
+/// with the Jordan-Wigner transformation.
+///
+/// The Hamiltonian is
+///
+/// ```text
+/// -hopping * Σ_σ (c†_aσ c_bσ + c†_bσ c_aσ)
+///     + interaction * Σ n_q↑ n_q↓ - chemical_potential * Σ_σ n_qσ
+/// ```
+///
+/// summing over the edges `(a, b)` and the sites `q`. The spin-up orbital of
+/// site `q` is qubit `q` and its spin-down orbital is qubit `q + n` for `n`
+/// sites, and a qubit is `|1>` when its orbital is occupied. Like terms are
+/// combined.
+pub fn fermi_hubbard(
+    graph: &CouplingMap,
+    hopping: f64,
+    interaction: f64,
+    chemical_potential: f64,
+) -> SparseObservable {
+    let num_sites = graph.num_qubits();
+    let mut obs = SparseObservable::zero(2 * num_sites);
+    for spin in [0, num_sites] {
+        for [a, b] in undirected_edges(graph) {
+            // The hopping between orbitals p < q picks up the parity of the
+            // orbitals between them.
+            let indices: Vec<u32> = (a + spin..=b + spin).collect();
+            for pauli in [BitTerm::X, BitTerm::Y] {
+                let mut bit_terms = vec![BitTerm::Z; indices.len()];
+                bit_terms[0] = pauli;
+                bit_terms[indices.len() - 1] = pauli;
+                add_term(&mut obs, &bit_terms, &indices, -hopping / 2.0);
+            }
+        }
+    }
+    for q in 0..num_sites {
+        // n_p n_q = (I - Z_p - Z_q + Z_p Z_q) / 4 and n_p = (I - Z_p) / 2.
+        let (up, down) = (q, q + num_sites);
+        add_term(&mut obs, &[], &[], interaction / 4.0 - chemical_potential);
+        add_term(
+            &mut obs,
+            &[BitTerm::Z],
+            &[up],
+            chemical_potential / 2.0 - interaction / 4.0,
+        );
+        add_term(
+            &mut obs,
+            &[BitTerm::Z],
+            &[down],
+            chemical_potential / 2.0 - interaction / 4.0,
+        );
+        add_term(
+            &mut obs,
+            &[BitTerm::Z, BitTerm::Z],
+            &[up, down],
+            interaction / 4.0,
+        );
+    }
+    obs.simplify(0.0)
+}
+
+/// Build the cost observable of the MaxCut problem on the graph,
+/// `Σ (Z_a Z_b - I) / 2` over its edges.
+///
+/// A computational basis state is an eigenstate whose eigenvalue is minus
+/// the number of edges it cuts, so the ground states are the maximum cuts.
+pub fn maxcut_observable(graph: &CouplingMap) -> SparseObservable {
+    let edges = undirected_edges(graph);
+    let mut obs = SparseObservable::zero(graph.num_qubits());
+    add_term(&mut obs, &[], &[], -0.5 * edges.len() as f64);
+    for edge in edges {
+        add_term(&mut obs, &[BitTerm::Z, BitTerm::Z], &edge, 0.5);
+    }
+    obs
+}
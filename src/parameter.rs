@@ -0,0 +1,911 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! Symbolic gate parameters.
+//!
+//! A [`Parameter`] is a named symbol that can be used in place of a gate
+//! angle, and a [`ParameterExpression`] is a mathematical expression built
+//! from parameters and floats. Gate methods on
+//! [`QuantumCircuit`](crate::QuantumCircuit) accept anything implementing
+//! [`Angle`], so the same circuit can be bound to new values with
+//! [`QuantumCircuit::assign_parameters`](crate::QuantumCircuit::assign_parameters)
+//! instead of being rebuilt.
+//!
+//! The qiskit 2.2 C API has no symbolic gate parameters, so a circuit keeps
+//! its unbound parameters on the Rust side and the C circuit holds NaN in
+//! their place. Functions that pass a circuit to the C API, such as the
+//! [transpiler](crate::transpiler) passes, return
+//! [`QiskitError::UnsupportedInstruction`](crate::QiskitError::UnsupportedInstruction)
+//! until every parameter is bound.
+//!
+//! # Example
+//!
+//! ```
+//! use qiskit_rs::QuantumCircuit;
+//! use qiskit_rs::parameter::Parameter;
+//! use std::collections::HashMap;
+//!
+//! let theta = Parameter::new("theta");
+//! let mut qc = QuantumCircuit::new(1, 0);
+//! qc.rx(&theta, 0);
+//! qc.rz(&theta * 2.0, 0);
+//! assert_eq!(qc.parameters(), vec![theta.clone()]);
+//!
+//! let bound = qc.assign_parameters(&HashMap::from([(theta, 0.5)])).unwrap();
+//! assert!(bound.parameters().is_empty());
+//! ```
+
+use crate::qiskit::{QiskitError, qk_to_qiskit_error};
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Div, Index, Mul, Neg, Sub};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// The C API only reports errors for null or malformed input, which the safe
+// wrappers below never pass.
+fn check(retval: qiskit_sys::QkExitCode) {
+    let err = qk_to_qiskit_error(retval);
+    assert_eq!(err, QiskitError::Success, "parameter operation failed");
+}
+
+fn param_to_string(param: *const qiskit_sys::QkParam) -> String {
+    unsafe {
+        let ptr = qiskit_sys::qk!(qk_param_str(param));
+        let out = CStr::from_ptr(ptr).to_string_lossy().into_owned();
+        qiskit_sys::qk!(qk_str_free(ptr));
+        out
+    }
+}
+
+/// A named symbol that stands in for a value until it is bound.
+///
+/// Parameters are compared by identity, so two parameters created
+/// separately with the same name are different parameters.
+pub struct Parameter {
+    param: *mut qiskit_sys::QkParam,
+    name: String,
+    // Set for the elements of a `ParameterVector`.
+    element: Option<VectorElement>,
+}
+
+// The position of a parameter in the `ParameterVector` that created it, read
+// by the Python conversions.
+#[derive(Clone, Debug)]
+#[cfg_attr(not(feature = "python"), allow(dead_code))]
+pub(crate) struct VectorElement {
+    // Identifies the vector, since two vectors can have the same name.
+    pub(crate) id: u64,
+    pub(crate) vector: String,
+    pub(crate) length: usize,
+    pub(crate) index: usize,
+}
+
+impl Parameter {
+    /// Create a new parameter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::parameter::Parameter;
+    ///
+    /// let theta = Parameter::new("theta");
+    /// assert_eq!(theta.name(), "theta");
+    /// assert_ne!(theta, Parameter::new("theta"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// With the `runtime-linking` feature, panics if libqiskit wasn't loaded
+    /// with `qiskit_rs::init` and the default library can't be loaded.
+    pub fn new(name: &str) -> Parameter {
+        let cname = CString::new(name).expect("String to CString conversion failed");
+        Parameter {
+            param: unsafe { qiskit_sys::qk!(qk_param_new_symbol(cname.as_ptr())) },
+            name: name.to_string(),
+            element: None,
+        }
+    }
+
+    /// Return the name of the parameter.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[cfg(feature = "python")]
+    pub(crate) fn element(&self) -> Option<&VectorElement> {
+        self.element.as_ref()
+    }
+
+    // Parameters are sorted by name, with the elements of a parameter vector
+    // in index order rather than lexicographic order.
+    pub(crate) fn sort_key(&self) -> (&str, Option<usize>) {
+        let indexed = self.name.strip_suffix(']').and_then(|name| {
+            let (prefix, index) = name.rsplit_once('[')?;
+            Some((prefix, Some(index.parse().ok()?)))
+        });
+        indexed.unwrap_or((&self.name, None))
+    }
+}
+
+impl Clone for Parameter {
+    fn clone(&self) -> Self {
+        Parameter {
+            param: unsafe { qiskit_sys::qk!(qk_param_copy(self.param)) },
+            name: self.name.clone(),
+            element: self.element.clone(),
+        }
+    }
+}
+
+impl PartialEq for Parameter {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qiskit_sys::qk!(qk_param_equal(self.param, other.param)) }
+    }
+}
+
+impl Eq for Parameter {}
+
+impl Hash for Parameter {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+impl fmt::Display for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+impl fmt::Debug for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Parameter({})", self.name)
+    }
+}
+
+impl Drop for Parameter {
+    fn drop(&mut self) {
+        unsafe { qiskit_sys::qk!(qk_param_free(self.param)) };
+    }
+}
+
+/// A fixed-length list of parameters sharing a name.
+///
+/// The element at index `i` is named `name[i]`.
+///
+/// # Example
+///
+/// ```
+/// use qiskit_rs::parameter::ParameterVector;
+///
+/// let theta = ParameterVector::new("theta", 3);
+/// assert_eq!(theta.len(), 3);
+/// assert_eq!(theta[1].name(), "theta[1]");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParameterVector {
+    name: String,
+    params: Vec<Parameter>,
+}
+
+impl ParameterVector {
+    /// Create a new vector of `length` parameters.
+    pub fn new(name: &str, length: usize) -> ParameterVector {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        ParameterVector {
+            name: name.to_string(),
+            params: (0..length)
+                .map(|index| {
+                    let mut param = Parameter::new(&format!("{name}[{index}]"));
+                    param.element = Some(VectorElement {
+                        id,
+                        vector: name.to_string(),
+                        length,
+                        index,
+                    });
+                    param
+                })
+                .collect(),
+        }
+    }
+
+    /// Return the name of the vector.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return the number of parameters in the vector.
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    /// Return whether the vector has no parameters.
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Return an iterator over the parameters in the vector.
+    pub fn iter(&self) -> std::slice::Iter<'_, Parameter> {
+        self.params.iter()
+    }
+}
+
+impl Index<usize> for ParameterVector {
+    type Output = Parameter;
+
+    fn index(&self, index: usize) -> &Parameter {
+        &self.params[index]
+    }
+}
+
+impl<'a> IntoIterator for &'a ParameterVector {
+    type Item = &'a Parameter;
+    type IntoIter = std::slice::Iter<'a, Parameter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.params.iter()
+    }
+}
+
+/// A mathematical expression of parameters and floats.
+///
+/// Expressions are built with the arithmetic operators and the elementary
+/// functions below, and can be partially or fully bound to values with
+/// [`ParameterExpression::bind`].
+///
+/// # Example
+///
+/// ```
+/// use qiskit_rs::parameter::Parameter;
+/// use std::collections::HashMap;
+///
+/// let x = Parameter::new("x");
+/// let y = Parameter::new("y");
+/// let expr = (&x + &y).sin() * 2.0;
+/// assert_eq!(expr.parameters(), &[x.clone(), y.clone()]);
+///
+/// let bound = expr.bind(&HashMap::from([(x, 0.25), (y, 0.5)]));
+/// assert_eq!(bound.value(), Some(0.75f64.sin() * 2.0));
+/// ```
+pub struct ParameterExpression {
+    param: *mut qiskit_sys::QkParam,
+    // The parameters the expression depends on, sorted.
+    parameters: Vec<Parameter>,
+    // The structure of the expression, which the C API doesn't expose.
+    node: Rc<Node>,
+}
+
+// A node of an expression tree. Operations on values alone are folded into a
+// value, so only nodes that depend on a parameter have children.
+#[derive(Debug)]
+pub(crate) enum Node {
+    Value(f64),
+    Symbol(Parameter),
+    Unary(UnaryOp, Rc<Node>),
+    Binary(BinaryOp, Rc<Node>, Rc<Node>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum UnaryOp {
+    Neg,
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Exp,
+    Log,
+    Abs,
+}
+
+impl UnaryOp {
+    fn qk(
+        self,
+    ) -> unsafe extern "C" fn(
+        *mut qiskit_sys::QkParam,
+        *const qiskit_sys::QkParam,
+    ) -> qiskit_sys::QkExitCode {
+        match self {
+            UnaryOp::Neg => qiskit_sys::qk!(qk_param_neg),
+            UnaryOp::Sin => qiskit_sys::qk!(qk_param_sin),
+            UnaryOp::Cos => qiskit_sys::qk!(qk_param_cos),
+            UnaryOp::Tan => qiskit_sys::qk!(qk_param_tan),
+            UnaryOp::Asin => qiskit_sys::qk!(qk_param_asin),
+            UnaryOp::Acos => qiskit_sys::qk!(qk_param_acos),
+            UnaryOp::Atan => qiskit_sys::qk!(qk_param_atan),
+            UnaryOp::Exp => qiskit_sys::qk!(qk_param_exp),
+            UnaryOp::Log => qiskit_sys::qk!(qk_param_log),
+            UnaryOp::Abs => qiskit_sys::qk!(qk_param_abs),
+        }
+    }
+
+    fn apply(self, x: f64) -> f64 {
+        match self {
+            UnaryOp::Neg => -x,
+            UnaryOp::Sin => x.sin(),
+            UnaryOp::Cos => x.cos(),
+            UnaryOp::Tan => x.tan(),
+            UnaryOp::Asin => x.asin(),
+            UnaryOp::Acos => x.acos(),
+            UnaryOp::Atan => x.atan(),
+            UnaryOp::Exp => x.exp(),
+            UnaryOp::Log => x.ln(),
+            UnaryOp::Abs => x.abs(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+impl BinaryOp {
+    fn qk(
+        self,
+    ) -> unsafe extern "C" fn(
+        *mut qiskit_sys::QkParam,
+        *const qiskit_sys::QkParam,
+        *const qiskit_sys::QkParam,
+    ) -> qiskit_sys::QkExitCode {
+        match self {
+            BinaryOp::Add => qiskit_sys::qk!(qk_param_add),
+            BinaryOp::Sub => qiskit_sys::qk!(qk_param_sub),
+            BinaryOp::Mul => qiskit_sys::qk!(qk_param_mul),
+            BinaryOp::Div => qiskit_sys::qk!(qk_param_div),
+            BinaryOp::Pow => qiskit_sys::qk!(qk_param_pow),
+        }
+    }
+
+    fn apply(self, lhs: f64, rhs: f64) -> f64 {
+        match self {
+            BinaryOp::Add => lhs + rhs,
+            BinaryOp::Sub => lhs - rhs,
+            BinaryOp::Mul => lhs * rhs,
+            BinaryOp::Div => lhs / rhs,
+            BinaryOp::Pow => lhs.powf(rhs),
+        }
+    }
+}
+
+fn unary_node(op: UnaryOp, arg: &Rc<Node>) -> Rc<Node> {
+    match **arg {
+        Node::Value(x) => Rc::new(Node::Value(op.apply(x))),
+        _ => Rc::new(Node::Unary(op, arg.clone())),
+    }
+}
+
+fn binary_node(op: BinaryOp, lhs: &Rc<Node>, rhs: &Rc<Node>) -> Rc<Node> {
+    match (&**lhs, &**rhs) {
+        (Node::Value(x), Node::Value(y)) => Rc::new(Node::Value(op.apply(*x, *y))),
+        _ => Rc::new(Node::Binary(op, lhs.clone(), rhs.clone())),
+    }
+}
+
+fn bind_node(node: &Rc<Node>, values: &HashMap<Parameter, f64>) -> Rc<Node> {
+    match &**node {
+        Node::Value(_) => node.clone(),
+        Node::Symbol(param) => match values.get(param) {
+            Some(value) => Rc::new(Node::Value(*value)),
+            None => node.clone(),
+        },
+        Node::Unary(op, arg) => unary_node(*op, &bind_node(arg, values)),
+        Node::Binary(op, lhs, rhs) => {
+            binary_node(*op, &bind_node(lhs, values), &bind_node(rhs, values))
+        }
+    }
+}
+
+impl ParameterExpression {
+    /// Return the parameters the expression depends on, sorted by name.
+    pub fn parameters(&self) -> &[Parameter] {
+        &self.parameters
+    }
+
+    #[cfg(feature = "python")]
+    pub(crate) fn node(&self) -> &Node {
+        &self.node
+    }
+
+    /// Return the value of the expression, or `None` if it still depends on
+    /// unbound parameters.
+    pub fn value(&self) -> Option<f64> {
+        if !self.parameters.is_empty() {
+            return None;
+        }
+        Some(unsafe { qiskit_sys::qk!(qk_param_as_real(self.param)) })
+    }
+
+    /// Return a new expression with the parameters in `values` replaced by
+    /// their values.
+    ///
+    /// Entries for parameters the expression does not depend on are
+    /// ignored.
+    pub fn bind(&self, values: &HashMap<Parameter, f64>) -> ParameterExpression {
+        let mut keys: Vec<*const qiskit_sys::QkParam> = Vec::new();
+        let mut bound: Vec<f64> = Vec::new();
+        let mut parameters: Vec<Parameter> = Vec::new();
+        for param in &self.parameters {
+            match values.get(param) {
+                Some(value) => {
+                    keys.push(param.param);
+                    bound.push(*value);
+                }
+                None => parameters.push(param.clone()),
+            }
+        }
+        let out = unsafe { qiskit_sys::qk!(qk_param_zero()) };
+        check(unsafe {
+            qiskit_sys::qk!(qk_param_bind(
+                out,
+                self.param,
+                keys.as_ptr(),
+                bound.as_ptr(),
+                keys.len()
+            ))
+        });
+        ParameterExpression {
+            param: out,
+            parameters,
+            node: bind_node(&self.node, values),
+        }
+    }
+
+    fn unary(&self, op: UnaryOp) -> ParameterExpression {
+        let out = unsafe { qiskit_sys::qk!(qk_param_zero()) };
+        check(unsafe { op.qk()(out, self.param) });
+        ParameterExpression {
+            param: out,
+            parameters: self.parameters.clone(),
+            node: unary_node(op, &self.node),
+        }
+    }
+
+    fn binary(&self, other: &ParameterExpression, op: BinaryOp) -> ParameterExpression {
+        let out = unsafe { qiskit_sys::qk!(qk_param_zero()) };
+        check(unsafe { op.qk()(out, self.param, other.param) });
+        let mut parameters = self.parameters.clone();
+        for param in &other.parameters {
+            if !parameters.contains(param) {
+                parameters.push(param.clone());
+            }
+        }
+        parameters.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+        ParameterExpression {
+            param: out,
+            parameters,
+            node: binary_node(op, &self.node, &other.node),
+        }
+    }
+
+    /// Return the sine of the expression.
+    pub fn sin(&self) -> ParameterExpression {
+        self.unary(UnaryOp::Sin)
+    }
+
+    /// Return the cosine of the expression.
+    pub fn cos(&self) -> ParameterExpression {
+        self.unary(UnaryOp::Cos)
+    }
+
+    /// Return the tangent of the expression.
+    pub fn tan(&self) -> ParameterExpression {
+        self.unary(UnaryOp::Tan)
+    }
+
+    /// Return the arcsine of the expression.
+    pub fn asin(&self) -> ParameterExpression {
+        self.unary(UnaryOp::Asin)
+    }
+
+    /// Return the arccosine of the expression.
+    pub fn acos(&self) -> ParameterExpression {
+        self.unary(UnaryOp::Acos)
+    }
+
+    /// Return the arctangent of the expression.
+    pub fn atan(&self) -> ParameterExpression {
+        self.unary(UnaryOp::Atan)
+    }
+
+    /// Return the exponential of the expression.
+    pub fn exp(&self) -> ParameterExpression {
+        self.unary(UnaryOp::Exp)
+    }
+
+    /// Return the natural logarithm of the expression.
+    pub fn log(&self) -> ParameterExpression {
+        self.unary(UnaryOp::Log)
+    }
+
+    /// Return the absolute value of the expression.
+    pub fn abs(&self) -> ParameterExpression {
+        self.unary(UnaryOp::Abs)
+    }
+
+    /// Return the expression raised to the power `exponent`.
+    pub fn pow(&self, exponent: impl Into<ParameterExpression>) -> ParameterExpression {
+        self.binary(&exponent.into(), BinaryOp::Pow)
+    }
+}
+
+impl Clone for ParameterExpression {
+    fn clone(&self) -> Self {
+        ParameterExpression {
+            param: unsafe { qiskit_sys::qk!(qk_param_copy(self.param)) },
+            parameters: self.parameters.clone(),
+            node: self.node.clone(),
+        }
+    }
+}
+
+impl PartialEq for ParameterExpression {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { qiskit_sys::qk!(qk_param_equal(self.param, other.param)) }
+    }
+}
+
+impl fmt::Display for ParameterExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&param_to_string(self.param))
+    }
+}
+
+impl fmt::Debug for ParameterExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ParameterExpression({self})")
+    }
+}
+
+impl Drop for ParameterExpression {
+    fn drop(&mut self) {
+        unsafe { qiskit_sys::qk!(qk_param_free(self.param)) };
+    }
+}
+
+impl From<f64> for ParameterExpression {
+    fn from(value: f64) -> Self {
+        ParameterExpression {
+            param: unsafe { qiskit_sys::qk!(qk_param_from_double(value)) },
+            parameters: Vec::new(),
+            node: Rc::new(Node::Value(value)),
+        }
+    }
+}
+
+impl From<Parameter> for ParameterExpression {
+    fn from(param: Parameter) -> Self {
+        ParameterExpression {
+            param: unsafe { qiskit_sys::qk!(qk_param_copy(param.param)) },
+            node: Rc::new(Node::Symbol(param.clone())),
+            parameters: vec![param],
+        }
+    }
+}
+
+impl From<&Parameter> for ParameterExpression {
+    fn from(param: &Parameter) -> Self {
+        param.clone().into()
+    }
+}
+
+impl From<&ParameterExpression> for ParameterExpression {
+    fn from(expr: &ParameterExpression) -> Self {
+        expr.clone()
+    }
+}
+
+impl Neg for &ParameterExpression {
+    type Output = ParameterExpression;
+
+    fn neg(self) -> ParameterExpression {
+        self.unary(UnaryOp::Neg)
+    }
+}
+
+impl Neg for ParameterExpression {
+    type Output = ParameterExpression;
+
+    fn neg(self) -> ParameterExpression {
+        -&self
+    }
+}
+
+impl Neg for &Parameter {
+    type Output = ParameterExpression;
+
+    fn neg(self) -> ParameterExpression {
+        -ParameterExpression::from(self)
+    }
+}
+
+impl Neg for Parameter {
+    type Output = ParameterExpression;
+
+    fn neg(self) -> ParameterExpression {
+        -ParameterExpression::from(self)
+    }
+}
+
+// Arithmetic between any mix of parameters, expressions and floats.
+macro_rules! expression_ops {
+    ($($lhs:ty),*) => {$(
+        impl<'a, R: Into<ParameterExpression>> Add<R> for $lhs {
+            type Output = ParameterExpression;
+
+            fn add(self, rhs: R) -> ParameterExpression {
+                ParameterExpression::from(self).binary(&rhs.into(), BinaryOp::Add)
+            }
+        }
+
+        impl<'a, R: Into<ParameterExpression>> Sub<R> for $lhs {
+            type Output = ParameterExpression;
+
+            fn sub(self, rhs: R) -> ParameterExpression {
+                ParameterExpression::from(self).binary(&rhs.into(), BinaryOp::Sub)
+            }
+        }
+
+        impl<'a, R: Into<ParameterExpression>> Mul<R> for $lhs {
+            type Output = ParameterExpression;
+
+            fn mul(self, rhs: R) -> ParameterExpression {
+                ParameterExpression::from(self).binary(&rhs.into(), BinaryOp::Mul)
+            }
+        }
+
+        impl<'a, R: Into<ParameterExpression>> Div<R> for $lhs {
+            type Output = ParameterExpression;
+
+            fn div(self, rhs: R) -> ParameterExpression {
+                ParameterExpression::from(self).binary(&rhs.into(), BinaryOp::Div)
+            }
+        }
+
+        impl<'a> Add<$lhs> for f64 {
+            type Output = ParameterExpression;
+
+            fn add(self, rhs: $lhs) -> ParameterExpression {
+                ParameterExpression::from(self) + rhs
+            }
+        }
+
+        impl<'a> Sub<$lhs> for f64 {
+            type Output = ParameterExpression;
+
+            fn sub(self, rhs: $lhs) -> ParameterExpression {
+                ParameterExpression::from(self) - rhs
+            }
+        }
+
+        impl<'a> Mul<$lhs> for f64 {
+            type Output = ParameterExpression;
+
+            fn mul(self, rhs: $lhs) -> ParameterExpression {
+                ParameterExpression::from(self) * rhs
+            }
+        }
+
+        impl<'a> Div<$lhs> for f64 {
+            type Output = ParameterExpression;
+
+            fn div(self, rhs: $lhs) -> ParameterExpression {
+                ParameterExpression::from(self) / rhs
+            }
+        }
+    )*};
+}
+
+expression_ops!(
+    ParameterExpression,
+    &'a ParameterExpression,
+    Parameter,
+    &'a Parameter
+);
+
+/// A gate parameter that is either a float or a symbolic expression.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParameterValue {
+    /// A bound value.
+    Float(f64),
+    /// An expression that depends on at least one unbound parameter.
+    Expression(ParameterExpression),
+}
+
+impl ParameterValue {
+    /// Return the value if it is bound.
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            ParameterValue::Float(value) => Some(*value),
+            ParameterValue::Expression(_) => None,
+        }
+    }
+
+    /// Return the parameters the value depends on.
+    pub fn parameters(&self) -> &[Parameter] {
+        match self {
+            ParameterValue::Float(_) => &[],
+            ParameterValue::Expression(expr) => expr.parameters(),
+        }
+    }
+
+    /// Return a new value with the parameters in `values` replaced by their
+    /// values.
+    pub fn bind(&self, values: &HashMap<Parameter, f64>) -> ParameterValue {
+        match self {
+            ParameterValue::Float(value) => ParameterValue::Float(*value),
+            ParameterValue::Expression(expr) => expr.bind(values).to_value(),
+        }
+    }
+
+    fn combine(
+        &self,
+        other: &ParameterValue,
+        float: fn(f64, f64) -> f64,
+        expr: fn(ParameterExpression, ParameterExpression) -> ParameterExpression,
+    ) -> ParameterValue {
+        match (self, other) {
+            (ParameterValue::Float(lhs), ParameterValue::Float(rhs)) => {
+                ParameterValue::Float(float(*lhs, *rhs))
+            }
+            _ => ParameterValue::Expression(expr(self.into(), other.into())),
+        }
+    }
+}
+
+impl fmt::Display for ParameterValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParameterValue::Float(value) => write!(f, "{value}"),
+            ParameterValue::Expression(expr) => write!(f, "{expr}"),
+        }
+    }
+}
+
+impl From<f64> for ParameterValue {
+    fn from(value: f64) -> Self {
+        ParameterValue::Float(value)
+    }
+}
+
+impl From<ParameterExpression> for ParameterValue {
+    fn from(expr: ParameterExpression) -> Self {
+        expr.to_value()
+    }
+}
+
+impl From<Parameter> for ParameterValue {
+    fn from(param: Parameter) -> Self {
+        ParameterValue::Expression(param.into())
+    }
+}
+
+impl From<&ParameterValue> for ParameterValue {
+    fn from(value: &ParameterValue) -> Self {
+        value.clone()
+    }
+}
+
+impl From<&ParameterValue> for ParameterExpression {
+    fn from(value: &ParameterValue) -> Self {
+        match value {
+            ParameterValue::Float(value) => ParameterExpression::from(*value),
+            ParameterValue::Expression(expr) => expr.clone(),
+        }
+    }
+}
+
+impl Neg for &ParameterValue {
+    type Output = ParameterValue;
+
+    fn neg(self) -> ParameterValue {
+        match self {
+            ParameterValue::Float(value) => ParameterValue::Float(-value),
+            ParameterValue::Expression(expr) => ParameterValue::Expression(-expr),
+        }
+    }
+}
+
+impl Neg for ParameterValue {
+    type Output = ParameterValue;
+
+    fn neg(self) -> ParameterValue {
+        -&self
+    }
+}
+
+// Arithmetic on values that keeps floats as floats.
+macro_rules! value_ops {
+    ($($lhs:ty),*) => {$(
+        impl<'a, R: Into<ParameterValue>> Add<R> for $lhs {
+            type Output = ParameterValue;
+
+            fn add(self, rhs: R) -> ParameterValue {
+                self.combine(&rhs.into(), |a, b| a + b, |a, b| a + b)
+            }
+        }
+
+        impl<'a, R: Into<ParameterValue>> Sub<R> for $lhs {
+            type Output = ParameterValue;
+
+            fn sub(self, rhs: R) -> ParameterValue {
+                self.combine(&rhs.into(), |a, b| a - b, |a, b| a - b)
+            }
+        }
+
+        impl<'a, R: Into<ParameterValue>> Mul<R> for $lhs {
+            type Output = ParameterValue;
+
+            fn mul(self, rhs: R) -> ParameterValue {
+                self.combine(&rhs.into(), |a, b| a * b, |a, b| a * b)
+            }
+        }
+
+        impl<'a, R: Into<ParameterValue>> Div<R> for $lhs {
+            type Output = ParameterValue;
+
+            fn div(self, rhs: R) -> ParameterValue {
+                self.combine(&rhs.into(), |a, b| a / b, |a, b| a / b)
+            }
+        }
+    )*};
+}
+
+value_ops!(ParameterValue, &'a ParameterValue);
+
+/// A gate angle: a float, a [`Parameter`] or a [`ParameterExpression`].
+pub trait Angle {
+    /// Convert the angle into a [`ParameterValue`].
+    fn to_value(&self) -> ParameterValue;
+}
+
+impl Angle for f64 {
+    fn to_value(&self) -> ParameterValue {
+        ParameterValue::Float(*self)
+    }
+}
+
+impl Angle for Parameter {
+    fn to_value(&self) -> ParameterValue {
+        ParameterValue::Expression(self.into())
+    }
+}
+
+impl Angle for ParameterExpression {
+    fn to_value(&self) -> ParameterValue {
+        match self.value() {
+            Some(value) => ParameterValue::Float(value),
+            None => ParameterValue::Expression(self.clone()),
+        }
+    }
+}
+
+impl Angle for ParameterValue {
+    fn to_value(&self) -> ParameterValue {
+        self.clone()
+    }
+}
+
+impl<T: Angle + ?Sized> Angle for &T {
+    fn to_value(&self) -> ParameterValue {
+        (**self).to_value()
+    }
+}
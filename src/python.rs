@@ -0,0 +1,556 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! Conversions to and from the objects of the Qiskit Python package.
+//!
+//! Circuits are converted instruction by instruction: standard gates,
+//! measurements, resets and barriers are supported, and other Python
+//! operations are replaced by their definition. Gate parameters can be
+//! floats or expressions of parameters built from the arithmetic operators,
+//! powers and the functions of [`ParameterExpression`]; elements of a
+//! [`ParameterVector`] stay elements of a vector of the same name and length.
+//! A parameter converts to one object on the other side, by identity rather
+//! than by name. Expressions coming from Python are read through their sympy
+//! form, so sympy must be installed.
+//!
+//! Registers are carried over. Rust circuits have their loose bits first and
+//! the registers after them, so Python registers that share bits, aren't
+//! contiguous or come before loose bits are rejected with a `ValueError`, as
+//! are control flow, operations without a definition and other functions.
+
+use crate::complex::Complex64;
+use crate::parameter::{
+    Angle, BinaryOp, Node, Parameter, ParameterExpression, ParameterValue, ParameterVector, UnaryOp,
+};
+use crate::qiskit::{
+    ClassicalRegister, QiskitError, QuantumCircuit, QuantumRegister, Registers, StandardGate,
+};
+use crate::sparse_observable::SparseObservable;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyComplex, PyFloat, PyTuple};
+use std::collections::HashMap;
+
+fn check(result: QiskitError) -> PyResult<()> {
+    result
+        .into_result()
+        .map_err(|e| PyValueError::new_err(format!("{e:?}")))
+}
+
+// The methods of Python's ParameterExpression for the functions of an expression.
+fn method(op: UnaryOp) -> &'static str {
+    match op {
+        UnaryOp::Neg => "__neg__",
+        UnaryOp::Sin => "sin",
+        UnaryOp::Cos => "cos",
+        UnaryOp::Tan => "tan",
+        UnaryOp::Asin => "arcsin",
+        UnaryOp::Acos => "arccos",
+        UnaryOp::Atan => "arctan",
+        UnaryOp::Exp => "exp",
+        UnaryOp::Log => "log",
+        UnaryOp::Abs => "abs",
+    }
+}
+
+// Builds Python parameter values, sharing one `Parameter` object per parameter and one
+// `ParameterVector` object per vector.
+struct PyParameters<'py> {
+    py: Python<'py>,
+    parameter: Bound<'py, PyAny>,
+    parameter_vector: Bound<'py, PyAny>,
+    parameters: HashMap<Parameter, Bound<'py, PyAny>>,
+    vectors: HashMap<u64, Bound<'py, PyAny>>,
+}
+
+impl<'py> PyParameters<'py> {
+    fn new(py: Python<'py>) -> PyResult<Self> {
+        let module = py.import("qiskit.circuit")?;
+        Ok(PyParameters {
+            py,
+            parameter: module.getattr("Parameter")?,
+            parameter_vector: module.getattr("ParameterVector")?,
+            parameters: HashMap::new(),
+            vectors: HashMap::new(),
+        })
+    }
+
+    fn value(&mut self, value: &ParameterValue) -> PyResult<Bound<'py, PyAny>> {
+        match value {
+            ParameterValue::Float(value) => Ok(PyFloat::new(self.py, *value).into_any()),
+            ParameterValue::Expression(expr) => self.expression(expr.node()),
+        }
+    }
+
+    // Rebuilds the expression with the operators and methods of the Python objects.
+    fn expression(&mut self, node: &Node) -> PyResult<Bound<'py, PyAny>> {
+        match node {
+            Node::Value(value) => Ok(PyFloat::new(self.py, *value).into_any()),
+            Node::Symbol(parameter) => self.parameter(parameter),
+            Node::Unary(op, arg) => self.expression(arg)?.call_method0(method(*op)),
+            Node::Binary(op, lhs, rhs) => {
+                let lhs = self.expression(lhs)?;
+                let rhs = self.expression(rhs)?;
+                match op {
+                    BinaryOp::Add => lhs.add(rhs),
+                    BinaryOp::Sub => lhs.sub(rhs),
+                    BinaryOp::Mul => lhs.mul(rhs),
+                    BinaryOp::Div => lhs.div(rhs),
+                    BinaryOp::Pow => lhs.pow(rhs, self.py.None()),
+                }
+            }
+        }
+    }
+
+    fn parameter(&mut self, parameter: &Parameter) -> PyResult<Bound<'py, PyAny>> {
+        if let Some(object) = self.parameters.get(parameter) {
+            return Ok(object.clone());
+        }
+        let object = match parameter.element() {
+            Some(element) => {
+                let vector = match self.vectors.get(&element.id) {
+                    Some(vector) => vector.clone(),
+                    None => {
+                        let vector = self
+                            .parameter_vector
+                            .call1((&element.vector, element.length))?;
+                        self.vectors.insert(element.id, vector.clone());
+                        vector
+                    }
+                };
+                vector.get_item(element.index)?
+            }
+            None => self.parameter.call1((parameter.name(),))?,
+        };
+        self.parameters.insert(parameter.clone(), object.clone());
+        Ok(object)
+    }
+}
+
+// Builds Rust parameter values, sharing one `Parameter` per Python parameter, told apart by
+// their uuid, and one `ParameterVector` per Python vector object.
+struct RustParameters<'py> {
+    expression: Bound<'py, PyAny>,
+    vector_element: Bound<'py, PyAny>,
+    parameters: HashMap<u128, Parameter>,
+    vectors: Vec<(Bound<'py, PyAny>, ParameterVector)>,
+}
+
+impl<'py> RustParameters<'py> {
+    fn new(py: Python<'py>) -> PyResult<Self> {
+        Ok(RustParameters {
+            expression: py
+                .import("qiskit.circuit")?
+                .getattr("ParameterExpression")?,
+            vector_element: py
+                .import("qiskit.circuit.parametervector")?
+                .getattr("ParameterVectorElement")?,
+            parameters: HashMap::new(),
+            vectors: Vec::new(),
+        })
+    }
+
+    fn value(&mut self, value: &Bound<'py, PyAny>) -> PyResult<ParameterValue> {
+        if let Ok(value) = value.extract::<f64>() {
+            return Ok(ParameterValue::Float(value));
+        }
+        if !value.is_instance(&self.expression)? {
+            return Err(PyValueError::new_err(format!(
+                "only floats and parameter expressions can be converted, got {value}"
+            )));
+        }
+        let mut symbols = HashMap::new();
+        for parameter in value.getattr("parameters")?.try_iter()? {
+            let parameter = parameter?;
+            let name: String = parameter.getattr("name")?.extract()?;
+            symbols.insert(name, parameter);
+        }
+        let expr = self.expression(&value.call_method0("sympify")?, &symbols)?;
+        Ok(expr.to_value())
+    }
+
+    // Rebuilds the sympy form of an expression, whose symbols are named after the parameters.
+    fn expression(
+        &mut self,
+        expr: &Bound<'py, PyAny>,
+        symbols: &HashMap<String, Bound<'py, PyAny>>,
+    ) -> PyResult<ParameterExpression> {
+        if expr.getattr("is_Symbol")?.is_truthy()? {
+            let name: String = expr.getattr("name")?.extract()?;
+            let parameter = symbols.get(&name).ok_or_else(|| {
+                PyValueError::new_err(format!("the symbol {name} isn't a parameter"))
+            })?;
+            return Ok(self.parameter(parameter)?.into());
+        }
+        if expr.getattr("is_number")?.is_truthy()? {
+            let value = expr.extract::<f64>().map_err(|_| {
+                PyValueError::new_err(format!("only real values can be converted, got {expr}"))
+            })?;
+            return Ok(value.into());
+        }
+        let function: String = expr.getattr("func")?.getattr("__name__")?.extract()?;
+        let args = expr
+            .getattr("args")?
+            .try_iter()?
+            .map(|arg| self.expression(&arg?, symbols))
+            .collect::<PyResult<Vec<_>>>()?;
+        let expr = match (function.as_str(), args.as_slice()) {
+            ("Add", [first, rest @ ..]) => rest.iter().fold(first.clone(), |acc, arg| acc + arg),
+            ("Mul", [first, rest @ ..]) => rest.iter().fold(first.clone(), |acc, arg| acc * arg),
+            ("Pow", [base, exponent]) => base.pow(exponent),
+            ("sin", [arg]) => arg.sin(),
+            ("cos", [arg]) => arg.cos(),
+            ("tan", [arg]) => arg.tan(),
+            ("asin", [arg]) => arg.asin(),
+            ("acos", [arg]) => arg.acos(),
+            ("atan", [arg]) => arg.atan(),
+            ("exp", [arg]) => arg.exp(),
+            ("log", [arg]) => arg.log(),
+            ("Abs", [arg]) => arg.abs(),
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "the function {function} can't be converted from Python"
+                )));
+            }
+        };
+        Ok(expr)
+    }
+
+    fn parameter(&mut self, parameter: &Bound<'py, PyAny>) -> PyResult<Parameter> {
+        let uuid: u128 = parameter.getattr("uuid")?.getattr("int")?.extract()?;
+        if let Some(parameter) = self.parameters.get(&uuid) {
+            return Ok(parameter.clone());
+        }
+        let out = if parameter.is_instance(&self.vector_element)? {
+            let vector = parameter.getattr("vector")?;
+            let index: usize = parameter.getattr("index")?.extract()?;
+            let position = self
+                .vectors
+                .iter()
+                .position(|(object, _)| object.is(&vector));
+            let position = match position {
+                Some(position) => position,
+                None => {
+                    let name: String = vector.getattr("name")?.extract()?;
+                    let rust_vector = ParameterVector::new(&name, vector.len()?);
+                    self.vectors.push((vector, rust_vector));
+                    self.vectors.len() - 1
+                }
+            };
+            self.vectors[position].1[index].clone()
+        } else {
+            Parameter::new(&parameter.getattr("name")?.extract::<String>()?)
+        };
+        self.parameters.insert(uuid, out.clone());
+        Ok(out)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &QuantumCircuit {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let module = py.import("qiskit.circuit")?;
+        let circuit = module.getattr("QuantumCircuit")?.call0()?;
+        let (qregs, cregs) = self.registers();
+        add_bits(
+            &circuit,
+            &module.getattr("Qubit")?,
+            &module.getattr("QuantumRegister")?,
+            self.num_qubits(),
+            qregs,
+        )?;
+        add_bits(
+            &circuit,
+            &module.getattr("Clbit")?,
+            &module.getattr("ClassicalRegister")?,
+            self.num_clbits(),
+            cregs,
+        )?;
+        let gates = py
+            .import("qiskit.circuit.library")?
+            .call_method0("get_standard_gate_name_mapping")?;
+        let mut parameters = PyParameters::new(py)?;
+        for inst in self.instructions() {
+            if inst.control_flow().is_some() {
+                return Err(PyValueError::new_err(format!(
+                    "control flow ({}) can't be converted to Python",
+                    inst.name
+                )));
+            }
+            match inst.name {
+                "measure" => {
+                    circuit.call_method1("measure", (inst.qubits[0], inst.clbits[0]))?;
+                }
+                "reset" => {
+                    circuit.call_method1("reset", (inst.qubits[0],))?;
+                }
+                "barrier" => {
+                    circuit.call_method1("barrier", (inst.qubits.to_vec(),))?;
+                }
+                name if StandardGate::from_name(name).is_some() => {
+                    let params = inst
+                        .param_values()
+                        .iter()
+                        .map(|value| parameters.value(value))
+                        .collect::<PyResult<Vec<_>>>()?;
+                    let gate = gates
+                        .get_item(name)?
+                        .get_type()
+                        .call1(PyTuple::new(py, params)?)?;
+                    circuit.call_method1("append", (gate, inst.qubits.to_vec()))?;
+                }
+                name => {
+                    return Err(PyValueError::new_err(format!(
+                        "the {name} operation can't be converted to Python"
+                    )));
+                }
+            }
+        }
+        Ok(circuit)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for QuantumCircuit {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        (&self).into_pyobject(py)
+    }
+}
+
+// Add the bits of a Rust circuit to a Python circuit: the loose bits before the first
+// register, then the registers.
+fn add_bits<'py>(
+    circuit: &Bound<'py, PyAny>,
+    bit: &Bound<'py, PyAny>,
+    register: &Bound<'py, PyAny>,
+    num_bits: u32,
+    registers: &Registers,
+) -> PyResult<()> {
+    let loose = registers.first().map_or(num_bits, |(_, bits)| bits.start);
+    let bits = (0..loose)
+        .map(|_| bit.call0())
+        .collect::<PyResult<Vec<_>>>()?;
+    circuit.call_method1("add_bits", (bits,))?;
+    for (name, bits) in registers {
+        circuit.call_method1("add_register", (register.call1((bits.len(), name))?,))?;
+    }
+    Ok(())
+}
+
+// The number of loose bits of a Python circuit and its registers after them, as names and
+// sizes, from the `qregs` or `cregs` attribute. Rust circuits add registers after the
+// existing bits, so the registers must hold the bits after the loose ones in order, without
+// sharing any.
+fn py_registers(
+    ob: &Bound<'_, PyAny>,
+    attr: &str,
+    num_bits: u32,
+) -> PyResult<(u32, Vec<(String, u32)>)> {
+    let mut registers = Vec::new();
+    for register in ob.getattr(attr)?.try_iter()? {
+        let register = register?;
+        let name: String = register.getattr("name")?.extract()?;
+        let bits = register
+            .try_iter()?
+            .map(|bit| bit_index(ob, &bit?))
+            .collect::<PyResult<Vec<u32>>>()?;
+        let start = bits.first().copied().unwrap_or(num_bits);
+        if bits
+            .iter()
+            .zip(start..)
+            .any(|(bit, expected)| *bit != expected)
+        {
+            return Err(PyValueError::new_err(format!(
+                "the bits of register {name} aren't contiguous, which can't be converted from Python"
+            )));
+        }
+        registers.push((start, name, bits.len() as u32));
+    }
+    registers.sort_by_key(|(start, _, _)| *start);
+    let loose = registers.first().map_or(num_bits, |(start, _, _)| *start);
+    let mut next = loose;
+    for (start, name, size) in &registers {
+        if *start != next {
+            return Err(PyValueError::new_err(format!(
+                "register {name} shares bits with another register or follows loose bits, which can't be converted from Python"
+            )));
+        }
+        next += size;
+    }
+    if next != num_bits {
+        return Err(PyValueError::new_err(
+            "loose bits after a register can't be converted from Python",
+        ));
+    }
+    let registers = registers
+        .into_iter()
+        .map(|(_, name, size)| (name, size))
+        .collect();
+    Ok((loose, registers))
+}
+
+fn bit_index(ob: &Bound<'_, PyAny>, bit: &Bound<'_, PyAny>) -> PyResult<u32> {
+    ob.call_method1("find_bit", (bit,))?
+        .getattr("index")?
+        .extract()
+}
+
+// Append the instructions of the Python circuit `ob` to `circuit`, with the bits of `ob`
+// mapped to `qubits` and `clbits`. Operations other than standard gates, measurements,
+// resets and barriers are replaced by their definition.
+fn append_instructions<'py>(
+    circuit: &mut QuantumCircuit,
+    ob: &Bound<'py, PyAny>,
+    qubits: &[u32],
+    clbits: &[u32],
+    gates: &Bound<'py, PyAny>,
+    parameters: &mut RustParameters<'py>,
+) -> PyResult<()> {
+    for inst in ob.getattr("data")?.try_iter()? {
+        let inst = inst?;
+        let operation = inst.getattr("operation")?;
+        let name: String = operation.getattr("name")?.extract()?;
+        let inst_qubits = inst
+            .getattr("qubits")?
+            .try_iter()?
+            .map(|bit| Ok(qubits[bit_index(ob, &bit?)? as usize]))
+            .collect::<PyResult<Vec<u32>>>()?;
+        let inst_clbits = inst
+            .getattr("clbits")?
+            .try_iter()?
+            .map(|bit| Ok(clbits[bit_index(ob, &bit?)? as usize]))
+            .collect::<PyResult<Vec<u32>>>()?;
+        // A gate only converts to a standard gate if it is the class of the standard gate of
+        // that name, not a custom gate with the same name.
+        let standard = match (StandardGate::from_name(&name), gates.get_item(&name)) {
+            (Some(gate), Ok(standard)) if operation.get_type().is(standard.get_type()) => {
+                Some(gate)
+            }
+            _ => None,
+        };
+        let result = match (name.as_str(), standard) {
+            ("measure", _) => circuit.measure(inst_qubits[0], inst_clbits[0]),
+            ("reset", _) => circuit.reset(inst_qubits[0]),
+            ("barrier", _) => circuit.barrier(&inst_qubits),
+            (_, Some(gate)) => {
+                let params = operation
+                    .getattr("params")?
+                    .try_iter()?
+                    .map(|param| parameters.value(&param?))
+                    .collect::<PyResult<Vec<_>>>()?;
+                circuit.append_parameterized_gate(gate, &inst_qubits, &params)
+            }
+            _ => {
+                let definition = operation.getattr("definition")?;
+                if definition.is_none() {
+                    return Err(PyValueError::new_err(format!(
+                        "the {name} operation has no definition and can't be converted from Python"
+                    )));
+                }
+                append_instructions(
+                    circuit,
+                    &definition,
+                    &inst_qubits,
+                    &inst_clbits,
+                    gates,
+                    parameters,
+                )?;
+                QiskitError::Success
+            }
+        };
+        check(result)?;
+    }
+    let phase = parameters.value(&ob.getattr("global_phase")?)?;
+    if phase.as_float() != Some(0.0) {
+        check(circuit.global_phase(phase))?;
+    }
+    Ok(())
+}
+
+impl<'py> FromPyObject<'py> for QuantumCircuit {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let py = ob.py();
+        let num_qubits: u32 = ob.getattr("num_qubits")?.extract()?;
+        let num_clbits: u32 = ob.getattr("num_clbits")?.extract()?;
+        let (loose_qubits, qregs) = py_registers(ob, "qregs", num_qubits)?;
+        let (loose_clbits, cregs) = py_registers(ob, "cregs", num_clbits)?;
+        let mut circuit = QuantumCircuit::new(loose_qubits, loose_clbits);
+        for (name, size) in qregs {
+            circuit.add_quantum_register(QuantumRegister::new(size, &name));
+        }
+        for (name, size) in cregs {
+            circuit.add_classical_register(ClassicalRegister::new(size, &name));
+        }
+        let gates = py
+            .import("qiskit.circuit.library")?
+            .call_method0("get_standard_gate_name_mapping")?;
+        let mut parameters = RustParameters::new(py)?;
+        let qubits: Vec<u32> = (0..num_qubits).collect();
+        let clbits: Vec<u32> = (0..num_clbits).collect();
+        append_instructions(&mut circuit, ob, &qubits, &clbits, &gates, &mut parameters)?;
+        Ok(circuit)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &SparseObservable {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let terms = self
+            .terms()
+            .map(|term| {
+                let label: String = term.bit_terms.iter().map(|bit| bit.label()).collect();
+                let coeff = PyComplex::from_doubles(py, term.coeff.re, term.coeff.im);
+                (label, term.indices, coeff)
+            })
+            .collect::<Vec<_>>();
+        py.import("qiskit.quantum_info")?
+            .getattr("SparseObservable")?
+            .call_method1("from_sparse_list", (terms, self.num_qubits()))
+    }
+}
+
+impl<'py> IntoPyObject<'py> for SparseObservable {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        (&self).into_pyobject(py)
+    }
+}
+
+impl<'py> FromPyObject<'py> for SparseObservable {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let num_qubits: u32 = ob.getattr("num_qubits")?.extract()?;
+        let mut terms = Vec::new();
+        for term in ob.call_method0("to_sparse_list")?.try_iter()? {
+            let (label, indices, coeff): (String, Vec<u32>, Bound<'py, PyComplex>) =
+                term?.extract()?;
+            terms.push((label, indices, Complex64::new(coeff.real(), coeff.imag())));
+        }
+        let terms: Vec<(&str, &[u32], Complex64)> = terms
+            .iter()
+            .map(|(label, indices, coeff)| (label.as_str(), indices.as_slice(), *coeff))
+            .collect();
+        SparseObservable::from_sparse_list(&terms, num_qubits)
+            .map_err(|e| PyValueError::new_err(format!("{e:?}")))
+    }
+}
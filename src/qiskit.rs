@@ -10,8 +10,14 @@
 // copyright notice, and modified files need to carry a notice indicating
 // that they have been altered from the originals.
 
-use qiskit_sys::qk_circuit_gate;
+use crate::control_flow::{Condition, ControlFlow, SwitchCases};
+use crate::parameter::{Angle, Parameter, ParameterExpression, ParameterValue};
+use crate::visualization::{self, DrawOptions};
+use std::collections::{BTreeMap, HashMap};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
 use std::ffi::{CStr, CString};
+use std::fmt;
+use std::ops::Range;
 
 #[derive(PartialEq, Eq, Debug)]
 /// The error enum that enumerates the different error types possible from Qiskit.
@@ -37,6 +43,9 @@ pub enum QiskitError {
     /// Mismatching number of qubits.
     MismatchedQubits,
 
+    /// Mismatching number of classical bits.
+    MismatchedClbits,
+
     /// Matrix is not unitary.
     ExpectedUnitary,
 
@@ -54,9 +63,30 @@ pub enum QiskitError {
 
     /// Querying an operation that doesn't exist in the Target.
     TargetInvalidInstKey,
+
+    /// The instruction is not supported by the requested operation.
+    UnsupportedInstruction,
+}
+
+impl QiskitError {
+    /// Convert into a [`Result`], mapping [`QiskitError::Success`] to `Ok(())`.
+    pub(crate) fn into_result(self) -> Result<(), QiskitError> {
+        match self {
+            QiskitError::Success => Ok(()),
+            err => Err(err),
+        }
+    }
+
+    /// The inverse of [`QiskitError::into_result`].
+    pub(crate) fn from_result(result: Result<(), QiskitError>) -> QiskitError {
+        match result {
+            Ok(()) => QiskitError::Success,
+            Err(err) => err,
+        }
+    }
 }
 
-fn qk_to_qiskit_error(err: qiskit_sys::QkExitCode) -> QiskitError {
+pub(crate) fn qk_to_qiskit_error(err: qiskit_sys::QkExitCode) -> QiskitError {
     match err {
         qiskit_sys::QkExitCode_QkExitCode_Success => QiskitError::Success,
         qiskit_sys::QkExitCode_QkExitCode_CInputError => QiskitError::CInputError,
@@ -79,9 +109,470 @@ fn qk_to_qiskit_error(err: qiskit_sys::QkExitCode) -> QiskitError {
     }
 }
 
+/// The standard gates natively supported by the C API.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StandardGate {
+    /// A global phase on the whole circuit.
+    GlobalPhase,
+    /// Hadamard gate.
+    H,
+    /// Identity gate.
+    I,
+    /// Pauli-X gate.
+    X,
+    /// Pauli-Y gate.
+    Y,
+    /// Pauli-Z gate.
+    Z,
+    /// Phase gate.
+    Phase,
+    /// Rotation about an axis in the XY plane.
+    R,
+    /// Rotation about the X axis.
+    RX,
+    /// Rotation about the Y axis.
+    RY,
+    /// Rotation about the Z axis.
+    RZ,
+    /// S gate.
+    S,
+    /// S-adjoint gate.
+    Sdg,
+    /// Sqrt(X) gate.
+    SX,
+    /// Inverse Sqrt(X) gate.
+    SXdg,
+    /// T gate.
+    T,
+    /// T-adjoint gate.
+    Tdg,
+    /// Generic single-qubit rotation.
+    U,
+    /// Legacy single-parameter U gate.
+    U1,
+    /// Legacy two-parameter U gate.
+    U2,
+    /// Legacy three-parameter U gate.
+    U3,
+    /// Controlled-Hadamard gate.
+    CH,
+    /// Controlled-X gate.
+    CX,
+    /// Controlled-Y gate.
+    CY,
+    /// Controlled-Z gate.
+    CZ,
+    /// Double-CNOT gate.
+    DCX,
+    /// Echoed cross-resonance gate.
+    ECR,
+    /// Swap gate.
+    Swap,
+    /// iSWAP gate.
+    ISwap,
+    /// Controlled-Phase gate.
+    CPhase,
+    /// Controlled rotation about the X axis.
+    CRX,
+    /// Controlled rotation about the Y axis.
+    CRY,
+    /// Controlled rotation about the Z axis.
+    CRZ,
+    /// Controlled-S gate.
+    CS,
+    /// Controlled-S-adjoint gate.
+    CSdg,
+    /// Controlled-Sqrt(X) gate.
+    CSX,
+    /// Controlled-U gate with a global phase parameter.
+    CU,
+    /// Legacy controlled-U1 gate.
+    CU1,
+    /// Legacy controlled-U3 gate.
+    CU3,
+    /// 2-qubit rotation about XX.
+    RXX,
+    /// 2-qubit rotation about YY.
+    RYY,
+    /// 2-qubit rotation about ZZ.
+    RZZ,
+    /// 2-qubit rotation about ZX.
+    RZX,
+    /// XX-YY interaction gate.
+    XXMinusYY,
+    /// XX+YY interaction gate.
+    XXPlusYY,
+    /// Toffoli gate.
+    CCX,
+    /// Doubly-controlled-Z gate.
+    CCZ,
+    /// Fredkin gate.
+    CSwap,
+    /// Simplified Toffoli gate.
+    RCCX,
+    /// 3-controlled X gate.
+    C3X,
+    /// 3-controlled Sqrt(X) gate.
+    C3SX,
+    /// Simplified 3-controlled Toffoli gate.
+    RC3X,
+}
+
+impl StandardGate {
+    /// All the standard gates, in the C API's order.
+    pub const ALL: [StandardGate; 52] = [
+        StandardGate::GlobalPhase,
+        StandardGate::H,
+        StandardGate::I,
+        StandardGate::X,
+        StandardGate::Y,
+        StandardGate::Z,
+        StandardGate::Phase,
+        StandardGate::R,
+        StandardGate::RX,
+        StandardGate::RY,
+        StandardGate::RZ,
+        StandardGate::S,
+        StandardGate::Sdg,
+        StandardGate::SX,
+        StandardGate::SXdg,
+        StandardGate::T,
+        StandardGate::Tdg,
+        StandardGate::U,
+        StandardGate::U1,
+        StandardGate::U2,
+        StandardGate::U3,
+        StandardGate::CH,
+        StandardGate::CX,
+        StandardGate::CY,
+        StandardGate::CZ,
+        StandardGate::DCX,
+        StandardGate::ECR,
+        StandardGate::Swap,
+        StandardGate::ISwap,
+        StandardGate::CPhase,
+        StandardGate::CRX,
+        StandardGate::CRY,
+        StandardGate::CRZ,
+        StandardGate::CS,
+        StandardGate::CSdg,
+        StandardGate::CSX,
+        StandardGate::CU,
+        StandardGate::CU1,
+        StandardGate::CU3,
+        StandardGate::RXX,
+        StandardGate::RYY,
+        StandardGate::RZZ,
+        StandardGate::RZX,
+        StandardGate::XXMinusYY,
+        StandardGate::XXPlusYY,
+        StandardGate::CCX,
+        StandardGate::CCZ,
+        StandardGate::CSwap,
+        StandardGate::RCCX,
+        StandardGate::C3X,
+        StandardGate::C3SX,
+        StandardGate::RC3X,
+    ];
+
+    /// Return the name of the gate as it appears in circuit instructions.
+    pub fn name(self) -> &'static str {
+        match self {
+            StandardGate::GlobalPhase => "global_phase",
+            StandardGate::H => "h",
+            StandardGate::I => "id",
+            StandardGate::X => "x",
+            StandardGate::Y => "y",
+            StandardGate::Z => "z",
+            StandardGate::Phase => "p",
+            StandardGate::R => "r",
+            StandardGate::RX => "rx",
+            StandardGate::RY => "ry",
+            StandardGate::RZ => "rz",
+            StandardGate::S => "s",
+            StandardGate::Sdg => "sdg",
+            StandardGate::SX => "sx",
+            StandardGate::SXdg => "sxdg",
+            StandardGate::T => "t",
+            StandardGate::Tdg => "tdg",
+            StandardGate::U => "u",
+            StandardGate::U1 => "u1",
+            StandardGate::U2 => "u2",
+            StandardGate::U3 => "u3",
+            StandardGate::CH => "ch",
+            StandardGate::CX => "cx",
+            StandardGate::CY => "cy",
+            StandardGate::CZ => "cz",
+            StandardGate::DCX => "dcx",
+            StandardGate::ECR => "ecr",
+            StandardGate::Swap => "swap",
+            StandardGate::ISwap => "iswap",
+            StandardGate::CPhase => "cp",
+            StandardGate::CRX => "crx",
+            StandardGate::CRY => "cry",
+            StandardGate::CRZ => "crz",
+            StandardGate::CS => "cs",
+            StandardGate::CSdg => "csdg",
+            StandardGate::CSX => "csx",
+            StandardGate::CU => "cu",
+            StandardGate::CU1 => "cu1",
+            StandardGate::CU3 => "cu3",
+            StandardGate::RXX => "rxx",
+            StandardGate::RYY => "ryy",
+            StandardGate::RZZ => "rzz",
+            StandardGate::RZX => "rzx",
+            StandardGate::XXMinusYY => "xx_minus_yy",
+            StandardGate::XXPlusYY => "xx_plus_yy",
+            StandardGate::CCX => "ccx",
+            StandardGate::CCZ => "ccz",
+            StandardGate::CSwap => "cswap",
+            StandardGate::RCCX => "rccx",
+            StandardGate::C3X => "mcx",
+            StandardGate::C3SX => "c3sx",
+            StandardGate::RC3X => "rcccx",
+        }
+    }
+
+    /// Look up a standard gate by its instruction name.
+    pub fn from_name(name: &str) -> Option<StandardGate> {
+        StandardGate::ALL
+            .into_iter()
+            .find(|gate| gate.name() == name)
+    }
+
+    /// Return the number of qubits the gate acts on.
+    pub fn num_qubits(self) -> u32 {
+        unsafe { qiskit_sys::qk!(qk_gate_num_qubits(self.to_qk())) }
+    }
+
+    /// Return the number of parameters the gate takes.
+    pub fn num_params(self) -> u32 {
+        unsafe { qiskit_sys::qk!(qk_gate_num_params(self.to_qk())) }
+    }
+
+    /// Return the gate with its control qubits removed.
+    ///
+    /// Gates that are not controlled versions of another standard gate are
+    /// returned unchanged.
+    pub fn base_gate(self) -> StandardGate {
+        use StandardGate::*;
+        match self {
+            CX | CCX | C3X => X,
+            CY => Y,
+            CZ | CCZ => Z,
+            CH => H,
+            CPhase => Phase,
+            CRX => RX,
+            CRY => RY,
+            CRZ => RZ,
+            CS => S,
+            CSdg => Sdg,
+            CSX | C3SX => SX,
+            CU => U,
+            CU1 => U1,
+            CU3 => U3,
+            CSwap => Swap,
+            gate => gate,
+        }
+    }
+
+    /// Return the standard gate that applies this gate controlled on
+    /// `num_ctrl_qubits` additional qubits, if there is one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::StandardGate;
+    ///
+    /// assert_eq!(StandardGate::X.controlled(2), Some(StandardGate::CCX));
+    /// assert_eq!(StandardGate::RZ.controlled(1), Some(StandardGate::CRZ));
+    /// assert_eq!(StandardGate::RZ.controlled(2), None);
+    /// ```
+    pub fn controlled(self, num_ctrl_qubits: u32) -> Option<StandardGate> {
+        use StandardGate::*;
+        match (self, num_ctrl_qubits) {
+            (gate, 0) => Some(gate),
+            (X, 1) => Some(CX),
+            (X, 2) | (CX, 1) => Some(CCX),
+            (X, 3) | (CX, 2) | (CCX, 1) => Some(C3X),
+            (Y, 1) => Some(CY),
+            (Z, 1) => Some(CZ),
+            (Z, 2) | (CZ, 1) => Some(CCZ),
+            (H, 1) => Some(CH),
+            (Phase, 1) => Some(CPhase),
+            (RX, 1) => Some(CRX),
+            (RY, 1) => Some(CRY),
+            (RZ, 1) => Some(CRZ),
+            (S, 1) => Some(CS),
+            (Sdg, 1) => Some(CSdg),
+            (SX, 1) => Some(CSX),
+            (SX, 3) => Some(C3SX),
+            (U1, 1) => Some(CU1),
+            (U3, 1) => Some(CU3),
+            (Swap, 1) => Some(CSwap),
+            _ => None,
+        }
+    }
+
+    // Qiskit's definition of the gates that have no direct multi-controlled
+    // construction, in terms of simpler standard gates.
+    fn definition(self, qubits: &[u32]) -> Vec<(StandardGate, Vec<u32>, Vec<ParameterValue>)> {
+        use StandardGate::*;
+        let steps: Vec<(StandardGate, &[usize], Vec<ParameterValue>)> = match self {
+            DCX => vec![(CX, &[0, 1], vec![]), (CX, &[1, 0], vec![])],
+            ECR => vec![
+                (RZX, &[0, 1], vec![FRAC_PI_4.into()]),
+                (X, &[0], vec![]),
+                (RZX, &[0, 1], vec![(-FRAC_PI_4).into()]),
+            ],
+            ISwap => vec![
+                (S, &[0], vec![]),
+                (S, &[1], vec![]),
+                (H, &[0], vec![]),
+                (CX, &[0, 1], vec![]),
+                (CX, &[1, 0], vec![]),
+                (H, &[1], vec![]),
+            ],
+            RCCX => vec![
+                (H, &[2], vec![]),
+                (T, &[2], vec![]),
+                (CX, &[1, 2], vec![]),
+                (Tdg, &[2], vec![]),
+                (CX, &[0, 2], vec![]),
+                (T, &[2], vec![]),
+                (CX, &[1, 2], vec![]),
+                (Tdg, &[2], vec![]),
+                (H, &[2], vec![]),
+            ],
+            RC3X => vec![
+                (H, &[3], vec![]),
+                (T, &[3], vec![]),
+                (CX, &[2, 3], vec![]),
+                (Tdg, &[3], vec![]),
+                (H, &[3], vec![]),
+                (CX, &[0, 3], vec![]),
+                (T, &[3], vec![]),
+                (CX, &[1, 3], vec![]),
+                (Tdg, &[3], vec![]),
+                (CX, &[0, 3], vec![]),
+                (T, &[3], vec![]),
+                (CX, &[1, 3], vec![]),
+                (Tdg, &[3], vec![]),
+                (H, &[3], vec![]),
+                (T, &[3], vec![]),
+                (CX, &[2, 3], vec![]),
+                (Tdg, &[3], vec![]),
+                (H, &[3], vec![]),
+            ],
+            gate => unreachable!("{} has no definition", gate.name()),
+        };
+        steps
+            .into_iter()
+            .map(|(gate, indices, params)| {
+                (gate, indices.iter().map(|i| qubits[*i]).collect(), params)
+            })
+            .collect()
+    }
+
+    pub(crate) fn to_qk(self) -> qiskit_sys::QkGate {
+        match self {
+            StandardGate::GlobalPhase => qiskit_sys::QkGate_QkGate_GlobalPhase,
+            StandardGate::H => qiskit_sys::QkGate_QkGate_H,
+            StandardGate::I => qiskit_sys::QkGate_QkGate_I,
+            StandardGate::X => qiskit_sys::QkGate_QkGate_X,
+            StandardGate::Y => qiskit_sys::QkGate_QkGate_Y,
+            StandardGate::Z => qiskit_sys::QkGate_QkGate_Z,
+            StandardGate::Phase => qiskit_sys::QkGate_QkGate_Phase,
+            StandardGate::R => qiskit_sys::QkGate_QkGate_R,
+            StandardGate::RX => qiskit_sys::QkGate_QkGate_RX,
+            StandardGate::RY => qiskit_sys::QkGate_QkGate_RY,
+            StandardGate::RZ => qiskit_sys::QkGate_QkGate_RZ,
+            StandardGate::S => qiskit_sys::QkGate_QkGate_S,
+            StandardGate::Sdg => qiskit_sys::QkGate_QkGate_Sdg,
+            StandardGate::SX => qiskit_sys::QkGate_QkGate_SX,
+            StandardGate::SXdg => qiskit_sys::QkGate_QkGate_SXdg,
+            StandardGate::T => qiskit_sys::QkGate_QkGate_T,
+            StandardGate::Tdg => qiskit_sys::QkGate_QkGate_Tdg,
+            StandardGate::U => qiskit_sys::QkGate_QkGate_U,
+            StandardGate::U1 => qiskit_sys::QkGate_QkGate_U1,
+            StandardGate::U2 => qiskit_sys::QkGate_QkGate_U2,
+            StandardGate::U3 => qiskit_sys::QkGate_QkGate_U3,
+            StandardGate::CH => qiskit_sys::QkGate_QkGate_CH,
+            StandardGate::CX => qiskit_sys::QkGate_QkGate_CX,
+            StandardGate::CY => qiskit_sys::QkGate_QkGate_CY,
+            StandardGate::CZ => qiskit_sys::QkGate_QkGate_CZ,
+            StandardGate::DCX => qiskit_sys::QkGate_QkGate_DCX,
+            StandardGate::ECR => qiskit_sys::QkGate_QkGate_ECR,
+            StandardGate::Swap => qiskit_sys::QkGate_QkGate_Swap,
+            StandardGate::ISwap => qiskit_sys::QkGate_QkGate_ISwap,
+            StandardGate::CPhase => qiskit_sys::QkGate_QkGate_CPhase,
+            StandardGate::CRX => qiskit_sys::QkGate_QkGate_CRX,
+            StandardGate::CRY => qiskit_sys::QkGate_QkGate_CRY,
+            StandardGate::CRZ => qiskit_sys::QkGate_QkGate_CRZ,
+            StandardGate::CS => qiskit_sys::QkGate_QkGate_CS,
+            StandardGate::CSdg => qiskit_sys::QkGate_QkGate_CSdg,
+            StandardGate::CSX => qiskit_sys::QkGate_QkGate_CSX,
+            StandardGate::CU => qiskit_sys::QkGate_QkGate_CU,
+            StandardGate::CU1 => qiskit_sys::QkGate_QkGate_CU1,
+            StandardGate::CU3 => qiskit_sys::QkGate_QkGate_CU3,
+            StandardGate::RXX => qiskit_sys::QkGate_QkGate_RXX,
+            StandardGate::RYY => qiskit_sys::QkGate_QkGate_RYY,
+            StandardGate::RZZ => qiskit_sys::QkGate_QkGate_RZZ,
+            StandardGate::RZX => qiskit_sys::QkGate_QkGate_RZX,
+            StandardGate::XXMinusYY => qiskit_sys::QkGate_QkGate_XXMinusYY,
+            StandardGate::XXPlusYY => qiskit_sys::QkGate_QkGate_XXPlusYY,
+            StandardGate::CCX => qiskit_sys::QkGate_QkGate_CCX,
+            StandardGate::CCZ => qiskit_sys::QkGate_QkGate_CCZ,
+            StandardGate::CSwap => qiskit_sys::QkGate_QkGate_CSwap,
+            StandardGate::RCCX => qiskit_sys::QkGate_QkGate_RCCX,
+            StandardGate::C3X => qiskit_sys::QkGate_QkGate_C3X,
+            StandardGate::C3SX => qiskit_sys::QkGate_QkGate_C3SX,
+            StandardGate::RC3X => qiskit_sys::QkGate_QkGate_RC3X,
+        }
+    }
+}
+
+// The names and bit ranges of the quantum or classical registers of a
+// circuit.
+pub(crate) type Registers = Vec<(String, Range<u32>)>;
+
 /// The core representation of a quantum circuit.
+///
+/// The circuit is stored by the C API, which has no symbolic gate
+/// parameters. Unbound [`Parameter`]s are kept next to the
+/// C circuit, which holds NaN in their place, so anything that hands the
+/// circuit to the C API, like the [transpiler](crate::transpiler) passes,
+/// returns [`QiskitError::UnsupportedInstruction`] for a circuit with
+/// unbound parameters. Bind them with
+/// [`assign_parameters`](QuantumCircuit::assign_parameters) first.
+///
+/// The C API can't represent control flow either, so operations added with
+/// [`if_test`](QuantumCircuit::if_test),
+/// [`for_loop`](QuantumCircuit::for_loop) and the other
+/// [control-flow](crate::control_flow) builders are also kept next to the C
+/// circuit. A circuit containing any of them can't be transpiled or
+/// converted to a DAG: both return [`QiskitError::UnsupportedInstruction`].
 pub struct QuantumCircuit {
     circuit: *mut qiskit_sys::QkCircuit,
+    // Gate parameters that are not bound to a value, keyed by instruction
+    // index and parameter position. The C circuit holds NaN in their place.
+    symbolic_params: BTreeMap<(usize, usize), ParameterExpression>,
+    // Control-flow operations, which the C API cannot represent, in order.
+    control_flow: Vec<ControlFlowInstruction>,
+    // The names and bit ranges of the registers added to the circuit, which
+    // the C API does not expose.
+    qregs: Registers,
+    cregs: Registers,
+}
+
+// A control-flow operation together with the bits it acts on.
+#[derive(Clone)]
+struct ControlFlowInstruction {
+    // The number of instructions in the C circuit that come before it.
+    position: usize,
+    op: ControlFlow,
+    qubits: Vec<u32>,
+    clbits: Vec<u32>,
 }
 
 impl QuantumCircuit {
@@ -96,10 +587,21 @@ impl QuantumCircuit {
     ///
     /// let qc = QuantumCircuit::new(10, 10);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// With the `runtime-linking` feature, panics if libqiskit wasn't loaded
+    /// with `qiskit_rs::init` and the default library can't be loaded.
     pub fn new(num_qubits: u32, num_clbits: u32) -> QuantumCircuit {
         let qc: *mut qiskit_sys::QkCircuit =
-            unsafe { qiskit_sys::qk_circuit_new(num_qubits, num_clbits) };
-        QuantumCircuit { circuit: qc }
+            unsafe { qiskit_sys::qk!(qk_circuit_new(num_qubits, num_clbits)) };
+        QuantumCircuit {
+            circuit: qc,
+            symbolic_params: BTreeMap::new(),
+            control_flow: Vec::new(),
+            qregs: Vec::new(),
+            cregs: Vec::new(),
+        }
     }
     /// Return the number of qubits in a QuantumCircuit.
     ///
@@ -111,8 +613,8 @@ impl QuantumCircuit {
     /// let mut qc = QuantumCircuit::new(10, 10);
     /// let n = qc.num_qubits();
     /// ```
-    pub fn num_qubits(&mut self) -> u32 {
-        unsafe { qiskit_sys::qk_circuit_num_qubits(self.circuit) }
+    pub fn num_qubits(&self) -> u32 {
+        unsafe { qiskit_sys::qk!(qk_circuit_num_qubits(self.circuit)) }
     }
     /// Return the number of classical bits in a QuantumCircuit.
     ///
@@ -124,18 +626,104 @@ impl QuantumCircuit {
     /// let mut qc = QuantumCircuit::new(10, 10);
     /// let n = qc.num_clbits();
     /// ```
-    pub fn num_clbits(&mut self) -> u32 {
-        unsafe { qiskit_sys::qk_circuit_num_clbits(self.circuit) }
+    pub fn num_clbits(&self) -> u32 {
+        unsafe { qiskit_sys::qk!(qk_circuit_num_clbits(self.circuit)) }
     }
 
     fn gate(&mut self, gate: qiskit_sys::QkGate, qubits: &[u32], params: &[f64]) -> QiskitError {
         let retval = if params.is_empty() {
-            unsafe { qk_circuit_gate(self.circuit, gate, qubits.as_ptr(), std::ptr::null()) }
+            unsafe {
+                qiskit_sys::qk!(qk_circuit_gate(
+                    self.circuit,
+                    gate,
+                    qubits.as_ptr(),
+                    std::ptr::null()
+                ))
+            }
         } else {
-            unsafe { qk_circuit_gate(self.circuit, gate, qubits.as_ptr(), params.as_ptr()) }
+            unsafe {
+                qiskit_sys::qk!(qk_circuit_gate(
+                    self.circuit,
+                    gate,
+                    qubits.as_ptr(),
+                    params.as_ptr()
+                ))
+            }
         };
         qk_to_qiskit_error(retval)
     }
+    fn angle_gate(
+        &mut self,
+        gate: qiskit_sys::QkGate,
+        qubits: &[u32],
+        params: &[ParameterValue],
+    ) -> QiskitError {
+        let index = self.num_qk_instructions();
+        let values: Vec<f64> = params
+            .iter()
+            .map(|param| param.as_float().unwrap_or(f64::NAN))
+            .collect();
+        let retval = self.gate(gate, qubits, &values);
+        if retval == QiskitError::Success {
+            for (i, param) in params.iter().enumerate() {
+                if let ParameterValue::Expression(expr) = param {
+                    self.symbolic_params.insert((index, i), expr.clone());
+                }
+            }
+        }
+        retval
+    }
+    /// Apply a standard gate to the given qubits.
+    ///
+    /// The number of qubits and parameters must match
+    /// [`StandardGate::num_qubits`] and [`StandardGate::num_params`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::{QiskitError, QuantumCircuit, StandardGate};
+    ///
+    /// let mut qc = QuantumCircuit::new(2, 0);
+    /// let ret = qc.append_gate(StandardGate::CRZ, &[0, 1], &[0.5]);
+    /// assert_eq!(ret, QiskitError::Success);
+    /// ```
+    pub fn append_gate(
+        &mut self,
+        gate: StandardGate,
+        qubits: &[u32],
+        params: &[f64],
+    ) -> QiskitError {
+        let params: Vec<ParameterValue> = params.iter().map(|param| (*param).into()).collect();
+        self.append_parameterized_gate(gate, qubits, &params)
+    }
+    /// Apply a standard gate whose parameters may be symbolic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::parameter::{Parameter, ParameterValue};
+    /// use qiskit_rs::{QiskitError, QuantumCircuit, StandardGate};
+    ///
+    /// let theta = Parameter::new("theta");
+    /// let mut qc = QuantumCircuit::new(1, 0);
+    /// let params = [ParameterValue::from(theta), ParameterValue::Float(0.0)];
+    /// let ret = qc.append_parameterized_gate(StandardGate::R, &[0], &params);
+    /// assert_eq!(ret, QiskitError::Success);
+    /// ```
+    pub fn append_parameterized_gate(
+        &mut self,
+        gate: StandardGate,
+        qubits: &[u32],
+        params: &[ParameterValue],
+    ) -> QiskitError {
+        if qubits.len() != gate.num_qubits() as usize {
+            return QiskitError::MismatchedQubits;
+        }
+        if params.len() != gate.num_params() as usize {
+            return QiskitError::CInputError;
+        }
+        self.angle_gate(gate.to_qk(), qubits, params)
+    }
     /// Apply a double-CNOT gate.
     pub fn dcx(&mut self, qubit1: u32, qubit2: u32) -> QiskitError {
         self.gate(qiskit_sys::QkGate_QkGate_DCX, &[qubit1, qubit2], &[])
@@ -144,6 +732,14 @@ impl QuantumCircuit {
     pub fn ecr(&mut self, qubit1: u32, qubit2: u32) -> QiskitError {
         self.gate(qiskit_sys::QkGate_QkGate_ECR, &[qubit1, qubit2], &[])
     }
+    /// Apply a global phase to the circuit.
+    pub fn global_phase(&mut self, theta: impl Angle) -> QiskitError {
+        self.angle_gate(
+            qiskit_sys::QkGate_QkGate_GlobalPhase,
+            &[],
+            &[theta.to_value()],
+        )
+    }
     /// Apply a Hadamard gate.
     pub fn h(&mut self, qubit: u32) -> QiskitError {
         self.gate(qiskit_sys::QkGate_QkGate_H, &[qubit], &[])
@@ -157,12 +753,20 @@ impl QuantumCircuit {
         self.gate(qiskit_sys::QkGate_QkGate_ISwap, &[qubit1, qubit2], &[])
     }
     /// Apply a Phase gate, a single-qubit rotation about the Z axis.
-    pub fn p(&mut self, theta: f64, qubit: u32) -> QiskitError {
-        self.gate(qiskit_sys::QkGate_QkGate_Phase, &[qubit], &[theta])
+    pub fn p(&mut self, theta: impl Angle, qubit: u32) -> QiskitError {
+        self.angle_gate(
+            qiskit_sys::QkGate_QkGate_Phase,
+            &[qubit],
+            &[theta.to_value()],
+        )
     }
     /// Apply an RGate
-    pub fn r(&mut self, theta: f64, phi: f64, qubit: u32) -> QiskitError {
-        self.gate(qiskit_sys::QkGate_QkGate_R, &[qubit], &[theta, phi])
+    pub fn r(&mut self, theta: impl Angle, phi: impl Angle, qubit: u32) -> QiskitError {
+        self.angle_gate(
+            qiskit_sys::QkGate_QkGate_R,
+            &[qubit],
+            &[theta.to_value(), phi.to_value()],
+        )
     }
     /// Apply a simplified 3-controlled Toffoli gate.
     pub fn rcccx(
@@ -223,8 +827,8 @@ impl QuantumCircuit {
     /// let mut qc = QuantumCircuit::new(1, 1);
     /// qc.rx(PI / 2.0, 0);
     /// ```
-    pub fn rx(&mut self, theta: f64, qubit: u32) -> QiskitError {
-        self.gate(qiskit_sys::QkGate_QkGate_RX, &[qubit], &[theta])
+    pub fn rx(&mut self, theta: impl Angle, qubit: u32) -> QiskitError {
+        self.angle_gate(qiskit_sys::QkGate_QkGate_RX, &[qubit], &[theta.to_value()])
     }
     /// Apply a 2-qubit rotation about XX.
     ///
@@ -243,28 +847,44 @@ impl QuantumCircuit {
     /// let mut qc = QuantumCircuit::new(2, 2);
     /// qc.rxx(PI / 2.0, 0, 1);
     /// ```
-    pub fn rxx(&mut self, theta: f64, qubit1: u32, qubit2: u32) -> QiskitError {
-        self.gate(qiskit_sys::QkGate_QkGate_RXX, &[qubit1, qubit2], &[theta])
+    pub fn rxx(&mut self, theta: impl Angle, qubit1: u32, qubit2: u32) -> QiskitError {
+        self.angle_gate(
+            qiskit_sys::QkGate_QkGate_RXX,
+            &[qubit1, qubit2],
+            &[theta.to_value()],
+        )
     }
     /// Apply a single-qubit rotation about the Y axis.
-    pub fn ry(&mut self, theta: f64, qubit: u32) -> QiskitError {
-        self.gate(qiskit_sys::QkGate_QkGate_RY, &[qubit], &[theta])
+    pub fn ry(&mut self, theta: impl Angle, qubit: u32) -> QiskitError {
+        self.angle_gate(qiskit_sys::QkGate_QkGate_RY, &[qubit], &[theta.to_value()])
     }
     /// Apply a 2-qubit rotation about YY.
-    pub fn ryy(&mut self, theta: f64, qubit1: u32, qubit2: u32) -> QiskitError {
-        self.gate(qiskit_sys::QkGate_QkGate_RY, &[qubit1, qubit2], &[theta])
+    pub fn ryy(&mut self, theta: impl Angle, qubit1: u32, qubit2: u32) -> QiskitError {
+        self.angle_gate(
+            qiskit_sys::QkGate_QkGate_RYY,
+            &[qubit1, qubit2],
+            &[theta.to_value()],
+        )
     }
     /// Apply a single-qubit rotation about the Z axis.
-    pub fn rz(&mut self, phi: f64, qubit: u32) -> QiskitError {
-        self.gate(qiskit_sys::QkGate_QkGate_RZ, &[qubit], &[phi])
+    pub fn rz(&mut self, phi: impl Angle, qubit: u32) -> QiskitError {
+        self.angle_gate(qiskit_sys::QkGate_QkGate_RZ, &[qubit], &[phi.to_value()])
     }
     /// Apply a 2-qubit rotation about ZX.
-    pub fn rzx(&mut self, theta: f64, qubit1: u32, qubit2: u32) -> QiskitError {
-        self.gate(qiskit_sys::QkGate_QkGate_RZX, &[qubit1, qubit2], &[theta])
+    pub fn rzx(&mut self, theta: impl Angle, qubit1: u32, qubit2: u32) -> QiskitError {
+        self.angle_gate(
+            qiskit_sys::QkGate_QkGate_RZX,
+            &[qubit1, qubit2],
+            &[theta.to_value()],
+        )
     }
     /// Apply a 2-qubit rotation about ZX.
-    pub fn rzz(&mut self, theta: f64, qubit1: u32, qubit2: u32) -> QiskitError {
-        self.gate(qiskit_sys::QkGate_QkGate_RZZ, &[qubit1, qubit2], &[theta])
+    pub fn rzz(&mut self, theta: impl Angle, qubit1: u32, qubit2: u32) -> QiskitError {
+        self.angle_gate(
+            qiskit_sys::QkGate_QkGate_RZZ,
+            &[qubit1, qubit2],
+            &[theta.to_value()],
+        )
     }
     /// Apply a single qubit S gate.
     pub fn s(&mut self, qubit: u32) -> QiskitError {
@@ -282,6 +902,10 @@ impl QuantumCircuit {
     pub fn sxdg(&mut self, qubit: u32) -> QiskitError {
         self.gate(qiskit_sys::QkGate_QkGate_SXdg, &[qubit], &[])
     }
+    /// Apply a swap gate.
+    pub fn swap(&mut self, qubit1: u32, qubit2: u32) -> QiskitError {
+        self.gate(qiskit_sys::QkGate_QkGate_Swap, &[qubit1, qubit2], &[])
+    }
     /// Apply a single qubit T gate.
     pub fn t(&mut self, qubit: u32) -> QiskitError {
         self.gate(qiskit_sys::QkGate_QkGate_T, &[qubit], &[])
@@ -291,8 +915,18 @@ impl QuantumCircuit {
         self.gate(qiskit_sys::QkGate_QkGate_Tdg, &[qubit], &[])
     }
     /// Apply a generic single-qubit rotation.
-    pub fn u(&mut self, theta: f64, phi: f64, lam: f64, qubit: u32) -> QiskitError {
-        self.gate(qiskit_sys::QkGate_QkGate_U, &[qubit], &[theta, phi, lam])
+    pub fn u(
+        &mut self,
+        theta: impl Angle,
+        phi: impl Angle,
+        lam: impl Angle,
+        qubit: u32,
+    ) -> QiskitError {
+        self.angle_gate(
+            qiskit_sys::QkGate_QkGate_U,
+            &[qubit],
+            &[theta.to_value(), phi.to_value(), lam.to_value()],
+        )
     }
     /// Apply a single-qubit Pauli-X gate.
     pub fn x(&mut self, qubit: u32) -> QiskitError {
@@ -314,31 +948,1035 @@ impl QuantumCircuit {
             &[],
         )
     }
+    /// Apply a controlled-Y gate.
+    pub fn cy(&mut self, control_qubit: u32, target_qubit: u32) -> QiskitError {
+        self.gate(
+            qiskit_sys::QkGate_QkGate_CY,
+            &[control_qubit, target_qubit],
+            &[],
+        )
+    }
+    /// Apply a controlled-Z gate.
+    pub fn cz(&mut self, control_qubit: u32, target_qubit: u32) -> QiskitError {
+        self.gate(
+            qiskit_sys::QkGate_QkGate_CZ,
+            &[control_qubit, target_qubit],
+            &[],
+        )
+    }
+    /// Apply a controlled-Hadamard gate.
+    pub fn ch(&mut self, control_qubit: u32, target_qubit: u32) -> QiskitError {
+        self.gate(
+            qiskit_sys::QkGate_QkGate_CH,
+            &[control_qubit, target_qubit],
+            &[],
+        )
+    }
+    /// Apply a controlled-Phase gate.
+    pub fn cp(&mut self, theta: impl Angle, control_qubit: u32, target_qubit: u32) -> QiskitError {
+        self.angle_gate(
+            qiskit_sys::QkGate_QkGate_CPhase,
+            &[control_qubit, target_qubit],
+            &[theta.to_value()],
+        )
+    }
+    /// Apply a controlled rotation about the X axis.
+    pub fn crx(&mut self, theta: impl Angle, control_qubit: u32, target_qubit: u32) -> QiskitError {
+        self.angle_gate(
+            qiskit_sys::QkGate_QkGate_CRX,
+            &[control_qubit, target_qubit],
+            &[theta.to_value()],
+        )
+    }
+    /// Apply a controlled rotation about the Y axis.
+    pub fn cry(&mut self, theta: impl Angle, control_qubit: u32, target_qubit: u32) -> QiskitError {
+        self.angle_gate(
+            qiskit_sys::QkGate_QkGate_CRY,
+            &[control_qubit, target_qubit],
+            &[theta.to_value()],
+        )
+    }
+    /// Apply a controlled rotation about the Z axis.
+    pub fn crz(&mut self, theta: impl Angle, control_qubit: u32, target_qubit: u32) -> QiskitError {
+        self.angle_gate(
+            qiskit_sys::QkGate_QkGate_CRZ,
+            &[control_qubit, target_qubit],
+            &[theta.to_value()],
+        )
+    }
+    /// Apply a Toffoli gate.
+    pub fn ccx(
+        &mut self,
+        control_qubit1: u32,
+        control_qubit2: u32,
+        target_qubit: u32,
+    ) -> QiskitError {
+        self.gate(
+            qiskit_sys::QkGate_QkGate_CCX,
+            &[control_qubit1, control_qubit2, target_qubit],
+            &[],
+        )
+    }
+    /// Apply a doubly-controlled-Z gate.
+    pub fn ccz(
+        &mut self,
+        control_qubit1: u32,
+        control_qubit2: u32,
+        target_qubit: u32,
+    ) -> QiskitError {
+        self.gate(
+            qiskit_sys::QkGate_QkGate_CCZ,
+            &[control_qubit1, control_qubit2, target_qubit],
+            &[],
+        )
+    }
+    /// Apply a controlled swap (Fredkin) gate.
+    pub fn cswap(&mut self, control_qubit: u32, qubit1: u32, qubit2: u32) -> QiskitError {
+        self.gate(
+            qiskit_sys::QkGate_QkGate_CSwap,
+            &[control_qubit, qubit1, qubit2],
+            &[],
+        )
+    }
+    /// Apply an X gate controlled on any number of qubits.
+    ///
+    /// Up to three controls map directly onto `x`, `cx`, `ccx` and the
+    /// 3-controlled X gate. Larger gates are decomposed without ancillas.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::QuantumCircuit;
+    ///
+    /// let mut qc = QuantumCircuit::new(5, 0);
+    /// qc.mcx(&[0, 1, 2, 3], 4);
+    /// ```
+    pub fn mcx(&mut self, control_qubits: &[u32], target_qubit: u32) -> QiskitError {
+        match control_qubits {
+            [] => self.x(target_qubit),
+            [c0] => self.cx(*c0, target_qubit),
+            [c0, c1] => self.ccx(*c0, *c1, target_qubit),
+            [c0, c1, c2] => self.gate(
+                qiskit_sys::QkGate_QkGate_C3X,
+                &[*c0, *c1, *c2, target_qubit],
+                &[],
+            ),
+            _ => QiskitError::from_result((|| {
+                self.h(target_qubit).into_result()?;
+                self.mcp(PI, control_qubits, target_qubit).into_result()?;
+                self.h(target_qubit).into_result()
+            })()),
+        }
+    }
+    /// Apply a Phase gate controlled on any number of qubits.
+    ///
+    /// More than one control is decomposed recursively into `cp` and
+    /// [`QuantumCircuit::mcx`] without ancillas.
+    pub fn mcp(
+        &mut self,
+        theta: impl Angle,
+        control_qubits: &[u32],
+        target_qubit: u32,
+    ) -> QiskitError {
+        let theta = theta.to_value();
+        let Some((last, rest)) = control_qubits.split_last() else {
+            return self.p(theta, target_qubit);
+        };
+        if rest.is_empty() {
+            return self.cp(theta, *last, target_qubit);
+        }
+        // The phase picked up by the target is theta / 2 * (l + r - (l ^ r)),
+        // which is theta exactly when both the last control and the rest are set.
+        QiskitError::from_result((|| {
+            self.cp(&theta / 2.0, *last, target_qubit).into_result()?;
+            self.mcx(rest, *last).into_result()?;
+            self.cp(-&theta / 2.0, *last, target_qubit).into_result()?;
+            self.mcx(rest, *last).into_result()?;
+            self.mcp(&theta / 2.0, rest, target_qubit).into_result()
+        })())
+    }
     /// Measure a qubit in the Z basis into a classical bit.
     pub fn measure(&mut self, qubit: u32, clbit: u32) -> QiskitError {
-        let retval = unsafe { qiskit_sys::qk_circuit_measure(self.circuit, qubit, clbit) };
+        let retval = unsafe { qiskit_sys::qk!(qk_circuit_measure(self.circuit, qubit, clbit)) };
         qk_to_qiskit_error(retval)
     }
+    /// Reset a qubit to the zero state.
+    pub fn reset(&mut self, qubit: u32) -> QiskitError {
+        let retval = unsafe { qiskit_sys::qk!(qk_circuit_reset(self.circuit, qubit)) };
+        qk_to_qiskit_error(retval)
+    }
+    /// Apply a barrier across the given qubits.
+    pub fn barrier(&mut self, qubits: &[u32]) -> QiskitError {
+        let retval = unsafe {
+            qiskit_sys::qk!(qk_circuit_barrier(
+                self.circuit,
+                qubits.as_ptr(),
+                qubits.len() as u32
+            ))
+        };
+        qk_to_qiskit_error(retval)
+    }
+    /// Run the instructions added by `body` only if `condition` holds.
+    ///
+    /// The body is built in a circuit with the same qubits and classical
+    /// bits as this one. Returns [`QiskitError::IndexError`] if the
+    /// condition reads a classical bit that is not in the circuit.
+    ///
+    /// Like all control flow, this makes the transpiler passes and the DAG
+    /// conversion return [`QiskitError::UnsupportedInstruction`] for the
+    /// circuit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::QuantumCircuit;
+    /// use qiskit_rs::control_flow::Condition;
+    ///
+    /// // Quantum teleportation of qubit 0 onto qubit 2.
+    /// let mut qc = QuantumCircuit::new(3, 2);
+    /// qc.h(1);
+    /// qc.cx(1, 2);
+    /// qc.cx(0, 1);
+    /// qc.h(0);
+    /// qc.measure(0, 0);
+    /// qc.measure(1, 1);
+    /// qc.if_test(Condition::bit(1, true), |body| {
+    ///     body.x(2);
+    /// });
+    /// qc.if_test(Condition::bit(0, true), |body| {
+    ///     body.z(2);
+    /// });
+    /// ```
+    pub fn if_test(
+        &mut self,
+        condition: Condition,
+        body: impl FnOnce(&mut QuantumCircuit),
+    ) -> QiskitError {
+        let true_body = self.build_block(body);
+        self.append_control_flow(ControlFlow::IfElse {
+            condition,
+            true_body,
+            false_body: None,
+        })
+    }
+    /// Run the instructions added by `true_body` if `condition` holds, and
+    /// the ones added by `false_body` otherwise.
+    pub fn if_else(
+        &mut self,
+        condition: Condition,
+        true_body: impl FnOnce(&mut QuantumCircuit),
+        false_body: impl FnOnce(&mut QuantumCircuit),
+    ) -> QiskitError {
+        let true_body = self.build_block(true_body);
+        let false_body = self.build_block(false_body);
+        self.append_control_flow(ControlFlow::IfElse {
+            condition,
+            true_body,
+            false_body: Some(false_body),
+        })
+    }
+    /// Repeat the instructions added by `body` for as long as `condition`
+    /// holds.
+    ///
+    /// Like all control flow, this makes the transpiler passes and the DAG
+    /// conversion return [`QiskitError::UnsupportedInstruction`] for the
+    /// circuit.
+    pub fn while_loop(
+        &mut self,
+        condition: Condition,
+        body: impl FnOnce(&mut QuantumCircuit),
+    ) -> QiskitError {
+        let body = self.build_block(body);
+        self.append_control_flow(ControlFlow::WhileLoop { condition, body })
+    }
+    /// Run the instructions added by `body` once for every value in
+    /// `indexset`.
+    ///
+    /// The body receives the loop parameter, which takes each value in turn
+    /// and can be used in gate angles.
+    ///
+    /// Like all control flow, this makes the transpiler passes and the DAG
+    /// conversion return [`QiskitError::UnsupportedInstruction`] for the
+    /// circuit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::QuantumCircuit;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut qc = QuantumCircuit::new(1, 0);
+    /// qc.for_loop(0..4, |body, i| {
+    ///     body.rz(i * (PI / 4.0), 0);
+    /// });
+    /// assert!(qc.parameters().is_empty());
+    /// ```
+    pub fn for_loop(
+        &mut self,
+        indexset: impl IntoIterator<Item = i64>,
+        body: impl FnOnce(&mut QuantumCircuit, &Parameter),
+    ) -> QiskitError {
+        let loop_parameter = Parameter::new("i");
+        let body = self.build_block(|block| body(block, &loop_parameter));
+        self.append_control_flow(ControlFlow::ForLoop {
+            indexset: indexset.into_iter().collect(),
+            loop_parameter,
+            body,
+        })
+    }
+    /// Run the body of the case matching the value of the `target` classical
+    /// bits, read as an integer with `target[0]` as the least significant
+    /// bit.
+    ///
+    /// Returns [`QiskitError::CInputError`] if a value is matched by more
+    /// than one case or does not fit in the target.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::QuantumCircuit;
+    ///
+    /// let mut qc = QuantumCircuit::new(2, 2);
+    /// qc.h(0);
+    /// qc.h(1);
+    /// qc.measure(0, 0);
+    /// qc.measure(1, 1);
+    /// qc.switch(&[0, 1], |cases| {
+    ///     cases.case(&[0, 3], |body| {
+    ///         body.x(0);
+    ///     });
+    ///     cases.default(|body| {
+    ///         body.z(0);
+    ///     });
+    /// });
+    /// ```
+    pub fn switch(&mut self, target: &[u32], cases: impl FnOnce(&mut SwitchCases)) -> QiskitError {
+        let mut builder = SwitchCases::new(self.num_qubits(), self.num_clbits());
+        cases(&mut builder);
+        self.append_control_flow(ControlFlow::Switch {
+            target: target.to_vec(),
+            cases: builder.cases,
+        })
+    }
+    // Build a control-flow body over the same bits as this circuit.
+    fn build_block(&self, body: impl FnOnce(&mut QuantumCircuit)) -> QuantumCircuit {
+        let mut block = QuantumCircuit::new(self.num_qubits(), self.num_clbits());
+        body(&mut block);
+        block
+    }
+    fn append_control_flow(&mut self, op: ControlFlow) -> QiskitError {
+        let retval = op.validate(self.num_qubits(), self.num_clbits());
+        if retval != QiskitError::Success {
+            return retval;
+        }
+        // The operation acts on every bit its bodies touch.
+        let mut qubits: Vec<u32> = Vec::new();
+        let mut clbits: Vec<u32> = op.condition_clbits().to_vec();
+        for block in op.blocks() {
+            for inst in block.instructions() {
+                qubits.extend_from_slice(inst.qubits);
+                clbits.extend_from_slice(inst.clbits);
+            }
+        }
+        qubits.sort_unstable();
+        qubits.dedup();
+        clbits.sort_unstable();
+        clbits.dedup();
+        self.control_flow.push(ControlFlowInstruction {
+            position: self.num_qk_instructions(),
+            op,
+            qubits,
+            clbits,
+        });
+        QiskitError::Success
+    }
     /// Add a quantum register to the circuit.
-    pub fn add_quantum_register(&mut self, register: QuantumRegister) {
-        unsafe { qiskit_sys::qk_circuit_add_quantum_register(self.circuit, register.register) };
+    ///
+    /// The register's qubits are added after the existing ones, and their
+    /// indices are returned.
+    pub fn add_quantum_register(&mut self, register: QuantumRegister) -> Vec<u32> {
+        let start = self.num_qubits();
+        unsafe {
+            qiskit_sys::qk!(qk_circuit_add_quantum_register(
+                self.circuit,
+                register.register
+            ))
+        };
+        let qubits = start..self.num_qubits();
+        self.qregs.push((register.name.clone(), qubits.clone()));
+        qubits.collect()
     }
     /// Add a classical register to the circuit.
-    pub fn add_classical_register(&mut self, register: ClassicalRegister) {
-        unsafe { qiskit_sys::qk_circuit_add_classical_register(self.circuit, register.register) };
+    ///
+    /// The register's classical bits are added after the existing ones, and
+    /// their indices are returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::control_flow::Condition;
+    /// use qiskit_rs::{ClassicalRegister, QuantumCircuit};
+    ///
+    /// let mut qc = QuantumCircuit::new(2, 1);
+    /// let creg = qc.add_classical_register(ClassicalRegister::new(2, "creg"));
+    /// assert_eq!(creg, vec![1, 2]);
+    /// qc.measure(0, creg[0]);
+    /// qc.measure(1, creg[1]);
+    /// qc.if_test(Condition::register(&creg, 3), |body| {
+    ///     body.x(0);
+    /// });
+    /// ```
+    pub fn add_classical_register(&mut self, register: ClassicalRegister) -> Vec<u32> {
+        let start = self.num_clbits();
+        unsafe {
+            qiskit_sys::qk!(qk_circuit_add_classical_register(
+                self.circuit,
+                register.register
+            ))
+        };
+        let clbits = start..self.num_clbits();
+        self.cregs.push((register.name.clone(), clbits.clone()));
+        clbits.collect()
     }
     /// Create a deepcopy of the circuit.
-    pub fn copy(&mut self) -> QuantumCircuit {
+    pub fn copy(&self) -> QuantumCircuit {
         QuantumCircuit {
-            circuit: unsafe { qiskit_sys::qk_circuit_copy(self.circuit) },
+            circuit: unsafe { qiskit_sys::qk!(qk_circuit_copy(self.circuit)) },
+            symbolic_params: self.symbolic_params.clone(),
+            control_flow: self.control_flow.clone(),
+            qregs: self.qregs.clone(),
+            cregs: self.cregs.clone(),
         }
     }
 
+    /// Append the instructions of `other` onto this circuit.
+    ///
+    /// Qubit `i` of `other` is mapped to `qubits[i]` and classical bit `j` to
+    /// `clbits[j]`, so the mappings must have the same widths as `other`.
+    /// The circuit is left unchanged if `other` contains an instruction that
+    /// cannot be copied through the C API.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::{QiskitError, QuantumCircuit};
+    ///
+    /// let mut bell = QuantumCircuit::new(2, 0);
+    /// bell.h(0);
+    /// bell.cx(0, 1);
+    ///
+    /// let mut qc = QuantumCircuit::new(3, 0);
+    /// assert_eq!(qc.compose(&bell, &[2, 0], &[]), QiskitError::Success);
+    /// assert_eq!(qc.num_instructions(), 2);
+    /// ```
+    pub fn compose(
+        &mut self,
+        other: &QuantumCircuit,
+        qubits: &[u32],
+        clbits: &[u32],
+    ) -> QiskitError {
+        if qubits.len() != other.num_qubits() as usize {
+            return QiskitError::MismatchedQubits;
+        }
+        if clbits.len() != other.num_clbits() as usize {
+            return QiskitError::MismatchedClbits;
+        }
+        if qubits.iter().any(|q| *q >= self.num_qubits())
+            || clbits.iter().any(|c| *c >= self.num_clbits())
+        {
+            return QiskitError::IndexError;
+        }
+        if has_duplicates(qubits) || has_duplicates(clbits) {
+            return QiskitError::CInputError;
+        }
+        if other.instructions().any(|inst| !is_copyable(inst.name)) {
+            return QiskitError::UnsupportedInstruction;
+        }
+        QiskitError::from_result(self.append_mapped(other, qubits, clbits))
+    }
+    /// Return the tensor product of this circuit with `other`.
+    ///
+    /// As in Qiskit, `other` occupies the lowest qubits and classical bits of
+    /// the result and this circuit the ones above.
+    pub fn tensor(&self, other: &QuantumCircuit) -> Result<QuantumCircuit, QiskitError> {
+        let (num_qubits, num_clbits) = (other.num_qubits(), other.num_clbits());
+        let mut out = QuantumCircuit::new(
+            num_qubits + self.num_qubits(),
+            num_clbits + self.num_clbits(),
+        );
+        let lower_qubits: Vec<u32> = (0..num_qubits).collect();
+        let lower_clbits: Vec<u32> = (0..num_clbits).collect();
+        out.compose(other, &lower_qubits, &lower_clbits)
+            .into_result()?;
+        let upper_qubits: Vec<u32> = (num_qubits..out.num_qubits()).collect();
+        let upper_clbits: Vec<u32> = (num_clbits..out.num_clbits()).collect();
+        out.compose(self, &upper_qubits, &upper_clbits)
+            .into_result()?;
+        Ok(out)
+    }
+    /// Return the inverse of the circuit.
+    ///
+    /// The instructions are reversed and each gate is replaced by its
+    /// inverse. Gates without a standard inverse are replaced by an
+    /// equivalent short sequence of standard gates. Returns
+    /// [`QiskitError::UnsupportedInstruction`] for non-invertible
+    /// instructions such as measurements and resets.
+    pub fn inverse(&self) -> Result<QuantumCircuit, QiskitError> {
+        let mut out = QuantumCircuit::new(self.num_qubits(), self.num_clbits());
+        let mut instructions: Vec<CircuitInstruction<'_>> = self.instructions().collect();
+        instructions.reverse();
+        for inst in instructions {
+            let retval = match inst.name {
+                "barrier" => out.barrier(inst.qubits),
+                name => match StandardGate::from_name(name) {
+                    Some(gate) => out.append_inverse_gate(gate, inst.qubits, &inst.param_values()),
+                    None => QiskitError::UnsupportedInstruction,
+                },
+            };
+            retval.into_result()?;
+        }
+        Ok(out)
+    }
+    /// Return a circuit with this circuit's instructions repeated `reps` times.
+    pub fn repeat(&self, reps: usize) -> Result<QuantumCircuit, QiskitError> {
+        let mut out = QuantumCircuit::new(self.num_qubits(), self.num_clbits());
+        let qubits: Vec<u32> = (0..self.num_qubits()).collect();
+        let clbits: Vec<u32> = (0..self.num_clbits()).collect();
+        for _ in 0..reps {
+            out.compose(self, &qubits, &clbits).into_result()?;
+        }
+        Ok(out)
+    }
+    /// Return a controlled version of the circuit.
+    ///
+    /// The result has `num_ctrl_qubits` new control qubits at the front,
+    /// followed by the qubits of this circuit. Every gate is replaced by its
+    /// controlled counterpart when one exists, for example `x` becomes `cx`,
+    /// `ccx` or the 3-controlled X gate, and `rz` becomes `crz`. Other gates
+    /// are decomposed into gates that can be controlled. Any global phase
+    /// becomes a relative phase on the controls. Returns
+    /// [`QiskitError::UnsupportedInstruction`] for non-unitary instructions
+    /// such as measurements and resets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::QuantumCircuit;
+    ///
+    /// let mut qc = QuantumCircuit::new(1, 0);
+    /// qc.x(0);
+    /// let controlled = qc.control(2).unwrap();
+    /// assert_eq!(controlled.num_qubits(), 3);
+    /// assert_eq!(controlled.instructions().next().unwrap().name, "ccx");
+    /// ```
+    pub fn control(&self, num_ctrl_qubits: u32) -> Result<QuantumCircuit, QiskitError> {
+        let mut out = QuantumCircuit::new(num_ctrl_qubits + self.num_qubits(), self.num_clbits());
+        let controls: Vec<u32> = (0..num_ctrl_qubits).collect();
+        for inst in self.instructions() {
+            let qubits: Vec<u32> = inst.qubits.iter().map(|q| q + num_ctrl_qubits).collect();
+            let retval = match inst.name {
+                "barrier" => out.barrier(&[controls.as_slice(), &qubits].concat()),
+                name => match StandardGate::from_name(name) {
+                    Some(gate) => {
+                        out.append_controlled_gate(&controls, gate, &qubits, &inst.param_values())
+                    }
+                    None => QiskitError::UnsupportedInstruction,
+                },
+            };
+            retval.into_result()?;
+        }
+        Ok(out)
+    }
+    /// Return the circuit raised to an integer power.
+    ///
+    /// A negative `power` repeats the inverse of the circuit, and a power of
+    /// zero gives an empty circuit of the same width.
+    pub fn power(&self, power: i32) -> Result<QuantumCircuit, QiskitError> {
+        if power < 0 {
+            self.inverse()?.repeat(power.unsigned_abs() as usize)
+        } else {
+            self.repeat(power as usize)
+        }
+    }
+    /// Return the unbound parameters of the circuit, sorted by name.
+    ///
+    /// The elements of a [`ParameterVector`](crate::parameter::ParameterVector)
+    /// are sorted by index.
+    pub fn parameters(&self) -> Vec<Parameter> {
+        let mut parameters: Vec<Parameter> = Vec::new();
+        let mut add = |param: &Parameter| {
+            if !parameters.contains(param) {
+                parameters.push(param.clone());
+            }
+        };
+        for expr in self.symbolic_params.values() {
+            expr.parameters().iter().for_each(&mut add);
+        }
+        // Parameters used in control-flow bodies belong to the circuit too,
+        // except for the loop parameters of for loops.
+        for cf in &self.control_flow {
+            let loop_parameter = match &cf.op {
+                ControlFlow::ForLoop { loop_parameter, .. } => Some(loop_parameter),
+                _ => None,
+            };
+            for block in cf.op.blocks() {
+                block
+                    .parameters()
+                    .iter()
+                    .filter(|param| Some(*param) != loop_parameter)
+                    .for_each(&mut add);
+            }
+        }
+        parameters.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+        parameters
+    }
+    /// Return the number of unbound parameters in the circuit.
+    pub fn num_parameters(&self) -> usize {
+        self.parameters().len()
+    }
+    /// Return a copy of the circuit with parameters replaced by values.
+    ///
+    /// Parameters that are missing from `values` are left unbound. Returns
+    /// [`QiskitError::CInputError`] if `values` contains a parameter that is
+    /// not in the circuit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::QuantumCircuit;
+    /// use qiskit_rs::parameter::ParameterVector;
+    /// use std::collections::HashMap;
+    ///
+    /// let theta = ParameterVector::new("theta", 2);
+    /// let mut qc = QuantumCircuit::new(1, 0);
+    /// qc.ry(&theta[0], 0);
+    /// qc.rz(&theta[1], 0);
+    ///
+    /// let values = HashMap::from([(theta[0].clone(), 0.5)]);
+    /// let bound = qc.assign_parameters(&values).unwrap();
+    /// assert_eq!(bound.parameters(), vec![theta[1].clone()]);
+    /// assert_eq!(bound.instructions().next().unwrap().params, &[0.5]);
+    /// ```
+    pub fn assign_parameters(
+        &self,
+        values: &HashMap<Parameter, f64>,
+    ) -> Result<QuantumCircuit, QiskitError> {
+        let parameters = self.parameters();
+        if values.keys().any(|param| !parameters.contains(param)) {
+            return Err(QiskitError::CInputError);
+        }
+        let mut out = QuantumCircuit::new(self.num_qubits(), self.num_clbits());
+        let qubits: Vec<u32> = (0..self.num_qubits()).collect();
+        let clbits: Vec<u32> = (0..self.num_clbits()).collect();
+        out.append_bound(self, &qubits, &clbits, values)?;
+        Ok(out)
+    }
+    // Append the inverse of a single standard gate.
+    fn append_inverse_gate(
+        &mut self,
+        gate: StandardGate,
+        qubits: &[u32],
+        params: &[ParameterValue],
+    ) -> QiskitError {
+        use StandardGate::*;
+        let neg: Vec<ParameterValue> = params.iter().map(|param| -param).collect();
+        match gate {
+            H | I | X | Y | Z | CH | CX | CY | CZ | ECR | Swap | CCX | CCZ | CSwap | RCCX | C3X => {
+                self.append_parameterized_gate(gate, qubits, params)
+            }
+            GlobalPhase | Phase | RX | RY | RZ | U1 | CPhase | CRX | CRY | CRZ | CU1 | RXX
+            | RYY | RZZ | RZX => self.append_parameterized_gate(gate, qubits, &neg),
+            R | XXMinusYY | XXPlusYY => {
+                self.append_parameterized_gate(gate, qubits, &[-&params[0], params[1].clone()])
+            }
+            U | U3 | CU3 => self.append_parameterized_gate(
+                gate,
+                qubits,
+                &[-&params[0], -&params[2], -&params[1]],
+            ),
+            CU => self.append_parameterized_gate(
+                gate,
+                qubits,
+                &[-&params[0], -&params[2], -&params[1], -&params[3]],
+            ),
+            U2 => {
+                self.append_parameterized_gate(gate, qubits, &[-&params[1] - PI, -&params[0] + PI])
+            }
+            S => self.append_parameterized_gate(Sdg, qubits, params),
+            Sdg => self.append_parameterized_gate(S, qubits, params),
+            SX => self.append_parameterized_gate(SXdg, qubits, params),
+            SXdg => self.append_parameterized_gate(SX, qubits, params),
+            T => self.append_parameterized_gate(Tdg, qubits, params),
+            Tdg => self.append_parameterized_gate(T, qubits, params),
+            CS => self.append_parameterized_gate(CSdg, qubits, params),
+            CSdg => self.append_parameterized_gate(CS, qubits, params),
+            DCX => self.append_parameterized_gate(DCX, &[qubits[1], qubits[0]], params),
+            ISwap => self.append_gate(XXPlusYY, qubits, &[PI, 0.0]),
+            // The adjoint of sqrt(X) is exp(-i pi / 4) RX(-pi / 2).
+            CSX => self.append_gate(CU, qubits, &[-FRAC_PI_2, -FRAC_PI_2, FRAC_PI_2, -FRAC_PI_4]),
+            // The adjoint of sqrt(X) is also H Sdg H.
+            C3SX => QiskitError::from_result((|| {
+                self.h(qubits[3]).into_result()?;
+                self.mcp(-FRAC_PI_2, &qubits[..3], qubits[3])
+                    .into_result()?;
+                self.h(qubits[3]).into_result()
+            })()),
+            // The inverse of Qiskit's definition of the gate in terms of H, T
+            // and CX.
+            RC3X => QiskitError::from_result((|| {
+                for (step, step_qubits, step_params) in gate.definition(qubits).iter().rev() {
+                    self.append_inverse_gate(*step, step_qubits, step_params)
+                        .into_result()?;
+                }
+                Ok(())
+            })()),
+        }
+    }
+    // Append a standard gate controlled on `controls`.
+    fn append_controlled_gate(
+        &mut self,
+        controls: &[u32],
+        gate: StandardGate,
+        qubits: &[u32],
+        params: &[ParameterValue],
+    ) -> QiskitError {
+        use StandardGate::*;
+        if controls.is_empty() {
+            return self.append_parameterized_gate(gate, qubits, params);
+        }
+        // Merge the gate's own controls with the new ones and work on the
+        // base gate from here on.
+        let base = gate.base_gate();
+        let num_gate_ctrls = gate.num_qubits() - base.num_qubits();
+        let (gate_ctrls, targets) = qubits.split_at(num_gate_ctrls as usize);
+        let ctrls = [controls, gate_ctrls].concat();
+        let (last, rest) = ctrls.split_last().unwrap();
+        if gate == CU {
+            // The extra phase of the controlled-U gate applies when its
+            // control is set.
+            return QiskitError::from_result((|| {
+                self.append_controlled_gate(&ctrls, U, targets, &params[..3])
+                    .into_result()?;
+                self.mcp(&params[3], controls, gate_ctrls[0]).into_result()
+            })());
+        }
+        if let Some(counterpart) = base.controlled(ctrls.len() as u32) {
+            return self.append_parameterized_gate(
+                counterpart,
+                &[ctrls.as_slice(), targets].concat(),
+                params,
+            );
+        }
+        if base == U && ctrls.len() == 1 {
+            let params = [params, &[ParameterValue::Float(0.0)]].concat();
+            return self.append_parameterized_gate(CU, &[*last, targets[0]], &params);
+        }
+        QiskitError::from_result((|| {
+            match base {
+                GlobalPhase => self.mcp(&params[0], rest, *last).into_result()?,
+                I => (),
+                X => self.mcx(&ctrls, targets[0]).into_result()?,
+                Z => self.mcp(PI, &ctrls, targets[0]).into_result()?,
+                Phase | U1 => self.mcp(&params[0], &ctrls, targets[0]).into_result()?,
+                S => self.mcp(FRAC_PI_2, &ctrls, targets[0]).into_result()?,
+                Sdg => self.mcp(-FRAC_PI_2, &ctrls, targets[0]).into_result()?,
+                T => self.mcp(FRAC_PI_4, &ctrls, targets[0]).into_result()?,
+                Tdg => self.mcp(-FRAC_PI_4, &ctrls, targets[0]).into_result()?,
+                Y => {
+                    self.sdg(targets[0]).into_result()?;
+                    self.mcx(&ctrls, targets[0]).into_result()?;
+                    self.s(targets[0]).into_result()?;
+                }
+                // RZ(theta) is exp(-i theta / 2) P(theta).
+                RZ => {
+                    self.mcp(&params[0], &ctrls, targets[0]).into_result()?;
+                    self.mcp(-&params[0] / 2.0, rest, *last).into_result()?;
+                }
+                RX => {
+                    self.h(targets[0]).into_result()?;
+                    self.append_controlled_gate(&ctrls, RZ, targets, params)
+                        .into_result()?;
+                    self.h(targets[0]).into_result()?;
+                }
+                RY => {
+                    self.sdg(targets[0]).into_result()?;
+                    self.append_controlled_gate(&ctrls, RX, targets, params)
+                        .into_result()?;
+                    self.s(targets[0]).into_result()?;
+                }
+                // SX is exp(i pi / 4) RX(pi / 2).
+                SX | SXdg => {
+                    let sign = if base == SX { 1.0 } else { -1.0 };
+                    self.append_controlled_gate(&ctrls, RX, targets, &[(sign * FRAC_PI_2).into()])
+                        .into_result()?;
+                    self.mcp(sign * FRAC_PI_4, rest, *last).into_result()?;
+                }
+                H => self
+                    .append_controlled_gate(
+                        &ctrls,
+                        U,
+                        targets,
+                        &[FRAC_PI_2.into(), 0.0.into(), PI.into()],
+                    )
+                    .into_result()?,
+                R => self
+                    .append_controlled_gate(
+                        &ctrls,
+                        U,
+                        targets,
+                        &[
+                            params[0].clone(),
+                            &params[1] - FRAC_PI_2,
+                            -&params[1] + FRAC_PI_2,
+                        ],
+                    )
+                    .into_result()?,
+                U2 => self
+                    .append_controlled_gate(
+                        &ctrls,
+                        U,
+                        targets,
+                        &[FRAC_PI_2.into(), params[0].clone(), params[1].clone()],
+                    )
+                    .into_result()?,
+                // U(theta, phi, lam) is exp(i (phi + lam) / 2) RZ(phi) RY(theta) RZ(lam).
+                U | U3 => {
+                    let (theta, phi, lam) = (&params[0..1], &params[1..2], &params[2..3]);
+                    self.append_controlled_gate(&ctrls, RZ, targets, lam)
+                        .into_result()?;
+                    self.append_controlled_gate(&ctrls, RY, targets, theta)
+                        .into_result()?;
+                    self.append_controlled_gate(&ctrls, RZ, targets, phi)
+                        .into_result()?;
+                    self.mcp((&phi[0] + &lam[0]) / 2.0, rest, *last)
+                        .into_result()?;
+                }
+                Swap => {
+                    let [q0, q1] = [targets[0], targets[1]];
+                    self.cx(q1, q0).into_result()?;
+                    self.mcx(&[ctrls.as_slice(), &[q0]].concat(), q1)
+                        .into_result()?;
+                    self.cx(q1, q0).into_result()?;
+                }
+                RZZ => {
+                    let [q0, q1] = [targets[0], targets[1]];
+                    self.cx(q0, q1).into_result()?;
+                    self.append_controlled_gate(&ctrls, RZ, &[q1], params)
+                        .into_result()?;
+                    self.cx(q0, q1).into_result()?;
+                }
+                RXX => {
+                    let [q0, q1] = [targets[0], targets[1]];
+                    self.h(q0).into_result()?;
+                    self.h(q1).into_result()?;
+                    self.append_controlled_gate(&ctrls, RZZ, targets, params)
+                        .into_result()?;
+                    self.h(q0).into_result()?;
+                    self.h(q1).into_result()?;
+                }
+                RYY => {
+                    let [q0, q1] = [targets[0], targets[1]];
+                    self.rx(FRAC_PI_2, q0).into_result()?;
+                    self.rx(FRAC_PI_2, q1).into_result()?;
+                    self.append_controlled_gate(&ctrls, RZZ, targets, params)
+                        .into_result()?;
+                    self.rx(-FRAC_PI_2, q0).into_result()?;
+                    self.rx(-FRAC_PI_2, q1).into_result()?;
+                }
+                RZX => {
+                    self.h(targets[1]).into_result()?;
+                    self.append_controlled_gate(&ctrls, RZZ, targets, params)
+                        .into_result()?;
+                    self.h(targets[1]).into_result()?;
+                }
+                // Both gates are an XX +/- YY rotation on the first qubit
+                // conjugated by an RZ, whose direction depends on the sign.
+                XXPlusYY | XXMinusYY => {
+                    let theta = &params[0];
+                    let (sign, beta) = if base == XXPlusYY {
+                        (1.0, params[1].clone())
+                    } else {
+                        (-1.0, -&params[1])
+                    };
+                    self.rz(&beta, targets[0]).into_result()?;
+                    self.append_controlled_gate(&ctrls, RXX, targets, &[theta / 2.0])
+                        .into_result()?;
+                    self.append_controlled_gate(&ctrls, RYY, targets, &[theta * (sign / 2.0)])
+                        .into_result()?;
+                    self.rz(-&beta, targets[0]).into_result()?;
+                }
+                // The remaining gates are controlled one step of their
+                // definition at a time.
+                DCX | ECR | ISwap | RCCX | RC3X => {
+                    for (step, step_qubits, step_params) in base.definition(targets) {
+                        self.append_controlled_gate(&ctrls, step, &step_qubits, &step_params)
+                            .into_result()?;
+                    }
+                }
+                _ => unreachable!("controlled gates are reduced to their base gate"),
+            }
+            Ok(())
+        })())
+    }
+
     /// Return the number of instructions in the circuit.
     pub fn num_instructions(&self) -> usize {
-        unsafe { qiskit_sys::qk_circuit_num_instructions(self.circuit) }
+        self.num_qk_instructions() + self.control_flow.len()
+    }
+
+    // The number of instructions stored in the C circuit, which excludes
+    // control flow.
+    fn num_qk_instructions(&self) -> usize {
+        unsafe { qiskit_sys::qk!(qk_circuit_num_instructions(self.circuit)) }
+    }
+
+    // The C circuit, for C API functions that read or rewrite it in place.
+    // They only see the instructions it holds, so circuits with symbolic
+    // parameters or control flow are rejected.
+    pub(crate) fn as_qk(&self) -> Result<*mut qiskit_sys::QkCircuit, QiskitError> {
+        if self.symbolic_params.is_empty() && self.control_flow.is_empty() {
+            Ok(self.circuit)
+        } else {
+            Err(QiskitError::UnsupportedInstruction)
+        }
+    }
+
+    // The C circuit, for conversions that carry the symbolic parameters over
+    // themselves. Control flow, which the C circuit doesn't hold, is rejected.
+    #[cfg(feature = "dag")]
+    pub(crate) fn as_qk_symbolic(&self) -> Result<*const qiskit_sys::QkCircuit, QiskitError> {
+        if self.control_flow.is_empty() {
+            Ok(self.circuit)
+        } else {
+            Err(QiskitError::UnsupportedInstruction)
+        }
     }
 
+    // The names and bit ranges of the quantum and classical registers.
+    #[cfg(any(feature = "dag", feature = "python"))]
+    pub(crate) fn registers(&self) -> (&Registers, &Registers) {
+        (&self.qregs, &self.cregs)
+    }
+
+    // Take ownership of a C circuit, with `symbolic_params` in place of its
+    // NaN parameters and the registers the C circuit was built with.
+    #[cfg(feature = "dag")]
+    pub(crate) unsafe fn from_qk(
+        circuit: *mut qiskit_sys::QkCircuit,
+        symbolic_params: BTreeMap<(usize, usize), ParameterExpression>,
+        qregs: Registers,
+        cregs: Registers,
+    ) -> QuantumCircuit {
+        QuantumCircuit {
+            circuit,
+            symbolic_params,
+            control_flow: Vec::new(),
+            qregs,
+            cregs,
+        }
+    }
+
+    // Replace the C circuit with a copy of `circuit` of the same width,
+    // keeping the registers. The symbolic parameters are kept too, so this is
+    // only for circuits that `as_qk` accepted, which have none.
+    pub(crate) unsafe fn replace_qk(&mut self, circuit: *const qiskit_sys::QkCircuit) {
+        unsafe {
+            qiskit_sys::qk!(qk_circuit_free(self.circuit));
+            self.circuit = qiskit_sys::qk!(qk_circuit_copy(circuit));
+        }
+    }
+
+    /// Draw the circuit as a text diagram, folded at 80 characters.
+    ///
+    /// This is also how the circuit is printed with [`Display`](fmt::Display).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::QuantumCircuit;
+    ///
+    /// let mut qc = QuantumCircuit::new(2, 2);
+    /// qc.h(0);
+    /// qc.cx(0, 1);
+    /// qc.measure(1, 1);
+    /// println!("{}", qc.draw_text());
+    /// ```
+    pub fn draw_text(&self) -> String {
+        self.draw_text_with(&DrawOptions::default())
+    }
+    /// Draw the circuit as a text diagram with the given options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::QuantumCircuit;
+    /// use qiskit_rs::visualization::DrawOptions;
+    ///
+    /// let mut qc = QuantumCircuit::new(1, 0);
+    /// for _ in 0..40 {
+    ///     qc.h(0);
+    /// }
+    /// let options = DrawOptions {
+    ///     fold: None,
+    ///     ..Default::default()
+    /// };
+    /// let unfolded = qc.draw_text_with(&options);
+    /// assert_eq!(unfolded.lines().count(), 3);
+    /// ```
+    pub fn draw_text_with(&self, options: &DrawOptions) -> String {
+        visualization::text::draw(self, options)
+    }
+    /// Draw the circuit as an SVG image.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::QuantumCircuit;
+    ///
+    /// let mut qc = QuantumCircuit::new(2, 0);
+    /// qc.h(0);
+    /// qc.cx(0, 1);
+    /// let svg = qc.draw_svg();
+    /// assert!(svg.starts_with("<svg"));
+    /// ```
+    pub fn draw_svg(&self) -> String {
+        self.draw_svg_with(&DrawOptions::default())
+    }
+    /// Draw the circuit as an SVG image with the given options.
+    pub fn draw_svg_with(&self, options: &DrawOptions) -> String {
+        visualization::svg::draw(self, options)
+    }
+    /// Draw the circuit as a standalone LaTeX document using the quantikz
+    /// package.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::QuantumCircuit;
+    ///
+    /// let mut qc = QuantumCircuit::new(2, 0);
+    /// qc.h(0);
+    /// qc.cx(0, 1);
+    /// let latex = qc.draw_latex();
+    /// assert!(latex.contains("\\begin{quantikz}"));
+    /// ```
+    pub fn draw_latex(&self) -> String {
+        self.draw_latex_with(&DrawOptions::default())
+    }
+    /// Draw the circuit as a standalone LaTeX document with the given
+    /// options.
+    pub fn draw_latex_with(&self, options: &DrawOptions) -> String {
+        visualization::latex::draw(self, options)
+    }
+    // The register and index in the register of a qubit, for drawings.
+    pub(crate) fn qubit_label(&self, qubit: u32) -> (String, u32) {
+        bit_label(&self.qregs, "q", qubit)
+    }
+    // The register and index in the register of a classical bit, for
+    // drawings.
+    pub(crate) fn clbit_label(&self, clbit: u32) -> (String, u32) {
+        bit_label(&self.cregs, "c", clbit)
+    }
     /// Return an iterator of all the instructions in the circuit.
     pub fn instructions(&self) -> impl ExactSizeIterator<Item = CircuitInstruction<'_>> + '_ {
         let num_inst = self.num_instructions();
@@ -346,57 +1984,186 @@ impl QuantumCircuit {
             len: num_inst,
             circuit: self,
             index: 0,
+            qk_index: 0,
+        }
+    }
+
+    /// Re-append every instruction of `other` onto this circuit, mapping
+    /// `other`'s qubit `i` to `qubits[i]` and clbit `j` to `clbits[j]`.
+    pub(crate) fn append_mapped(
+        &mut self,
+        other: &QuantumCircuit,
+        qubits: &[u32],
+        clbits: &[u32],
+    ) -> Result<(), QiskitError> {
+        self.append_bound(other, qubits, clbits, &HashMap::new())
+    }
+
+    /// Like [`QuantumCircuit::append_mapped`], but also binds the parameters
+    /// in `values`.
+    pub(crate) fn append_bound(
+        &mut self,
+        other: &QuantumCircuit,
+        qubits: &[u32],
+        clbits: &[u32],
+        values: &HashMap<Parameter, f64>,
+    ) -> Result<(), QiskitError> {
+        for inst in other.instructions() {
+            self.append_instruction(&inst, qubits, clbits, values)
+                .into_result()?;
+        }
+        Ok(())
+    }
+
+    // Re-append a single instruction of another circuit with the bit mapping
+    // and parameter binding of `append_bound`.
+    pub(crate) fn append_instruction(
+        &mut self,
+        inst: &CircuitInstruction<'_>,
+        qubits: &[u32],
+        clbits: &[u32],
+        values: &HashMap<Parameter, f64>,
+    ) -> QiskitError {
+        if let Some(op) = inst.control_flow() {
+            return match op.remap(self.num_qubits(), self.num_clbits(), qubits, clbits, values) {
+                Ok(op) => self.append_control_flow(op),
+                Err(err) => err,
+            };
+        }
+        let inst_qubits: Vec<u32> = inst.qubits.iter().map(|q| qubits[*q as usize]).collect();
+        match inst.name {
+            "measure" => self.measure(inst_qubits[0], clbits[inst.clbits[0] as usize]),
+            "reset" => self.reset(inst_qubits[0]),
+            "barrier" => self.barrier(&inst_qubits),
+            name => match StandardGate::from_name(name) {
+                Some(gate) => {
+                    let params: Vec<ParameterValue> = inst
+                        .param_values()
+                        .iter()
+                        .map(|param| param.bind(values))
+                        .collect();
+                    self.append_parameterized_gate(gate, &inst_qubits, &params)
+                }
+                None => QiskitError::UnsupportedInstruction,
+            },
         }
     }
 }
 
+// Whether an instruction can be re-appended through the C API.
+fn is_copyable(name: &str) -> bool {
+    matches!(
+        name,
+        "measure" | "reset" | "barrier" | "if_else" | "while_loop" | "for_loop" | "switch_case"
+    ) || StandardGate::from_name(name).is_some()
+}
+
+fn has_duplicates(bits: &[u32]) -> bool {
+    bits.iter()
+        .enumerate()
+        .any(|(i, bit)| bits[..i].contains(bit))
+}
+
+// Label a bit by its register and index in the register. Bits that are not in
+// a register are labelled as if they were in one called `default`.
+fn bit_label(registers: &[(String, Range<u32>)], default: &str, bit: u32) -> (String, u32) {
+    match registers.iter().find(|(_, bits)| bits.contains(&bit)) {
+        Some((name, bits)) => (name.clone(), bit - bits.start),
+        None => (default.to_string(), bit),
+    }
+}
+
+impl Clone for QuantumCircuit {
+    fn clone(&self) -> Self {
+        self.copy()
+    }
+}
+
+impl fmt::Debug for QuantumCircuit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QuantumCircuit")
+            .field("num_qubits", &self.num_qubits())
+            .field("num_clbits", &self.num_clbits())
+            .field("instructions", &self.instructions().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl fmt::Display for QuantumCircuit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.draw_text())
+    }
+}
+
 impl Drop for QuantumCircuit {
     fn drop(&mut self) {
-        unsafe { qiskit_sys::qk_circuit_free(self.circuit) };
+        unsafe { qiskit_sys::qk!(qk_circuit_free(self.circuit)) };
     }
 }
 
 /// A quantum register.
 pub struct QuantumRegister {
-    register: *mut qiskit_sys::QkQuantumRegister,
+    pub(crate) register: *mut qiskit_sys::QkQuantumRegister,
+    name: String,
 }
 
 impl QuantumRegister {
     /// Create a new quantum register.
+    ///
+    /// # Panics
+    ///
+    /// With the `runtime-linking` feature, panics if libqiskit wasn't loaded
+    /// with `qiskit_rs::init` and the default library can't be loaded.
     pub fn new(num_qubits: u32, name: &str) -> QuantumRegister {
         let cname = CString::new(name).expect("String to CString conversion failed");
         let cname = cname.as_ptr();
         QuantumRegister {
-            register: unsafe { qiskit_sys::qk_quantum_register_new(num_qubits, cname) },
+            register: unsafe { qiskit_sys::qk!(qk_quantum_register_new(num_qubits, cname)) },
+            name: name.to_string(),
         }
     }
+    /// Return the name of the register.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
 }
 
 impl Drop for QuantumRegister {
     fn drop(&mut self) {
-        unsafe { qiskit_sys::qk_quantum_register_free(self.register) };
+        unsafe { qiskit_sys::qk!(qk_quantum_register_free(self.register)) };
     }
 }
 
 /// A classical register.
 pub struct ClassicalRegister {
-    register: *mut qiskit_sys::QkClassicalRegister,
+    pub(crate) register: *mut qiskit_sys::QkClassicalRegister,
+    name: String,
 }
 
 impl ClassicalRegister {
     /// Create a new classical register.
+    ///
+    /// # Panics
+    ///
+    /// With the `runtime-linking` feature, panics if libqiskit wasn't loaded
+    /// with `qiskit_rs::init` and the default library can't be loaded.
     pub fn new(num_clbits: u32, name: &str) -> ClassicalRegister {
         let cname = CString::new(name).expect("String to CString conversion failed");
         let cname = cname.as_ptr();
         ClassicalRegister {
-            register: unsafe { qiskit_sys::qk_classical_register_new(num_clbits, cname) },
+            register: unsafe { qiskit_sys::qk!(qk_classical_register_new(num_clbits, cname)) },
+            name: name.to_string(),
         }
     }
+    /// Return the name of the register.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
 }
 
 impl Drop for ClassicalRegister {
     fn drop(&mut self) {
-        unsafe { qiskit_sys::qk_classical_register_free(self.register) };
+        unsafe { qiskit_sys::qk!(qk_classical_register_free(self.register)) };
     }
 }
 
@@ -412,15 +2179,71 @@ pub struct CircuitInstruction<'a> {
     pub qubits: &'a [u32],
     /// The clbits the instruction acts upon
     pub clbits: &'a [u32],
-    /// The parameters for the instruction
+    /// The parameters for the instruction, with NaN in place of unbound
+    /// parameters
     pub params: &'a [f64],
-    inst: qiskit_sys::QkCircuitInstruction,
+    // The unbound parameters by position.
+    symbolic: Vec<(usize, &'a ParameterExpression)>,
+    control_flow: Option<&'a ControlFlow>,
+    // The C API's copy of the instruction, if it is stored in the C circuit.
+    inst: Option<qiskit_sys::QkCircuitInstruction>,
+}
+
+impl<'a> CircuitInstruction<'a> {
+    // An instruction for the C API to fill in.
+    pub(crate) fn empty_qk() -> qiskit_sys::QkCircuitInstruction {
+        qiskit_sys::QkCircuitInstruction {
+            name: std::ptr::null_mut(),
+            qubits: std::ptr::null_mut(),
+            clbits: std::ptr::null_mut(),
+            params: std::ptr::null_mut(),
+            num_qubits: u32::MAX,
+            num_clbits: u32::MAX,
+            num_params: u32::MAX,
+        }
+    }
+
+    // Wrap an instruction filled in by the C API, which is cleared on drop.
+    //
+    // The instruction must have been filled in by the C API and must not be
+    // cleared elsewhere.
+    pub(crate) unsafe fn from_qk(
+        inst: qiskit_sys::QkCircuitInstruction,
+        symbolic: Vec<(usize, &'a ParameterExpression)>,
+    ) -> CircuitInstruction<'a> {
+        unsafe {
+            CircuitInstruction {
+                name: CStr::from_ptr(inst.name).to_str().unwrap(),
+                qubits: std::slice::from_raw_parts(inst.qubits, inst.num_qubits as usize),
+                clbits: std::slice::from_raw_parts(inst.clbits, inst.num_clbits as usize),
+                params: std::slice::from_raw_parts(inst.params, inst.num_params as usize),
+                symbolic,
+                control_flow: None,
+                inst: Some(inst),
+            }
+        }
+    }
+
+    /// Return the parameters of the instruction, including unbound ones.
+    pub fn param_values(&self) -> Vec<ParameterValue> {
+        let mut values: Vec<ParameterValue> =
+            self.params.iter().map(|param| (*param).into()).collect();
+        for (i, expr) in &self.symbolic {
+            values[*i] = ParameterValue::Expression((*expr).clone());
+        }
+        values
+    }
+
+    /// Return the control-flow operation, if the instruction is one.
+    pub fn control_flow(&self) -> Option<&'a ControlFlow> {
+        self.control_flow
+    }
 }
 
 impl<'a> Drop for CircuitInstruction<'a> {
     fn drop(&mut self) {
-        unsafe {
-            qiskit_sys::qk_circuit_instruction_clear(&mut self.inst);
+        if let Some(inst) = &mut self.inst {
+            unsafe { qiskit_sys::qk!(qk_circuit_instruction_clear(inst)) };
         }
     }
 }
@@ -429,6 +2252,8 @@ impl<'a> Drop for CircuitInstruction<'a> {
 pub struct CircuitInstructions<'a> {
     len: usize,
     index: usize,
+    // The index of the next instruction stored in the C circuit.
+    qk_index: usize,
     circuit: &'a QuantumCircuit,
 }
 
@@ -445,32 +2270,38 @@ impl<'a> Iterator for CircuitInstructions<'a> {
         if self.index >= self.len {
             return None;
         }
-        let out = unsafe {
-            let mut inst = qiskit_sys::QkCircuitInstruction {
-                name: std::ptr::null_mut(),
-                qubits: std::ptr::null_mut(),
-                clbits: std::ptr::null_mut(),
-                params: std::ptr::null_mut(),
-                num_qubits: u32::MAX,
-                num_clbits: u32::MAX,
-                num_params: u32::MAX,
-            };
-
-            qiskit_sys::qk_circuit_get_instruction(self.circuit.circuit, self.index, &mut inst);
-            let qubits = std::slice::from_raw_parts(inst.qubits, inst.num_qubits as usize);
-            let clbits = std::slice::from_raw_parts(inst.clbits, inst.num_clbits as usize);
-            let params = std::slice::from_raw_parts(inst.params, inst.num_params as usize);
-            let name = CStr::from_ptr(inst.name).to_str().unwrap();
-            Some(CircuitInstruction {
-                name,
-                qubits,
-                clbits,
-                params,
-                inst,
-            })
+        let control_flow = &self.circuit.control_flow;
+        if let Some(cf) = control_flow.get(self.index - self.qk_index) {
+            if cf.position == self.qk_index {
+                self.index += 1;
+                return Some(CircuitInstruction {
+                    name: cf.op.name(),
+                    qubits: &cf.qubits,
+                    clbits: &cf.clbits,
+                    params: &[],
+                    symbolic: Vec::new(),
+                    control_flow: Some(&cf.op),
+                    inst: None,
+                });
+            }
+        }
+        let mut inst = CircuitInstruction::empty_qk();
+        unsafe {
+            qiskit_sys::qk!(qk_circuit_get_instruction(
+                self.circuit.circuit,
+                self.qk_index,
+                &mut inst
+            ))
         };
+        let symbolic = self
+            .circuit
+            .symbolic_params
+            .range((self.qk_index, 0)..(self.qk_index + 1, 0))
+            .map(|((_, i), expr)| (*i, expr))
+            .collect();
         self.index += 1;
-        out
+        self.qk_index += 1;
+        Some(unsafe { CircuitInstruction::from_qk(inst, symbolic) })
     }
 }
 
@@ -495,24 +2326,24 @@ mod tests {
             if idx == 0 || idx == 2 {
                 assert_eq!(inst.name, "rz");
                 assert_eq!(&[0,], inst.qubits);
-                assert_eq!(inst.clbits, &[]);
+                assert_eq!(inst.clbits, &[] as &[u32]);
                 assert_eq!(&[FRAC_PI_2,], inst.params);
             } else if idx == 1 {
                 assert_eq!(inst.name, "sx");
                 assert_eq!(&[0,], inst.qubits);
-                assert_eq!(inst.clbits, &[]);
-                assert_eq!(inst.params, &[]);
+                assert_eq!(inst.clbits, &[] as &[u32]);
+                assert_eq!(inst.params, &[] as &[f64]);
             } else {
                 let expected_name = if (idx - 3) % 2 == 0 { "cx" } else { "measure" };
                 assert_eq!(expected_name, inst.name);
-                assert_eq!(inst.params, &[]);
+                assert_eq!(inst.params, &[] as &[f64]);
                 if expected_name == "measure" {
                     assert_eq!(inst.qubits, &[target]);
                     assert_eq!(inst.clbits, &[target]);
                     target += 1;
                 } else {
                     assert_eq!(inst.qubits, &[0, target]);
-                    assert_eq!(inst.clbits, &[]);
+                    assert_eq!(inst.clbits, &[] as &[u32]);
                 }
             }
         }
@@ -0,0 +1,574 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+use crate::complex::Complex64;
+use crate::qiskit::{QiskitError, qk_to_qiskit_error};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::fmt;
+
+/// A single-qubit term of a [`SparseObservable`].
+///
+/// Besides the Pauli operators, a bit term can be a projector onto an
+/// eigenstate of one of the Paulis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BitTerm {
+    /// Pauli X.
+    X,
+    /// Projector onto the positive eigenstate of Pauli X.
+    Plus,
+    /// Projector onto the negative eigenstate of Pauli X.
+    Minus,
+    /// Pauli Y.
+    Y,
+    /// Projector onto the positive eigenstate of Pauli Y.
+    Right,
+    /// Projector onto the negative eigenstate of Pauli Y.
+    Left,
+    /// Pauli Z.
+    Z,
+    /// Projector onto the positive eigenstate of Pauli Z.
+    Zero,
+    /// Projector onto the negative eigenstate of Pauli Z.
+    One,
+}
+
+impl BitTerm {
+    /// Return the single character label of the bit term.
+    pub fn label(self) -> char {
+        match self {
+            BitTerm::X => 'X',
+            BitTerm::Plus => '+',
+            BitTerm::Minus => '-',
+            BitTerm::Y => 'Y',
+            BitTerm::Right => 'r',
+            BitTerm::Left => 'l',
+            BitTerm::Z => 'Z',
+            BitTerm::Zero => '0',
+            BitTerm::One => '1',
+        }
+    }
+
+    /// Parse a bit term from its single character label.
+    pub fn from_label(label: char) -> Option<BitTerm> {
+        match label {
+            'X' => Some(BitTerm::X),
+            '+' => Some(BitTerm::Plus),
+            '-' => Some(BitTerm::Minus),
+            'Y' => Some(BitTerm::Y),
+            'r' => Some(BitTerm::Right),
+            'l' => Some(BitTerm::Left),
+            'Z' => Some(BitTerm::Z),
+            '0' => Some(BitTerm::Zero),
+            '1' => Some(BitTerm::One),
+            _ => None,
+        }
+    }
+
+    /// Whether the bit term is a Pauli operator rather than a projector.
+    pub fn is_pauli(self) -> bool {
+        matches!(self, BitTerm::X | BitTerm::Y | BitTerm::Z)
+    }
+
+    // The Pauli whose eigenbasis the bit term is diagonal in.
+    pub(crate) fn basis(self) -> BitTerm {
+        match self {
+            BitTerm::X | BitTerm::Plus | BitTerm::Minus => BitTerm::X,
+            BitTerm::Y | BitTerm::Right | BitTerm::Left => BitTerm::Y,
+            BitTerm::Z | BitTerm::Zero | BitTerm::One => BitTerm::Z,
+        }
+    }
+
+    // The projector as `(I + sign * P) / 2` for its Pauli `P`, or `None` for
+    // a Pauli.
+    fn projector_sign(self) -> Option<f64> {
+        match self {
+            BitTerm::Plus | BitTerm::Right | BitTerm::Zero => Some(1.0),
+            BitTerm::Minus | BitTerm::Left | BitTerm::One => Some(-1.0),
+            BitTerm::X | BitTerm::Y | BitTerm::Z => None,
+        }
+    }
+
+    // The 2x2 matrix of the bit term, row by row.
+    fn matrix(self) -> [Complex64; 4] {
+        let (zero, one, i) = (Complex64::ZERO, Complex64::ONE, Complex64::I);
+        match (self, self.projector_sign()) {
+            (BitTerm::X, _) => [zero, one, one, zero],
+            (BitTerm::Y, _) => [zero, -i, i, zero],
+            (BitTerm::Z, _) => [one, zero, zero, -one],
+            (_, Some(sign)) => {
+                let pauli = self.basis().matrix();
+                std::array::from_fn(|k| {
+                    let identity = if k == 0 || k == 3 { one } else { zero };
+                    (identity + pauli[k].scale(sign)).scale(0.5)
+                })
+            }
+            (_, None) => unreachable!("only Paulis have no projector sign"),
+        }
+    }
+
+    pub(crate) fn to_qk(self) -> qiskit_sys::QkBitTerm {
+        match self {
+            BitTerm::X => qiskit_sys::QkBitTerm_QkBitTerm_X,
+            BitTerm::Plus => qiskit_sys::QkBitTerm_QkBitTerm_Plus,
+            BitTerm::Minus => qiskit_sys::QkBitTerm_QkBitTerm_Minus,
+            BitTerm::Y => qiskit_sys::QkBitTerm_QkBitTerm_Y,
+            BitTerm::Right => qiskit_sys::QkBitTerm_QkBitTerm_Right,
+            BitTerm::Left => qiskit_sys::QkBitTerm_QkBitTerm_Left,
+            BitTerm::Z => qiskit_sys::QkBitTerm_QkBitTerm_Z,
+            BitTerm::Zero => qiskit_sys::QkBitTerm_QkBitTerm_Zero,
+            BitTerm::One => qiskit_sys::QkBitTerm_QkBitTerm_One,
+        }
+    }
+
+    pub(crate) fn from_qk(bit_term: qiskit_sys::QkBitTerm) -> BitTerm {
+        match bit_term {
+            qiskit_sys::QkBitTerm_QkBitTerm_X => BitTerm::X,
+            qiskit_sys::QkBitTerm_QkBitTerm_Plus => BitTerm::Plus,
+            qiskit_sys::QkBitTerm_QkBitTerm_Minus => BitTerm::Minus,
+            qiskit_sys::QkBitTerm_QkBitTerm_Y => BitTerm::Y,
+            qiskit_sys::QkBitTerm_QkBitTerm_Right => BitTerm::Right,
+            qiskit_sys::QkBitTerm_QkBitTerm_Left => BitTerm::Left,
+            qiskit_sys::QkBitTerm_QkBitTerm_Z => BitTerm::Z,
+            qiskit_sys::QkBitTerm_QkBitTerm_Zero => BitTerm::Zero,
+            qiskit_sys::QkBitTerm_QkBitTerm_One => BitTerm::One,
+            _ => panic!("Invalid option for BitTerm"),
+        }
+    }
+}
+
+// The entries of the products of bit terms are small multiples of powers of two, so the
+// tolerance only absorbs rounding.
+const TOLERANCE: f64 = 1e-12;
+
+fn matrix_product(a: &[Complex64; 4], b: &[Complex64; 4]) -> [Complex64; 4] {
+    [
+        a[0] * b[0] + a[1] * b[2],
+        a[0] * b[1] + a[1] * b[3],
+        a[2] * b[0] + a[3] * b[2],
+        a[2] * b[1] + a[3] * b[3],
+    ]
+}
+
+fn is_zero(m: &[Complex64; 4]) -> bool {
+    m.iter().all(|entry| entry.norm() < TOLERANCE)
+}
+
+// The factor `c` with `a = c * b`, for a nonzero `b`, if there is one.
+fn matrix_ratio(a: &[Complex64; 4], b: &[Complex64; 4]) -> Option<Complex64> {
+    let largest = (0..4).max_by(|&i, &j| b[i].norm().total_cmp(&b[j].norm()))?;
+    let ratio = a[largest] / b[largest];
+    a.iter()
+        .zip(b)
+        .all(|(a, b)| (*a - ratio * *b).norm() < TOLERANCE)
+        .then_some(ratio)
+}
+
+/// An owned copy of a single term of a [`SparseObservable`].
+///
+/// `bit_terms[i]` acts on qubit `indices[i]`, and qubits not listed are
+/// implicitly the identity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseTerm {
+    /// The coefficient of the term.
+    pub coeff: Complex64,
+    /// The non-identity bit terms.
+    pub bit_terms: Vec<BitTerm>,
+    /// The qubits each of the bit terms acts on.
+    pub indices: Vec<u32>,
+}
+
+impl SparseTerm {
+    // The pairs of bit terms of `self` and `other` on each qubit both act on.
+    fn shared<'a>(
+        &'a self,
+        other: &'a SparseTerm,
+    ) -> impl Iterator<Item = (BitTerm, BitTerm)> + 'a {
+        self.indices
+            .iter()
+            .zip(&self.bit_terms)
+            .filter_map(move |(q, bit_term)| {
+                let position = other.indices.iter().position(|p| p == q)?;
+                Some((*bit_term, other.bit_terms[position]))
+            })
+    }
+
+    /// Whether the term commutes with `other`, ignoring coefficients.
+    ///
+    /// The answer is exact, for projectors as well as Paulis. Both products
+    /// of the terms are tensor products of their single-qubit products, so
+    /// they are equal when both have a zero factor, as terms with orthogonal
+    /// projectors on the same qubit do, or when their factors on each qubit
+    /// are proportional with ratios multiplying to one. Pauli terms, for
+    /// instance, commute when they differ on an even number of the qubits
+    /// they share.
+    pub fn commutes_with(&self, other: &SparseTerm) -> bool {
+        let (mut ab_zero, mut ba_zero) = (false, false);
+        let mut proportional = true;
+        let mut ratio = Complex64::ONE;
+        for (a, b) in self.shared(other) {
+            let (a, b) = (a.matrix(), b.matrix());
+            let (ab, ba) = (matrix_product(&a, &b), matrix_product(&b, &a));
+            let (ab_is_zero, ba_is_zero) = (is_zero(&ab), is_zero(&ba));
+            ab_zero |= ab_is_zero;
+            ba_zero |= ba_is_zero;
+            if ab_is_zero || ba_is_zero {
+                continue;
+            }
+            match matrix_ratio(&ab, &ba) {
+                Some(factor) => ratio *= factor,
+                None => proportional = false,
+            }
+        }
+        if ab_zero || ba_zero {
+            ab_zero && ba_zero
+        } else {
+            proportional && (ratio - Complex64::ONE).norm() < TOLERANCE
+        }
+    }
+
+    /// Whether the term commutes with `other` on every qubit, so both can be
+    /// measured in the same basis.
+    pub fn qubit_wise_commutes(&self, other: &SparseTerm) -> bool {
+        self.shared(other).all(|(a, b)| a.basis() == b.basis())
+    }
+}
+
+/// An observable over Pauli and projector bases, stored term by term.
+///
+/// This wraps the C API's `QkObs`.
+///
+/// # Example
+///
+/// ```
+/// use qiskit_rs::{Complex64, SparseObservable};
+///
+/// let obs = SparseObservable::from_sparse_list(
+///     &[("ZZ", &[0, 1], Complex64::ONE), ("X", &[2], Complex64::new(0.5, 0.0))],
+///     3,
+/// )
+/// .unwrap();
+/// assert_eq!(obs.num_terms(), 2);
+/// ```
+pub struct SparseObservable {
+    pub(crate) obs: *mut qiskit_sys::QkObs,
+}
+
+impl SparseObservable {
+    /// Create the zero observable on `num_qubits` qubits.
+    ///
+    /// # Panics
+    ///
+    /// With the `runtime-linking` feature, panics if libqiskit wasn't loaded
+    /// with `qiskit_rs::init` and the default library can't be loaded.
+    pub fn zero(num_qubits: u32) -> SparseObservable {
+        SparseObservable {
+            obs: unsafe { qiskit_sys::qk!(qk_obs_zero(num_qubits)) },
+        }
+    }
+
+    /// Create the identity observable on `num_qubits` qubits.
+    ///
+    /// # Panics
+    ///
+    /// With the `runtime-linking` feature, panics if libqiskit wasn't loaded
+    /// with `qiskit_rs::init` and the default library can't be loaded.
+    pub fn identity(num_qubits: u32) -> SparseObservable {
+        SparseObservable {
+            obs: unsafe { qiskit_sys::qk!(qk_obs_identity(num_qubits)) },
+        }
+    }
+
+    /// Create an observable from a list of `(label, indices, coeff)` triples.
+    ///
+    /// Each character of `label` is the [`BitTerm`] label of the matching
+    /// entry in `indices`, so `("XZ", &[1, 4], coeff)` is X on qubit 1 and
+    /// Z on qubit 4.
+    pub fn from_sparse_list(
+        terms: &[(&str, &[u32], Complex64)],
+        num_qubits: u32,
+    ) -> Result<SparseObservable, QiskitError> {
+        let mut out = SparseObservable::zero(num_qubits);
+        for (label, indices, coeff) in terms {
+            let bit_terms = label
+                .chars()
+                .map(BitTerm::from_label)
+                .collect::<Option<Vec<_>>>()
+                .ok_or(QiskitError::CInputError)?;
+            if bit_terms.len() != indices.len() {
+                return Err(QiskitError::MismatchedQubits);
+            }
+            let term = SparseTerm {
+                coeff: *coeff,
+                bit_terms,
+                indices: indices.to_vec(),
+            };
+            out.add_term(&term).into_result()?;
+        }
+        Ok(out)
+    }
+
+    /// Add a single term to the observable.
+    ///
+    /// The term's indices need not be sorted, but must be distinct and in
+    /// range.
+    pub fn add_term(&mut self, term: &SparseTerm) -> QiskitError {
+        if term.bit_terms.len() != term.indices.len() {
+            return QiskitError::MismatchedQubits;
+        }
+        let mut pairs: Vec<(u32, BitTerm)> = term
+            .indices
+            .iter()
+            .copied()
+            .zip(term.bit_terms.iter().copied())
+            .collect();
+        pairs.sort_by_key(|(index, _)| *index);
+        if pairs.windows(2).any(|w| w[0].0 == w[1].0) {
+            return QiskitError::CInputError;
+        }
+        let mut indices: Vec<u32> = pairs.iter().map(|(index, _)| *index).collect();
+        let mut bit_terms: Vec<qiskit_sys::QkBitTerm> =
+            pairs.iter().map(|(_, bit)| bit.to_qk()).collect();
+        let cterm = qiskit_sys::QkObsTerm {
+            coeff: term.coeff.to_qk(),
+            len: indices.len(),
+            bit_terms: bit_terms.as_mut_ptr(),
+            indices: indices.as_mut_ptr(),
+            num_qubits: self.num_qubits(),
+        };
+        let retval = unsafe { qiskit_sys::qk!(qk_obs_add_term(self.obs, &cterm)) };
+        qk_to_qiskit_error(retval)
+    }
+
+    // An observable of terms with valid indices for `num_qubits` qubits,
+    // which the C API always accepts.
+    fn from_terms(
+        num_qubits: u32,
+        terms: impl IntoIterator<Item = SparseTerm>,
+    ) -> SparseObservable {
+        let mut out = SparseObservable::zero(num_qubits);
+        for term in terms {
+            let result = out.add_term(&term);
+            debug_assert_eq!(result, QiskitError::Success);
+        }
+        out
+    }
+
+    /// Return the number of qubits the observable acts on.
+    pub fn num_qubits(&self) -> u32 {
+        unsafe { qiskit_sys::qk!(qk_obs_num_qubits(self.obs)) }
+    }
+
+    /// Return the number of terms in the observable.
+    pub fn num_terms(&self) -> usize {
+        unsafe { qiskit_sys::qk!(qk_obs_num_terms(self.obs)) }
+    }
+
+    /// Return an iterator over copies of the terms of the observable.
+    pub fn terms(&self) -> impl ExactSizeIterator<Item = SparseTerm> + '_ {
+        let num_terms = self.num_terms();
+        let (coeffs, bit_terms, indices, boundaries) = unsafe {
+            let len = qiskit_sys::qk!(qk_obs_len(self.obs));
+            (
+                raw_slice(qiskit_sys::qk!(qk_obs_coeffs(self.obs)), num_terms),
+                raw_slice(qiskit_sys::qk!(qk_obs_bit_terms(self.obs)), len),
+                raw_slice(qiskit_sys::qk!(qk_obs_indices(self.obs)), len),
+                raw_slice(qiskit_sys::qk!(qk_obs_boundaries(self.obs)), num_terms + 1),
+            )
+        };
+        (0..num_terms).map(move |term| {
+            let range = boundaries[term]..boundaries[term + 1];
+            SparseTerm {
+                coeff: Complex64::from_qk(coeffs[term]),
+                bit_terms: bit_terms[range.clone()]
+                    .iter()
+                    .map(|bit| BitTerm::from_qk(*bit))
+                    .collect(),
+                indices: indices[range].to_vec(),
+            }
+        })
+    }
+
+    /// Split the terms into groups that all commute with each other.
+    ///
+    /// With `qubit_wise`, terms are grouped when they commute on every
+    /// qubit, as [`SparseTerm::qubit_wise_commutes`], so each group can be
+    /// measured with a single circuit; otherwise by
+    /// [`SparseTerm::commutes_with`]. The groups come from a greedy coloring
+    /// of the graph of non-commuting terms, taking the terms with the most
+    /// conflicts first, and keep the order of the terms within each group.
+    pub fn group_commuting(&self, qubit_wise: bool) -> Vec<SparseObservable> {
+        let terms: Vec<SparseTerm> = self.terms().collect();
+        let commutes = |a: &SparseTerm, b: &SparseTerm| {
+            if qubit_wise {
+                a.qubit_wise_commutes(b)
+            } else {
+                a.commutes_with(b)
+            }
+        };
+        let conflicts: Vec<Vec<usize>> = terms
+            .iter()
+            .enumerate()
+            .map(|(i, a)| {
+                (0..terms.len())
+                    .filter(|&j| j != i && !commutes(a, &terms[j]))
+                    .collect()
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..terms.len()).collect();
+        order.sort_by_key(|&i| Reverse(conflicts[i].len()));
+        let mut colors: Vec<Option<usize>> = vec![None; terms.len()];
+        for i in order {
+            let color = (0..)
+                .find(|color| conflicts[i].iter().all(|&j| colors[j] != Some(*color)))
+                .unwrap();
+            colors[i] = Some(color);
+        }
+        let num_groups = colors.iter().flatten().map(|color| color + 1).max();
+        let mut groups = vec![Vec::new(); num_groups.unwrap_or(0)];
+        for (term, color) in terms.into_iter().zip(colors) {
+            groups[color.unwrap()].push(term);
+        }
+        groups
+            .into_iter()
+            .map(|terms| SparseObservable::from_terms(self.num_qubits(), terms))
+            .collect()
+    }
+
+    /// Return the adjoint of the observable.
+    ///
+    /// Every bit term is hermitian, so this only conjugates the
+    /// coefficients.
+    pub fn adjoint(&self) -> SparseObservable {
+        let terms = self.terms().map(|term| SparseTerm {
+            coeff: term.coeff.conj(),
+            ..term
+        });
+        SparseObservable::from_terms(self.num_qubits(), terms)
+    }
+
+    /// Return the observable with like terms summed and the terms with a
+    /// coefficient of magnitude at most `tol` removed.
+    ///
+    /// Terms stay in the order they first appear. Projectors aren't
+    /// expanded, so a term with a projector and one with its Pauli aren't
+    /// combined; see [`SparseObservable::as_paulis`].
+    pub fn simplify(&self, tol: f64) -> SparseObservable {
+        let mut terms: Vec<SparseTerm> = Vec::new();
+        let mut positions: HashMap<(Vec<BitTerm>, Vec<u32>), usize> = HashMap::new();
+        for term in self.terms() {
+            let key = (term.bit_terms.clone(), term.indices.clone());
+            match positions.get(&key) {
+                Some(&position) => terms[position].coeff += term.coeff,
+                None => {
+                    positions.insert(key, terms.len());
+                    terms.push(term);
+                }
+            }
+        }
+        let terms = terms.into_iter().filter(|term| term.coeff.norm() > tol);
+        SparseObservable::from_terms(self.num_qubits(), terms)
+    }
+
+    /// Return the observable with every projector expanded into Paulis, as
+    /// `(I ± P) / 2`.
+    ///
+    /// A term with `k` projectors becomes `2^k` Pauli terms, which aren't
+    /// combined with any others; call [`SparseObservable::simplify`] on the
+    /// result to do so.
+    pub fn as_paulis(&self) -> SparseObservable {
+        let mut out = SparseObservable::zero(self.num_qubits());
+        for term in self.terms() {
+            let mut expanded = vec![SparseTerm {
+                coeff: term.coeff,
+                bit_terms: Vec::new(),
+                indices: Vec::new(),
+            }];
+            for (q, bit_term) in term.indices.iter().zip(&term.bit_terms) {
+                let Some(sign) = bit_term.projector_sign() else {
+                    for pauli in &mut expanded {
+                        pauli.bit_terms.push(*bit_term);
+                        pauli.indices.push(*q);
+                    }
+                    continue;
+                };
+                expanded = expanded
+                    .into_iter()
+                    .flat_map(|identity| {
+                        let mut pauli = identity.clone();
+                        pauli.coeff = pauli.coeff.scale(0.5 * sign);
+                        pauli.bit_terms.push(bit_term.basis());
+                        pauli.indices.push(*q);
+                        let identity = SparseTerm {
+                            coeff: identity.coeff.scale(0.5),
+                            ..identity
+                        };
+                        [identity, pauli]
+                    })
+                    .collect();
+            }
+            for pauli in expanded {
+                let result = out.add_term(&pauli);
+                debug_assert_eq!(result, QiskitError::Success);
+            }
+        }
+        out
+    }
+}
+
+// The C API may hand back a null pointer for an empty buffer, which
+// `slice::from_raw_parts` does not accept.
+unsafe fn raw_slice<'a, T>(data: *const T, len: usize) -> &'a [T] {
+    if len == 0 {
+        &[]
+    } else {
+        unsafe { std::slice::from_raw_parts(data, len) }
+    }
+}
+
+impl Clone for SparseObservable {
+    fn clone(&self) -> SparseObservable {
+        SparseObservable {
+            obs: unsafe { qiskit_sys::qk!(qk_obs_copy(self.obs)) },
+        }
+    }
+}
+
+impl PartialEq for SparseObservable {
+    fn eq(&self, other: &SparseObservable) -> bool {
+        unsafe { qiskit_sys::qk!(qk_obs_equal(self.obs, other.obs)) }
+    }
+}
+
+impl fmt::Display for SparseObservable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        unsafe {
+            let string = qiskit_sys::qk!(qk_obs_str(self.obs));
+            let out = f.write_str(&CStr::from_ptr(string).to_string_lossy());
+            qiskit_sys::qk!(qk_str_free(string));
+            out
+        }
+    }
+}
+
+impl fmt::Debug for SparseObservable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SparseObservable({self})")
+    }
+}
+
+impl Drop for SparseObservable {
+    fn drop(&mut self) {
+        unsafe { qiskit_sys::qk!(qk_obs_free(self.obs)) };
+    }
+}
@@ -0,0 +1,341 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! Descriptions of the devices circuits are compiled for.
+//!
+//! A [`Target`] lists the operations a device supports, the qubits each of
+//! them can act on and the duration and error of each of those
+//! instructions. Operations added without any qubits are global: they are
+//! ideal and available on every qubit.
+//!
+//! # Example
+//!
+//! ```
+//! use qiskit_rs::StandardGate;
+//! use qiskit_rs::target::{InstructionProperties, Target, TargetOperation};
+//!
+//! let mut target = Target::new(2);
+//! target.add_global_instruction(StandardGate::RZ);
+//! target.add_instruction(
+//!     StandardGate::CX,
+//!     [([0, 1], InstructionProperties::new(3e-7, 1e-2))],
+//! );
+//! target.add_instruction(
+//!     TargetOperation::Measure,
+//!     [
+//!         ([0], InstructionProperties::new(1e-6, 2e-2)),
+//!         ([1], InstructionProperties::default()),
+//!     ],
+//! );
+//!
+//! assert!(target.instruction_supported(StandardGate::CX, &[0, 1]));
+//! assert!(!target.instruction_supported(StandardGate::CX, &[1, 0]));
+//! assert!(target.instruction_supported(StandardGate::RZ, &[1]));
+//! assert_eq!(target.operation_names(), ["rz", "cx", "measure"]);
+//! ```
+
+use crate::qiskit::{QiskitError, StandardGate, qk_to_qiskit_error};
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[cfg(feature = "json")]
+mod json;
+
+#[cfg(feature = "json")]
+pub use json::{BackendJson, BackendJsonError};
+
+/// An operation a [`Target`] can support.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TargetOperation {
+    /// A standard gate with any parameters
+    Gate(StandardGate),
+    /// A measurement
+    Measure,
+    /// A reset
+    Reset,
+}
+
+impl TargetOperation {
+    /// Return the name of the operation, as used for instructions in a circuit.
+    pub fn name(self) -> &'static str {
+        match self {
+            TargetOperation::Gate(gate) => gate.name(),
+            TargetOperation::Measure => "measure",
+            TargetOperation::Reset => "reset",
+        }
+    }
+
+    /// Look up an operation by its name.
+    pub fn from_name(name: &str) -> Option<TargetOperation> {
+        match name {
+            "measure" => Some(TargetOperation::Measure),
+            "reset" => Some(TargetOperation::Reset),
+            name => StandardGate::from_name(name).map(TargetOperation::Gate),
+        }
+    }
+
+    /// Return the number of qubits the operation acts on.
+    pub fn num_qubits(self) -> u32 {
+        match self {
+            TargetOperation::Gate(gate) => gate.num_qubits(),
+            TargetOperation::Measure | TargetOperation::Reset => 1,
+        }
+    }
+}
+
+impl From<StandardGate> for TargetOperation {
+    fn from(gate: StandardGate) -> Self {
+        TargetOperation::Gate(gate)
+    }
+}
+
+/// The duration and error of an instruction on specific qubits.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct InstructionProperties {
+    /// The duration of the instruction in seconds, if known
+    pub duration: Option<f64>,
+    /// The error rate of the instruction, if known
+    pub error: Option<f64>,
+}
+
+impl InstructionProperties {
+    /// Create properties with a known duration and error.
+    pub fn new(duration: f64, error: f64) -> InstructionProperties {
+        InstructionProperties {
+            duration: Some(duration),
+            error: Some(error),
+        }
+    }
+}
+
+/// A description of the operations a device supports.
+pub struct Target {
+    pub(crate) target: *mut qiskit_sys::QkTarget,
+    // The operations added to the target in order, with their properties
+    // keyed by qubits, which the C API does not expose. Global operations
+    // have no properties.
+    instructions: Vec<(TargetOperation, BTreeMap<Vec<u32>, InstructionProperties>)>,
+}
+
+impl Target {
+    /// Create an empty target for a device with `num_qubits` qubits.
+    ///
+    /// # Panics
+    ///
+    /// With the `runtime-linking` feature, panics if libqiskit wasn't loaded
+    /// with `qiskit_rs::init` and the default library can't be loaded.
+    pub fn new(num_qubits: u32) -> Target {
+        Target {
+            target: unsafe { qiskit_sys::qk!(qk_target_new(num_qubits)) },
+            instructions: Vec::new(),
+        }
+    }
+
+    /// Return the number of qubits of the device.
+    pub fn num_qubits(&self) -> u32 {
+        unsafe { qiskit_sys::qk!(qk_target_num_qubits(self.target)) }
+    }
+
+    /// Return the duration of a time step of the device in seconds, if set.
+    pub fn dt(&self) -> Option<f64> {
+        let dt = unsafe { qiskit_sys::qk!(qk_target_dt(self.target)) };
+        (!dt.is_nan()).then_some(dt)
+    }
+
+    /// Set the duration of a time step of the device in seconds.
+    pub fn set_dt(&mut self, dt: f64) -> QiskitError {
+        qk_to_qiskit_error(unsafe { qiskit_sys::qk!(qk_target_set_dt(self.target, dt)) })
+    }
+
+    /// Add an operation supported on the given qubits, with the properties
+    /// of each instruction.
+    ///
+    /// Each set of qubits must match the width of the operation and be on
+    /// the device. An operation can only be added once, and without any
+    /// properties it is global.
+    pub fn add_instruction<Q: AsRef<[u32]>>(
+        &mut self,
+        operation: impl Into<TargetOperation>,
+        properties: impl IntoIterator<Item = (Q, InstructionProperties)>,
+    ) -> QiskitError {
+        let operation = operation.into();
+        let properties: BTreeMap<Vec<u32>, InstructionProperties> = properties
+            .into_iter()
+            .map(|(qargs, props)| (qargs.as_ref().to_vec(), props))
+            .collect();
+        self.add_entry(operation, properties)
+    }
+
+    /// Add an ideal operation supported on every set of qubits.
+    pub fn add_global_instruction(&mut self, operation: impl Into<TargetOperation>) -> QiskitError {
+        self.add_entry(operation.into(), BTreeMap::new())
+    }
+
+    fn add_entry(
+        &mut self,
+        operation: TargetOperation,
+        properties: BTreeMap<Vec<u32>, InstructionProperties>,
+    ) -> QiskitError {
+        let entry = unsafe {
+            match operation {
+                TargetOperation::Gate(gate) => qiskit_sys::qk!(qk_target_entry_new(gate.to_qk())),
+                TargetOperation::Measure => qiskit_sys::qk!(qk_target_entry_new_measure()),
+                TargetOperation::Reset => qiskit_sys::qk!(qk_target_entry_new_reset()),
+            }
+        };
+        for (qargs, props) in &properties {
+            let mut qargs = qargs.clone();
+            let retval = qk_to_qiskit_error(unsafe {
+                qiskit_sys::qk!(qk_target_entry_add_property(
+                    entry,
+                    qargs.as_mut_ptr(),
+                    qargs.len() as u32,
+                    props.duration.unwrap_or(f64::NAN),
+                    props.error.unwrap_or(f64::NAN),
+                ))
+            });
+            if retval != QiskitError::Success {
+                unsafe { qiskit_sys::qk!(qk_target_entry_free(entry)) };
+                return retval;
+            }
+        }
+        // The target takes ownership of the entry.
+        let retval = qk_to_qiskit_error(unsafe {
+            qiskit_sys::qk!(qk_target_add_instruction(self.target, entry))
+        });
+        if retval == QiskitError::Success {
+            self.instructions.push((operation, properties));
+        }
+        retval
+    }
+
+    /// Update the properties of a gate on the given qubits.
+    pub fn update_instruction_properties(
+        &mut self,
+        gate: StandardGate,
+        qargs: &[u32],
+        properties: InstructionProperties,
+    ) -> QiskitError {
+        let mut qargs_buf = qargs.to_vec();
+        let retval = qk_to_qiskit_error(unsafe {
+            qiskit_sys::qk!(qk_target_update_property(
+                self.target,
+                gate.to_qk(),
+                qargs_buf.as_mut_ptr(),
+                qargs_buf.len() as u32,
+                properties.duration.unwrap_or(f64::NAN),
+                properties.error.unwrap_or(f64::NAN),
+            ))
+        });
+        if retval == QiskitError::Success {
+            if let Some(props) = self
+                .properties_map(TargetOperation::Gate(gate))
+                .and_then(|map| map.get_mut(qargs))
+            {
+                *props = properties;
+            }
+        }
+        retval
+    }
+
+    /// Return the number of operations in the target.
+    pub fn num_instructions(&self) -> usize {
+        unsafe { qiskit_sys::qk!(qk_target_num_instructions(self.target)) }
+    }
+
+    /// Return the operations in the target, in the order they were added.
+    pub fn operations(&self) -> impl ExactSizeIterator<Item = TargetOperation> + '_ {
+        self.instructions.iter().map(|(op, _)| *op)
+    }
+
+    /// Return the names of the operations in the target, in the order they
+    /// were added.
+    pub fn operation_names(&self) -> Vec<&'static str> {
+        self.operations().map(TargetOperation::name).collect()
+    }
+
+    /// Return the sets of qubits an operation is supported on, or `None` if
+    /// the target doesn't have it. Global operations have no sets of qubits.
+    pub fn qargs(&self, operation: impl Into<TargetOperation>) -> Option<Vec<&[u32]>> {
+        let operation = operation.into();
+        self.instructions
+            .iter()
+            .find(|(op, _)| *op == operation)
+            .map(|(_, props)| props.keys().map(Vec::as_slice).collect())
+    }
+
+    /// Return the properties of an operation on the given qubits, or `None`
+    /// if it isn't supported there. Global operations have no properties.
+    pub fn instruction_properties(
+        &self,
+        operation: impl Into<TargetOperation>,
+        qargs: &[u32],
+    ) -> Option<InstructionProperties> {
+        let operation = operation.into();
+        self.instructions
+            .iter()
+            .find(|(op, _)| *op == operation)
+            .and_then(|(_, props)| props.get(qargs).copied())
+    }
+
+    /// Return whether an operation is supported on the given qubits.
+    pub fn instruction_supported(
+        &self,
+        operation: impl Into<TargetOperation>,
+        qargs: &[u32],
+    ) -> bool {
+        let operation = operation.into();
+        match self.instructions.iter().find(|(op, _)| *op == operation) {
+            Some((_, props)) if props.is_empty() => {
+                qargs.len() == operation.num_qubits() as usize
+                    && qargs.iter().all(|qubit| *qubit < self.num_qubits())
+            }
+            Some((_, props)) => props.contains_key(qargs),
+            None => false,
+        }
+    }
+
+    fn properties_map(
+        &mut self,
+        operation: TargetOperation,
+    ) -> Option<&mut BTreeMap<Vec<u32>, InstructionProperties>> {
+        self.instructions
+            .iter_mut()
+            .find(|(op, _)| *op == operation)
+            .map(|(_, props)| props)
+    }
+}
+
+impl Clone for Target {
+    fn clone(&self) -> Self {
+        Target {
+            target: unsafe { qiskit_sys::qk!(qk_target_copy(self.target)) },
+            instructions: self.instructions.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Target")
+            .field("num_qubits", &self.num_qubits())
+            .field("dt", &self.dt())
+            .field("instructions", &self.instructions)
+            .finish()
+    }
+}
+
+impl Drop for Target {
+    fn drop(&mut self) {
+        unsafe { qiskit_sys::qk!(qk_target_free(self.target)) };
+    }
+}
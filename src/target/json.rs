@@ -0,0 +1,373 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+use super::{InstructionProperties, Target, TargetOperation};
+use crate::qiskit::QiskitError;
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// An error loading a target from backend json.
+#[derive(Debug)]
+pub enum BackendJsonError {
+    /// The input isn't valid json.
+    Json(serde_json::Error),
+    /// A field is missing or has the wrong type.
+    InvalidField(String),
+    /// The basis gates with names that aren't standard gates, measurements
+    /// or resets.
+    UnknownGates(Vec<String>),
+    /// The gates with properties or couplings that aren't basis gates or
+    /// supported instructions.
+    UnlistedGates(Vec<String>),
+    /// The C API rejected an instruction.
+    Target(QiskitError),
+}
+
+impl fmt::Display for BackendJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BackendJsonError::Json(e) => write!(f, "invalid backend json: {e}"),
+            BackendJsonError::InvalidField(field) => {
+                write!(f, "missing or invalid field \"{field}\" in backend json")
+            }
+            BackendJsonError::UnknownGates(names) => {
+                write!(f, "unknown gates in backend json: {}", names.join(", "))
+            }
+            BackendJsonError::UnlistedGates(names) => write!(
+                f,
+                "properties of gates that aren't basis gates in backend json: {}",
+                names.join(", ")
+            ),
+            BackendJsonError::Target(e) => write!(f, "couldn't add an instruction: {e:?}"),
+        }
+    }
+}
+
+impl std::error::Error for BackendJsonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BackendJsonError::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for BackendJsonError {
+    fn from(e: serde_json::Error) -> BackendJsonError {
+        BackendJsonError::Json(e)
+    }
+}
+
+/// A target in the json format of IBM backends, as returned by
+/// [`Target::to_json`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BackendJson {
+    /// The backend configuration, with the basis gates and couplings.
+    pub configuration: String,
+    /// The backend properties, with the error and duration of each
+    /// instruction.
+    pub properties: String,
+}
+
+fn invalid(field: &str) -> BackendJsonError {
+    BackendJsonError::InvalidField(field.to_string())
+}
+
+fn field<'a>(object: &'a Value, name: &str) -> Result<&'a Value, BackendJsonError> {
+    object.get(name).ok_or_else(|| invalid(name))
+}
+
+fn array<'a>(object: &'a Value, name: &str) -> Result<&'a Vec<Value>, BackendJsonError> {
+    field(object, name)?.as_array().ok_or_else(|| invalid(name))
+}
+
+fn qargs(value: &Value, name: &str) -> Result<Vec<u32>, BackendJsonError> {
+    value
+        .as_array()
+        .and_then(|qubits| {
+            qubits
+                .iter()
+                .map(|q| q.as_u64().map(|q| q as u32))
+                .collect()
+        })
+        .ok_or_else(|| invalid(name))
+}
+
+fn seconds(value: f64, unit: &str) -> Option<f64> {
+    let scale = match unit {
+        "s" => 1.0,
+        "ms" => 1e-3,
+        "us" | "µs" => 1e-6,
+        "ns" => 1e-9,
+        _ => return None,
+    };
+    Some(value * scale)
+}
+
+// Read the named value of a list of `{"name", "unit", "value"}` entries, as
+// the properties give gate and qubit parameters, converting durations to
+// seconds.
+fn parameter(parameters: &[Value], name: &str) -> Result<Option<f64>, BackendJsonError> {
+    let Some(parameter) = parameters
+        .iter()
+        .find(|p| p.get("name") == Some(&json!(name)))
+    else {
+        return Ok(None);
+    };
+    let value = field(parameter, "value")?
+        .as_f64()
+        .ok_or_else(|| invalid(name))?;
+    match parameter.get("unit").and_then(Value::as_str) {
+        None | Some("") => Ok(Some(value)),
+        Some(unit) => seconds(value, unit)
+            .map(Some)
+            .ok_or_else(|| invalid("unit")),
+    }
+}
+
+fn parameters_value(properties: InstructionProperties, error: &str, duration: &str) -> Value {
+    let mut parameters = Vec::new();
+    if let Some(value) = properties.error {
+        parameters.push(json!({"name": error, "unit": "", "value": value}));
+    }
+    if let Some(value) = properties.duration {
+        parameters.push(json!({"name": duration, "unit": "ns", "value": value * 1e9}));
+    }
+    Value::Array(parameters)
+}
+
+impl Target {
+    /// Create a target from the json backend configuration of an IBM device
+    /// and, optionally, its backend properties.
+    ///
+    /// Every basis gate becomes an instruction, with the error and duration
+    /// of each of its qubits taken from the properties. Without properties,
+    /// a gate is supported on the `coupling_map` of its entry in the
+    /// configuration's `gates`, or on every qubit if it has none.
+    /// Measurements are added when the configuration lists them as
+    /// supported instructions or the properties give readout errors.
+    ///
+    /// Basis gates that aren't standard gates, measurements or resets are
+    /// reported all together as [`BackendJsonError::UnknownGates`], and
+    /// gates with properties or couplings that aren't basis gates or
+    /// supported instructions as [`BackendJsonError::UnlistedGates`].
+    ///
+    /// Only available with the `json` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::{StandardGate, Target};
+    ///
+    /// let config = r#"{
+    ///     "n_qubits": 2,
+    ///     "basis_gates": ["rz", "sx", "cx"],
+    ///     "dt": 0.2222222222222222,
+    ///     "gates": [{"name": "cx", "coupling_map": [[0, 1]]}]
+    /// }"#;
+    /// let target = Target::from_backend_json(config, None).unwrap();
+    /// assert_eq!(target.operation_names(), ["rz", "sx", "cx"]);
+    /// assert!(target.instruction_supported(StandardGate::CX, &[0, 1]));
+    /// assert!(!target.instruction_supported(StandardGate::CX, &[1, 0]));
+    /// ```
+    pub fn from_backend_json(
+        configuration: &str,
+        properties: Option<&str>,
+    ) -> Result<Target, BackendJsonError> {
+        let config: Value = serde_json::from_str(configuration)?;
+        let properties: Option<Value> = properties.map(serde_json::from_str).transpose()?;
+
+        let num_qubits = field(&config, "n_qubits")?
+            .as_u64()
+            .ok_or_else(|| invalid("n_qubits"))? as u32;
+        let mut operations = Vec::new();
+        let mut unknown = Vec::new();
+        for name in array(&config, "basis_gates")? {
+            let name = name.as_str().ok_or_else(|| invalid("basis_gates"))?;
+            match TargetOperation::from_name(name) {
+                Some(operation) if !operations.contains(&operation) => operations.push(operation),
+                Some(_) => {}
+                None => unknown.push(name.to_string()),
+            }
+        }
+        if !unknown.is_empty() {
+            return Err(BackendJsonError::UnknownGates(unknown));
+        }
+        let supported = config
+            .get("supported_instructions")
+            .and_then(Value::as_array)
+            .map_or(&[][..], Vec::as_slice);
+        for operation in [TargetOperation::Measure, TargetOperation::Reset] {
+            if supported.contains(&json!(operation.name())) && !operations.contains(&operation) {
+                operations.push(operation);
+            }
+        }
+
+        // The properties of each instruction, keyed by operation and qargs.
+        let mut instructions: BTreeMap<&str, BTreeMap<Vec<u32>, InstructionProperties>> =
+            BTreeMap::new();
+        if let Some(properties) = &properties {
+            for gate in array(properties, "gates")? {
+                let name = field(gate, "gate")?
+                    .as_str()
+                    .ok_or_else(|| invalid("gate"))?;
+                let parameters = array(gate, "parameters")?;
+                let props = InstructionProperties {
+                    duration: parameter(parameters, "gate_length")?,
+                    error: parameter(parameters, "gate_error")?,
+                };
+                instructions
+                    .entry(name)
+                    .or_default()
+                    .insert(qargs(field(gate, "qubits")?, "qubits")?, props);
+            }
+            for (qubit, parameters) in array(properties, "qubits")?.iter().enumerate() {
+                let parameters = parameters.as_array().ok_or_else(|| invalid("qubits"))?;
+                let props = InstructionProperties {
+                    duration: parameter(parameters, "readout_length")?,
+                    error: parameter(parameters, "readout_error")?,
+                };
+                if props != InstructionProperties::default() {
+                    instructions
+                        .entry("measure")
+                        .or_default()
+                        .insert(vec![qubit as u32], props);
+                }
+            }
+            if instructions.contains_key("measure")
+                && !operations.contains(&TargetOperation::Measure)
+            {
+                operations.push(TargetOperation::Measure);
+            }
+        } else if let Some(gates) = config.get("gates") {
+            for gate in gates.as_array().ok_or_else(|| invalid("gates"))? {
+                let name = field(gate, "name")?
+                    .as_str()
+                    .ok_or_else(|| invalid("name"))?;
+                if let Some(coupling_map) = gate.get("coupling_map") {
+                    let coupling_map = coupling_map
+                        .as_array()
+                        .ok_or_else(|| invalid("coupling_map"))?;
+                    for qubits in coupling_map {
+                        instructions
+                            .entry(name)
+                            .or_default()
+                            .insert(qargs(qubits, "coupling_map")?, Default::default());
+                    }
+                }
+            }
+        }
+
+        let unlisted: Vec<String> = instructions
+            .keys()
+            .filter(|name| !operations.iter().any(|op| op.name() == **name))
+            .map(|name| name.to_string())
+            .collect();
+        if !unlisted.is_empty() {
+            return Err(BackendJsonError::UnlistedGates(unlisted));
+        }
+
+        let mut target = Target::new(num_qubits);
+        if let Some(dt) = config.get("dt") {
+            // Backend configurations give dt in nanoseconds.
+            let dt = dt.as_f64().ok_or_else(|| invalid("dt"))?;
+            target
+                .set_dt(dt * 1e-9)
+                .into_result()
+                .map_err(BackendJsonError::Target)?;
+        }
+        for operation in operations {
+            let properties = instructions.remove(operation.name()).unwrap_or_default();
+            target
+                .add_instruction(operation, properties)
+                .into_result()
+                .map_err(BackendJsonError::Target)?;
+        }
+        Ok(target)
+    }
+
+    /// Return the target in the json format of IBM backend configurations
+    /// and properties, as read by [`Target::from_backend_json`].
+    ///
+    /// Only available with the `json` feature.
+    pub fn to_json(&self) -> BackendJson {
+        let mut coupling_map: Vec<&[u32]> = Vec::new();
+        let mut gates = Vec::new();
+        let mut gate_properties = Vec::new();
+        let mut qubits = vec![Vec::new(); self.num_qubits() as usize];
+        for (operation, properties) in &self.instructions {
+            let name = operation.name();
+            if *operation == TargetOperation::Measure {
+                for (qargs, props) in properties {
+                    qubits[qargs[0] as usize] =
+                        parameters_value(*props, "readout_error", "readout_length")
+                            .as_array()
+                            .cloned()
+                            .unwrap_or_default();
+                }
+                continue;
+            }
+            let mut gate = Map::new();
+            gate.insert("name".to_string(), json!(name));
+            gate.insert("parameters".to_string(), json!([]));
+            if !properties.is_empty() {
+                gate.insert(
+                    "coupling_map".to_string(),
+                    json!(properties.keys().collect::<Vec<_>>()),
+                );
+            }
+            gates.push(Value::Object(gate));
+            for (qargs, props) in properties {
+                if qargs.len() == 2 && !coupling_map.contains(&qargs.as_slice()) {
+                    coupling_map.push(qargs);
+                }
+                let suffix: Vec<String> = qargs.iter().map(u32::to_string).collect();
+                gate_properties.push(json!({
+                    "gate": name,
+                    "name": format!("{name}{}", suffix.join("_")),
+                    "qubits": qargs,
+                    "parameters": parameters_value(*props, "gate_error", "gate_length"),
+                }));
+            }
+        }
+
+        let mut configuration = json!({
+            "backend_name": "qiskit_rs_target",
+            "n_qubits": self.num_qubits(),
+            "basis_gates": self
+                .operations()
+                .filter(|op| matches!(op, TargetOperation::Gate(_)))
+                .map(TargetOperation::name)
+                .collect::<Vec<_>>(),
+            "supported_instructions": self
+                .operations()
+                .map(TargetOperation::name)
+                .collect::<Vec<_>>(),
+            "coupling_map": coupling_map,
+            "gates": gates,
+        });
+        if let Some(dt) = self.dt() {
+            configuration["dt"] = json!(dt * 1e9);
+        }
+        let properties = json!({
+            "backend_name": "qiskit_rs_target",
+            "qubits": qubits,
+            "gates": gate_properties,
+            "general": [],
+        });
+        BackendJson {
+            configuration: serde_json::to_string_pretty(&configuration).unwrap(),
+            properties: serde_json::to_string_pretty(&properties).unwrap(),
+        }
+    }
+}
@@ -0,0 +1,282 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! Running transpiler passes over circuits.
+//!
+//! A [`PassManager`] runs a sequence of [`TranspilerPass`]es that rewrite a
+//! circuit for a [`Target`], and reports how long each pass took and how it
+//! changed the circuit. The passes in this module wrap the standalone
+//! transpiler passes of the C API. They only see gates, measurements,
+//! resets and barriers, so they reject circuits with symbolic parameters or
+//! control flow. Passes written in Rust implement [`TranspilerPass`], or
+//! [`AnalysisPass`] if they only inspect the circuit, and can run between
+//! them.
+//!
+//! # Example
+//!
+//! ```
+//! use qiskit_rs::transpiler::{BasisTranslator, Optimize1qGatesDecomposition, PassManager};
+//! use qiskit_rs::{QuantumCircuit, StandardGate, Target};
+//!
+//! let mut target = Target::new(2);
+//! for gate in [StandardGate::RZ, StandardGate::SX, StandardGate::CX] {
+//!     target.add_global_instruction(gate);
+//! }
+//!
+//! let mut qc = QuantumCircuit::new(2, 0);
+//! qc.h(0);
+//! qc.cx(0, 1);
+//!
+//! let mut pm = PassManager::new();
+//! pm.append(BasisTranslator::default())
+//!     .append(Optimize1qGatesDecomposition);
+//! let report = pm.run(&mut qc, &target).unwrap();
+//! assert_eq!(report.passes.len(), 2);
+//! assert!(qc.instructions().all(|inst| ["rz", "sx", "cx"].contains(&inst.name)));
+//! ```
+
+mod passes;
+mod property_set;
+mod vf2_layout;
+
+pub use passes::{
+    BasisTranslator, CheckGateDirection, CommutativeCancellation, ConsolidateBlocks,
+    ElidePermutations, GateDirection, Optimize1qGatesDecomposition, RemoveIdentityEquivalent,
+};
+pub use property_set::PropertySet;
+pub use vf2_layout::{Vf2Layout, Vf2LayoutResult};
+
+use crate::{QiskitError, QuantumCircuit, Target};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// A pass that transforms circuits for a target.
+///
+/// Passes share a [`PropertySet`] while a [`PassManager`] runs them, so a
+/// pass can leave results for the passes after it.
+pub trait TranspilerPass {
+    /// The name of the pass, shown in reports.
+    fn name(&self) -> &str;
+
+    /// Run the pass on `circuit` for `target`.
+    fn run(
+        &mut self,
+        circuit: &mut QuantumCircuit,
+        target: &Target,
+        property_set: &mut PropertySet,
+    ) -> Result<(), QiskitError>;
+}
+
+/// A pass that only analyzes circuits, recording what it finds in the
+/// [`PropertySet`].
+///
+/// Every analysis pass is also a [`TranspilerPass`] that leaves the circuit
+/// unchanged.
+pub trait AnalysisPass {
+    /// The name of the pass, shown in reports.
+    fn name(&self) -> &str;
+
+    /// Analyze `circuit` for `target`.
+    fn analyze(
+        &mut self,
+        circuit: &QuantumCircuit,
+        target: &Target,
+        property_set: &mut PropertySet,
+    ) -> Result<(), QiskitError>;
+}
+
+impl<P: AnalysisPass> TranspilerPass for P {
+    fn name(&self) -> &str {
+        AnalysisPass::name(self)
+    }
+
+    fn run(
+        &mut self,
+        circuit: &mut QuantumCircuit,
+        target: &Target,
+        property_set: &mut PropertySet,
+    ) -> Result<(), QiskitError> {
+        self.analyze(circuit, target, property_set)
+    }
+}
+
+/// A sequence of transpiler passes to run over circuits.
+#[derive(Default)]
+pub struct PassManager {
+    passes: Vec<Box<dyn TranspilerPass>>,
+}
+
+impl PassManager {
+    /// Create an empty pass manager.
+    pub fn new() -> PassManager {
+        PassManager::default()
+    }
+
+    /// Append a pass to run after the passes already added.
+    pub fn append(&mut self, pass: impl TranspilerPass + 'static) -> &mut Self {
+        self.passes.push(Box::new(pass));
+        self
+    }
+
+    /// Return the number of passes.
+    pub fn len(&self) -> usize {
+        self.passes.len()
+    }
+
+    /// Return whether there are no passes.
+    pub fn is_empty(&self) -> bool {
+        self.passes.is_empty()
+    }
+
+    /// Return the names of the passes in the order they run.
+    pub fn pass_names(&self) -> Vec<&str> {
+        self.passes.iter().map(|pass| pass.name()).collect()
+    }
+
+    /// Run the passes in order on `circuit` for `target`, starting from an
+    /// empty property set.
+    ///
+    /// Stops at the first pass that fails, leaving the circuit as the
+    /// passes before it left it.
+    pub fn run(
+        &mut self,
+        circuit: &mut QuantumCircuit,
+        target: &Target,
+    ) -> Result<PassManagerReport, QiskitError> {
+        self.run_with_properties(circuit, target, &mut PropertySet::new())
+    }
+
+    /// Run the passes in order on `circuit` for `target`, sharing
+    /// `property_set` between them so their results can be read afterwards.
+    pub fn run_with_properties(
+        &mut self,
+        circuit: &mut QuantumCircuit,
+        target: &Target,
+        property_set: &mut PropertySet,
+    ) -> Result<PassManagerReport, QiskitError> {
+        let mut report = PassManagerReport::default();
+        for pass in &mut self.passes {
+            let before = CircuitStats::of(circuit);
+            let start = Instant::now();
+            pass.run(circuit, target, property_set)?;
+            let duration = start.elapsed();
+            let after = CircuitStats::of(circuit);
+            report.passes.push(PassReport {
+                name: pass.name().to_string(),
+                duration,
+                num_instructions_before: before.num_instructions,
+                num_instructions_after: after.num_instructions,
+                num_two_qubit_gates_before: before.num_two_qubit_gates,
+                num_two_qubit_gates_after: after.num_two_qubit_gates,
+            });
+        }
+        Ok(report)
+    }
+}
+
+impl fmt::Debug for PassManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PassManager")
+            .field("passes", &self.pass_names())
+            .finish()
+    }
+}
+
+struct CircuitStats {
+    num_instructions: usize,
+    num_two_qubit_gates: usize,
+}
+
+impl CircuitStats {
+    fn of(circuit: &QuantumCircuit) -> CircuitStats {
+        CircuitStats {
+            num_instructions: circuit.num_instructions(),
+            num_two_qubit_gates: circuit
+                .instructions()
+                .filter(|inst| inst.qubits.len() == 2 && inst.name != "barrier")
+                .count(),
+        }
+    }
+}
+
+/// How long one pass of a [`PassManager`] run took and how it changed the
+/// circuit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PassReport {
+    /// The name of the pass
+    pub name: String,
+    /// The time the pass took to run
+    pub duration: Duration,
+    /// The number of instructions before the pass
+    pub num_instructions_before: usize,
+    /// The number of instructions after the pass
+    pub num_instructions_after: usize,
+    /// The number of two-qubit gates before the pass
+    pub num_two_qubit_gates_before: usize,
+    /// The number of two-qubit gates after the pass
+    pub num_two_qubit_gates_after: usize,
+}
+
+/// The reports of the passes of a [`PassManager`] run, in order.
+///
+/// Printing the report shows a table with a row per pass.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PassManagerReport {
+    /// The report of each pass in the order they ran
+    pub passes: Vec<PassReport>,
+}
+
+impl PassManagerReport {
+    /// Return the time all the passes took.
+    pub fn total_duration(&self) -> Duration {
+        self.passes.iter().map(|pass| pass.duration).sum()
+    }
+}
+
+impl fmt::Display for PassManagerReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .passes
+            .iter()
+            .map(|pass| pass.name.len())
+            .chain(["pass".len()])
+            .max()
+            .unwrap_or_default();
+        writeln!(
+            f,
+            "{:<width$}  {:>10}  {:>14}  {:>14}",
+            "pass", "time (ms)", "instructions", "2q gates"
+        )?;
+        for pass in &self.passes {
+            writeln!(
+                f,
+                "{:<width$}  {:>10.3}  {:>14}  {:>14}",
+                pass.name,
+                pass.duration.as_secs_f64() * 1e3,
+                format!(
+                    "{} -> {}",
+                    pass.num_instructions_before, pass.num_instructions_after
+                ),
+                format!(
+                    "{} -> {}",
+                    pass.num_two_qubit_gates_before, pass.num_two_qubit_gates_after
+                ),
+            )?;
+        }
+        write!(
+            f,
+            "{:<width$}  {:>10.3}",
+            "total",
+            self.total_duration().as_secs_f64() * 1e3
+        )
+    }
+}
@@ -0,0 +1,311 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+use super::{AnalysisPass, PropertySet, TranspilerPass};
+use crate::qiskit::qk_to_qiskit_error;
+use crate::{QiskitError, QuantumCircuit, Target};
+
+// The C API takes NaN for an approximation degree taken from the target's
+// error rates.
+fn approximation_degree(degree: Option<f64>) -> f64 {
+    degree.unwrap_or(f64::NAN)
+}
+
+/// Remove swap gates by relabelling the qubits of the gates after them.
+///
+/// The circuit's outputs end up permuted by the removed swaps. The qubit
+/// each qubit's state ends up on is stored as the
+/// `"virtual_permutation_layout"` property.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ElidePermutations;
+
+impl TranspilerPass for ElidePermutations {
+    fn name(&self) -> &str {
+        "ElidePermutations"
+    }
+
+    fn run(
+        &mut self,
+        circuit: &mut QuantumCircuit,
+        _target: &Target,
+        property_set: &mut PropertySet,
+    ) -> Result<(), QiskitError> {
+        let qc = circuit.as_qk()?;
+        let num_qubits = circuit.num_qubits() as usize;
+        let permutation: Vec<u32> = unsafe {
+            let result = qiskit_sys::qk!(qk_transpiler_pass_standalone_elide_permutations(qc));
+            let permutation = if qiskit_sys::qk!(qk_elide_permutations_result_elided_gates(result))
+            {
+                circuit.replace_qk(qiskit_sys::qk!(qk_elide_permutations_result_circuit(
+                    result
+                )));
+                let permutation = qiskit_sys::qk!(qk_elide_permutations_result_permutation(result));
+                std::slice::from_raw_parts(permutation, num_qubits)
+                    .iter()
+                    .map(|qubit| *qubit as u32)
+                    .collect()
+            } else {
+                (0..num_qubits as u32).collect()
+            };
+            qiskit_sys::qk!(qk_elide_permutations_result_free(result));
+            permutation
+        };
+        property_set.insert("virtual_permutation_layout", permutation);
+        Ok(())
+    }
+}
+
+/// Remove gates that act as the identity, such as rotations by zero.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RemoveIdentityEquivalent {
+    /// How closely a gate must match the identity to be removed, from 0 to
+    /// 1, or `None` to allow the error rate of the gate in the target.
+    pub approximation_degree: Option<f64>,
+}
+
+impl Default for RemoveIdentityEquivalent {
+    fn default() -> Self {
+        RemoveIdentityEquivalent {
+            approximation_degree: Some(1.0),
+        }
+    }
+}
+
+impl TranspilerPass for RemoveIdentityEquivalent {
+    fn name(&self) -> &str {
+        "RemoveIdentityEquivalent"
+    }
+
+    fn run(
+        &mut self,
+        circuit: &mut QuantumCircuit,
+        target: &Target,
+        _property_set: &mut PropertySet,
+    ) -> Result<(), QiskitError> {
+        let qc = circuit.as_qk()?;
+        qk_to_qiskit_error(unsafe {
+            qiskit_sys::qk!(qk_transpiler_pass_standalone_remove_identity_equivalent(
+                qc,
+                target.target,
+                approximation_degree(self.approximation_degree),
+            ))
+        })
+        .into_result()
+    }
+}
+
+/// Cancel and merge gates that meet after commuting through the gates
+/// between them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CommutativeCancellation {
+    /// How exactly gates must commute, from 0 to 1, or `None` to allow the
+    /// error rates of the gates in the target.
+    pub approximation_degree: Option<f64>,
+}
+
+impl Default for CommutativeCancellation {
+    fn default() -> Self {
+        CommutativeCancellation {
+            approximation_degree: Some(1.0),
+        }
+    }
+}
+
+impl TranspilerPass for CommutativeCancellation {
+    fn name(&self) -> &str {
+        "CommutativeCancellation"
+    }
+
+    fn run(
+        &mut self,
+        circuit: &mut QuantumCircuit,
+        target: &Target,
+        _property_set: &mut PropertySet,
+    ) -> Result<(), QiskitError> {
+        let qc = circuit.as_qk()?;
+        qk_to_qiskit_error(unsafe {
+            qiskit_sys::qk!(qk_transpiler_pass_standalone_commutative_cancellation(
+                qc,
+                target.target,
+                approximation_degree(self.approximation_degree),
+            ))
+        })
+        .into_result()
+    }
+}
+
+/// Resynthesize runs of single-qubit gates into the shortest sequence of
+/// gates the target supports on each qubit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Optimize1qGatesDecomposition;
+
+impl TranspilerPass for Optimize1qGatesDecomposition {
+    fn name(&self) -> &str {
+        "Optimize1qGatesDecomposition"
+    }
+
+    fn run(
+        &mut self,
+        circuit: &mut QuantumCircuit,
+        target: &Target,
+        _property_set: &mut PropertySet,
+    ) -> Result<(), QiskitError> {
+        let qc = circuit.as_qk()?;
+        qk_to_qiskit_error(unsafe {
+            qiskit_sys::qk!(qk_transpiler_pass_standalone_optimize_1q_sequences(
+                qc,
+                target.target
+            ))
+        })
+        .into_result()
+    }
+}
+
+/// Translate gates the target doesn't support into ones it does, using
+/// Qiskit's standard equivalence library.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BasisTranslator {
+    /// Leave gates on fewer qubits than this untranslated.
+    pub min_qubits: usize,
+}
+
+impl TranspilerPass for BasisTranslator {
+    fn name(&self) -> &str {
+        "BasisTranslator"
+    }
+
+    fn run(
+        &mut self,
+        circuit: &mut QuantumCircuit,
+        target: &Target,
+        _property_set: &mut PropertySet,
+    ) -> Result<(), QiskitError> {
+        let qc = circuit.as_qk()?;
+        qk_to_qiskit_error(unsafe {
+            qiskit_sys::qk!(qk_transpiler_pass_standalone_basis_translator(
+                qc,
+                target.target,
+                self.min_qubits,
+            ))
+        })
+        .into_result()
+    }
+}
+
+/// Flip two-qubit gates that the target only supports in the other
+/// direction, adding single-qubit gates around them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GateDirection;
+
+impl GateDirection {
+    /// Return whether every two-qubit gate of `circuit` is in a direction
+    /// the target supports.
+    pub fn check(circuit: &QuantumCircuit, target: &Target) -> Result<bool, QiskitError> {
+        let qc = circuit.as_qk()?;
+        Ok(unsafe {
+            qiskit_sys::qk!(qk_transpiler_pass_standalone_check_gate_direction(
+                qc,
+                target.target
+            ))
+        })
+    }
+}
+
+impl TranspilerPass for GateDirection {
+    fn name(&self) -> &str {
+        "GateDirection"
+    }
+
+    fn run(
+        &mut self,
+        circuit: &mut QuantumCircuit,
+        target: &Target,
+        _property_set: &mut PropertySet,
+    ) -> Result<(), QiskitError> {
+        let qc = circuit.as_qk()?;
+        qk_to_qiskit_error(unsafe {
+            qiskit_sys::qk!(qk_transpiler_pass_standalone_gate_direction(
+                qc,
+                target.target
+            ))
+        })
+        .into_result()
+    }
+}
+
+/// Replace blocks of gates on the same two qubits with a single unitary
+/// when that is expected to reduce the number of two-qubit gates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConsolidateBlocks {
+    /// How closely the resynthesized block must match, from 0 to 1, or
+    /// `None` to allow the error rates of the gates in the target.
+    pub approximation_degree: Option<f64>,
+    /// Consolidate every block, even when it wouldn't get shorter.
+    pub force_consolidate: bool,
+}
+
+impl Default for ConsolidateBlocks {
+    fn default() -> Self {
+        ConsolidateBlocks {
+            approximation_degree: Some(1.0),
+            force_consolidate: false,
+        }
+    }
+}
+
+impl TranspilerPass for ConsolidateBlocks {
+    fn name(&self) -> &str {
+        "ConsolidateBlocks"
+    }
+
+    fn run(
+        &mut self,
+        circuit: &mut QuantumCircuit,
+        target: &Target,
+        _property_set: &mut PropertySet,
+    ) -> Result<(), QiskitError> {
+        let qc = circuit.as_qk()?;
+        qk_to_qiskit_error(unsafe {
+            qiskit_sys::qk!(qk_transpiler_pass_standalone_consolidate_blocks(
+                qc,
+                target.target,
+                approximation_degree(self.approximation_degree),
+                self.force_consolidate,
+            ))
+        })
+        .into_result()
+    }
+}
+
+/// Check whether every two-qubit gate is in a direction the target
+/// supports, storing the answer as the `"is_direction_mapped"` property.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CheckGateDirection;
+
+impl AnalysisPass for CheckGateDirection {
+    fn name(&self) -> &str {
+        "CheckGateDirection"
+    }
+
+    fn analyze(
+        &mut self,
+        circuit: &QuantumCircuit,
+        target: &Target,
+        property_set: &mut PropertySet,
+    ) -> Result<(), QiskitError> {
+        property_set.insert(
+            "is_direction_mapped",
+            GateDirection::check(circuit, target)?,
+        );
+        Ok(())
+    }
+}
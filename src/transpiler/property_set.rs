@@ -0,0 +1,98 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Values that transpiler passes share while a
+/// [`PassManager`](super::PassManager) runs them, keyed by name.
+///
+/// Values can have any type and are read back with the type they were
+/// stored with. The passes of this module store:
+///
+/// - `"layout"`: a `Vec<u32>` of the physical qubit of each virtual qubit,
+///   from [`Vf2Layout`](super::Vf2Layout)
+/// - `"virtual_permutation_layout"`: a `Vec<u32>` of the qubit each qubit
+///   ends up on, from [`ElidePermutations`](super::ElidePermutations)
+/// - `"is_direction_mapped"`: a `bool`, from
+///   [`CheckGateDirection`](super::CheckGateDirection)
+///
+/// # Example
+///
+/// ```
+/// use qiskit_rs::transpiler::PropertySet;
+///
+/// let mut property_set = PropertySet::new();
+/// property_set.insert("depth", 4usize);
+/// assert_eq!(property_set.get::<usize>("depth"), Some(&4));
+/// assert_eq!(property_set.get::<u32>("depth"), None);
+/// ```
+#[derive(Default)]
+pub struct PropertySet {
+    properties: BTreeMap<String, Box<dyn Any>>,
+}
+
+impl PropertySet {
+    /// Create an empty property set.
+    pub fn new() -> PropertySet {
+        PropertySet::default()
+    }
+
+    /// Set the value of a property, replacing any previous value.
+    pub fn insert<T: Any>(&mut self, key: impl Into<String>, value: T) {
+        self.properties.insert(key.into(), Box::new(value));
+    }
+
+    /// Return the value of a property, or `None` if it isn't set or has a
+    /// different type.
+    pub fn get<T: Any>(&self, key: &str) -> Option<&T> {
+        self.properties.get(key)?.downcast_ref()
+    }
+
+    /// Return the value of a property mutably, or `None` if it isn't set or
+    /// has a different type.
+    pub fn get_mut<T: Any>(&mut self, key: &str) -> Option<&mut T> {
+        self.properties.get_mut(key)?.downcast_mut()
+    }
+
+    /// Remove a property, returning whether it was set.
+    pub fn remove(&mut self, key: &str) -> bool {
+        self.properties.remove(key).is_some()
+    }
+
+    /// Return whether a property is set.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.properties.contains_key(key)
+    }
+
+    /// Return the names of the properties that are set, in sorted order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> + '_ {
+        self.properties.keys().map(String::as_str)
+    }
+
+    /// Return the number of properties that are set.
+    pub fn len(&self) -> usize {
+        self.properties.len()
+    }
+
+    /// Return whether no properties are set.
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+    }
+}
+
+impl fmt::Debug for PropertySet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.keys()).finish()
+    }
+}
@@ -0,0 +1,188 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+use super::{PropertySet, TranspilerPass};
+use crate::{QiskitError, QuantumCircuit, Target};
+use qiskit_sys::QkVF2LayoutResult;
+use std::fmt;
+
+/// Map the circuit's qubits onto the device's with a VF2 subgraph
+/// isomorphism search, so every two-qubit gate lands on a coupled pair.
+///
+/// As a pass, the circuit is widened to the target's qubits with the
+/// layout [`search`](Vf2Layout::search) finds applied, and the physical
+/// qubit of each virtual qubit is stored as the `"layout"` property. When
+/// the search finds no layout the circuit is left unchanged.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Vf2Layout {
+    /// Only match two-qubit gates to device couplings in the same direction.
+    pub strict_direction: bool,
+    /// The number of search states to visit before giving up, or `None`
+    /// for no limit.
+    pub call_limit: Option<u64>,
+    /// The time in seconds to search before giving up, or `None` for no
+    /// limit.
+    pub time_limit: Option<f64>,
+    /// The number of layouts to compare before picking the one with the
+    /// lowest error, or `None` for the C API's default.
+    pub max_trials: Option<u64>,
+}
+
+impl Vf2Layout {
+    /// Search for a layout of `circuit` on `target` that needs no swaps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qiskit_rs::target::InstructionProperties;
+    /// use qiskit_rs::transpiler::Vf2Layout;
+    /// use qiskit_rs::{QuantumCircuit, StandardGate, Target};
+    ///
+    /// let mut target = Target::new(3);
+    /// let props = InstructionProperties::new(3e-7, 1e-2);
+    /// target.add_instruction(StandardGate::CX, [([0, 1], props), ([1, 2], props)]);
+    ///
+    /// let mut qc = QuantumCircuit::new(2, 0);
+    /// qc.cx(0, 1);
+    /// let result = Vf2Layout::default().search(&qc, &target).unwrap();
+    /// assert!(result.found());
+    /// assert!(result.apply(&mut qc).unwrap());
+    /// assert_eq!(qc.num_qubits(), 3);
+    /// ```
+    pub fn search(
+        &self,
+        circuit: &QuantumCircuit,
+        target: &Target,
+    ) -> Result<Vf2LayoutResult, QiskitError> {
+        let qc = circuit.as_qk()?;
+        let limit = |value: Option<u64>| value.map_or(-1, |value| value as i64);
+        let result = unsafe {
+            qiskit_sys::qk!(qk_transpiler_pass_standalone_vf2_layout(
+                qc,
+                target.target,
+                self.strict_direction,
+                limit(self.call_limit),
+                self.time_limit.unwrap_or(-1.0),
+                limit(self.max_trials),
+            ))
+        };
+        Ok(Vf2LayoutResult {
+            result,
+            num_virtual_qubits: circuit.num_qubits(),
+            num_physical_qubits: target.num_qubits(),
+        })
+    }
+}
+
+impl TranspilerPass for Vf2Layout {
+    fn name(&self) -> &str {
+        "VF2Layout"
+    }
+
+    fn run(
+        &mut self,
+        circuit: &mut QuantumCircuit,
+        target: &Target,
+        property_set: &mut PropertySet,
+    ) -> Result<(), QiskitError> {
+        let result = self.search(circuit, target)?;
+        if result.apply(circuit)? {
+            let layout: Vec<u32> = result
+                .mapping()
+                .into_iter()
+                .map(|(_, physical)| physical)
+                .collect();
+            property_set.insert("layout", layout);
+        }
+        Ok(())
+    }
+}
+
+/// The outcome of a [`Vf2Layout`] search.
+pub struct Vf2LayoutResult {
+    result: *mut QkVF2LayoutResult,
+    num_virtual_qubits: u32,
+    num_physical_qubits: u32,
+}
+
+impl Vf2LayoutResult {
+    /// Return whether the search found a layout.
+    pub fn found(&self) -> bool {
+        unsafe { qiskit_sys::qk!(qk_vf2_layout_result_has_match(self.result)) }
+    }
+
+    /// Return the physical qubit virtual qubit `qubit` is mapped to, or
+    /// `None` if no layout was found or the qubit is out of range.
+    pub fn physical_qubit(&self, qubit: u32) -> Option<u32> {
+        (self.found() && qubit < self.num_virtual_qubits).then(|| unsafe {
+            qiskit_sys::qk!(qk_vf2_layout_result_map_virtual_qubit(self.result, qubit))
+        })
+    }
+
+    /// Return the `(virtual, physical)` pairs of the layout in order of
+    /// virtual qubit, or an empty list if no layout was found.
+    pub fn mapping(&self) -> Vec<(u32, u32)> {
+        (0..self.num_virtual_qubits)
+            .map_while(|qubit| Some((qubit, self.physical_qubit(qubit)?)))
+            .collect()
+    }
+
+    /// Return the number of qubits of the searched circuit.
+    pub fn num_virtual_qubits(&self) -> u32 {
+        self.num_virtual_qubits
+    }
+
+    /// Return the number of qubits of the target searched on.
+    pub fn num_physical_qubits(&self) -> u32 {
+        self.num_physical_qubits
+    }
+
+    /// Apply the layout to `circuit`, widening it to the target's qubits with
+    /// each virtual qubit moved to its physical qubit.
+    ///
+    /// Returns whether a layout was applied, leaving `circuit` unchanged if
+    /// none was found. `circuit` must have as many qubits as the searched
+    /// circuit.
+    pub fn apply(&self, circuit: &mut QuantumCircuit) -> Result<bool, QiskitError> {
+        if circuit.num_qubits() != self.num_virtual_qubits {
+            return Err(QiskitError::MismatchedQubits);
+        }
+        if !self.found() {
+            return Ok(false);
+        }
+        let qubits: Vec<u32> = self
+            .mapping()
+            .into_iter()
+            .map(|(_, physical)| physical)
+            .collect();
+        let clbits: Vec<u32> = (0..circuit.num_clbits()).collect();
+        let mut mapped = QuantumCircuit::new(self.num_physical_qubits, circuit.num_clbits());
+        mapped.append_mapped(circuit, &qubits, &clbits)?;
+        *circuit = mapped;
+        Ok(true)
+    }
+}
+
+impl fmt::Debug for Vf2LayoutResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Vf2LayoutResult")
+            .field("mapping", &self.mapping())
+            .field("num_physical_qubits", &self.num_physical_qubits)
+            .finish()
+    }
+}
+
+impl Drop for Vf2LayoutResult {
+    fn drop(&mut self) {
+        unsafe { qiskit_sys::qk!(qk_vf2_layout_result_free(self.result)) };
+    }
+}
@@ -0,0 +1,100 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+use std::fmt;
+
+/// A version of the qiskit c api.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    /// The major version
+    pub major: u32,
+    /// The minor version
+    pub minor: u32,
+    /// The patch version
+    pub patch: u32,
+}
+
+impl Version {
+    /// Create a version from its parts.
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Version {
+        Version {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Whether two versions of the qiskit c api are compatible, that is
+    /// whether they have the same major and minor version.
+    ///
+    /// ```
+    /// use qiskit_rs::Version;
+    ///
+    /// assert!(Version::new(2, 2, 3).is_compatible_with(&Version::new(2, 2, 0)));
+    /// assert!(!Version::new(2, 2, 3).is_compatible_with(&Version::new(2, 1, 3)));
+    /// ```
+    pub const fn is_compatible_with(&self, other: &Version) -> bool {
+        self.major == other.major && self.minor == other.minor
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl From<(u32, u32, u32)> for Version {
+    fn from((major, minor, patch): (u32, u32, u32)) -> Self {
+        Version::new(major, minor, patch)
+    }
+}
+
+/// The version of the qiskit c api headers qiskit-rs was compiled against.
+pub const HEADER_VERSION: Version = {
+    let (major, minor, patch) = qiskit_sys::HEADER_VERSION;
+    Version::new(major, minor, patch)
+};
+
+/// The version of the qiskit c api library qiskit-rs uses.
+///
+/// Without the `runtime-linking` feature, this is the version of the library
+/// linked at build time, read from the version macros of the installation's
+/// headers or from pkg-config, and the build fails when it doesn't match
+/// [`HEADER_VERSION`].
+///
+/// With the `runtime-linking` feature, this is the version of the loaded
+/// library, read from the headers installed next to it. Loading a library of
+/// another major or minor version fails with `LoadError::IncompatibleVersion`.
+/// When the version can't be found, for example for a library found on the
+/// default search path, the library found at build time and then
+/// [`HEADER_VERSION`] are assumed.
+///
+/// # Panics
+///
+/// With the `runtime-linking` feature, panics if libqiskit wasn't loaded
+/// with `qiskit_rs::init` and the default library can't be loaded.
+///
+/// ```
+/// let version = qiskit_rs::version();
+/// assert!(version.is_compatible_with(&qiskit_rs::HEADER_VERSION));
+/// println!("Using qiskit {version}");
+/// ```
+pub fn version() -> Version {
+    #[cfg(feature = "runtime-linking")]
+    if let Some(version) = qiskit_sys::library_version() {
+        return Version::from(version);
+    }
+    qiskit_sys::LIBRARY_VERSION
+        .map(Version::from)
+        .unwrap_or(HEADER_VERSION)
+}
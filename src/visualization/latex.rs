@@ -0,0 +1,250 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! The LaTeX drawer.
+//!
+//! The output is a standalone document using the `quantikz2` TikZ library,
+//! with one row per wire and one column per layer. Gates are filled with a
+//! tint of their color so that their labels stay readable.
+
+use super::{Diagram, DrawOptions, Op, OpKind, format_param, gate_color};
+use crate::qiskit::{QuantumCircuit, StandardGate};
+use std::collections::BTreeSet;
+
+pub(crate) fn draw(circuit: &QuantumCircuit, options: &DrawOptions) -> String {
+    let diagram = Diagram::new(circuit, options);
+    let mut colors = BTreeSet::new();
+    let mut rows: Vec<Vec<String>> = diagram
+        .wires
+        .iter()
+        .map(|wire| {
+            let mut label = format!(
+                "\\lstick{{${{{}}}_{{{}}}$}}",
+                escape(&wire.register),
+                wire.index
+            );
+            if wire.classical {
+                label.push_str(" \\setwiretype{c}");
+            }
+            vec![label]
+        })
+        .collect();
+    for layer in &diagram.layers {
+        let mut column = vec![String::new(); rows.len()];
+        for op in layer {
+            let color = gate_color(op, options);
+            let style = format!("style={{fill={}!25}}", color_name(&color));
+            if draw_op(op, &style, &mut column) {
+                colors.insert(color);
+            }
+        }
+        for (row, cell) in rows.iter_mut().zip(column) {
+            row.push(cell);
+        }
+    }
+
+    let mut out = String::new();
+    match diagram.global_phase {
+        Some(_) => out.push_str("\\documentclass[border=2px,varwidth]{standalone}\n"),
+        None => out.push_str("\\documentclass[border=2px]{standalone}\n"),
+    }
+    out.push_str("\\usepackage{tikz}\n\\usetikzlibrary{quantikz2}\n");
+    for color in &colors {
+        out.push_str(&format!(
+            "\\definecolor{{{}}}{{HTML}}{{{}}}\n",
+            color_name(color),
+            color.trim_start_matches('#').to_uppercase()
+        ));
+    }
+    out.push_str("\\begin{document}\n");
+    if let Some(phase) = &diagram.global_phase {
+        out.push_str(&format!(
+            "Global Phase: ${}$\\\\\n",
+            format_param(phase, "\\pi")
+        ));
+    }
+    out.push_str("\\begin{quantikz}\n");
+    let rows: Vec<String> = rows
+        .into_iter()
+        .map(|mut row| {
+            // An empty last column extends the wires past the last gate.
+            row.push(String::new());
+            row.join(" & ").trim_end().to_string()
+        })
+        .collect();
+    out.push_str(&rows.join(" \\\\\n"));
+    out.push_str("\n\\end{quantikz}\n\\end{document}\n");
+    out
+}
+
+// Fill in the cells of an op, and return whether the op uses `style`.
+fn draw_op(op: &Op, style: &str, column: &mut [String]) -> bool {
+    let Some((top, bottom)) = op.span() else {
+        return false;
+    };
+    let label = label(op);
+    match &op.kind {
+        OpKind::Gate { controls, targets } => {
+            let first = *targets.iter().min().unwrap();
+            let last = *targets.iter().max().unwrap();
+            for control in controls {
+                let target = if *control < first { first } else { last };
+                column[*control] = ctrl(*control, target);
+            }
+            let (cell, styled) = match targets.len() {
+                1 if op.label == "X" && !controls.is_empty() => ("\\targ{}".to_string(), false),
+                1 => (format!("\\gate[{style}]{{{label}}}"), true),
+                _ => {
+                    let wires = last - first + 1;
+                    (format!("\\gate[wires={wires}, {style}]{{{label}}}"), true)
+                }
+            };
+            column[first] = cell;
+            styled
+        }
+        OpKind::Dots(qubits) => {
+            for qubit in qubits {
+                column[*qubit] = match *qubit == top {
+                    true => ctrl(top, bottom),
+                    false => "\\control{}".to_string(),
+                };
+            }
+            false
+        }
+        OpKind::Swap { controls, targets } => {
+            let (first, last) = (targets[0].min(targets[1]), targets[0].max(targets[1]));
+            for control in controls {
+                let target = if *control < first { first } else { last };
+                column[*control] = ctrl(*control, target);
+            }
+            column[first] = format!("\\swap{{{}}}", last - first);
+            column[last] = "\\targX{}".to_string();
+            false
+        }
+        OpKind::Measure { qubit, clbit } => {
+            column[*qubit] = format!("\\meter{{}} \\wire[d][{}]{{c}}", clbit - qubit);
+            false
+        }
+        OpKind::Reset(qubit) => {
+            column[*qubit] = format!("\\gate[{style}]{{\\ket{{0}}}}");
+            true
+        }
+        OpKind::Barrier(qubits) => {
+            // quantikz slices cross every wire, so one is enough.
+            let first = qubits.iter().min().unwrap();
+            column[*first] = "\\slice{}".to_string();
+            false
+        }
+        OpKind::ControlFlow { qubits, condition } => {
+            let mut last = top;
+            let boxed = !qubits.is_empty();
+            if let (Some(first), Some(end)) = (qubits.iter().min(), qubits.iter().max()) {
+                column[*first] = match first == end {
+                    true => format!("\\gate[{style}]{{{label}}}"),
+                    false => format!("\\gate[wires={}, {style}]{{{label}}}", end - first + 1),
+                };
+                last = *end;
+            }
+            if !condition.is_empty() && bottom > last {
+                let cell = &mut column[last];
+                if !cell.is_empty() {
+                    cell.push(' ');
+                }
+                cell.push_str(&format!("\\wire[d][{}]{{c}}", bottom - last));
+            }
+            for (clbit, value) in condition {
+                column[*clbit] = match value {
+                    Some(false) => "\\ocontrol{}".to_string(),
+                    _ => "\\control{}".to_string(),
+                };
+            }
+            boxed
+        }
+    }
+}
+
+fn ctrl(control: usize, target: usize) -> String {
+    format!("\\ctrl{{{}}}", target as isize - control as isize)
+}
+
+// The math-mode label of an op, with its parameters.
+fn label(op: &Op) -> String {
+    let name = match StandardGate::from_name(&op.name) {
+        Some(gate) => gate_label(gate.base_gate()).to_string(),
+        None => format!("\\mathrm{{{}}}", escape(&op.label)),
+    };
+    if op.params.is_empty() {
+        return name;
+    }
+    let params: Vec<String> = op
+        .params
+        .iter()
+        .map(|param| format_param(param, "\\pi"))
+        .collect();
+    format!("{name}\\left({}\\right)", params.join(", "))
+}
+
+fn gate_label(gate: StandardGate) -> &'static str {
+    use StandardGate::*;
+    match gate {
+        GlobalPhase => "\\mathrm{GlobalPhase}",
+        H => "H",
+        I => "I",
+        X => "X",
+        Y => "Y",
+        Z => "Z",
+        Phase => "P",
+        R => "R",
+        RX => "R_X",
+        RY => "R_Y",
+        RZ => "R_Z",
+        S => "S",
+        Sdg => "S^\\dagger",
+        SX => "\\sqrt{X}",
+        SXdg => "\\sqrt{X}^\\dagger",
+        T => "T",
+        Tdg => "T^\\dagger",
+        U => "U",
+        U1 => "U_1",
+        U2 => "U_2",
+        U3 => "U_3",
+        DCX => "\\mathrm{DCX}",
+        ECR => "\\mathrm{ECR}",
+        ISwap => "\\mathrm{iSwap}",
+        RXX => "R_{XX}",
+        RYY => "R_{YY}",
+        RZZ => "R_{ZZ}",
+        RZX => "R_{ZX}",
+        XXMinusYY => "\\mathrm{XX{-}YY}",
+        XXPlusYY => "\\mathrm{XX{+}YY}",
+        RCCX => "\\mathrm{RCCX}",
+        RC3X => "\\mathrm{RC3X}",
+        // Controlled gates are drawn through their base gate.
+        gate => gate.name(),
+    }
+}
+
+// The name a `#rrggbb` color is defined under.
+fn color_name(color: &str) -> String {
+    format!("qk{}", color.trim_start_matches('#').to_uppercase())
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::new();
+    for c in text.chars() {
+        if matches!(c, '_' | '#' | '%' | '&' | '$' | '{' | '}') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
@@ -0,0 +1,446 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! Circuit drawers.
+//!
+//! The drawers are called through [`QuantumCircuit::draw_text`],
+//! [`QuantumCircuit::draw_svg`], [`QuantumCircuit::draw_latex`] and the
+//! [`Display`](std::fmt::Display) implementation of [`QuantumCircuit`]. They
+//! share a layer scheduling: each instruction is placed in the first layer
+//! after every instruction that touches a wire between its topmost and
+//! bottommost wire.
+//!
+//! The output of every drawer depends only on the circuit and the options,
+//! so it can be compared against stored snapshots.
+
+pub(crate) mod latex;
+pub(crate) mod svg;
+pub(crate) mod text;
+
+use crate::control_flow::ControlFlow;
+use crate::parameter::ParameterValue;
+use crate::qiskit::{CircuitInstruction, QuantumCircuit, StandardGate};
+use std::collections::BTreeMap;
+use std::f64::consts::PI;
+
+/// Options for the circuit drawers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DrawOptions {
+    /// The width in characters at which the text drawer folds the diagram
+    /// onto a new block, or `None` to never fold.
+    pub fold: Option<usize>,
+    /// Draw the last qubit and classical bit at the top instead of the
+    /// first.
+    pub reverse_bits: bool,
+    /// Fill colors for gates in the SVG and LaTeX drawers, as `#rrggbb`
+    /// strings keyed by instruction name. They take precedence over the
+    /// default colors, and the color of a controlled gate's base gate is
+    /// used when the controlled gate has none.
+    pub gate_colors: BTreeMap<String, String>,
+}
+
+impl Default for DrawOptions {
+    fn default() -> Self {
+        DrawOptions {
+            fold: Some(80),
+            reverse_bits: false,
+            gate_colors: BTreeMap::new(),
+        }
+    }
+}
+
+// A wire of the diagram, with qubits above classical bits.
+pub(crate) struct Wire {
+    pub(crate) register: String,
+    pub(crate) index: u32,
+    pub(crate) classical: bool,
+}
+
+impl Wire {
+    pub(crate) fn label(&self) -> String {
+        format!("{}_{}", self.register, self.index)
+    }
+}
+
+// How an instruction is drawn. Bits are given as wire indices.
+pub(crate) enum OpKind {
+    // A boxed gate on `targets`, with a control dot on each of `controls`.
+    Gate {
+        controls: Vec<usize>,
+        targets: Vec<usize>,
+    },
+    // A gate drawn as a control dot on every qubit, such as `cz`.
+    Dots(Vec<usize>),
+    Swap {
+        controls: Vec<usize>,
+        targets: [usize; 2],
+    },
+    Measure {
+        qubit: usize,
+        clbit: usize,
+    },
+    Reset(usize),
+    Barrier(Vec<usize>),
+    // A control-flow box over `qubits`, reading the classical bits in
+    // `condition`. Bits compared with a value carry that value.
+    ControlFlow {
+        qubits: Vec<usize>,
+        condition: Vec<(usize, Option<bool>)>,
+    },
+}
+
+// An instruction to draw.
+pub(crate) struct Op {
+    // The name of the instruction in the circuit.
+    pub(crate) name: String,
+    // The text shown in the instruction's box, without parameters.
+    pub(crate) label: String,
+    pub(crate) params: Vec<ParameterValue>,
+    pub(crate) kind: OpKind,
+}
+
+impl Op {
+    // Return the wires the op touches, in no particular order.
+    pub(crate) fn wires(&self) -> Vec<usize> {
+        match &self.kind {
+            OpKind::Gate { controls, targets } => controls.iter().chain(targets).copied().collect(),
+            OpKind::Dots(qubits) | OpKind::Barrier(qubits) => qubits.clone(),
+            OpKind::Swap { controls, targets } => controls.iter().chain(targets).copied().collect(),
+            OpKind::Measure { qubit, clbit } => vec![*qubit, *clbit],
+            OpKind::Reset(qubit) => vec![*qubit],
+            OpKind::ControlFlow { qubits, condition } => qubits
+                .iter()
+                .copied()
+                .chain(condition.iter().map(|(clbit, _)| *clbit))
+                .collect(),
+        }
+    }
+
+    // Return the topmost and bottommost wires the op touches.
+    pub(crate) fn span(&self) -> Option<(usize, usize)> {
+        let wires = self.wires();
+        Some((*wires.iter().min()?, *wires.iter().max()?))
+    }
+}
+
+// A circuit broken into wires and layers of ops that can be drawn side by
+// side.
+pub(crate) struct Diagram {
+    pub(crate) wires: Vec<Wire>,
+    pub(crate) global_phase: Option<ParameterValue>,
+    pub(crate) layers: Vec<Vec<Op>>,
+}
+
+impl Diagram {
+    pub(crate) fn new(circuit: &QuantumCircuit, options: &DrawOptions) -> Diagram {
+        let num_qubits = circuit.num_qubits();
+        let num_clbits = circuit.num_clbits();
+        // The bits drawn on each wire, from the top.
+        let order = |num_bits: u32| -> Vec<u32> {
+            match options.reverse_bits {
+                true => (0..num_bits).rev().collect(),
+                false => (0..num_bits).collect(),
+            }
+        };
+        let mut wires: Vec<Wire> = order(num_qubits)
+            .into_iter()
+            .map(|qubit| {
+                let (register, index) = circuit.qubit_label(qubit);
+                Wire {
+                    register,
+                    index,
+                    classical: false,
+                }
+            })
+            .collect();
+        wires.extend(order(num_clbits).into_iter().map(|clbit| {
+            let (register, index) = circuit.clbit_label(clbit);
+            Wire {
+                register,
+                index,
+                classical: true,
+            }
+        }));
+        let mut qubit_wires = vec![0; num_qubits as usize];
+        let mut clbit_wires = vec![0; num_clbits as usize];
+        for (wire, bit) in order(num_qubits).into_iter().enumerate() {
+            qubit_wires[bit as usize] = wire;
+        }
+        for (wire, bit) in order(num_clbits).into_iter().enumerate() {
+            clbit_wires[bit as usize] = num_qubits as usize + wire;
+        }
+
+        let mut global_phase: Option<ParameterValue> = None;
+        let mut layers: Vec<Vec<Op>> = Vec::new();
+        // The number of layers each wire is busy for.
+        let mut depth = vec![0; wires.len()];
+        for inst in circuit.instructions() {
+            if inst.name == "global_phase" {
+                let phase = inst.param_values().remove(0);
+                global_phase = Some(match global_phase {
+                    Some(total) => total + phase,
+                    None => phase,
+                });
+                continue;
+            }
+            let op = to_op(&inst, &qubit_wires, &clbit_wires);
+            let Some((top, bottom)) = op.span() else {
+                continue;
+            };
+            let layer = *depth[top..=bottom].iter().max().unwrap();
+            if layer == layers.len() {
+                layers.push(Vec::new());
+            }
+            layers[layer].push(op);
+            depth[top..=bottom].fill(layer + 1);
+        }
+        Diagram {
+            wires,
+            global_phase,
+            layers,
+        }
+    }
+}
+
+fn to_op(inst: &CircuitInstruction, qubit_wires: &[usize], clbit_wires: &[usize]) -> Op {
+    let qubits: Vec<usize> = inst
+        .qubits
+        .iter()
+        .map(|q| qubit_wires[*q as usize])
+        .collect();
+    let clbits: Vec<usize> = inst
+        .clbits
+        .iter()
+        .map(|c| clbit_wires[*c as usize])
+        .collect();
+    let op = |label: &str, kind: OpKind| Op {
+        name: inst.name.to_string(),
+        label: label.to_string(),
+        params: inst.param_values(),
+        kind,
+    };
+    if let Some(control_flow) = inst.control_flow() {
+        let (label, condition) = match control_flow {
+            ControlFlow::IfElse {
+                condition,
+                false_body,
+                ..
+            } => {
+                let label = if false_body.is_some() {
+                    "If-Else"
+                } else {
+                    "If"
+                };
+                (label, Some(condition))
+            }
+            ControlFlow::WhileLoop { condition, .. } => ("While", Some(condition)),
+            ControlFlow::ForLoop { .. } => ("For", None),
+            ControlFlow::Switch { .. } => ("Switch", None),
+        };
+        let condition = match condition {
+            Some(condition) => condition
+                .clbits()
+                .iter()
+                .enumerate()
+                .map(|(i, clbit)| {
+                    let value = condition.value() >> i & 1 == 1;
+                    (clbit_wires[*clbit as usize], Some(value))
+                })
+                .collect(),
+            None => control_flow
+                .condition_clbits()
+                .iter()
+                .map(|clbit| (clbit_wires[*clbit as usize], None))
+                .collect(),
+        };
+        // Ops with empty bodies are drawn on the first qubit so the box
+        // still shows.
+        let qubits = if qubits.is_empty() && !qubit_wires.is_empty() {
+            vec![qubit_wires[0]]
+        } else {
+            qubits
+        };
+        return op(label, OpKind::ControlFlow { qubits, condition });
+    }
+    match inst.name {
+        "measure" => op(
+            "M",
+            OpKind::Measure {
+                qubit: qubits[0],
+                clbit: clbits[0],
+            },
+        ),
+        "reset" => op("|0>", OpKind::Reset(qubits[0])),
+        "barrier" => op("", OpKind::Barrier(qubits)),
+        name => {
+            let Some(gate) = StandardGate::from_name(name) else {
+                return op(
+                    name,
+                    OpKind::Gate {
+                        controls: Vec::new(),
+                        targets: qubits,
+                    },
+                );
+            };
+            let base = gate.base_gate();
+            let num_ctrl = qubits.len() - base.num_qubits() as usize;
+            let controls = qubits[..num_ctrl].to_vec();
+            let targets = qubits[num_ctrl..].to_vec();
+            match base {
+                StandardGate::Z if matches!(gate, StandardGate::CZ | StandardGate::CCZ) => {
+                    op("Z", OpKind::Dots(qubits))
+                }
+                StandardGate::Swap => op(
+                    "",
+                    OpKind::Swap {
+                        controls,
+                        targets: [targets[0], targets[1]],
+                    },
+                ),
+                _ => op(gate_label(base), OpKind::Gate { controls, targets }),
+            }
+        }
+    }
+}
+
+// The label Qiskit's drawers use for a standard gate.
+fn gate_label(gate: StandardGate) -> &'static str {
+    use StandardGate::*;
+    match gate {
+        GlobalPhase => "GlobalPhase",
+        H => "H",
+        I => "I",
+        X => "X",
+        Y => "Y",
+        Z => "Z",
+        Phase => "P",
+        R => "R",
+        RX => "Rx",
+        RY => "Ry",
+        RZ => "Rz",
+        S => "S",
+        Sdg => "Sdg",
+        SX => "√X",
+        SXdg => "√Xdg",
+        T => "T",
+        Tdg => "Tdg",
+        U => "U",
+        U1 => "U1",
+        U2 => "U2",
+        U3 => "U3",
+        DCX => "Dcx",
+        ECR => "Ecr",
+        ISwap => "Iswap",
+        RXX => "Rxx",
+        RYY => "Ryy",
+        RZZ => "Rzz",
+        RZX => "Rzx",
+        XXMinusYY => "(XX-YY)",
+        XXPlusYY => "(XX+YY)",
+        RCCX => "Rccx",
+        RC3X => "Rcccx",
+        // Controlled gates are drawn through their base gate.
+        gate => gate.name(),
+    }
+}
+
+// The fill color of an op in the SVG and LaTeX drawers.
+pub(crate) fn gate_color(op: &Op, options: &DrawOptions) -> String {
+    let base = StandardGate::from_name(&op.name).map(|gate| gate.base_gate().name());
+    if let Some(color) = options
+        .gate_colors
+        .get(&op.name)
+        .or_else(|| options.gate_colors.get(base?))
+    {
+        return color.clone();
+    }
+    let color = match base.unwrap_or(&op.name) {
+        "x" | "y" | "z" | "id" => "#002d9c",
+        "h" => "#fa4d56",
+        "s" | "sdg" | "sx" | "sxdg" | "t" | "tdg" => "#bb8bff",
+        "p" | "r" | "rx" | "ry" | "rz" | "u" | "u1" | "u2" | "u3" | "rxx" | "ryy" | "rzz"
+        | "rzx" | "xx_minus_yy" | "xx_plus_yy" => "#9f1853",
+        "measure" => "#a8a8a8",
+        "reset" => "#000000",
+        "barrier" => "#dddddd",
+        "if_else" | "while_loop" | "for_loop" | "switch_case" => "#ff7eb6",
+        _ => "#33b1ff",
+    };
+    color.to_string()
+}
+
+// Black or white, whichever reads better on a `#rrggbb` fill.
+pub(crate) fn text_color(fill: &str) -> &'static str {
+    let channel = |i: usize| {
+        fill.get(i..i + 2)
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            .map_or(1.0, |value| f64::from(value) / 255.0)
+    };
+    let luminance = 0.299 * channel(1) + 0.587 * channel(3) + 0.114 * channel(5);
+    if luminance < 0.5 {
+        "#ffffff"
+    } else {
+        "#000000"
+    }
+}
+
+// Format a parameter, writing multiples of π as fractions with `pi` as the
+// symbol for π.
+pub(crate) fn format_param(value: &ParameterValue, pi: &str) -> String {
+    match value {
+        ParameterValue::Float(value) => format_angle(*value, pi),
+        ParameterValue::Expression(expr) => expr.to_string(),
+    }
+}
+
+fn format_angle(value: f64, pi: &str) -> String {
+    if value == 0.0 {
+        return "0".to_string();
+    }
+    for denominator in 1..=16 {
+        let numerator = value * denominator as f64 / PI;
+        let rounded = numerator.round();
+        if (numerator - rounded).abs() > 1e-9 || rounded.abs() > 64.0 {
+            continue;
+        }
+        let sign = if rounded < 0.0 { "-" } else { "" };
+        let numerator = match rounded.abs() as i64 {
+            1 => String::new(),
+            n => n.to_string(),
+        };
+        return match denominator {
+            1 => format!("{sign}{numerator}{pi}"),
+            d => format!("{sign}{numerator}{pi}/{d}"),
+        };
+    }
+    format_float(value)
+}
+
+// Format a float with five significant digits, like Python's `%.5g`.
+fn format_float(value: f64) -> String {
+    let exponent = value.abs().log10().floor() as i32;
+    if !(-4..5).contains(&exponent) {
+        let formatted = format!("{value:.4e}");
+        let (mantissa, exponent) = formatted.split_once('e').unwrap();
+        return format!("{}e{exponent}", trim_zeros(mantissa));
+    }
+    let decimals = (4 - exponent).max(0) as usize;
+    trim_zeros(&format!("{value:.decimals$}")).to_string()
+}
+
+fn trim_zeros(value: &str) -> &str {
+    if value.contains('.') {
+        value.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        value
+    }
+}
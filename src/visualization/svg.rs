@@ -0,0 +1,401 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! The SVG drawer.
+//!
+//! Text widths are estimated from the number of characters, so the layout
+//! does not depend on the fonts available where the image is rendered.
+
+use super::{Diagram, DrawOptions, Op, OpKind, format_param, gate_color, text_color};
+use crate::qiskit::QuantumCircuit;
+use std::fmt::Write;
+
+// The vertical distance between wires.
+const WIRE_SPACING: f64 = 48.0;
+// The height, and minimum width, of a gate on a single wire.
+const GATE_SIZE: f64 = 32.0;
+// The estimated width of a character at the label font size.
+const CHAR_WIDTH: f64 = 8.0;
+const FONT_SIZE: f64 = 13.0;
+const SMALL_FONT_SIZE: f64 = 10.0;
+const LAYER_GAP: f64 = 16.0;
+const MARGIN: f64 = 16.0;
+const CONTROL_RADIUS: f64 = 5.0;
+
+pub(crate) fn draw(circuit: &QuantumCircuit, options: &DrawOptions) -> String {
+    let diagram = Diagram::new(circuit, options);
+    let mut body = String::new();
+
+    let header = match &diagram.global_phase {
+        Some(phase) => {
+            let phase = format!("Global Phase: {}", format_param(phase, "π"));
+            text(
+                &mut body, MARGIN, MARGIN, &phase, "start", FONT_SIZE, "#000000",
+            );
+            MARGIN + FONT_SIZE
+        }
+        None => 0.0,
+    };
+    let wire_y = |wire: usize| header + MARGIN + GATE_SIZE / 2.0 + wire as f64 * WIRE_SPACING;
+
+    // The labels are drawn with the index as a subscript.
+    let label_width = diagram
+        .wires
+        .iter()
+        .map(|wire| {
+            let register = wire.register.chars().count() as f64;
+            let index = wire.index.to_string().len() as f64;
+            (register + 0.8 * index) * CHAR_WIDTH
+        })
+        .fold(0.0, f64::max);
+    let start = MARGIN + label_width + LAYER_GAP / 2.0;
+
+    let mut drawer = Drawer {
+        out: String::new(),
+        options,
+        wire_y: &wire_y,
+    };
+    let mut x = start + LAYER_GAP / 2.0;
+    for layer in &diagram.layers {
+        let width = layer.iter().map(op_width).fold(GATE_SIZE, f64::max);
+        for op in layer {
+            drawer.op(op, x + width / 2.0);
+        }
+        x += width + LAYER_GAP;
+    }
+    let end = x;
+
+    for (i, wire) in diagram.wires.iter().enumerate() {
+        let y = wire_y(i);
+        match wire.classical {
+            true => {
+                line(&mut body, start, y - 1.5, end, y - 1.5, "#000000");
+                line(&mut body, start, y + 1.5, end, y + 1.5, "#000000");
+            }
+            false => line(&mut body, start, y, end, y, "#000000"),
+        }
+        writeln!(
+            body,
+            r#"<text x="{}" y="{}" text-anchor="end" dominant-baseline="central" font-size="{}">{}<tspan dy="4" font-size="{}">{}</tspan></text>"#,
+            num(start - LAYER_GAP / 2.0),
+            num(y),
+            num(FONT_SIZE),
+            escape(&wire.register),
+            num(SMALL_FONT_SIZE),
+            wire.index
+        )
+        .unwrap();
+    }
+    body.push_str(&drawer.out);
+
+    let width = end + MARGIN;
+    let height = match diagram.wires.len() {
+        0 => header + MARGIN,
+        n => wire_y(n - 1) + GATE_SIZE / 2.0 + MARGIN,
+    };
+    format!(
+        concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" "#,
+            r#"font-family="Helvetica, Arial, sans-serif">"#,
+            "\n",
+            r##"<rect width="{w}" height="{h}" fill="#ffffff"/>"##,
+            "\n{body}</svg>\n"
+        ),
+        w = num(width),
+        h = num(height),
+        body = body,
+    )
+}
+
+// The width an op takes up in its layer.
+fn op_width(op: &Op) -> f64 {
+    match &op.kind {
+        OpKind::Gate { .. } | OpKind::ControlFlow { .. } => {
+            let (label, params) = labels(op);
+            let label = label.chars().count() as f64 * CHAR_WIDTH;
+            let params = params.chars().count() as f64 * CHAR_WIDTH * 0.8;
+            let index = if is_multi(op) { CHAR_WIDTH * 2.0 } else { 0.0 };
+            (label.max(params) + index + 12.0).max(GATE_SIZE)
+        }
+        OpKind::Reset(_) => GATE_SIZE + 4.0,
+        _ => GATE_SIZE,
+    }
+}
+
+// Whether an op is drawn as a box over more than one wire.
+fn is_multi(op: &Op) -> bool {
+    match &op.kind {
+        OpKind::Gate { targets, .. } => targets.len() > 1,
+        OpKind::ControlFlow { qubits, .. } => qubits.len() > 1,
+        _ => false,
+    }
+}
+
+// The name and the formatted parameters of an op.
+fn labels(op: &Op) -> (String, String) {
+    let params: Vec<String> = op
+        .params
+        .iter()
+        .map(|param| format_param(param, "π"))
+        .collect();
+    (op.label.clone(), params.join(", "))
+}
+
+struct Drawer<'a> {
+    out: String,
+    options: &'a DrawOptions,
+    wire_y: &'a dyn Fn(usize) -> f64,
+}
+
+impl Drawer<'_> {
+    fn op(&mut self, op: &Op, x: f64) {
+        let Some((top, bottom)) = op.span() else {
+            return;
+        };
+        let y = self.wire_y;
+        let color = gate_color(op, self.options);
+        let out = &mut self.out;
+        match &op.kind {
+            OpKind::Gate { controls, targets } => {
+                if !controls.is_empty() {
+                    line(out, x, y(top), x, y(bottom), &color);
+                }
+                for control in controls {
+                    circle(out, x, y(*control), CONTROL_RADIUS, &color, &color);
+                }
+                let first = *targets.iter().min().unwrap();
+                let last = *targets.iter().max().unwrap();
+                if op.label == "X" && !controls.is_empty() {
+                    // A controlled X is drawn as a target symbol.
+                    let r = GATE_SIZE * 0.35;
+                    let y = y(first);
+                    let ink = text_color(&color);
+                    circle(out, x, y, r, &color, &color);
+                    line(out, x - r, y, x + r, y, ink);
+                    line(out, x, y - r, x, y + r, ink);
+                    return;
+                }
+                let indices: Vec<(usize, String)> = match targets.len() {
+                    1 => Vec::new(),
+                    _ => targets
+                        .iter()
+                        .enumerate()
+                        .map(|(i, target)| (*target, i.to_string()))
+                        .collect(),
+                };
+                self.gate_box(op, x, (first, last), &indices, &color);
+            }
+            OpKind::Dots(qubits) => {
+                line(out, x, y(top), x, y(bottom), &color);
+                for qubit in qubits {
+                    circle(out, x, y(*qubit), CONTROL_RADIUS, &color, &color);
+                }
+            }
+            OpKind::Swap { controls, targets } => {
+                line(out, x, y(top), x, y(bottom), &color);
+                for control in controls {
+                    circle(out, x, y(*control), CONTROL_RADIUS, &color, &color);
+                }
+                let r = GATE_SIZE * 0.2;
+                for target in targets {
+                    let y = y(*target);
+                    line(out, x - r, y - r, x + r, y + r, &color);
+                    line(out, x - r, y + r, x + r, y - r, &color);
+                }
+            }
+            OpKind::Measure { qubit, clbit } => {
+                let (y0, y1) = (y(*qubit), y(*clbit));
+                line(out, x - 1.5, y0, x - 1.5, y1 - 6.0, "#a8a8a8");
+                line(out, x + 1.5, y0, x + 1.5, y1 - 6.0, "#a8a8a8");
+                writeln!(
+                    out,
+                    r##"<path d="M {} {} L {} {} L {} {} Z" fill="#a8a8a8"/>"##,
+                    num(x - 5.0),
+                    num(y1 - 8.0),
+                    num(x + 5.0),
+                    num(y1 - 8.0),
+                    num(x),
+                    num(y1),
+                )
+                .unwrap();
+                let s = GATE_SIZE / 2.0;
+                rect(out, x - s, y0 - s, GATE_SIZE, GATE_SIZE, &color);
+                let ink = text_color(&color);
+                writeln!(
+                    out,
+                    r#"<path d="M {} {} A {r} {r} 0 0 1 {} {}" fill="none" stroke="{ink}"/>"#,
+                    num(x - 10.0),
+                    num(y0 + 6.0),
+                    num(x + 10.0),
+                    num(y0 + 6.0),
+                    r = num(11.0),
+                )
+                .unwrap();
+                line(out, x, y0 + 6.0, x + 7.0, y0 - 8.0, ink);
+            }
+            OpKind::Reset(qubit) => {
+                let w = op_width(op);
+                rect(
+                    out,
+                    x - w / 2.0,
+                    y(*qubit) - GATE_SIZE / 2.0,
+                    w,
+                    GATE_SIZE,
+                    &color,
+                );
+                let ink = text_color(&color);
+                text(out, x, y(*qubit), "|0⟩", "middle", FONT_SIZE, ink);
+            }
+            OpKind::Barrier(qubits) => {
+                for qubit in qubits {
+                    let (y0, y1) = (
+                        y(*qubit) - WIRE_SPACING / 2.0,
+                        y(*qubit) + WIRE_SPACING / 2.0,
+                    );
+                    rect(out, x - 6.0, y0, 12.0, WIRE_SPACING, &color);
+                    writeln!(
+                        out,
+                        r##"<line x1="{x}" y1="{}" x2="{x}" y2="{}" stroke="#000000" stroke-dasharray="4 2"/>"##,
+                        num(y0),
+                        num(y1),
+                        x = num(x),
+                    )
+                    .unwrap();
+                }
+            }
+            OpKind::ControlFlow { qubits, condition } => {
+                if !condition.is_empty() {
+                    line(out, x - 1.5, y(top), x - 1.5, y(bottom), &color);
+                    line(out, x + 1.5, y(top), x + 1.5, y(bottom), &color);
+                }
+                for (clbit, value) in condition {
+                    let fill = if *value == Some(false) {
+                        "#ffffff"
+                    } else {
+                        &color
+                    };
+                    circle(out, x, y(*clbit), CONTROL_RADIUS, fill, &color);
+                }
+                if let (Some(first), Some(last)) = (qubits.iter().min(), qubits.iter().max()) {
+                    self.gate_box(op, x, (*first, *last), &[], &color);
+                }
+            }
+        }
+    }
+
+    // Draw a labelled box over the wires from `wires.0` to `wires.1`, with
+    // the given index labels at its left edge.
+    fn gate_box(
+        &mut self,
+        op: &Op,
+        x: f64,
+        wires: (usize, usize),
+        indices: &[(usize, String)],
+        color: &str,
+    ) {
+        let y = self.wire_y;
+        let out = &mut self.out;
+        let width = op_width(op);
+        let (y0, y1) = (y(wires.0) - GATE_SIZE / 2.0, y(wires.1) + GATE_SIZE / 2.0);
+        rect(out, x - width / 2.0, y0, width, y1 - y0, color);
+        let ink = text_color(color);
+        for (wire, index) in indices {
+            let left = x - width / 2.0 + 4.0;
+            text(out, left, y(*wire), index, "start", SMALL_FONT_SIZE, ink);
+        }
+        let center = (y0 + y1) / 2.0;
+        let label_x = match indices.is_empty() {
+            true => x,
+            false => x + CHAR_WIDTH,
+        };
+        let (label, params) = labels(op);
+        match params.is_empty() {
+            true => text(out, label_x, center, &label, "middle", FONT_SIZE, ink),
+            false => {
+                text(out, label_x, center - 6.0, &label, "middle", FONT_SIZE, ink);
+                text(
+                    out,
+                    label_x,
+                    center + 8.0,
+                    &params,
+                    "middle",
+                    SMALL_FONT_SIZE,
+                    ink,
+                );
+            }
+        }
+    }
+}
+
+fn line(out: &mut String, x1: f64, y1: f64, x2: f64, y2: f64, stroke: &str) {
+    writeln!(
+        out,
+        r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{stroke}"/>"#,
+        num(x1),
+        num(y1),
+        num(x2),
+        num(y2),
+    )
+    .unwrap();
+}
+
+fn rect(out: &mut String, x: f64, y: f64, width: f64, height: f64, fill: &str) {
+    writeln!(
+        out,
+        r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{fill}"/>"#,
+        num(x),
+        num(y),
+        num(width),
+        num(height),
+    )
+    .unwrap();
+}
+
+fn circle(out: &mut String, x: f64, y: f64, r: f64, fill: &str, stroke: &str) {
+    writeln!(
+        out,
+        r#"<circle cx="{}" cy="{}" r="{}" fill="{fill}" stroke="{stroke}"/>"#,
+        num(x),
+        num(y),
+        num(r),
+    )
+    .unwrap();
+}
+
+fn text(out: &mut String, x: f64, y: f64, content: &str, anchor: &str, size: f64, fill: &str) {
+    writeln!(
+        out,
+        r#"<text x="{}" y="{}" text-anchor="{anchor}" dominant-baseline="central" font-size="{}" fill="{fill}">{}</text>"#,
+        num(x),
+        num(y),
+        num(size),
+        escape(content),
+    )
+    .unwrap();
+}
+
+// Format a coordinate with at most two decimals.
+fn num(value: f64) -> String {
+    let formatted = format!("{value:.2}");
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "-0" => "0".to_string(),
+        trimmed => trimmed.to_string(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,392 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! The text drawer.
+//!
+//! Every wire takes a row, and the rows between wires hold box edges and
+//! vertical connectors. Each layer is drawn as a column of cells, one per
+//! wire, with the bottom row of a cell merged into the top row of the cell
+//! below it.
+
+use super::{Diagram, DrawOptions, Op, OpKind, Wire, format_param};
+use crate::qiskit::QuantumCircuit;
+use std::ops::{Range, RangeInclusive};
+
+pub(crate) fn draw(circuit: &QuantumCircuit, options: &DrawOptions) -> String {
+    let diagram = Diagram::new(circuit, options);
+    let mut lines = Vec::new();
+    if let Some(phase) = &diagram.global_phase {
+        lines.push(format!("global phase: {}", format_param(phase, "π")));
+    }
+    let wires = &diagram.wires;
+    if wires.is_empty() {
+        return lines.join("\n");
+    }
+
+    let columns: Vec<Vec<String>> = diagram
+        .layers
+        .iter()
+        .map(|layer| draw_layer(wires, layer))
+        .collect();
+    let num_rows = 2 * wires.len() + 1;
+    let wire_labels: Vec<String> = wires.iter().map(Wire::label).collect();
+    let label_width = wire_labels
+        .iter()
+        .map(|label| label.chars().count())
+        .max()
+        .unwrap_or(0);
+    let labels: Vec<String> = (0..num_rows)
+        .map(|row| match row % 2 {
+            1 => format!("{:>label_width$}: ", wire_labels[row / 2]),
+            _ => " ".repeat(label_width + 2),
+        })
+        .collect();
+    let separators: Vec<char> = (0..num_rows)
+        .map(|row| match row % 2 {
+            1 => wire_char(&wires[row / 2]),
+            _ => ' ',
+        })
+        .collect();
+
+    for (i, block) in fold(&columns, label_width + 2, options.fold)
+        .into_iter()
+        .enumerate()
+    {
+        let continued = i > 0;
+        let more = block.end < columns.len();
+        if continued {
+            lines.push(String::new());
+        }
+        for row in 0..num_rows {
+            let mut line = String::new();
+            if continued {
+                line.push('«');
+            }
+            line.push_str(&labels[row]);
+            line.push(separators[row]);
+            for column in &columns[block.clone()] {
+                line.push_str(&column[row]);
+                line.push(separators[row]);
+            }
+            if more {
+                line.push('»');
+            }
+            lines.push(line.trim_end().to_string());
+        }
+    }
+    lines.join("\n")
+}
+
+// Split the columns into blocks that fit in `width` characters, with at
+// least one column in every block.
+fn fold(columns: &[Vec<String>], label_width: usize, width: Option<usize>) -> Vec<Range<usize>> {
+    let mut blocks = Vec::new();
+    let mut start = 0;
+    loop {
+        // The label, the leading separator and the fold markers.
+        let mut used = label_width + 1 + usize::from(start > 0) + 1;
+        let mut end = start;
+        while end < columns.len() {
+            let column_width = columns[end][0].chars().count() + 1;
+            if end > start && width.is_some_and(|width| used + column_width > width) {
+                break;
+            }
+            used += column_width;
+            end += 1;
+        }
+        blocks.push(start..end);
+        if end >= columns.len() {
+            return blocks;
+        }
+        start = end;
+    }
+}
+
+fn wire_char(wire: &Wire) -> char {
+    if wire.classical { '═' } else { '─' }
+}
+
+// The three rows an op draws on a wire: above it, on it and below it.
+struct Cell {
+    top: Vec<char>,
+    mid: Vec<char>,
+    bot: Vec<char>,
+}
+
+impl Cell {
+    fn new(top: &str, mid: &str, bot: &str) -> Cell {
+        Cell {
+            top: top.chars().collect(),
+            mid: mid.chars().collect(),
+            bot: bot.chars().collect(),
+        }
+    }
+
+    fn width(&self) -> usize {
+        self.mid.len()
+    }
+
+    // Pad the cell to `width`, keeping its center on the column's center.
+    fn pad(self, width: usize, wire: char) -> Cell {
+        let left = (width - 1) / 2 - (self.width() - 1) / 2;
+        let right = width - self.width() - left;
+        let pad = |row: Vec<char>, fill: char| {
+            let mut out = vec![fill; left];
+            out.extend(row);
+            out.extend(std::iter::repeat_n(fill, right));
+            out
+        };
+        Cell {
+            top: pad(self.top, ' '),
+            mid: pad(self.mid, wire),
+            bot: pad(self.bot, ' '),
+        }
+    }
+}
+
+// Draw a layer as one string per row of the diagram.
+fn draw_layer(wires: &[Wire], layer: &[Op]) -> Vec<String> {
+    let mut cells: Vec<Option<Cell>> = wires.iter().map(|_| None).collect();
+    for op in layer {
+        draw_op(op, wires, &mut cells);
+    }
+    let width = cells.iter().flatten().map(Cell::width).max().unwrap_or(1);
+    let cells: Vec<Cell> = cells
+        .into_iter()
+        .zip(wires)
+        .map(|(cell, wire)| {
+            let wire = wire_char(wire);
+            cell.unwrap_or_else(|| Cell::new(" ", &wire.to_string(), " "))
+                .pad(width, wire)
+        })
+        .collect();
+
+    let mut rows = vec![cells[0].top.iter().collect()];
+    for (i, cell) in cells.iter().enumerate() {
+        rows.push(cell.mid.iter().collect());
+        match cells.get(i + 1) {
+            Some(below) => rows.push(
+                cell.bot
+                    .iter()
+                    .zip(&below.top)
+                    .map(|(upper, lower)| merge(*upper, *lower))
+                    .collect(),
+            ),
+            None => rows.push(cell.bot.iter().collect()),
+        }
+    }
+    rows
+}
+
+// Combine the bottom row of a cell with the top row of the cell below it.
+fn merge(upper: char, lower: char) -> char {
+    match (upper, lower) {
+        (' ', c) | (c, ' ') => c,
+        (a, b) if a == b => a,
+        ('└', '┌') => '├',
+        ('┘', '┐') => '┤',
+        ('─', '┌' | '┐') => '┬',
+        ('└' | '┘', '─') => '┴',
+        ('└', '┐') | ('┘', '┌') | ('┬', '┴') => '┼',
+        // Vertical lines and barriers give way to anything else.
+        ('│' | '║' | '░', c) | (c, '│' | '║' | '░') => c,
+        (c, _) => c,
+    }
+}
+
+fn draw_op(op: &Op, wires: &[Wire], cells: &mut [Option<Cell>]) {
+    let Some((top, bottom)) = op.span() else {
+        return;
+    };
+    let label = box_label(op);
+    // Draw a vertical line across the wires in `range` that are still free.
+    let cross = |cells: &mut [Option<Cell>], range: RangeInclusive<usize>, double: bool| {
+        for wire in range {
+            if cells[wire].is_none() {
+                cells[wire] = Some(crossing(wires[wire].classical, double));
+            }
+        }
+    };
+    match &op.kind {
+        OpKind::Gate { controls, targets } => {
+            let first = *targets.iter().min().unwrap();
+            let last = *targets.iter().max().unwrap();
+            let up = (first > top).then_some('┴');
+            let down = (last < bottom).then_some('┬');
+            if targets.len() == 1 {
+                cells[first] = Some(boxed(&label, up, down));
+            } else {
+                let index = |wire| {
+                    targets
+                        .iter()
+                        .position(|target| *target == wire)
+                        .map(|i| i.to_string())
+                };
+                multi_box(cells, first..=last, index, &label, up, down);
+            }
+            for control in controls {
+                cells[*control] = Some(node('■', *control > top, *control < bottom, false));
+            }
+            cross(cells, top..=bottom, false);
+        }
+        OpKind::Dots(qubits) => {
+            for qubit in qubits {
+                cells[*qubit] = Some(node('■', *qubit > top, *qubit < bottom, false));
+            }
+            cross(cells, top..=bottom, false);
+        }
+        OpKind::Swap { controls, targets } => {
+            for (qubit, mark) in controls
+                .iter()
+                .map(|control| (control, '■'))
+                .chain(targets.iter().map(|target| (target, 'X')))
+            {
+                cells[*qubit] = Some(node(mark, *qubit > top, *qubit < bottom, false));
+            }
+            cross(cells, top..=bottom, false);
+        }
+        OpKind::Measure { qubit, clbit } => {
+            cells[*qubit] = Some(Cell::new("┌─┐", "┤M├", "└╥┘"));
+            cells[*clbit] = Some(Cell::new("║", "╩", " "));
+            cross(cells, top..=bottom, true);
+        }
+        OpKind::Reset(qubit) => {
+            cells[*qubit] = Some(Cell::new("   ", &label, "   "));
+        }
+        OpKind::Barrier(qubits) => {
+            for qubit in qubits {
+                cells[*qubit] = Some(Cell::new("░", "░", "░"));
+            }
+        }
+        OpKind::ControlFlow { qubits, condition } => {
+            let down = (!condition.is_empty()).then_some('╥');
+            if let (Some(first), Some(last)) = (qubits.iter().min(), qubits.iter().max()) {
+                if first == last {
+                    cells[*first] = Some(boxed(&label, None, down));
+                } else {
+                    let index = |wire| qubits.contains(&wire).then(String::new);
+                    multi_box(cells, *first..=*last, index, &label, None, down);
+                }
+            }
+            for (clbit, value) in condition {
+                let mark = if *value == Some(false) { 'o' } else { '■' };
+                cells[*clbit] = Some(node(mark, *clbit > top, *clbit < bottom, true));
+            }
+            cross(cells, top..=bottom, true);
+        }
+    }
+}
+
+// The text in an op's box, with its parameters.
+fn box_label(op: &Op) -> String {
+    if op.params.is_empty() {
+        return op.label.clone();
+    }
+    let params: Vec<String> = op
+        .params
+        .iter()
+        .map(|param| format_param(param, "π"))
+        .collect();
+    format!("{}({})", op.label, params.join(","))
+}
+
+// A mark on a wire, connected to the wires above and below it.
+fn node(mark: char, up: bool, down: bool, double: bool) -> Cell {
+    let line = if double { "║" } else { "│" };
+    Cell::new(
+        if up { line } else { " " },
+        &mark.to_string(),
+        if down { line } else { " " },
+    )
+}
+
+// A vertical line passing over a wire.
+fn crossing(classical: bool, double: bool) -> Cell {
+    let (line, mid) = match (double, classical) {
+        (false, false) => ("│", "┼"),
+        (false, true) => ("│", "╪"),
+        (true, false) => ("║", "╫"),
+        (true, true) => ("║", "╬"),
+    };
+    Cell::new(line, mid, line)
+}
+
+// A horizontal box edge of `width` characters with an optional connector in
+// its center.
+fn edge(left: char, right: char, width: usize, connector: Option<char>) -> String {
+    let mut edge = vec!['─'; width];
+    edge[0] = left;
+    edge[width - 1] = right;
+    if let Some(connector) = connector {
+        edge[(width - 1) / 2] = connector;
+    }
+    edge.into_iter().collect()
+}
+
+// A box around `label` on a single wire.
+fn boxed(label: &str, up: Option<char>, down: Option<char>) -> Cell {
+    let width = label.chars().count() + 4;
+    Cell::new(
+        &edge('┌', '┐', width, up),
+        &format!("┤ {label} ├"),
+        &edge('└', '┘', width, down),
+    )
+}
+
+// A box around `label` covering the wires in `range`. Wires the op acts on
+// are given an index by `index`, which is shown at the left edge.
+fn multi_box(
+    cells: &mut [Option<Cell>],
+    range: RangeInclusive<usize>,
+    index: impl Fn(usize) -> Option<String>,
+    label: &str,
+    up: Option<char>,
+    down: Option<char>,
+) {
+    let (first, last) = (*range.start(), *range.end());
+    let indices: Vec<Option<String>> = range.clone().map(&index).collect();
+    let index_width = indices
+        .iter()
+        .flatten()
+        .map(|index| index.chars().count())
+        .max()
+        .unwrap_or(0);
+    let label_width = label.chars().count();
+    let inner = index_width + label_width + 2;
+    let row = |index: &str, label: &str, edges: (char, char)| {
+        format!(
+            "{}{index:<index_width$} {label:^label_width$} {}",
+            edges.0, edges.1
+        )
+    };
+    // The label goes on the middle row of the box, which is the row of a
+    // wire or the row between two wires.
+    let middle = last - first;
+    let label_at = |row: usize| if row == middle { label } else { "" };
+    let blank = ('│', '│');
+    for (k, wire) in range.enumerate() {
+        let edges = match indices[k] {
+            Some(_) => ('┤', '├'),
+            None => blank,
+        };
+        let top = match k {
+            0 => edge('┌', '┐', inner + 2, up),
+            _ => row("", "", blank),
+        };
+        let mid = row(indices[k].as_deref().unwrap_or(""), label_at(2 * k), edges);
+        let bot = match wire == last {
+            true => edge('└', '┘', inner + 2, down),
+            false => row("", label_at(2 * k + 1), blank),
+        };
+        cells[wire] = Some(Cell::new(&top, &mid, &bot));
+    }
+}
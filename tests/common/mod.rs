@@ -0,0 +1,600 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+//! A small dense statevector simulator used to check circuits in tests.
+//!
+//! Qubit `k` is bit `k` of a basis state index, and the qubits of a
+//! multi-qubit gate matrix follow the instruction's qubit order, matching
+//! Qiskit's little-endian convention.
+
+#![allow(dead_code)]
+
+use qiskit_rs::{BitTerm, Complex64, QuantumCircuit, SparseObservable};
+use std::collections::HashMap;
+use std::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_2, FRAC_PI_4};
+
+pub const TOL: f64 = 1e-9;
+
+fn c64(re: f64, im: f64) -> Complex64 {
+    Complex64::new(re, im)
+}
+
+/// Return the real number `re` as a complex coefficient.
+pub fn c(re: f64) -> Complex64 {
+    Complex64::new(re, 0.0)
+}
+
+/// A dense square matrix stored row-major.
+#[derive(Clone, Debug)]
+pub struct Matrix {
+    pub dim: usize,
+    pub data: Vec<Complex64>,
+}
+
+impl Matrix {
+    pub fn identity(dim: usize) -> Matrix {
+        let mut data = vec![Complex64::ZERO; dim * dim];
+        for i in 0..dim {
+            data[i * dim + i] = Complex64::ONE;
+        }
+        Matrix { dim, data }
+    }
+
+    pub fn from_rows(rows: &[&[Complex64]]) -> Matrix {
+        let dim = rows.len();
+        Matrix {
+            dim,
+            data: rows.iter().flat_map(|row| row.iter().copied()).collect(),
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Complex64 {
+        self.data[row * self.dim + col]
+    }
+
+    pub fn scale(&self, factor: Complex64) -> Matrix {
+        Matrix {
+            dim: self.dim,
+            data: self.data.iter().map(|x| *x * factor).collect(),
+        }
+    }
+
+    pub fn matmul(&self, other: &Matrix) -> Matrix {
+        let dim = self.dim;
+        let mut data = vec![Complex64::ZERO; dim * dim];
+        for i in 0..dim {
+            for k in 0..dim {
+                let a = self.get(i, k);
+                for j in 0..dim {
+                    data[i * dim + j] += a * other.get(k, j);
+                }
+            }
+        }
+        Matrix { dim, data }
+    }
+
+    pub fn adjoint(&self) -> Matrix {
+        let dim = self.dim;
+        let mut data = vec![Complex64::ZERO; dim * dim];
+        for i in 0..dim {
+            for j in 0..dim {
+                data[j * dim + i] = self.get(i, j).conj();
+            }
+        }
+        Matrix { dim, data }
+    }
+
+    /// Extend the matrix to one controlled on `num_ctrl` extra low qubits.
+    pub fn controlled(&self, num_ctrl: u32) -> Matrix {
+        let mask = (1usize << num_ctrl) - 1;
+        let dim = self.dim << num_ctrl;
+        let mut out = Matrix::identity(dim);
+        for i in 0..self.dim {
+            for j in 0..self.dim {
+                out.data[((i << num_ctrl) | mask) * dim + ((j << num_ctrl) | mask)] =
+                    self.get(i, j);
+            }
+        }
+        out
+    }
+}
+
+fn diag(phase: f64) -> Matrix {
+    Matrix::from_rows(&[
+        &[c64(1., 0.), c64(0., 0.)],
+        &[c64(0., 0.), Complex64::cis(phase)],
+    ])
+}
+
+fn u(theta: f64, phi: f64, lam: f64) -> Matrix {
+    let (cos, sin) = ((theta / 2.).cos(), (theta / 2.).sin());
+    Matrix::from_rows(&[
+        &[c64(cos, 0.), -Complex64::cis(lam).scale(sin)],
+        &[
+            Complex64::cis(phi).scale(sin),
+            Complex64::cis(phi + lam).scale(cos),
+        ],
+    ])
+}
+
+/// Return `exp(-i theta / 2 P)` where `paulis[k]` acts on the gate's qubit `k`.
+pub fn pauli_rotation(paulis: &str, theta: f64) -> Matrix {
+    let string = pauli_string(paulis);
+    let mut out = Matrix::identity(string.dim).scale(c64((theta / 2.).cos(), 0.));
+    for (x, p) in out.data.iter_mut().zip(&string.data) {
+        *x += *p * c64(0., -(theta / 2.).sin());
+    }
+    out
+}
+
+/// Return the matrix of a Pauli string where `paulis[k]` acts on qubit `k`.
+pub fn pauli_string(paulis: &str) -> Matrix {
+    let single = |p: char| match p {
+        'I' => Matrix::identity(2),
+        'X' => Matrix::from_rows(&[&[c64(0., 0.), c64(1., 0.)], &[c64(1., 0.), c64(0., 0.)]]),
+        'Y' => Matrix::from_rows(&[&[c64(0., 0.), c64(0., -1.)], &[c64(0., 1.), c64(0., 0.)]]),
+        'Z' => Matrix::from_rows(&[&[c64(1., 0.), c64(0., 0.)], &[c64(0., 0.), c64(-1., 0.)]]),
+        _ => panic!("unknown Pauli {p}"),
+    };
+    let factors: Vec<Matrix> = paulis.chars().map(single).collect();
+    let dim = 1 << factors.len();
+    let mut data = vec![Complex64::ZERO; dim * dim];
+    for i in 0..dim {
+        for j in 0..dim {
+            data[i * dim + j] = factors
+                .iter()
+                .enumerate()
+                .fold(Complex64::ONE, |acc, (k, f)| {
+                    acc * f.get((i >> k) & 1, (j >> k) & 1)
+                });
+        }
+    }
+    Matrix { dim, data }
+}
+
+/// Return the matrix of a standard gate by its instruction name.
+pub fn gate_matrix(name: &str, params: &[f64]) -> Matrix {
+    let x = || Matrix::from_rows(&[&[c64(0., 0.), c64(1., 0.)], &[c64(1., 0.), c64(0., 0.)]]);
+    let sx = || {
+        Matrix::from_rows(&[
+            &[c64(0.5, 0.5), c64(0.5, -0.5)],
+            &[c64(0.5, -0.5), c64(0.5, 0.5)],
+        ])
+    };
+    let swap = || {
+        let mut m = Matrix::identity(4);
+        m.data[4 + 1] = Complex64::ZERO;
+        m.data[2 * 4 + 2] = Complex64::ZERO;
+        m.data[4 + 2] = Complex64::ONE;
+        m.data[2 * 4 + 1] = Complex64::ONE;
+        m
+    };
+    let base = |name: &str| gate_matrix(name, params);
+    let s2 = FRAC_1_SQRT_2;
+    match name {
+        "id" => Matrix::identity(2),
+        "x" => x(),
+        "y" => Matrix::from_rows(&[&[c64(0., 0.), c64(0., -1.)], &[c64(0., 1.), c64(0., 0.)]]),
+        "z" => diag(std::f64::consts::PI),
+        "h" => Matrix::from_rows(&[&[c64(s2, 0.), c64(s2, 0.)], &[c64(s2, 0.), c64(-s2, 0.)]]),
+        "s" => diag(FRAC_PI_2),
+        "sdg" => diag(-FRAC_PI_2),
+        "t" => diag(FRAC_PI_4),
+        "tdg" => diag(-FRAC_PI_4),
+        "sx" => sx(),
+        "sxdg" => sx().adjoint(),
+        "p" | "u1" => diag(params[0]),
+        "rx" => pauli_rotation("X", params[0]),
+        "ry" => pauli_rotation("Y", params[0]),
+        "rz" => pauli_rotation("Z", params[0]),
+        "r" => u(params[0], params[1] - FRAC_PI_2, -params[1] + FRAC_PI_2),
+        "u" | "u3" => u(params[0], params[1], params[2]),
+        "u2" => u(FRAC_PI_2, params[0], params[1]),
+        "ch" => base("h").controlled(1),
+        "cx" => x().controlled(1),
+        "cy" => base("y").controlled(1),
+        "cz" => base("z").controlled(1),
+        "cp" | "cu1" => diag(params[0]).controlled(1),
+        "crx" => base("rx").controlled(1),
+        "cry" => base("ry").controlled(1),
+        "crz" => base("rz").controlled(1),
+        "cs" => base("s").controlled(1),
+        "csdg" => base("sdg").controlled(1),
+        "csx" => sx().controlled(1),
+        "cu" => u(params[0], params[1], params[2])
+            .scale(Complex64::cis(params[3]))
+            .controlled(1),
+        "cu3" => base("u3").controlled(1),
+        "ccx" => x().controlled(2),
+        "ccz" => base("z").controlled(2),
+        "mcx" => x().controlled(3),
+        "c3sx" => sx().controlled(3),
+        "cswap" => swap().controlled(1),
+        "swap" => swap(),
+        "iswap" => Matrix::from_rows(&[
+            &[c64(1., 0.), c64(0., 0.), c64(0., 0.), c64(0., 0.)],
+            &[c64(0., 0.), c64(0., 0.), c64(0., 1.), c64(0., 0.)],
+            &[c64(0., 0.), c64(0., 1.), c64(0., 0.), c64(0., 0.)],
+            &[c64(0., 0.), c64(0., 0.), c64(0., 0.), c64(1., 0.)],
+        ]),
+        "dcx" => Matrix::from_rows(&[
+            &[c64(1., 0.), c64(0., 0.), c64(0., 0.), c64(0., 0.)],
+            &[c64(0., 0.), c64(0., 0.), c64(0., 0.), c64(1., 0.)],
+            &[c64(0., 0.), c64(1., 0.), c64(0., 0.), c64(0., 0.)],
+            &[c64(0., 0.), c64(0., 0.), c64(1., 0.), c64(0., 0.)],
+        ]),
+        "ecr" => Matrix::from_rows(&[
+            &[c64(0., 0.), c64(s2, 0.), c64(0., 0.), c64(0., s2)],
+            &[c64(s2, 0.), c64(0., 0.), c64(0., -s2), c64(0., 0.)],
+            &[c64(0., 0.), c64(0., s2), c64(0., 0.), c64(s2, 0.)],
+            &[c64(0., -s2), c64(0., 0.), c64(s2, 0.), c64(0., 0.)],
+        ]),
+        "rxx" => pauli_rotation("XX", params[0]),
+        "ryy" => pauli_rotation("YY", params[0]),
+        "rzz" => pauli_rotation("ZZ", params[0]),
+        "rzx" => pauli_rotation("ZX", params[0]),
+        "xx_plus_yy" | "xx_minus_yy" => {
+            let (cos, sin) = ((params[0] / 2.).cos(), (params[0] / 2.).sin());
+            let (a, b) = if name == "xx_plus_yy" { (1, 2) } else { (0, 3) };
+            let mut m = Matrix::identity(4);
+            m.data[a * 4 + a] = c64(cos, 0.);
+            m.data[b * 4 + b] = c64(cos, 0.);
+            m.data[a * 4 + b] = c64(0., -sin) * Complex64::cis(-params[1]);
+            m.data[b * 4 + a] = c64(0., -sin) * Complex64::cis(params[1]);
+            m
+        }
+        // Qiskit's definitions of the relative-phase Toffoli gates.
+        "rccx" => sequence_matrix(
+            3,
+            &[
+                ("h", &[2]),
+                ("t", &[2]),
+                ("cx", &[1, 2]),
+                ("tdg", &[2]),
+                ("cx", &[0, 2]),
+                ("t", &[2]),
+                ("cx", &[1, 2]),
+                ("tdg", &[2]),
+                ("h", &[2]),
+            ],
+        ),
+        "rcccx" => sequence_matrix(
+            4,
+            &[
+                ("h", &[3]),
+                ("t", &[3]),
+                ("cx", &[2, 3]),
+                ("tdg", &[3]),
+                ("h", &[3]),
+                ("cx", &[0, 3]),
+                ("t", &[3]),
+                ("cx", &[1, 3]),
+                ("tdg", &[3]),
+                ("cx", &[0, 3]),
+                ("t", &[3]),
+                ("cx", &[1, 3]),
+                ("tdg", &[3]),
+                ("h", &[3]),
+                ("t", &[3]),
+                ("cx", &[2, 3]),
+                ("tdg", &[3]),
+                ("h", &[3]),
+            ],
+        ),
+        _ => panic!("the test simulator does not support {name}"),
+    }
+}
+
+/// Return the matrix of a sequence of parameterless gates.
+fn sequence_matrix(num_qubits: u32, gates: &[(&str, &[u32])]) -> Matrix {
+    let dim = 1 << num_qubits;
+    let mut out = Matrix::identity(dim);
+    for col in 0..dim {
+        let mut state = vec![Complex64::ZERO; dim];
+        state[col] = Complex64::ONE;
+        for (name, qubits) in gates {
+            apply_matrix(&mut state, &gate_matrix(name, &[]), qubits);
+        }
+        for (row, amp) in state.into_iter().enumerate() {
+            out.data[row * dim + col] = amp;
+        }
+    }
+    out
+}
+
+/// Apply a gate matrix to the given qubits of a statevector.
+pub fn apply_matrix(state: &mut [Complex64], matrix: &Matrix, qubits: &[u32]) {
+    let mask: usize = qubits.iter().map(|q| 1usize << q).sum();
+    let offsets: Vec<usize> = (0..matrix.dim)
+        .map(|sub| {
+            qubits
+                .iter()
+                .enumerate()
+                .filter(|(k, _)| (sub >> k) & 1 == 1)
+                .map(|(_, q)| 1usize << q)
+                .sum()
+        })
+        .collect();
+    for base in (0..state.len()).filter(|i| i & mask == 0) {
+        let old: Vec<Complex64> = offsets.iter().map(|off| state[base | off]).collect();
+        for (row, off) in offsets.iter().enumerate() {
+            state[base | off] = (0..matrix.dim).fold(Complex64::ZERO, |acc, col| {
+                acc + matrix.get(row, col) * old[col]
+            });
+        }
+    }
+}
+
+/// Evolve `state` through every instruction of the circuit.
+pub fn evolve(qc: &QuantumCircuit, state: &mut [Complex64]) {
+    for inst in qc.instructions() {
+        match inst.name {
+            "barrier" => {}
+            "global_phase" => {
+                let phase = Complex64::cis(inst.params[0]);
+                state.iter_mut().for_each(|amp| *amp *= phase);
+            }
+            name => apply_matrix(state, &gate_matrix(name, inst.params), inst.qubits),
+        }
+    }
+}
+
+/// Bind the parameters of the circuit to `values`, in the order of
+/// `QuantumCircuit::parameters`.
+pub fn bind(qc: &QuantumCircuit, values: &[f64]) -> QuantumCircuit {
+    assert_eq!(qc.num_parameters(), values.len());
+    let values = qc
+        .parameters()
+        .into_iter()
+        .zip(values.iter().copied())
+        .collect();
+    qc.assign_parameters(&values).unwrap()
+}
+
+/// Return the statevector produced by the circuit from the all-zero state.
+pub fn statevector(qc: &QuantumCircuit) -> Vec<Complex64> {
+    let mut state = vec![Complex64::ZERO; 1 << qc.num_qubits()];
+    state[0] = Complex64::ONE;
+    evolve(qc, &mut state);
+    state
+}
+
+/// Return the unitary matrix of the circuit.
+pub fn unitary(qc: &QuantumCircuit) -> Matrix {
+    let dim = 1 << qc.num_qubits();
+    let mut out = Matrix::identity(dim);
+    for col in 0..dim {
+        let mut state = vec![Complex64::ZERO; dim];
+        state[col] = Complex64::ONE;
+        evolve(qc, &mut state);
+        for (row, amp) in state.into_iter().enumerate() {
+            out.data[row * dim + col] = amp;
+        }
+    }
+    out
+}
+
+/// Return the counts of `shots` ideal runs of a circuit ending in
+/// measurements, rounding each outcome's probability times `shots`.
+///
+/// Bitstrings put the last classical bit first, as Qiskit does.
+pub fn exact_counts(qc: &QuantumCircuit, shots: u64) -> HashMap<String, u64> {
+    let mut state = vec![Complex64::ZERO; 1 << qc.num_qubits()];
+    state[0] = Complex64::ONE;
+    let mut measured = Vec::new();
+    for inst in qc.instructions() {
+        match inst.name {
+            "barrier" => {}
+            "measure" => measured.push((inst.qubits[0], inst.clbits[0])),
+            "global_phase" => {
+                let phase = Complex64::cis(inst.params[0]);
+                state.iter_mut().for_each(|amp| *amp *= phase);
+            }
+            name => {
+                assert!(measured.is_empty(), "{name} after a measurement");
+                apply_matrix(&mut state, &gate_matrix(name, inst.params), inst.qubits)
+            }
+        }
+    }
+    let mut probabilities: HashMap<String, f64> = HashMap::new();
+    for (index, amp) in state.iter().enumerate() {
+        let mut bits = vec!['0'; qc.num_clbits() as usize];
+        for (qubit, clbit) in &measured {
+            if (index >> qubit) & 1 == 1 {
+                bits[*clbit as usize] = '1';
+            }
+        }
+        let bitstring: String = bits.into_iter().rev().collect();
+        *probabilities.entry(bitstring).or_default() += amp.norm_sqr();
+    }
+    probabilities
+        .into_iter()
+        .map(|(bitstring, p)| (bitstring, (p * shots as f64).round() as u64))
+        .filter(|(_, count)| *count > 0)
+        .collect()
+}
+
+/// Return the dense matrix of an observable.
+pub fn observable_matrix(obs: &SparseObservable) -> Matrix {
+    let single = |bit: BitTerm| {
+        let h = 0.5;
+        match bit {
+            BitTerm::X => {
+                Matrix::from_rows(&[&[c64(0., 0.), c64(1., 0.)], &[c64(1., 0.), c64(0., 0.)]])
+            }
+            BitTerm::Plus => {
+                Matrix::from_rows(&[&[c64(h, 0.), c64(h, 0.)], &[c64(h, 0.), c64(h, 0.)]])
+            }
+            BitTerm::Minus => {
+                Matrix::from_rows(&[&[c64(h, 0.), c64(-h, 0.)], &[c64(-h, 0.), c64(h, 0.)]])
+            }
+            BitTerm::Y => {
+                Matrix::from_rows(&[&[c64(0., 0.), c64(0., -1.)], &[c64(0., 1.), c64(0., 0.)]])
+            }
+            BitTerm::Right => {
+                Matrix::from_rows(&[&[c64(h, 0.), c64(0., -h)], &[c64(0., h), c64(h, 0.)]])
+            }
+            BitTerm::Left => {
+                Matrix::from_rows(&[&[c64(h, 0.), c64(0., h)], &[c64(0., -h), c64(h, 0.)]])
+            }
+            BitTerm::Z => {
+                Matrix::from_rows(&[&[c64(1., 0.), c64(0., 0.)], &[c64(0., 0.), c64(-1., 0.)]])
+            }
+            BitTerm::Zero => {
+                Matrix::from_rows(&[&[c64(1., 0.), c64(0., 0.)], &[c64(0., 0.), c64(0., 0.)]])
+            }
+            BitTerm::One => {
+                Matrix::from_rows(&[&[c64(0., 0.), c64(0., 0.)], &[c64(0., 0.), c64(1., 0.)]])
+            }
+        }
+    };
+    let dim = 1 << obs.num_qubits();
+    let mut out = Matrix {
+        dim,
+        data: vec![Complex64::ZERO; dim * dim],
+    };
+    for term in obs.terms() {
+        let factors: Vec<(usize, Matrix)> = term
+            .indices
+            .iter()
+            .zip(&term.bit_terms)
+            .map(|(q, bit)| (*q as usize, single(*bit)))
+            .collect();
+        for i in 0..dim {
+            for j in 0..dim {
+                let untouched = factors.iter().fold(i ^ j, |acc, (q, _)| acc & !(1 << q));
+                if untouched != 0 {
+                    continue;
+                }
+                out.data[i * dim + j] += factors.iter().fold(term.coeff, |acc, (q, m)| {
+                    acc * m.get((i >> q) & 1, (j >> q) & 1)
+                });
+            }
+        }
+    }
+    out
+}
+
+/// Return `exp(factor * matrix)`, from a Taylor series with scaling and
+/// squaring.
+pub fn expm(matrix: &Matrix, factor: Complex64) -> Matrix {
+    let scaled = matrix.scale(factor);
+    let norm: f64 = scaled.data.iter().map(|x| x.norm()).sum();
+    let squarings = norm.max(1.).log2().ceil() as i32 + 4;
+    let small = scaled.scale(c64(0.5f64.powi(squarings), 0.));
+    let mut out = Matrix::identity(matrix.dim);
+    let mut power = Matrix::identity(matrix.dim);
+    for k in 1..24 {
+        power = power.matmul(&small).scale(c64(1. / k as f64, 0.));
+        for (x, p) in out.data.iter_mut().zip(&power.data) {
+            *x += *p;
+        }
+    }
+    for _ in 0..squarings {
+        out = out.matmul(&out);
+    }
+    out
+}
+
+/// Return the eigenvalues of a hermitian matrix in ascending order.
+///
+/// `A + iB` has the same eigenvalues as the real symmetric matrix
+/// `[[A, -B], [B, A]]`, each twice, which are found with Jacobi rotations.
+pub fn eigenvalues(matrix: &Matrix) -> Vec<f64> {
+    let n = matrix.dim;
+    let m = 2 * n;
+    let mut a = vec![0.; m * m];
+    for i in 0..n {
+        for j in 0..n {
+            let h = matrix.get(i, j);
+            a[i * m + j] = h.re;
+            a[(i + n) * m + j + n] = h.re;
+            a[i * m + j + n] = -h.im;
+            a[(i + n) * m + j] = h.im;
+        }
+    }
+    for _ in 0..100 {
+        let off: f64 = (0..m)
+            .flat_map(|p| (0..m).filter(move |q| *q != p).map(move |q| (p, q)))
+            .map(|(p, q)| a[p * m + q].powi(2))
+            .sum();
+        if off < 1e-24 {
+            break;
+        }
+        for p in 0..m {
+            for q in p + 1..m {
+                let apq = a[p * m + q];
+                if apq.abs() < 1e-300 {
+                    continue;
+                }
+                let theta = (a[q * m + q] - a[p * m + p]) / (2. * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.).sqrt());
+                let cos = 1. / (t * t + 1.).sqrt();
+                let sin = t * cos;
+                for k in 0..m {
+                    let (akp, akq) = (a[k * m + p], a[k * m + q]);
+                    a[k * m + p] = cos * akp - sin * akq;
+                    a[k * m + q] = sin * akp + cos * akq;
+                }
+                for k in 0..m {
+                    let (apk, aqk) = (a[p * m + k], a[q * m + k]);
+                    a[p * m + k] = cos * apk - sin * aqk;
+                    a[q * m + k] = sin * apk + cos * aqk;
+                }
+            }
+        }
+    }
+    let mut values: Vec<f64> = (0..m).map(|i| a[i * m + i]).collect();
+    values.sort_by(f64::total_cmp);
+    values.into_iter().step_by(2).collect()
+}
+
+/// Return whether two matrices commute.
+pub fn commute(a: &Matrix, b: &Matrix) -> bool {
+    let (ab, ba) = (a.matmul(b), b.matmul(a));
+    ab.data
+        .iter()
+        .zip(&ba.data)
+        .all(|(x, y)| (*x - *y).norm() < TOL)
+}
+
+/// Return `<state| matrix |state>`.
+pub fn expectation(state: &[Complex64], matrix: &Matrix) -> Complex64 {
+    let mut out = Complex64::ZERO;
+    for (i, bra) in state.iter().enumerate() {
+        for (j, ket) in state.iter().enumerate() {
+            out += bra.conj() * matrix.get(i, j) * *ket;
+        }
+    }
+    out
+}
+
+pub fn assert_close(actual: &[Complex64], expected: &[Complex64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (i, (a, e)) in actual.iter().zip(expected).enumerate() {
+        assert!((*a - *e).norm() < TOL, "index {i}: {a} != {e}");
+    }
+}
+
+/// Assert that two vectors are equal up to a global phase.
+pub fn assert_close_up_to_phase(actual: &[Complex64], expected: &[Complex64]) {
+    assert_eq!(actual.len(), expected.len());
+    let pivot = expected
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.norm().total_cmp(&b.1.norm()))
+        .map(|(i, _)| i)
+        .unwrap();
+    let phase = actual[pivot] / expected[pivot];
+    let rotated: Vec<Complex64> = expected.iter().map(|e| *e * phase).collect();
+    assert_close(actual, &rotated);
+}
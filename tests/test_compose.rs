@@ -0,0 +1,144 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+mod common;
+
+use common::{Matrix, assert_close, unitary};
+use qiskit_rs::{QiskitError, QuantumCircuit, StandardGate};
+
+fn bell() -> QuantumCircuit {
+    let mut qc = QuantumCircuit::new(2, 2);
+    qc.h(0);
+    qc.cx(0, 1);
+    qc
+}
+
+#[test]
+fn test_compose_with_mapping() {
+    let mut qc = QuantumCircuit::new(3, 3);
+    let mut other = bell();
+    other.measure(1, 0);
+    assert_eq!(qc.compose(&other, &[2, 0], &[1, 2]), QiskitError::Success);
+
+    let insts: Vec<_> = qc.instructions().collect();
+    assert_eq!(insts.len(), 3);
+    assert_eq!(insts[0].name, "h");
+    assert_eq!(insts[0].qubits, &[2]);
+    assert_eq!(insts[1].name, "cx");
+    assert_eq!(insts[1].qubits, &[2, 0]);
+    assert_eq!(insts[2].name, "measure");
+    assert_eq!(insts[2].qubits, &[0]);
+    assert_eq!(insts[2].clbits, &[1]);
+}
+
+#[test]
+fn test_compose_errors() {
+    let mut qc = QuantumCircuit::new(3, 1);
+    let other = bell();
+    assert_eq!(
+        qc.compose(&other, &[0], &[0, 0]),
+        QiskitError::MismatchedQubits
+    );
+    assert_eq!(
+        qc.compose(&other, &[0, 1], &[0]),
+        QiskitError::MismatchedClbits
+    );
+    let mut qc = QuantumCircuit::new(3, 2);
+    assert_eq!(
+        qc.compose(&other, &[0, 3], &[0, 1]),
+        QiskitError::IndexError
+    );
+    assert_eq!(
+        qc.compose(&other, &[1, 1], &[0, 1]),
+        QiskitError::CInputError
+    );
+    assert_eq!(qc.num_instructions(), 0);
+}
+
+#[test]
+fn test_tensor() {
+    let mut top = QuantumCircuit::new(1, 0);
+    top.x(0);
+    let mut bottom = QuantumCircuit::new(2, 0);
+    bottom.h(0);
+    bottom.cx(0, 1);
+    let qc = top.tensor(&bottom).unwrap();
+    assert_eq!(qc.num_qubits(), 3);
+
+    let insts: Vec<_> = qc.instructions().collect();
+    assert_eq!(insts[0].qubits, &[0]);
+    assert_eq!(insts[1].qubits, &[0, 1]);
+    assert_eq!(insts[2].name, "x");
+    assert_eq!(insts[2].qubits, &[2]);
+}
+
+#[test]
+fn test_inverse_of_every_standard_gate() {
+    for gate in StandardGate::ALL {
+        let num_qubits = gate.num_qubits().max(1);
+        let qubits: Vec<u32> = (0..gate.num_qubits()).rev().collect();
+        let params: Vec<f64> = (0..gate.num_params())
+            .map(|i| 0.3 + 0.4 * f64::from(i))
+            .collect();
+        let mut qc = QuantumCircuit::new(num_qubits, 0);
+        assert_eq!(qc.append_gate(gate, &qubits, &params), QiskitError::Success);
+        let inverse = qc.inverse().unwrap();
+        let product = unitary(&qc).matmul(&unitary(&inverse));
+        let identity = Matrix::identity(1 << num_qubits);
+        assert_close(&product.data, &identity.data);
+    }
+}
+
+#[test]
+fn test_inverse_reverses_order() {
+    let mut qc = QuantumCircuit::new(2, 0);
+    qc.h(0);
+    qc.s(1);
+    qc.barrier(&[0, 1]);
+    qc.rz(0.5, 1);
+    let inverse = qc.inverse().unwrap();
+    let names: Vec<String> = inverse
+        .instructions()
+        .map(|inst| inst.name.to_string())
+        .collect();
+    assert_eq!(names, ["rz", "barrier", "sdg", "h"]);
+    assert_eq!(inverse.instructions().next().unwrap().params, &[-0.5]);
+}
+
+#[test]
+fn test_inverse_rejects_measure() {
+    let mut qc = bell();
+    qc.measure(0, 0);
+    assert_eq!(
+        qc.inverse().err(),
+        Some(QiskitError::UnsupportedInstruction)
+    );
+    let mut qc = QuantumCircuit::new(1, 0);
+    qc.reset(0);
+    assert_eq!(
+        qc.inverse().err(),
+        Some(QiskitError::UnsupportedInstruction)
+    );
+}
+
+#[test]
+fn test_repeat() {
+    let mut qc = QuantumCircuit::new(2, 0);
+    qc.rx(0.2, 0);
+    qc.cx(0, 1);
+    let repeated = qc.repeat(3).unwrap();
+    assert_eq!(repeated.num_instructions(), 6);
+    let single = unitary(&qc);
+    let expected = single.matmul(&single).matmul(&single);
+    assert_close(&unitary(&repeated).data, &expected.data);
+    assert_eq!(qc.repeat(0).unwrap().num_instructions(), 0);
+}
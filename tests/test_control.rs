@@ -0,0 +1,110 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+mod common;
+
+use common::{Matrix, assert_close, unitary};
+use qiskit_rs::{QiskitError, QuantumCircuit, StandardGate};
+
+fn names(qc: &QuantumCircuit) -> Vec<String> {
+    qc.instructions()
+        .map(|inst| inst.name.to_string())
+        .collect()
+}
+
+#[test]
+fn test_controlled_counterparts() {
+    let mut qc = QuantumCircuit::new(1, 0);
+    qc.x(0);
+    assert_eq!(names(&qc.control(1).unwrap()), ["cx"]);
+    assert_eq!(names(&qc.control(2).unwrap()), ["ccx"]);
+    assert_eq!(names(&qc.control(3).unwrap()), ["mcx"]);
+
+    let mut qc = QuantumCircuit::new(1, 0);
+    qc.rz(0.3, 0);
+    let controlled = qc.control(1).unwrap();
+    assert_eq!(names(&controlled), ["crz"]);
+    let inst = controlled.instructions().next().unwrap();
+    assert_eq!(inst.qubits, &[0, 1]);
+    assert_eq!(inst.params, &[0.3]);
+
+    let mut qc = QuantumCircuit::new(2, 0);
+    qc.cx(1, 0);
+    let controlled = qc.control(1).unwrap();
+    assert_eq!(names(&controlled), ["ccx"]);
+    assert_eq!(controlled.instructions().next().unwrap().qubits, &[0, 2, 1]);
+
+    assert_eq!(StandardGate::CX.base_gate(), StandardGate::X);
+    assert_eq!(StandardGate::C3SX.base_gate(), StandardGate::SX);
+    assert_eq!(StandardGate::ECR.base_gate(), StandardGate::ECR);
+    assert_eq!(StandardGate::CZ.controlled(1), Some(StandardGate::CCZ));
+    assert_eq!(StandardGate::H.controlled(2), None);
+}
+
+#[test]
+fn test_control_every_standard_gate() {
+    for gate in StandardGate::ALL {
+        let num_qubits = gate.num_qubits().max(1);
+        let qubits: Vec<u32> = (0..gate.num_qubits()).rev().collect();
+        let params: Vec<f64> = (0..gate.num_params())
+            .map(|i| 0.3 + 0.4 * f64::from(i))
+            .collect();
+        let mut qc = QuantumCircuit::new(num_qubits, 0);
+        assert_eq!(qc.append_gate(gate, &qubits, &params), QiskitError::Success);
+        for num_ctrl in 1..=3 {
+            let controlled = qc.control(num_ctrl).unwrap();
+            assert_eq!(controlled.num_qubits(), num_qubits + num_ctrl);
+            let expected = unitary(&qc).controlled(num_ctrl);
+            assert_close(&unitary(&controlled).data, &expected.data);
+        }
+    }
+}
+
+#[test]
+fn test_control_circuit() {
+    let mut qc = QuantumCircuit::new(3, 0);
+    qc.h(0);
+    qc.global_phase(0.7);
+    qc.cx(0, 1);
+    qc.barrier(&[0, 1, 2]);
+    qc.u(0.1, 0.2, 0.3, 2);
+    qc.rxx(0.4, 1, 2);
+    let controlled = qc.control(2).unwrap();
+    assert_eq!(controlled.num_qubits(), 5);
+    assert_close(&unitary(&controlled).data, &unitary(&qc).controlled(2).data);
+
+    let mut qc = QuantumCircuit::new(1, 1);
+    qc.measure(0, 0);
+    assert_eq!(
+        qc.control(1).err(),
+        Some(QiskitError::UnsupportedInstruction)
+    );
+}
+
+#[test]
+fn test_power() {
+    let mut qc = QuantumCircuit::new(2, 0);
+    qc.h(0);
+    qc.cp(0.3, 0, 1);
+    qc.t(1);
+    let single = unitary(&qc);
+
+    let cubed = qc.power(3).unwrap();
+    let expected = single.matmul(&single).matmul(&single);
+    assert_close(&unitary(&cubed).data, &expected.data);
+
+    let inverse_squared = qc.power(-2).unwrap();
+    let product = unitary(&inverse_squared).matmul(&single).matmul(&single);
+    assert_close(&product.data, &Matrix::identity(4).data);
+
+    assert_eq!(qc.power(0).unwrap().num_instructions(), 0);
+}
@@ -0,0 +1,238 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+use qiskit_rs::control_flow::{CaseValue, Condition, ControlFlow};
+use qiskit_rs::transpiler::{Optimize1qGatesDecomposition, PassManager};
+use qiskit_rs::{ClassicalRegister, Parameter, QiskitError, QuantumCircuit, StandardGate, Target};
+use std::collections::HashMap;
+
+#[test]
+fn test_control_flow_instructions() {
+    let mut qc = QuantumCircuit::new(3, 2);
+    qc.h(0);
+    qc.measure(0, 0);
+    assert_eq!(
+        qc.if_else(
+            Condition::bit(0, true),
+            |body| {
+                body.x(1);
+            },
+            |body| {
+                body.cx(1, 2);
+            },
+        ),
+        QiskitError::Success
+    );
+    qc.measure(1, 1);
+    assert_eq!(
+        qc.while_loop(Condition::register(&[0, 1], 2), |body| {
+            body.h(2);
+        }),
+        QiskitError::Success
+    );
+    assert_eq!(qc.num_instructions(), 5);
+
+    let insts: Vec<_> = qc.instructions().collect();
+    let names: Vec<&str> = insts.iter().map(|inst| inst.name).collect();
+    assert_eq!(names, ["h", "measure", "if_else", "measure", "while_loop"]);
+    assert_eq!(insts[2].qubits, &[1, 2]);
+    assert_eq!(insts[2].clbits, &[0]);
+    assert_eq!(insts[4].qubits, &[2]);
+    assert_eq!(insts[4].clbits, &[0, 1]);
+    assert!(insts[3].control_flow().is_none());
+    match insts[2].control_flow() {
+        Some(ControlFlow::IfElse {
+            condition,
+            true_body,
+            false_body: Some(false_body),
+        }) => {
+            assert_eq!(condition, &Condition::bit(0, true));
+            assert_eq!(true_body.instructions().next().unwrap().name, "x");
+            assert_eq!(false_body.instructions().next().unwrap().name, "cx");
+        }
+        _ => panic!("expected an if_else with two bodies"),
+    }
+}
+
+#[test]
+fn test_nested_blocks() {
+    let mut qc = QuantumCircuit::new(2, 1);
+    qc.for_loop(0..3, |body, _| {
+        body.if_test(Condition::bit(0, false), |inner| {
+            inner.x(1);
+        });
+        body.measure(1, 0);
+    });
+    let inst = qc.instructions().next().unwrap();
+    assert_eq!(inst.name, "for_loop");
+    assert_eq!(inst.qubits, &[1]);
+    assert_eq!(inst.clbits, &[0]);
+    let Some(ControlFlow::ForLoop { indexset, body, .. }) = inst.control_flow() else {
+        panic!("expected a for_loop");
+    };
+    assert_eq!(indexset, &[0, 1, 2]);
+    let names: Vec<String> = body
+        .instructions()
+        .map(|inst| inst.name.to_string())
+        .collect();
+    assert_eq!(names, ["if_else", "measure"]);
+}
+
+#[test]
+fn test_compose_remaps_control_flow() {
+    let mut other = QuantumCircuit::new(2, 2);
+    other.h(0);
+    other.measure(0, 1);
+    other.if_test(Condition::bit(1, true), |body| {
+        body.x(1);
+    });
+    other.switch(&[0, 1], |cases| {
+        cases.case(&[1], |body| {
+            body.z(0);
+        });
+    });
+
+    let mut qc = QuantumCircuit::new(3, 3);
+    qc.x(2);
+    assert_eq!(qc.compose(&other, &[2, 0], &[2, 0]), QiskitError::Success);
+    let insts: Vec<_> = qc.instructions().collect();
+    assert_eq!(insts.len(), 5);
+    assert_eq!(insts[3].name, "if_else");
+    assert_eq!(insts[3].qubits, &[0]);
+    assert_eq!(insts[3].clbits, &[0]);
+    let Some(ControlFlow::IfElse { true_body, .. }) = insts[3].control_flow() else {
+        panic!("expected an if_else");
+    };
+    assert_eq!(true_body.num_qubits(), 3);
+    assert_eq!(true_body.instructions().next().unwrap().qubits, &[0]);
+    let Some(ControlFlow::Switch { target, cases }) = insts[4].control_flow() else {
+        panic!("expected a switch_case");
+    };
+    assert_eq!(target, &[2, 0]);
+    assert_eq!(cases[0].0, [CaseValue::Value(1)]);
+    assert_eq!(insts[4].qubits, &[2]);
+}
+
+#[test]
+fn test_loop_parameters() {
+    let theta = Parameter::new("theta");
+    let mut qc = QuantumCircuit::new(1, 0);
+    qc.for_loop([4, 1, 7], |body, i| {
+        body.rx(i * &theta, 0);
+    });
+    assert_eq!(qc.parameters(), std::slice::from_ref(&theta));
+
+    let bound = qc
+        .assign_parameters(&HashMap::from([(theta.clone(), 0.5)]))
+        .unwrap();
+    assert!(bound.parameters().is_empty());
+    let inst = bound.instructions().next().unwrap();
+    let Some(ControlFlow::ForLoop {
+        loop_parameter,
+        body,
+        ..
+    }) = inst.control_flow()
+    else {
+        panic!("expected a for_loop");
+    };
+    assert_eq!(body.parameters(), std::slice::from_ref(loop_parameter));
+}
+
+#[test]
+fn test_invalid_control_flow() {
+    let mut qc = QuantumCircuit::new(1, 2);
+    assert_eq!(
+        qc.if_test(Condition::bit(2, true), |body| {
+            body.x(0);
+        }),
+        QiskitError::IndexError
+    );
+    assert_eq!(
+        qc.while_loop(Condition::register(&[0, 1], 4), |_| {}),
+        QiskitError::CInputError
+    );
+    assert_eq!(
+        qc.switch(&[0], |cases| {
+            cases.case(&[0], |_| {}).case(&[0], |_| {});
+        }),
+        QiskitError::CInputError
+    );
+    assert_eq!(
+        qc.switch(&[0], |cases| {
+            cases.case(&[2], |_| {});
+        }),
+        QiskitError::CInputError
+    );
+    assert_eq!(qc.num_instructions(), 0);
+
+    qc.if_test(Condition::bit(0, true), |body| {
+        body.x(0);
+    });
+    assert_eq!(
+        qc.inverse().err(),
+        Some(QiskitError::UnsupportedInstruction)
+    );
+    assert_eq!(
+        qc.control(1).err(),
+        Some(QiskitError::UnsupportedInstruction)
+    );
+}
+
+#[test]
+fn test_register_condition() {
+    let mut qc = QuantumCircuit::new(1, 1);
+    let creg = ClassicalRegister::new(2, "c");
+    let clbits = qc.add_classical_register(creg);
+    assert_eq!(clbits, &[1, 2]);
+    qc.measure(0, 2);
+    assert_eq!(
+        qc.if_test(Condition::register(&clbits, 2), |body| {
+            body.x(0);
+        }),
+        QiskitError::Success
+    );
+    assert_eq!(qc.instructions().last().unwrap().clbits, &[1, 2]);
+}
+
+// Circuits with control flow can't be handed to the C API, which has no
+// control flow, so passes fail cleanly instead of dropping the operations.
+#[test]
+fn test_control_flow_not_transpiled() {
+    let mut target = Target::new(1);
+    target.add_global_instruction(StandardGate::RZ);
+    let mut pm = PassManager::new();
+    pm.append(Optimize1qGatesDecomposition);
+
+    let mut if_circuit = QuantumCircuit::new(1, 1);
+    if_circuit.measure(0, 0);
+    if_circuit.if_test(Condition::bit(0, true), |body| {
+        body.x(0);
+    });
+    let mut while_circuit = QuantumCircuit::new(1, 1);
+    while_circuit.while_loop(Condition::bit(0, false), |body| {
+        body.h(0);
+        body.measure(0, 0);
+    });
+    for mut qc in [if_circuit, while_circuit] {
+        let num_instructions = qc.num_instructions();
+        assert_eq!(
+            pm.run(&mut qc, &target).unwrap_err(),
+            QiskitError::UnsupportedInstruction
+        );
+        assert_eq!(qc.num_instructions(), num_instructions);
+        #[cfg(feature = "dag")]
+        assert_eq!(
+            qiskit_rs::DagCircuit::from_circuit(&qc).err(),
+            Some(QiskitError::UnsupportedInstruction)
+        );
+    }
+}
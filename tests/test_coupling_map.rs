@@ -0,0 +1,167 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+use qiskit_rs::target::InstructionProperties;
+use qiskit_rs::{CouplingMap, QiskitError, StandardGate, Target};
+
+#[test]
+fn test_constructors() {
+    let line = CouplingMap::line(4);
+    assert_eq!(line.num_qubits(), 4);
+    assert_eq!(line.edges().len(), 6);
+    assert!(line.has_edge(1, 2) && line.has_edge(2, 1));
+    assert!(!line.has_edge(0, 3));
+
+    let ring = CouplingMap::ring(4);
+    assert_eq!(ring.edges().len(), 8);
+    assert!(ring.has_edge(3, 0));
+
+    let grid = CouplingMap::grid(2, 3);
+    assert_eq!(grid.num_qubits(), 6);
+    assert_eq!(grid.edges().len(), 2 * 7);
+    assert_eq!(grid.neighbors(1), [0, 2, 4]);
+    assert_eq!(grid.neighbors(4), [1, 3, 5]);
+
+    let map = CouplingMap::from_edges([[0, 1], [2, 1], [0, 1]]);
+    assert_eq!(map.num_qubits(), 3);
+    assert_eq!(map.edges(), [[0, 1], [2, 1]]);
+    assert_eq!(map.neighbors(1), [0, 2]);
+    assert_eq!(map.neighbors(5), Vec::<u32>::new());
+
+    let mut map = CouplingMap::new(2);
+    assert!(map.edges().is_empty());
+    assert_eq!(map.add_qubit(), 2);
+    map.add_edge(4, 0);
+    assert_eq!(map.num_qubits(), 5);
+}
+
+#[test]
+fn test_heavy_hex() {
+    for distance in [1, 3, 5, 7] {
+        let map = CouplingMap::heavy_hex(distance);
+        assert_eq!(
+            map.num_qubits(),
+            (5 * distance * distance - 2 * distance - 1) / 2
+        );
+        assert!(map.is_connected());
+        for q in 0..map.num_qubits() {
+            assert!((1..=3).contains(&map.neighbors(q).len()) || map.num_qubits() == 1);
+        }
+        for [a, b] in map.edges() {
+            assert!(map.has_edge(*b, *a));
+        }
+    }
+
+    // Row 0 is qubits 0 to 4, bridged to row 1 (7 to 11) by qubits 5 and 6.
+    let map = CouplingMap::heavy_hex(3);
+    assert_eq!(map.edges().len(), 2 * 20);
+    assert_eq!(map.shortest_path(0, 4), Some(vec![0, 1, 2, 3, 4]));
+    assert_eq!(map.distance(0, 7), Some(2));
+    assert_eq!(map.distance(5, 10), Some(4));
+    assert_eq!(map.neighbors(6), [4, 11]);
+}
+
+#[test]
+#[should_panic(expected = "odd")]
+fn test_heavy_hex_even_distance() {
+    CouplingMap::heavy_hex(4);
+}
+
+#[test]
+fn test_paths() {
+    let grid = CouplingMap::grid(3, 3);
+    assert_eq!(grid.distance(0, 8), Some(4));
+    assert_eq!(grid.distance(4, 4), Some(0));
+    assert_eq!(grid.shortest_path(4, 4), Some(vec![4]));
+    let path = grid.shortest_path(0, 8).unwrap();
+    assert_eq!(path.len(), 5);
+    assert_eq!((path[0], path[4]), (0, 8));
+    for pair in path.windows(2) {
+        assert!(grid.has_edge(pair[0], pair[1]));
+    }
+
+    // Paths may run against the direction of the edges.
+    let directed = CouplingMap::from_edges([[0, 1], [2, 1], [2, 3]]);
+    assert_eq!(directed.shortest_path(0, 3), Some(vec![0, 1, 2, 3]));
+    assert!(directed.is_connected());
+
+    let mut split = CouplingMap::line(2);
+    split.add_edge(2, 3);
+    assert!(!split.is_connected());
+    assert_eq!(split.distance(0, 3), None);
+    assert_eq!(split.shortest_path(1, 2), None);
+    assert_eq!(split.distance(0, 9), None);
+}
+
+#[test]
+fn test_subgraph_and_reduce() {
+    let grid = CouplingMap::grid(3, 3);
+    let corner = grid.subgraph(&[4, 1, 0, 3]).unwrap();
+    assert_eq!(corner.num_qubits(), 4);
+    assert!(corner.has_edge(0, 1) && corner.has_edge(1, 2) && corner.has_edge(2, 3));
+    assert!(corner.has_edge(3, 0));
+    assert!(!corner.has_edge(0, 2));
+    assert_eq!(grid.reduce(&[4, 1, 0, 3]), Ok(corner));
+
+    let apart = grid.subgraph(&[0, 8]).unwrap();
+    assert!(apart.edges().is_empty());
+    assert_eq!(grid.reduce(&[0, 8]), Err(QiskitError::CInputError));
+    assert_eq!(grid.subgraph(&[0, 9]), Err(QiskitError::IndexError));
+    assert_eq!(grid.subgraph(&[1, 1]), Err(QiskitError::CInputError));
+}
+
+#[test]
+fn test_has_edge() {
+    let mut map = CouplingMap::from_edges([[0, 1], [1, 2], [3, 1]]);
+    assert!(map.has_edge(3, 1));
+    assert!(!map.has_edge(1, 3));
+    assert!(!map.has_edge(0, 7));
+    // Adding an edge twice keeps one copy.
+    map.add_edge(1, 2);
+    assert_eq!(map.edges(), [[0, 1], [1, 2], [3, 1]]);
+    map.add_edge(1, 3);
+    assert!(map.has_edge(1, 3));
+    assert_eq!(map.neighbors(1), [0, 2, 3]);
+}
+
+#[test]
+fn test_target_conversion() {
+    let map = CouplingMap::from_edges([[0, 1], [1, 2]]);
+    let target = map
+        .to_target(StandardGate::ECR, &[StandardGate::RZ, StandardGate::SX])
+        .unwrap();
+    assert_eq!(target.num_qubits(), 3);
+    assert_eq!(target.operation_names(), ["rz", "sx", "ecr"]);
+    assert_eq!(
+        target.qargs(StandardGate::ECR),
+        Some(vec![&[0, 1][..], &[1, 2]])
+    );
+    assert!(target.instruction_supported(StandardGate::SX, &[2]));
+    assert!(!target.instruction_supported(StandardGate::ECR, &[1, 0]));
+    assert_eq!(CouplingMap::from(&target), map);
+
+    assert_eq!(
+        map.to_target(StandardGate::H, &[]).unwrap_err(),
+        QiskitError::TargetQargMismatch
+    );
+
+    let mut target = Target::new(3);
+    target.add_global_instruction(StandardGate::CX);
+    target.add_instruction(
+        StandardGate::CZ,
+        [([2, 0], InstructionProperties::default())],
+    );
+    assert_eq!(
+        CouplingMap::from(&target),
+        CouplingMap::from_edges([[2, 0]])
+    );
+}
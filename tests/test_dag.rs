@@ -0,0 +1,212 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+#![cfg(feature = "dag")]
+
+use qiskit_rs::dag::{DagCircuit, DagNode, Wire};
+use qiskit_rs::{ClassicalRegister, Parameter, QiskitError, QuantumCircuit, QuantumRegister};
+
+fn assert_same_instructions(a: &QuantumCircuit, b: &QuantumCircuit) {
+    assert_eq!(a.num_qubits(), b.num_qubits());
+    assert_eq!(a.num_clbits(), b.num_clbits());
+    assert_eq!(a.num_instructions(), b.num_instructions());
+    for (x, y) in a.instructions().zip(b.instructions()) {
+        assert_eq!(x.name, y.name);
+        assert_eq!(x.qubits, y.qubits);
+        assert_eq!(x.clbits, y.clbits);
+        assert_eq!(
+            format!("{:?}", x.param_values()),
+            format!("{:?}", y.param_values())
+        );
+    }
+}
+
+fn names(dag: &DagCircuit, nodes: &[u32]) -> Vec<String> {
+    nodes
+        .iter()
+        .map(|node| dag.instruction(*node).unwrap().name.to_string())
+        .collect()
+}
+
+#[test]
+fn test_round_trip() {
+    let theta = Parameter::new("theta");
+    let mut qc = QuantumCircuit::new(3, 2);
+    qc.h(0);
+    qc.cx(0, 1);
+    qc.rz(&theta, 2);
+    qc.u(0.1, 2.0 * &theta, -0.3, 1);
+    qc.barrier(&[0, 1, 2]);
+    qc.global_phase(0.5);
+    qc.reset(2);
+    qc.measure(0, 0);
+    qc.measure(1, 1);
+
+    let dag = DagCircuit::from_circuit(&qc).unwrap();
+    assert_eq!(dag.num_qubits(), 3);
+    assert_eq!(dag.num_clbits(), 2);
+    assert_eq!(dag.num_op_nodes(), 9);
+    let back = dag.to_circuit();
+    assert_same_instructions(&qc, &back);
+    assert_eq!(back.parameters(), vec![theta]);
+
+    let empty = DagCircuit::from_circuit(&QuantumCircuit::new(2, 0)).unwrap();
+    assert!(empty.op_nodes().is_empty());
+    assert!(empty.layers().is_empty());
+    assert!(empty.longest_path().is_empty());
+    assert_same_instructions(&QuantumCircuit::new(2, 0), &empty.to_circuit());
+}
+
+#[test]
+fn test_registers_round_trip() {
+    let mut qc = QuantumCircuit::new(1, 0);
+    let qr = qc.add_quantum_register(QuantumRegister::new(2, "qr"));
+    let cr = qc.add_classical_register(ClassicalRegister::new(2, "cr"));
+    qc.h(0);
+    qc.cx(qr[0], qr[1]);
+    qc.measure(qr[1], cr[0]);
+
+    let back = DagCircuit::from_circuit(&qc).unwrap().to_circuit();
+    assert_same_instructions(&qc, &back);
+    assert_eq!(back.draw_text(), qc.draw_text());
+    assert!(back.draw_text().contains("qr_1"));
+}
+
+#[test]
+fn test_control_flow_unsupported() {
+    let mut qc = QuantumCircuit::new(1, 0);
+    qc.for_loop(0..2, |body, _| {
+        body.x(0);
+    });
+    assert_eq!(
+        DagCircuit::from_circuit(&qc).unwrap_err(),
+        QiskitError::UnsupportedInstruction
+    );
+}
+
+#[test]
+fn test_neighbors_and_wires() {
+    let mut qc = QuantumCircuit::new(3, 1);
+    qc.h(0);
+    qc.cx(0, 1);
+    qc.x(1);
+    qc.ccx(0, 1, 2);
+    qc.measure(2, 0);
+    let dag = DagCircuit::from_circuit(&qc).unwrap();
+    let nodes = dag.op_nodes();
+    assert_eq!(names(&dag, &nodes), ["h", "cx", "x", "ccx", "measure"]);
+    let [h, cx, x, ccx, measure] = nodes[..] else {
+        panic!("expected 5 nodes");
+    };
+
+    let in_q2 = dag.in_node(Wire::Qubit(2));
+    let out_c0 = dag.out_node(Wire::Clbit(0));
+    assert_eq!(dag.node(in_q2), Some(DagNode::In(Wire::Qubit(2))));
+    assert_eq!(dag.node(out_c0), Some(DagNode::Out(Wire::Clbit(0))));
+    assert_eq!(dag.node(h), Some(DagNode::Operation));
+    assert_eq!(dag.node(u32::MAX), None);
+
+    let mut preds = dag.predecessors(ccx);
+    preds.sort();
+    let mut expected = vec![cx, x, in_q2];
+    expected.sort();
+    assert_eq!(preds, expected);
+    let mut succs = dag.successors(ccx);
+    succs.sort();
+    let out_q0 = dag.out_node(Wire::Qubit(0));
+    let out_q1 = dag.out_node(Wire::Qubit(1));
+    let mut expected = vec![out_q0, out_q1, measure];
+    expected.sort();
+    assert_eq!(succs, expected);
+
+    // ccx follows cx on qubit 0 and x on qubit 1.
+    assert_eq!(dag.predecessor_on_wire(ccx, Wire::Qubit(0)), Some(cx));
+    assert_eq!(dag.predecessor_on_wire(ccx, Wire::Qubit(1)), Some(x));
+    assert_eq!(dag.predecessor_on_wire(ccx, Wire::Qubit(2)), Some(in_q2));
+    assert_eq!(dag.successor_on_wire(ccx, Wire::Qubit(0)), Some(out_q0));
+    // ccx also follows cx, through qubit 0, but comes after x on qubit 1.
+    assert_eq!(dag.successor_on_wire(cx, Wire::Qubit(1)), Some(x));
+    assert_eq!(dag.successor_on_wire(measure, Wire::Clbit(0)), Some(out_c0));
+    assert_eq!(dag.predecessor_on_wire(h, Wire::Qubit(1)), None);
+    assert_eq!(dag.successor_on_wire(out_c0, Wire::Clbit(0)), None);
+    assert_eq!(dag.nodes_on_wire(Wire::Qubit(1)), [cx, x, ccx]);
+    assert_eq!(dag.nodes_on_wire(Wire::Clbit(0)), [measure]);
+}
+
+#[test]
+fn test_layers() {
+    let mut qc = QuantumCircuit::new(4, 0);
+    qc.h(0);
+    qc.h(1);
+    qc.x(3);
+    qc.cx(0, 1);
+    qc.cx(2, 3);
+    qc.cz(1, 2);
+    qc.z(0);
+    let dag = DagCircuit::from_circuit(&qc).unwrap();
+
+    assert_eq!(names(&dag, &dag.front_layer()), ["h", "h", "x"]);
+    let layers: Vec<Vec<String>> = dag
+        .layers()
+        .iter()
+        .map(|layer| names(&dag, layer))
+        .collect();
+    assert_eq!(
+        layers,
+        [vec!["h", "h", "x"], vec!["cx", "cx"], vec!["cz", "z"]]
+    );
+    let path = dag.longest_path();
+    assert_eq!(path.len(), 3);
+    assert_eq!(names(&dag, &path), ["h", "cx", "cz"]);
+    for pair in path.windows(2) {
+        assert!(dag.successors(pair[0]).contains(&pair[1]));
+    }
+}
+
+#[test]
+fn test_substitute_node() {
+    let theta = Parameter::new("theta");
+    let mut qc = QuantumCircuit::new(3, 1);
+    qc.h(0);
+    qc.cx(0, 2);
+    qc.measure(2, 0);
+    let mut dag = DagCircuit::from_circuit(&qc).unwrap();
+    let cx = dag.op_nodes()[1];
+
+    let mut replacement = QuantumCircuit::new(2, 0);
+    replacement.h(1);
+    replacement.crz(&theta, 0, 1);
+    replacement.h(1);
+    let replacement = DagCircuit::from_circuit(&replacement).unwrap();
+    let new_nodes = dag.substitute_node(cx, &replacement).unwrap();
+    assert_eq!(names(&dag, &new_nodes), ["h", "crz", "h"]);
+    assert_eq!(dag.node(cx), None);
+    assert_eq!(dag.instruction(new_nodes[1]).unwrap().qubits, &[0, 2]);
+
+    let mut expected = QuantumCircuit::new(3, 1);
+    expected.h(0);
+    expected.h(2);
+    expected.crz(&theta, 0, 2);
+    expected.h(2);
+    expected.measure(2, 0);
+    assert_same_instructions(&expected, &dag.to_circuit());
+
+    let h = dag.op_nodes()[0];
+    assert_eq!(
+        dag.substitute_node(h, &replacement).unwrap_err(),
+        QiskitError::MismatchedQubits
+    );
+    assert_eq!(
+        dag.substitute_node(cx, &replacement).unwrap_err(),
+        QiskitError::IndexError
+    );
+}
@@ -0,0 +1,205 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+use qiskit_rs::control_flow::Condition;
+use qiskit_rs::visualization::DrawOptions;
+use qiskit_rs::{ClassicalRegister, Parameter, QuantumCircuit, QuantumRegister};
+use std::collections::BTreeMap;
+use std::f64::consts::PI;
+
+fn assert_snapshot(actual: &str, expected: &str) {
+    let expected = expected.strip_suffix('\n').unwrap_or(expected);
+    assert!(
+        actual == expected,
+        "drawing does not match the snapshot\n--- actual\n{actual}\n--- expected\n{expected}"
+    );
+}
+
+// The SVG and LaTeX snapshots are stored as drawn, including their final newline.
+fn assert_document(actual: &str, expected: &str) {
+    assert_snapshot(actual, &format!("{expected}\n"));
+}
+
+fn bell() -> QuantumCircuit {
+    let mut qc = QuantumCircuit::new(2, 2);
+    qc.h(0);
+    qc.cx(0, 1);
+    qc.measure(0, 0);
+    qc.measure(1, 1);
+    qc
+}
+
+// A circuit with registers, parameters, a global phase and control flow.
+fn styled() -> QuantumCircuit {
+    let mut qc = QuantumCircuit::new(1, 0);
+    let anc = qc.add_quantum_register(QuantumRegister::new(2, "anc"));
+    let out = qc.add_classical_register(ClassicalRegister::new(1, "out"));
+    qc.global_phase(PI);
+    qc.sx(0);
+    qc.crx(-PI / 3.0, anc[1], 0);
+    qc.rxx(0.75, anc[0], anc[1]);
+    qc.cswap(0, anc[0], anc[1]);
+    qc.measure(anc[1], out[0]);
+    qc.if_test(Condition::bit(out[0], false), |body| {
+        body.tdg(0);
+    });
+    qc
+}
+
+fn styled_options() -> DrawOptions {
+    DrawOptions {
+        reverse_bits: true,
+        gate_colors: BTreeMap::from([
+            ("x".to_string(), "#ffcc00".to_string()),
+            ("rxx".to_string(), "#00aa55".to_string()),
+        ]),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_draw_bell() {
+    let qc = bell();
+    assert_snapshot(&qc.draw_text(), include_str!("snapshots/text/bell.txt"));
+    assert_eq!(qc.to_string(), qc.draw_text());
+}
+
+#[test]
+fn test_draw_multi_qubit_gates() {
+    let mut qc = QuantumCircuit::new(4, 0);
+    qc.cx(2, 0);
+    qc.ccx(0, 1, 3);
+    qc.cz(1, 3);
+    qc.swap(0, 3);
+    qc.cswap(1, 0, 2);
+    qc.rzz(0.25, 3, 1);
+    qc.ecr(0, 1);
+    qc.barrier(&[0, 1, 2, 3]);
+    qc.reset(2);
+    assert_snapshot(
+        &qc.draw_text(),
+        include_str!("snapshots/text/multi_qubit.txt"),
+    );
+}
+
+#[test]
+fn test_draw_params() {
+    let theta = Parameter::new("theta");
+    let mut qc = QuantumCircuit::new(2, 0);
+    qc.global_phase(-PI / 2.0);
+    qc.rx(PI, 0);
+    qc.ry(-3.0 * PI / 4.0, 1);
+    qc.u(PI / 2.0, 0.0, 0.1, 0);
+    qc.p(2.0 * PI, 1);
+    qc.crz(1.23456789, 0, 1);
+    qc.rz(&theta, 1);
+    assert_snapshot(&qc.draw_text(), include_str!("snapshots/text/params.txt"));
+}
+
+#[test]
+fn test_draw_registers_and_control_flow() {
+    let mut qc = QuantumCircuit::new(1, 0);
+    let anc = qc.add_quantum_register(QuantumRegister::new(2, "anc"));
+    let out = qc.add_classical_register(ClassicalRegister::new(2, "out"));
+    qc.h(0);
+    qc.cx(0, anc[0]);
+    qc.cx(0, anc[1]);
+    qc.measure(anc[0], out[0]);
+    qc.measure(anc[1], out[1]);
+    qc.if_else(
+        Condition::register(&out, 1),
+        |body| {
+            body.x(0);
+        },
+        |body| {
+            body.z(0);
+        },
+    );
+    qc.while_loop(Condition::bit(out[1], false), |body| {
+        body.h(anc[1]);
+        body.measure(anc[1], out[1]);
+    });
+    qc.for_loop(0..2, |body, _| {
+        body.x(0);
+        body.x(anc[0]);
+    });
+    qc.switch(&out, |cases| {
+        cases.case(&[0], |body| {
+            body.x(anc[0]);
+        });
+        cases.default(|_| {});
+    });
+    assert_snapshot(
+        &qc.draw_text(),
+        include_str!("snapshots/text/control_flow.txt"),
+    );
+}
+
+#[test]
+fn test_draw_folding() {
+    let mut qc = QuantumCircuit::new(2, 0);
+    for i in 0..6 {
+        qc.rx(f64::from(i) * 0.5, i % 2);
+        qc.cx(0, 1);
+    }
+    let folded = qc.draw_text_with(&DrawOptions {
+        fold: Some(40),
+        ..Default::default()
+    });
+    assert_snapshot(&folded, include_str!("snapshots/text/folded.txt"));
+    assert!(folded.lines().all(|line| line.chars().count() <= 40));
+
+    let unfolded = qc.draw_text_with(&DrawOptions {
+        fold: None,
+        ..Default::default()
+    });
+    assert_eq!(unfolded.lines().count(), 5);
+    assert!(!unfolded.contains('»'));
+}
+
+#[test]
+fn test_draw_empty() {
+    assert_eq!(QuantumCircuit::new(0, 0).draw_text(), "");
+    let qc = QuantumCircuit::new(1, 1);
+    assert_eq!(qc.draw_text(), "\nq_0: ─\n\nc_0: ═\n");
+}
+
+#[test]
+fn test_draw_reverse_bits() {
+    let qc = styled();
+    assert_snapshot(
+        &qc.draw_text_with(&styled_options()),
+        include_str!("snapshots/text/reversed.txt"),
+    );
+}
+
+#[test]
+fn test_draw_svg() {
+    assert_document(&bell().draw_svg(), include_str!("snapshots/svg/bell.svg"));
+    let qc = styled();
+    let svg = qc.draw_svg_with(&styled_options());
+    assert_document(&svg, include_str!("snapshots/svg/styled.svg"));
+    assert!(svg.contains(r##"fill="#00aa55""##));
+    assert_eq!(svg, qc.draw_svg_with(&styled_options()));
+}
+
+#[test]
+fn test_draw_latex() {
+    assert_document(
+        &bell().draw_latex(),
+        include_str!("snapshots/latex/bell.tex"),
+    );
+    let qc = styled();
+    let latex = qc.draw_latex_with(&styled_options());
+    assert_document(&latex, include_str!("snapshots/latex/styled.tex"));
+    assert!(latex.contains(r"R_X\left(-\pi/3\right)"));
+}
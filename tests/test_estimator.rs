@@ -0,0 +1,182 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+mod common;
+
+use common::{c, exact_counts, expectation, observable_matrix, statevector};
+use qiskit_rs::estimator::{Estimator, MeasurementBasis};
+use qiskit_rs::{Complex64, QiskitError, QuantumCircuit, SparseObservable};
+use std::collections::HashMap;
+
+fn prepare() -> QuantumCircuit {
+    let mut qc = QuantumCircuit::new(3, 0);
+    qc.ry(0.7, 0);
+    qc.cx(0, 1);
+    qc.rx(-1.1, 2);
+    qc.cry(0.4, 2, 1);
+    qc.u(0.3, 1.2, -0.5, 0);
+    qc
+}
+
+#[test]
+fn test_grouping() {
+    let obs = SparseObservable::from_sparse_list(
+        &[
+            ("ZZ", &[0, 1], c(1.0)),
+            ("X", &[2], c(0.5)),
+            ("XX", &[0, 1], c(-0.3)),
+            ("Z", &[1], c(0.2)),
+            ("", &[], c(1.5)),
+            ("Y+", &[0, 2], c(0.25)),
+            ("X", &[0], c(0.4)),
+        ],
+        3,
+    )
+    .unwrap();
+    let estimator = Estimator::new(&prepare(), &obs).unwrap();
+    assert_eq!(estimator.num_groups(), 3);
+    let groups = estimator.groups();
+    assert_eq!(groups[0].terms().len(), 3);
+    assert_eq!(
+        groups[0].bases(),
+        [
+            (0, MeasurementBasis::Z),
+            (1, MeasurementBasis::Z),
+            (2, MeasurementBasis::X)
+        ]
+    );
+    assert_eq!(groups[1].terms().len(), 2);
+    assert_eq!(
+        groups[2].bases(),
+        [(0, MeasurementBasis::Y), (2, MeasurementBasis::X)]
+    );
+
+    let circuit = groups[2].circuit();
+    assert_eq!(circuit.num_clbits(), 2);
+    let names: Vec<String> = circuit
+        .instructions()
+        .map(|inst| inst.name.to_string())
+        .collect();
+    assert_eq!(
+        names[prepare().num_instructions()..],
+        ["sdg", "h", "h", "measure", "measure"]
+    );
+    assert_eq!(estimator.circuits().len(), 3);
+}
+
+#[test]
+fn test_exact_estimate() {
+    let obs = SparseObservable::from_sparse_list(
+        &[
+            ("ZZ", &[0, 1], c(1.0)),
+            ("X", &[2], c(0.5)),
+            ("XX", &[0, 1], c(-0.3)),
+            ("YZ", &[1, 2], c(0.7)),
+            ("", &[], c(1.5)),
+            ("Y+", &[0, 2], c(0.25)),
+            ("01", &[1, 2], c(-0.6)),
+            ("rl", &[0, 1], c(0.35)),
+        ],
+        3,
+    )
+    .unwrap();
+    let qc = prepare();
+    let exact = expectation(&statevector(&qc), &observable_matrix(&obs));
+    assert!(exact.im.abs() < 1e-12);
+
+    let estimator = Estimator::new(&qc, &obs).unwrap();
+    let shots = 10_000_000;
+    let counts: Vec<HashMap<String, u64>> = estimator
+        .circuits()
+        .into_iter()
+        .map(|circuit| exact_counts(circuit, shots))
+        .collect();
+    let estimate = estimator.estimate(&counts).unwrap();
+    assert!((estimate.value - exact.re).abs() < 1e-5);
+    assert!(estimate.std_error > 0.0 && estimate.std_error < 1e-3);
+
+    // A tenth of the shots gives about sqrt(10) times the error.
+    let fewer: Vec<HashMap<String, u64>> = estimator
+        .circuits()
+        .into_iter()
+        .map(|circuit| exact_counts(circuit, shots / 10))
+        .collect();
+    let ratio = estimator.estimate(&fewer).unwrap().std_error / estimate.std_error;
+    assert!((ratio - 10f64.sqrt()).abs() < 1e-3);
+}
+
+#[test]
+fn test_single_group_error() {
+    // <Z> of ry(theta)|0> is cos(theta), with single-shot variance sin²(theta).
+    let theta: f64 = 1.2;
+    let mut qc = QuantumCircuit::new(1, 0);
+    qc.ry(theta, 0);
+    let obs = SparseObservable::from_sparse_list(&[("Z", &[0], c(2.0))], 1).unwrap();
+    let estimator = Estimator::new(&qc, &obs).unwrap();
+    let shots = 1_000_000;
+    let counts = exact_counts(estimator.circuits()[0], shots);
+    let estimate = estimator.estimate(&[counts]).unwrap();
+    assert!((estimate.value - 2.0 * theta.cos()).abs() < 1e-5);
+    let std_error = 2.0 * theta.sin() / (shots as f64).sqrt();
+    assert!((estimate.std_error - std_error).abs() < 1e-6);
+}
+
+#[test]
+fn test_circuit_clbits() {
+    let mut qc = QuantumCircuit::new(2, 1);
+    qc.x(1);
+    qc.measure(1, 0);
+    let obs =
+        SparseObservable::from_sparse_list(&[("Z", &[0], c(1.0)), ("Z", &[1], c(1.0))], 2).unwrap();
+    let estimator = Estimator::new(&qc, &obs).unwrap();
+    let group = &estimator.groups()[0];
+    assert_eq!(group.circuit().num_clbits(), 3);
+    // The first bit from the right is the circuit's own.
+    let counts = HashMap::from([("1 0 1".to_string(), 100)]);
+    let estimate = estimator.estimate(&[counts]).unwrap();
+    assert_eq!(estimate.value, 0.0);
+}
+
+#[test]
+fn test_errors() {
+    let qc = prepare();
+    let obs = SparseObservable::from_sparse_list(&[("Z", &[0], c(1.0))], 2).unwrap();
+    assert_eq!(
+        Estimator::new(&qc, &obs).unwrap_err(),
+        QiskitError::MismatchedQubits
+    );
+    let obs =
+        SparseObservable::from_sparse_list(&[("Z", &[0], Complex64::new(0.0, 1.0))], 3).unwrap();
+    assert_eq!(
+        Estimator::new(&qc, &obs).unwrap_err(),
+        QiskitError::CInputError
+    );
+
+    let obs = SparseObservable::from_sparse_list(&[("Z", &[0], c(1.0))], 3).unwrap();
+    let estimator = Estimator::new(&qc, &obs).unwrap();
+    for counts in [
+        vec![],
+        vec![HashMap::new()],
+        vec![HashMap::from([("01".to_string(), 3)])],
+        vec![HashMap::from([("x".to_string(), 3)])],
+    ] {
+        assert_eq!(
+            estimator.estimate(&counts).unwrap_err(),
+            QiskitError::CInputError
+        );
+    }
+
+    // The identity needs no measurements.
+    let estimator = Estimator::new(&qc, &SparseObservable::identity(3)).unwrap();
+    assert_eq!(estimator.num_groups(), 0);
+    assert_eq!(estimator.estimate(&[]).unwrap().value, 1.0);
+}
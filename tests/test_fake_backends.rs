@@ -0,0 +1,141 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+use qiskit_rs::fake_backends;
+use qiskit_rs::target::{InstructionProperties, TargetOperation};
+use qiskit_rs::transpiler::{
+    BasisTranslator, GateDirection, Optimize1qGatesDecomposition, PassManager, Vf2Layout,
+};
+use qiskit_rs::{CouplingMap, QuantumCircuit, StandardGate, Target};
+
+// Every instruction property of the target, in a fixed order.
+fn all_properties(target: &Target) -> Vec<InstructionProperties> {
+    target
+        .operations()
+        .flat_map(|operation| {
+            target
+                .qargs(operation)
+                .unwrap()
+                .into_iter()
+                .map(move |qargs| target.instruction_properties(operation, qargs).unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn test_line() {
+    let target = fake_backends::line_5q(7);
+    assert_eq!(target.num_qubits(), 5);
+    assert_eq!(target.operation_names(), ["rz", "sx", "x", "cx", "measure"]);
+    assert_eq!(CouplingMap::from(&target), CouplingMap::line(5));
+    assert!(target.dt().is_some());
+    assert_eq!(
+        target.instruction_properties(StandardGate::RZ, &[3]),
+        Some(InstructionProperties::new(0.0, 0.0))
+    );
+    for props in all_properties(&target) {
+        let error = props.error.unwrap();
+        assert!((0.0..0.05).contains(&error));
+        assert!((0.0..1e-5).contains(&props.duration.unwrap()));
+    }
+    let cx = target
+        .instruction_properties(StandardGate::CX, &[0, 1])
+        .unwrap();
+    let sx = target
+        .instruction_properties(StandardGate::SX, &[0])
+        .unwrap();
+    assert!(cx.error > sx.error && cx.duration > sx.duration);
+}
+
+#[test]
+fn test_heavy_hex() {
+    let target = fake_backends::heavy_hex_27q(7);
+    assert_eq!(target.num_qubits(), 27);
+    assert_eq!(
+        target.operation_names(),
+        ["rz", "sx", "x", "ecr", "measure"]
+    );
+    let map = CouplingMap::from(&target);
+    assert_eq!(map.edges().len(), 28);
+    assert!(map.is_connected());
+    assert!((0..27).all(|q| map.neighbors(q).len() <= 3));
+    for [a, b] in map.edges() {
+        assert!(!target.instruction_supported(StandardGate::ECR, &[*b, *a]));
+    }
+}
+
+#[test]
+fn test_ion_trap() {
+    let target = fake_backends::ion_trap(4, 7);
+    assert_eq!(target.operation_names(), ["rz", "rx", "rxx", "measure"]);
+    assert_eq!(target.dt(), None);
+    assert_eq!(target.qargs(StandardGate::RXX).unwrap().len(), 12);
+    for a in 0..4 {
+        for b in 0..4 {
+            assert_eq!(
+                target.instruction_supported(StandardGate::RXX, &[a, b]),
+                a != b
+            );
+        }
+    }
+    assert_eq!(
+        target.instruction_properties(StandardGate::RXX, &[0, 3]),
+        target.instruction_properties(StandardGate::RXX, &[3, 0])
+    );
+    let ion_rxx = target
+        .instruction_properties(StandardGate::RXX, &[0, 1])
+        .unwrap();
+    let line = fake_backends::line_5q(7);
+    let cx = line
+        .instruction_properties(StandardGate::CX, &[0, 1])
+        .unwrap();
+    assert!(ion_rxx.duration > cx.duration);
+    assert!(target.instruction_supported(TargetOperation::Measure, &[3]));
+}
+
+#[test]
+fn test_seeds() {
+    assert_eq!(
+        all_properties(&fake_backends::heavy_hex_27q(3)),
+        all_properties(&fake_backends::heavy_hex_27q(3))
+    );
+    assert_ne!(
+        all_properties(&fake_backends::heavy_hex_27q(3)),
+        all_properties(&fake_backends::heavy_hex_27q(4))
+    );
+    assert_eq!(
+        all_properties(&fake_backends::ion_trap(5, 11)),
+        all_properties(&fake_backends::ion_trap(5, 11))
+    );
+}
+
+#[test]
+fn test_compile_for_heavy_hex() {
+    let target = fake_backends::heavy_hex_27q(42);
+    let mut qc = QuantumCircuit::new(3, 0);
+    qc.h(0);
+    qc.cx(0, 1);
+    qc.cx(1, 2);
+
+    let mut pm = PassManager::new();
+    pm.append(Vf2Layout::default())
+        .append(BasisTranslator::default())
+        .append(GateDirection)
+        .append(BasisTranslator::default())
+        .append(Optimize1qGatesDecomposition);
+    pm.run(&mut qc, &target).unwrap();
+    assert_eq!(qc.num_qubits(), 27);
+    assert!(GateDirection::check(&qc, &target).unwrap());
+    assert!(qc.instructions().all(|inst| {
+        target.instruction_supported(TargetOperation::from_name(inst.name).unwrap(), inst.qubits)
+    }));
+}
@@ -0,0 +1,289 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+mod common;
+
+use common::{assert_close, c, eigenvalues, observable_matrix};
+use qiskit_rs::fermion::{FermionicOp, FermionicTerm, LadderOp, QubitMapper};
+use qiskit_rs::models::fermi_hubbard;
+use qiskit_rs::{Complex64, CouplingMap, QiskitError, SparseObservable};
+
+const MAPPERS: [QubitMapper; 3] = [
+    QubitMapper::JordanWigner,
+    QubitMapper::Parity,
+    QubitMapper::BravyiKitaev,
+];
+
+fn op(terms: &[(&str, &[u32], Complex64)], num_modes: u32) -> FermionicOp {
+    FermionicOp::from_sparse_list(terms, num_modes).unwrap()
+}
+
+fn plus_adjoint(op: &FermionicOp) -> FermionicOp {
+    let mut out = op.clone();
+    for term in op.adjoint().terms() {
+        assert_eq!(out.add_term(term), QiskitError::Success);
+    }
+    out
+}
+
+// A hermitian operator with one- and two-body terms of every kind on five
+// modes, which isn't a power of two for the Bravyi-Kitaev tree.
+fn molecule() -> FermionicOp {
+    let one_body = op(
+        &[
+            ("+-", &[0, 0], c(-1.25)),
+            ("+-", &[1, 1], c(-0.5)),
+            ("+-", &[3, 3], c(0.75)),
+            ("+-", &[4, 4], c(0.2)),
+            ("+-", &[0, 2], Complex64::new(0.3, 0.1)),
+            ("+-", &[1, 4], c(-0.45)),
+            ("+-", &[3, 0], Complex64::new(0.0, 0.6)),
+        ],
+        5,
+    );
+    let two_body = op(
+        &[
+            ("+-+-", &[0, 0, 1, 1], c(0.35)),
+            ("++--", &[2, 4, 3, 1], Complex64::new(0.15, -0.05)),
+            ("+-+-", &[4, 1, 2, 2], c(0.25)),
+            ("+--+", &[0, 3, 1, 2], c(-0.1)),
+        ],
+        5,
+    );
+    let mut out = plus_adjoint(&one_body);
+    for term in plus_adjoint(&two_body).terms() {
+        assert_eq!(out.add_term(term), QiskitError::Success);
+    }
+    out
+}
+
+#[test]
+fn test_construction() {
+    let hop = op(&[("+-", &[2, 0], Complex64::new(1.0, 2.0))], 3);
+    assert_eq!(hop.num_modes(), 3);
+    assert_eq!(
+        hop.terms(),
+        [FermionicTerm {
+            coeff: Complex64::new(1.0, 2.0),
+            ops: vec![LadderOp::Create, LadderOp::Annihilate],
+            indices: vec![2, 0],
+        }]
+    );
+    assert_eq!(
+        hop.adjoint(),
+        op(&[("+-", &[0, 2], Complex64::new(1.0, -2.0))], 3)
+    );
+    assert_eq!(hop.adjoint().adjoint(), hop);
+
+    assert_eq!(
+        FermionicOp::from_sparse_list(&[("+x", &[0, 1], c(1.0))], 2),
+        Err(QiskitError::CInputError)
+    );
+    assert_eq!(
+        FermionicOp::from_sparse_list(&[("+", &[0, 1], c(1.0))], 2),
+        Err(QiskitError::MismatchedQubits)
+    );
+    assert_eq!(
+        FermionicOp::from_sparse_list(&[("+", &[2], c(1.0))], 2),
+        Err(QiskitError::IndexError)
+    );
+    assert_eq!(FermionicOp::identity(2).num_terms(), 1);
+    assert_eq!(FermionicOp::zero(2).num_terms(), 0);
+}
+
+#[test]
+fn test_simplify() {
+    let sum = op(
+        &[
+            ("+-", &[0, 1], c(1.0)),
+            ("-+", &[1, 0], c(2.0)),
+            ("+-", &[0, 1], c(-0.5)),
+            ("", &[], c(1e-12)),
+        ],
+        2,
+    );
+    let expected = op(&[("+-", &[0, 1], c(0.5)), ("-+", &[1, 0], c(2.0))], 2);
+    assert_eq!(sum.simplify(1e-9), expected);
+    assert_eq!(sum.simplify(0.0).num_terms(), 3);
+}
+
+#[test]
+fn test_normal_order() {
+    // a_0 a†_0 = 1 - a†_0 a_0
+    let ordered = op(&[("-+", &[0, 0], c(2.0))], 1).normal_order();
+    assert_eq!(
+        ordered,
+        op(&[("+-", &[0, 0], c(-2.0)), ("", &[], c(2.0))], 1)
+    );
+    // Creation operators move left and higher modes first, changing sign.
+    let ordered = op(&[("-+", &[0, 1], c(1.0))], 2).normal_order();
+    assert_eq!(ordered, op(&[("+-", &[1, 0], c(-1.0))], 2));
+    let ordered = op(&[("++", &[0, 1], c(1.0))], 2).normal_order();
+    assert_eq!(ordered, op(&[("++", &[1, 0], c(-1.0))], 2));
+    // Pauli exclusion.
+    let ordered = op(&[("+-+", &[1, 0, 1], c(1.0))], 2).normal_order();
+    assert_eq!(ordered.num_terms(), 0);
+    // The anticommutator of a mode's operators is the identity.
+    let anticommutator = op(&[("-+", &[2, 2], c(1.0)), ("+-", &[2, 2], c(1.0))], 3);
+    assert_eq!(anticommutator.normal_order(), FermionicOp::identity(3));
+
+    // Normal ordering doesn't change the operator.
+    let molecule = molecule();
+    let ordered = molecule.normal_order();
+    for term in ordered.terms() {
+        let creations = term
+            .ops
+            .iter()
+            .take_while(|op| **op == LadderOp::Create)
+            .count();
+        assert!(
+            term.ops[creations..]
+                .iter()
+                .all(|op| *op == LadderOp::Annihilate)
+        );
+        assert!(term.indices[..creations].windows(2).all(|w| w[0] > w[1]));
+        assert!(term.indices[creations..].windows(2).all(|w| w[0] > w[1]));
+    }
+    for mapper in MAPPERS {
+        assert_close(
+            &observable_matrix(&mapper.map(&ordered)).data,
+            &observable_matrix(&mapper.map(&molecule)).data,
+        );
+    }
+    // The molecule is hermitian, so its normal order is too, up to the order
+    // of the terms.
+    let adjoint = ordered.adjoint().normal_order();
+    assert_eq!(adjoint.num_terms(), ordered.num_terms());
+    for term in adjoint.terms() {
+        let matching = ordered
+            .terms()
+            .iter()
+            .find(|other| other.ops == term.ops && other.indices == term.indices)
+            .unwrap();
+        assert!((matching.coeff - term.coeff).norm() < 1e-12);
+    }
+}
+
+#[test]
+fn test_anticommutation_relations() {
+    let n = 5;
+    for mapper in MAPPERS {
+        for i in 0..n {
+            for j in 0..n {
+                // {a_i, a†_j} = δ_ij and {a_i, a_j} = 0
+                let mixed = op(&[("-+", &[i, j], c(1.0)), ("+-", &[j, i], c(1.0))], n);
+                let expected = if i == j {
+                    SparseObservable::identity(n)
+                } else {
+                    SparseObservable::zero(n)
+                };
+                assert_eq!(mapper.map(&mixed), expected, "{mapper:?} {i} {j}");
+                let annihilate = op(&[("--", &[i, j], c(1.0)), ("--", &[j, i], c(1.0))], n);
+                assert_eq!(mapper.map(&annihilate), SparseObservable::zero(n));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_jordan_wigner() {
+    // a†_2 a_0 hops over mode 1, picking up its parity.
+    let hop = op(&[("+-", &[2, 0], c(1.0))], 3);
+    let matrix = observable_matrix(&QubitMapper::JordanWigner.map(&hop));
+    assert!((matrix.get(0b100, 0b001) - c(1.0)).norm() < 1e-12);
+    assert!((matrix.get(0b110, 0b011) - c(-1.0)).norm() < 1e-12);
+
+    // The Fermi-Hubbard model built from fermionic operators matches the
+    // one from the models module.
+    let (t, u, mu) = (1.0, 4.0, 0.5);
+    let mut hubbard = FermionicOp::zero(4);
+    for spin in [0, 2] {
+        let hop = op(&[("+-", &[spin, spin + 1], c(-t))], 4);
+        for term in plus_adjoint(&hop).terms() {
+            assert_eq!(hubbard.add_term(term), QiskitError::Success);
+        }
+    }
+    for site in 0..2 {
+        let (up, down) = (site, site + 2);
+        let terms = op(
+            &[
+                ("+-+-", &[up, up, down, down], c(u)),
+                ("+-", &[up, up], c(-mu)),
+                ("+-", &[down, down], c(-mu)),
+            ],
+            4,
+        );
+        for term in terms.terms() {
+            assert_eq!(hubbard.add_term(term), QiskitError::Success);
+        }
+    }
+    assert_close(
+        &observable_matrix(&QubitMapper::JordanWigner.map(&hubbard)).data,
+        &observable_matrix(&fermi_hubbard(&CouplingMap::line(2), t, u, mu)).data,
+    );
+}
+
+#[test]
+fn test_spectra() {
+    let molecule = molecule();
+    let spectra: Vec<Vec<f64>> = MAPPERS
+        .iter()
+        .map(|mapper| {
+            let obs = mapper.map(&molecule);
+            assert!(obs.terms().all(|term| term.coeff.im.abs() < 1e-12));
+            eigenvalues(&observable_matrix(&obs))
+        })
+        .collect();
+    for spectrum in &spectra[1..] {
+        for (a, b) in spectrum.iter().zip(&spectra[0]) {
+            assert!((a - b).abs() < 1e-8, "{a} != {b}");
+        }
+    }
+
+    // The total number operator has eigenvalue k with multiplicity
+    // binomial(4, k) under every mapping.
+    let terms: Vec<[u32; 2]> = (0..4).map(|q| [q, q]).collect();
+    let list: Vec<(&str, &[u32], Complex64)> =
+        terms.iter().map(|t| ("+-", &t[..], c(1.0))).collect();
+    let number = op(&list, 4);
+    let expected = [
+        0., 1., 1., 1., 1., 2., 2., 2., 2., 2., 2., 3., 3., 3., 3., 4.,
+    ];
+    for mapper in MAPPERS {
+        let spectrum = eigenvalues(&observable_matrix(&mapper.map(&number)));
+        for (a, b) in spectrum.iter().zip(expected) {
+            assert!((a - b).abs() < 1e-8, "{mapper:?}: {a} != {b}");
+        }
+    }
+}
+
+#[test]
+fn test_bravyi_kitaev_strings() {
+    // a†_0 on four modes is (X_0 X_1 X_3 - i Y_0 X_1 X_3) / 2.
+    let create = op(&[("+", &[0], c(1.0))], 4);
+    let expected = SparseObservable::from_sparse_list(
+        &[
+            ("XXX", &[0, 1, 3], c(0.5)),
+            ("YXX", &[0, 1, 3], Complex64::new(0.0, -0.5)),
+        ],
+        4,
+    )
+    .unwrap();
+    assert_eq!(QubitMapper::BravyiKitaev.map(&create), expected);
+    // The number operator of mode 3 is stored in the parity of all modes.
+    let number = op(&[("+-", &[3, 3], c(1.0))], 4);
+    let obs = QubitMapper::BravyiKitaev.map(&number).simplify(1e-12);
+    let expected =
+        SparseObservable::from_sparse_list(&[("", &[], c(0.5)), ("ZZZ", &[1, 2, 3], c(-0.5))], 4)
+            .unwrap();
+    assert_eq!(obs, expected);
+}
@@ -10,7 +10,9 @@
 // copyright notice, and modified files need to carry a notice indicating
 // that they have been altered from the originals.
 
-use qiskit_rs::{QiskitError, QuantumCircuit};
+mod common;
+
+use qiskit_rs::{QiskitError, QuantumCircuit, StandardGate};
 
 #[test]
 fn test_single_qubit_gates() {
@@ -41,7 +43,11 @@ fn test_two_qubit_gates() {
         QuantumCircuit::dcx,
         QuantumCircuit::ecr,
         QuantumCircuit::iswap,
+        QuantumCircuit::swap,
         QuantumCircuit::cx,
+        QuantumCircuit::cy,
+        QuantumCircuit::cz,
+        QuantumCircuit::ch,
     ];
 
     for gate in gate_funcs {
@@ -66,3 +72,56 @@ fn test_single_param_gates() {
         assert_eq!(ret, QiskitError::Success);
     }
 }
+
+#[test]
+fn test_controlled_param_gates() {
+    let gate_funcs = [
+        QuantumCircuit::cp,
+        QuantumCircuit::crx,
+        QuantumCircuit::cry,
+        QuantumCircuit::crz,
+    ];
+
+    for gate in gate_funcs {
+        let mut qc = QuantumCircuit::new(2, 0);
+        let ret = gate(&mut qc, 0.0, 0, 1);
+        assert_eq!(ret, QiskitError::Success);
+    }
+}
+
+#[test]
+fn test_append_gate() {
+    let mut qc = QuantumCircuit::new(4, 0);
+    for gate in StandardGate::ALL {
+        let qubits: Vec<u32> = (0..gate.num_qubits()).collect();
+        let params = vec![0.5; gate.num_params() as usize];
+        assert_eq!(qc.append_gate(gate, &qubits, &params), QiskitError::Success);
+        assert_eq!(StandardGate::from_name(gate.name()), Some(gate));
+    }
+    assert_eq!(
+        qc.append_gate(StandardGate::CX, &[0], &[]),
+        QiskitError::MismatchedQubits
+    );
+    assert_eq!(
+        qc.append_gate(StandardGate::RZ, &[0], &[]),
+        QiskitError::CInputError
+    );
+}
+
+#[test]
+fn test_multi_controlled_gates() {
+    for num_ctrl in 0..6u32 {
+        let controls: Vec<u32> = (0..num_ctrl).collect();
+        let mut qc = QuantumCircuit::new(num_ctrl + 1, 0);
+        assert_eq!(qc.mcx(&controls, num_ctrl), QiskitError::Success);
+        let expected = common::gate_matrix("x", &[]).controlled(num_ctrl);
+        let actual = common::unitary(&qc);
+        common::assert_close(&actual.data, &expected.data);
+
+        let mut qc = QuantumCircuit::new(num_ctrl + 1, 0);
+        assert_eq!(qc.mcp(0.3, &controls, num_ctrl), QiskitError::Success);
+        let expected = common::gate_matrix("p", &[0.3]).controlled(num_ctrl);
+        let actual = common::unitary(&qc);
+        common::assert_close(&actual.data, &expected.data);
+    }
+}
@@ -0,0 +1,450 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+mod common;
+
+use common::{
+    Matrix, assert_close, assert_close_up_to_phase, bind, expm, observable_matrix, statevector,
+    unitary,
+};
+use qiskit_rs::library::{
+    Entanglement, EvolutionSynthesis, efficient_su2, ghz, grover_operator, pauli_evolution,
+    qaoa_ansatz, qft, real_amplitudes, w_state,
+};
+use qiskit_rs::{Complex64, QiskitError, QuantumCircuit, SparseObservable};
+use std::f64::consts::PI;
+
+fn count(qc: &QuantumCircuit, name: &str) -> usize {
+    qc.instructions().filter(|inst| inst.name == name).count()
+}
+
+#[test]
+fn test_qft_counts() {
+    let qc = qft(5, false, 0);
+    assert_eq!(count(&qc, "h"), 5);
+    assert_eq!(count(&qc, "cp"), 10);
+    assert_eq!(count(&qc, "swap"), 2);
+
+    let qc = qft(5, false, 2);
+    assert_eq!(count(&qc, "cp"), 10 - 3);
+}
+
+#[test]
+fn test_qft_statevector() {
+    let n = 3;
+    let dim = 1 << n;
+    let x = 5;
+    let mut qc = QuantumCircuit::new(n, 0);
+    for q in 0..n {
+        if (x >> q) & 1 == 1 {
+            qc.x(q);
+        }
+    }
+    let ops: Vec<(String, Vec<u32>, Vec<f64>)> = qft(n, false, 0)
+        .instructions()
+        .map(|inst| {
+            (
+                inst.name.to_string(),
+                inst.qubits.to_vec(),
+                inst.params.to_vec(),
+            )
+        })
+        .collect();
+    for (name, qubits, params) in ops {
+        match name.as_str() {
+            "h" => qc.h(qubits[0]),
+            "cp" => qc.cp(params[0], qubits[0], qubits[1]),
+            "swap" => qc.swap(qubits[0], qubits[1]),
+            _ => unreachable!(),
+        };
+    }
+    let expected: Vec<Complex64> = (0..dim)
+        .map(|k| {
+            Complex64::cis(2.0 * PI * (x * k) as f64 / dim as f64).scale(1.0 / (dim as f64).sqrt())
+        })
+        .collect();
+    assert_close(&statevector(&qc), &expected);
+}
+
+#[test]
+fn test_inverse_qft_undoes_qft() {
+    let n = 4;
+    let mut qc = qft(n, false, 0);
+    for inst in qft(n, true, 0).instructions() {
+        match inst.name {
+            "h" => qc.h(inst.qubits[0]),
+            "cp" => qc.cp(inst.params[0], inst.qubits[0], inst.qubits[1]),
+            "swap" => qc.swap(inst.qubits[0], inst.qubits[1]),
+            _ => unreachable!(),
+        };
+    }
+    let mut expected = vec![Complex64::ZERO; 1 << n];
+    expected[0] = Complex64::ONE;
+    assert_close(&statevector(&qc), &expected);
+}
+
+#[test]
+fn test_ghz() {
+    let qc = ghz(4);
+    assert_eq!(qc.num_instructions(), 4);
+    let mut expected = vec![Complex64::ZERO; 16];
+    expected[0] = Complex64::new(1.0 / 2f64.sqrt(), 0.0);
+    expected[15] = expected[0];
+    assert_close(&statevector(&qc), &expected);
+}
+
+#[test]
+fn test_w_state() {
+    let n = 5;
+    let qc = w_state(n);
+    assert_eq!(qc.num_instructions(), 1 + 2 * (n as usize - 1));
+    let mut expected = vec![Complex64::ZERO; 1 << n];
+    for q in 0..n {
+        expected[1 << q] = Complex64::new(1.0 / f64::from(n).sqrt(), 0.0);
+    }
+    assert_close(&statevector(&qc), &expected);
+}
+
+#[test]
+fn test_grover_operator_finds_marked_state() {
+    // Marks |101> on three qubits.
+    let mut oracle = QuantumCircuit::new(3, 0);
+    oracle.x(1);
+    oracle.ccz(0, 1, 2);
+    oracle.x(1);
+    let grover = grover_operator(&oracle).unwrap();
+
+    let mut qc = QuantumCircuit::new(3, 0);
+    for q in 0..3 {
+        qc.h(q);
+    }
+    let state = statevector(&qc);
+    let mut state_after = state.clone();
+    for _ in 0..2 {
+        common::evolve(&grover, &mut state_after);
+    }
+    let probability = state_after[0b101].norm_sqr();
+    assert!(probability > 0.94, "{probability}");
+    assert!(state[0b101].norm_sqr() < 0.2);
+}
+
+#[test]
+fn test_grover_operator_rejects_measurements() {
+    let mut oracle = QuantumCircuit::new(1, 1);
+    oracle.measure(0, 0);
+    assert_eq!(
+        grover_operator(&oracle).err(),
+        Some(QiskitError::UnsupportedInstruction)
+    );
+}
+
+#[test]
+fn test_qaoa_ansatz() {
+    let cost = SparseObservable::from_sparse_list(
+        &[
+            ("ZZ", &[0, 1], Complex64::ONE),
+            ("ZZ", &[1, 2], Complex64::ONE),
+        ],
+        3,
+    )
+    .unwrap();
+    let qc = qaoa_ansatz(&cost, 2).unwrap();
+    assert_eq!(count(&qc, "h"), 3);
+    assert_eq!(count(&qc, "rzz"), 4);
+    assert_eq!(count(&qc, "rx"), 6);
+    let names: Vec<String> = qc
+        .parameters()
+        .iter()
+        .map(|param| param.name().to_string())
+        .collect();
+    assert_eq!(names, ["β[0]", "β[1]", "γ[0]", "γ[1]"]);
+
+    // With all angles zero only the uniform superposition is left.
+    let qc = bind(&qaoa_ansatz(&cost, 1).unwrap(), &[0.0, 0.0]);
+    let expected = vec![Complex64::new(1.0 / 8f64.sqrt(), 0.0); 8];
+    assert_close(&statevector(&qc), &expected);
+
+    let cost = SparseObservable::from_sparse_list(&[("ZZ", &[0, 1], Complex64::I)], 2).unwrap();
+    assert_eq!(qaoa_ansatz(&cost, 1).err(), Some(QiskitError::CInputError));
+}
+
+#[test]
+fn test_qaoa_ansatz_general_paulis() {
+    let cost =
+        SparseObservable::from_sparse_list(&[("XYZ", &[0, 1, 2], Complex64::ONE)], 3).unwrap();
+    let gamma = 0.7;
+    let qc = bind(&qaoa_ansatz(&cost, 1).unwrap(), &[0.0, gamma]);
+
+    // exp(-i gamma XYZ) acting on the uniform superposition.
+    let mut uniform = QuantumCircuit::new(3, 0);
+    for q in 0..3 {
+        uniform.h(q);
+    }
+    let mut expected = statevector(&uniform);
+    common::apply_matrix(
+        &mut expected,
+        &common::pauli_rotation("XYZ", 2.0 * gamma),
+        &[0, 1, 2],
+    );
+    assert_close_up_to_phase(&statevector(&qc), &expected);
+}
+
+#[test]
+fn test_qaoa_ansatz_mixer() {
+    // The mixer angle of a layer is bound to rx(2 beta) on every qubit.
+    let cost = SparseObservable::from_sparse_list(&[("Z", &[0], Complex64::ONE)], 2).unwrap();
+    let (beta, gamma) = (0.3, 0.0);
+    let qc = bind(&qaoa_ansatz(&cost, 1).unwrap(), &[beta, gamma]);
+    let angles: Vec<f64> = qc
+        .instructions()
+        .filter(|inst| inst.name == "rx")
+        .map(|inst| inst.params[0])
+        .collect();
+    assert_eq!(angles, [2.0 * beta, 2.0 * beta]);
+}
+
+#[test]
+fn test_efficient_su2() {
+    let n = 3;
+    let reps = 2;
+    let qc = efficient_su2(n, reps, Entanglement::Linear);
+    assert_eq!(count(&qc, "ry"), 9);
+    assert_eq!(count(&qc, "rz"), 9);
+    assert_eq!(count(&qc, "cx"), 4);
+    assert_eq!(qc.num_parameters(), 2 * 3 * (reps + 1));
+    let bound = bind(&qc, &vec![0.0; 2 * 3 * (reps + 1)]);
+    let mut expected = vec![Complex64::ZERO; 8];
+    expected[0] = Complex64::ONE;
+    assert_close(&statevector(&bound), &expected);
+
+    // The ry angles of a layer come before its rz angles.
+    let values: Vec<f64> = (0..qc.num_parameters()).map(|i| i as f64).collect();
+    let bound = bind(&qc, &values);
+    let first_layer: Vec<(String, f64)> = bound
+        .instructions()
+        .take(6)
+        .map(|inst| (inst.name.to_string(), inst.params[0]))
+        .collect();
+    let expected: Vec<(String, f64)> = [("ry", 0.0), ("ry", 1.0), ("ry", 2.0)]
+        .into_iter()
+        .chain([("rz", 3.0), ("rz", 4.0), ("rz", 5.0)])
+        .map(|(name, angle)| (name.to_string(), angle))
+        .collect();
+    assert_eq!(first_layer, expected);
+
+    let qc = efficient_su2(n, reps, Entanglement::Full);
+    assert_eq!(count(&qc, "cx"), 6);
+}
+
+#[test]
+fn test_real_amplitudes() {
+    let qc = real_amplitudes(4, 2, Entanglement::ReverseLinear);
+    assert_eq!(count(&qc, "ry"), 12);
+    assert_eq!(count(&qc, "cx"), 6);
+    assert_eq!(qc.num_parameters(), 12);
+    assert!(
+        qc.parameters()
+            .iter()
+            .all(|param| param.name().starts_with("θ["))
+    );
+    let values: Vec<f64> = (0..4 * 3).map(|i| 0.3 * i as f64).collect();
+    let state = statevector(&bind(&qc, &values));
+    assert!(state.iter().all(|amp| amp.im.abs() < common::TOL));
+    let norm: f64 = state.iter().map(|amp| amp.norm_sqr()).sum();
+    assert!((norm - 1.0).abs() < common::TOL);
+}
+
+#[test]
+fn test_entanglement_pairs() {
+    assert_eq!(Entanglement::Linear.pairs(3), vec![(0, 1), (1, 2)]);
+    assert_eq!(Entanglement::ReverseLinear.pairs(3), vec![(1, 2), (0, 1)]);
+    assert_eq!(
+        Entanglement::Circular.pairs(3),
+        vec![(2, 0), (0, 1), (1, 2)]
+    );
+    assert_eq!(
+        Entanglement::Pairwise.pairs(5),
+        vec![(0, 1), (2, 3), (1, 2), (3, 4)]
+    );
+    assert_eq!(Entanglement::Full.pairs(3), vec![(0, 1), (0, 2), (1, 2)]);
+}
+
+fn max_distance(a: &Matrix, b: &Matrix) -> f64 {
+    a.data
+        .iter()
+        .zip(&b.data)
+        .map(|(x, y)| (*x - *y).norm())
+        .fold(0.0, f64::max)
+}
+
+fn evolution_error(obs: &SparseObservable, time: f64, synthesis: EvolutionSynthesis) -> f64 {
+    let qc = pauli_evolution(obs, time, synthesis).unwrap();
+    let exact = expm(&observable_matrix(obs), Complex64::new(0.0, -time));
+    max_distance(&unitary(&qc), &exact)
+}
+
+// A Heisenberg chain in a field, with a three-qubit term that needs a cx
+// ladder.
+fn spin_chain() -> SparseObservable {
+    let c = |re| Complex64::new(re, 0.0);
+    SparseObservable::from_sparse_list(
+        &[
+            ("XX", &[0, 1], c(1.0)),
+            ("YY", &[0, 1], c(0.8)),
+            ("ZZ", &[0, 1], c(0.6)),
+            ("XX", &[1, 2], c(1.0)),
+            ("YY", &[1, 2], c(0.8)),
+            ("ZZ", &[1, 2], c(0.6)),
+            ("Z", &[0], c(0.3)),
+            ("X", &[2], c(-0.4)),
+            ("XYZ", &[0, 1, 2], c(0.25)),
+            ("", &[], c(0.5)),
+        ],
+        3,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_pauli_evolution_commuting_terms() {
+    // Every term commutes, so a single Lie-Trotter step is exact, including
+    // the phase of the identity terms from the projector.
+    let obs = SparseObservable::from_sparse_list(
+        &[
+            ("XX", &[0, 1], Complex64::new(0.7, 0.0)),
+            ("YY", &[0, 1], Complex64::new(-0.4, 0.0)),
+            ("ZZ", &[0, 1], Complex64::new(1.1, 0.0)),
+            ("1", &[2], Complex64::new(0.9, 0.0)),
+            ("", &[], Complex64::new(-0.3, 0.0)),
+        ],
+        3,
+    )
+    .unwrap();
+    let synthesis = EvolutionSynthesis::LieTrotter { reps: 1 };
+    assert!(evolution_error(&obs, 1.3, synthesis) < 1e-9);
+    let qc = pauli_evolution(&obs, 1.3, synthesis).unwrap();
+    for name in ["rxx", "ryy", "rzz", "rz"] {
+        assert_eq!(count(&qc, name), 1);
+    }
+}
+
+#[test]
+fn test_pauli_evolution_orders() {
+    let obs = spin_chain();
+    let time = 1.0;
+    // Doubling the reps divides the error by 2^order.
+    for (order, synthesis) in [
+        (1, |reps| EvolutionSynthesis::LieTrotter { reps }),
+        (2, |reps| EvolutionSynthesis::SuzukiTrotter {
+            order: 2,
+            reps,
+        }),
+        (4, |reps| EvolutionSynthesis::SuzukiTrotter {
+            order: 4,
+            reps,
+        }),
+    ] as [(i32, fn(usize) -> EvolutionSynthesis); 3]
+    {
+        let coarse = evolution_error(&obs, time, synthesis(8));
+        let fine = evolution_error(&obs, time, synthesis(16));
+        let ratio = coarse / fine;
+        let expected = 2f64.powi(order);
+        assert!(
+            (ratio / expected - 1.0).abs() < 0.15,
+            "order {order}: ratio {ratio}"
+        );
+    }
+    let synthesis = EvolutionSynthesis::SuzukiTrotter { order: 4, reps: 16 };
+    assert!(evolution_error(&obs, time, synthesis) < 1e-5);
+}
+
+#[test]
+fn test_pauli_evolution_gates() {
+    let obs = spin_chain();
+    let qc = pauli_evolution(&obs, 0.5, EvolutionSynthesis::LieTrotter { reps: 2 }).unwrap();
+    assert_eq!(count(&qc, "rxx"), 4);
+    assert_eq!(count(&qc, "ryy"), 4);
+    assert_eq!(count(&qc, "rzz"), 4);
+    assert_eq!(count(&qc, "rx"), 2);
+    // Each XYZ rotation takes a cx ladder and a single rz, next to the rz of
+    // the field on qubit 0.
+    assert_eq!(count(&qc, "cx"), 2 * 4);
+    assert_eq!(count(&qc, "rz"), 2 * 2);
+    assert_eq!(count(&qc, "h"), 2 * 4);
+    assert_eq!(count(&qc, "sdg"), 2);
+    assert_eq!(count(&qc, "s"), 2);
+
+    // The order 2 formula evolves the last term for a full step in the middle.
+    let synthesis = EvolutionSynthesis::SuzukiTrotter { order: 2, reps: 1 };
+    let qc = pauli_evolution(&obs, 0.5, synthesis).unwrap();
+    assert_eq!(count(&qc, "rxx"), 4);
+    assert_eq!(count(&qc, "cx"), 4);
+}
+
+#[test]
+fn test_pauli_evolution_qdrift() {
+    let obs = spin_chain();
+    let time = 0.5;
+    let lambda = 1.0 + 0.8 + 0.6 + 1.0 + 0.8 + 0.6 + 0.3 + 0.4 + 0.25;
+    let synthesis = EvolutionSynthesis::QDrift { reps: 4, seed: 7 };
+    let qc = pauli_evolution(&obs, time, synthesis).unwrap();
+    let num_samples = (2.0 * lambda * lambda * time * time * 4.0_f64).ceil() as usize;
+    let rotations = ["rxx", "ryy", "rzz", "rx", "rz"]
+        .iter()
+        .map(|name| count(&qc, name))
+        .sum::<usize>();
+    // Each XYZ sample has its own rz next to its cx ladder.
+    assert_eq!(rotations, num_samples);
+    let same = pauli_evolution(&obs, time, synthesis).unwrap();
+    assert_eq!(unitary(&qc).data, unitary(&same).data);
+    let other = pauli_evolution(&obs, time, EvolutionSynthesis::QDrift { reps: 4, seed: 8 });
+    assert_ne!(unitary(&qc).data, unitary(&other.unwrap()).data);
+
+    // The error shrinks with the number of samples.
+    let coarse = evolution_error(&obs, time, synthesis);
+    let fine = evolution_error(
+        &obs,
+        time,
+        EvolutionSynthesis::QDrift { reps: 400, seed: 7 },
+    );
+    assert!(fine < 0.05, "{fine}");
+    assert!(fine < coarse);
+
+    // A single term is evolved exactly by any number of samples.
+    let single =
+        SparseObservable::from_sparse_list(&[("XZ", &[0, 1], Complex64::new(-0.8, 0.0))], 2)
+            .unwrap();
+    assert!(evolution_error(&single, 2.0, synthesis) < 1e-9);
+}
+
+#[test]
+fn test_pauli_evolution_errors() {
+    let obs = spin_chain();
+    for synthesis in [
+        EvolutionSynthesis::LieTrotter { reps: 0 },
+        EvolutionSynthesis::SuzukiTrotter { order: 3, reps: 1 },
+        EvolutionSynthesis::SuzukiTrotter { order: 0, reps: 1 },
+        EvolutionSynthesis::SuzukiTrotter { order: 2, reps: 0 },
+        EvolutionSynthesis::QDrift { reps: 0, seed: 0 },
+    ] {
+        assert_eq!(
+            pauli_evolution(&obs, 1.0, synthesis).unwrap_err(),
+            QiskitError::CInputError
+        );
+    }
+    let complex =
+        SparseObservable::from_sparse_list(&[("Z", &[0], Complex64::new(1.0, 1.0))], 1).unwrap();
+    assert_eq!(
+        pauli_evolution(&complex, 1.0, EvolutionSynthesis::LieTrotter { reps: 1 }).unwrap_err(),
+        QiskitError::CInputError
+    );
+}
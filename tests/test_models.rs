@@ -0,0 +1,123 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+mod common;
+
+use common::{Matrix, TOL, bind, c, commute, expectation, observable_matrix, statevector};
+use qiskit_rs::library::{EvolutionSynthesis, pauli_evolution, qaoa_ansatz};
+use qiskit_rs::models::{fermi_hubbard, heisenberg_xxz, maxcut_observable, transverse_field_ising};
+use qiskit_rs::{BitTerm, Complex64, CouplingMap, SparseObservable};
+
+// The sum of a single bit term over every qubit.
+fn total(label: &str, num_qubits: u32) -> Matrix {
+    let indices: Vec<[u32; 1]> = (0..num_qubits).map(|q| [q]).collect();
+    let terms: Vec<(&str, &[u32], Complex64)> =
+        indices.iter().map(|q| (label, &q[..], c(1.0))).collect();
+    observable_matrix(&SparseObservable::from_sparse_list(&terms, num_qubits).unwrap())
+}
+
+#[test]
+fn test_transverse_field_ising() {
+    let ising = transverse_field_ising(&CouplingMap::line(3), -1.0, 0.5);
+    let expected = SparseObservable::from_sparse_list(
+        &[
+            ("ZZ", &[0, 1], c(-1.0)),
+            ("ZZ", &[1, 2], c(-1.0)),
+            ("X", &[0], c(0.5)),
+            ("X", &[1], c(0.5)),
+            ("X", &[2], c(0.5)),
+        ],
+        3,
+    )
+    .unwrap();
+    assert_eq!(ising, expected);
+
+    // Edges are undirected, and isolated qubits still get a field.
+    let mut graph = CouplingMap::from_edges([[1, 0], [0, 1], [2, 1]]);
+    graph.add_qubit();
+    let ising = transverse_field_ising(&graph, 2.0, 0.0);
+    let expected =
+        SparseObservable::from_sparse_list(&[("ZZ", &[0, 1], c(2.0)), ("ZZ", &[1, 2], c(2.0))], 4)
+            .unwrap();
+    assert_eq!(ising, expected);
+    assert_eq!(transverse_field_ising(&graph, 0.0, 1.0).num_terms(), 4);
+}
+
+#[test]
+fn test_heisenberg_xxz() {
+    let ring = CouplingMap::ring(4);
+    let xxz = heisenberg_xxz(&ring, 1.0, 0.5, -0.2);
+    assert_eq!(xxz.num_terms(), 4 * 3 + 4);
+    let zz: Vec<f64> = xxz
+        .terms()
+        .filter(|term| term.bit_terms.len() == 2 && term.bit_terms[0] == BitTerm::Z)
+        .map(|term| term.coeff.re)
+        .collect();
+    assert_eq!(zz, [0.5; 4]);
+    // The XXZ model conserves the magnetization.
+    assert!(commute(&observable_matrix(&xxz), &total("Z", 4)));
+    assert_eq!(heisenberg_xxz(&ring, 1.0, 0.0, 0.0).num_terms(), 4 * 2);
+}
+
+#[test]
+fn test_fermi_hubbard() {
+    let (t, u, mu) = (1.0, 4.0, 0.5);
+    // Two sites, with orbitals (0↑, 1↑, 0↓, 1↓) on qubits 0 to 3.
+    let hubbard = fermi_hubbard(&CouplingMap::line(2), t, u, mu);
+    assert_eq!(hubbard.num_qubits(), 4);
+    assert!(hubbard.terms().all(|term| term.coeff.im == 0.0));
+    let matrix = observable_matrix(&hubbard);
+    assert!(commute(&matrix, &total("1", 4)));
+    // Both electrons on site 0.
+    assert!((matrix.get(0b0101, 0b0101) - c(u - 2.0 * mu)).norm() < TOL);
+    // One electron on each site, with opposite spins.
+    assert!((matrix.get(0b1001, 0b1001) - c(-2.0 * mu)).norm() < TOL);
+    // Hopping of a spin-up and a spin-down electron.
+    assert!((matrix.get(0b0010, 0b0001) - c(-t)).norm() < TOL);
+    assert!((matrix.get(0b1000, 0b0100) - c(-t)).norm() < TOL);
+
+    // Around a ring, hopping from orbital 0 to 2 passes orbital 1 and picks
+    // up a sign when it is occupied.
+    let hubbard = fermi_hubbard(&CouplingMap::ring(3), t, 0.0, 0.0);
+    let matrix = observable_matrix(&hubbard);
+    assert!((matrix.get(0b100, 0b001) - c(-t)).norm() < TOL);
+    assert!((matrix.get(0b110, 0b011) - c(t)).norm() < TOL);
+    let synthesis = EvolutionSynthesis::LieTrotter { reps: 2 };
+    assert!(pauli_evolution(&hubbard, 0.1, synthesis).is_ok());
+}
+
+#[test]
+fn test_maxcut_observable() {
+    // A triangle with a pendant edge, whose best cut has three edges.
+    let edges = [[0, 1], [1, 2], [2, 0], [2, 3]];
+    let graph = CouplingMap::from_edges(edges);
+    let cost = maxcut_observable(&graph);
+    let matrix = observable_matrix(&cost);
+    let mut best = 0;
+    for i in 0..16usize {
+        for j in 0..16 {
+            let cut = edges
+                .iter()
+                .filter(|[a, b]| (i >> a) & 1 != (i >> b) & 1)
+                .count();
+            let expected = if i == j { -(cut as f64) } else { 0.0 };
+            assert!((matrix.get(i, j) - c(expected)).norm() < TOL);
+            best = best.max(cut);
+        }
+    }
+    assert_eq!(best, 3);
+
+    let qc = bind(&qaoa_ansatz(&cost, 2).unwrap(), &[0.4, 0.2, 0.7, 0.3]);
+    let energy = expectation(&statevector(&qc), &matrix);
+    assert!(energy.im.abs() < TOL);
+    assert!((-3.0..=0.0).contains(&energy.re));
+}
@@ -0,0 +1,149 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+mod common;
+
+use common::{assert_close, unitary};
+use qiskit_rs::{
+    Parameter, ParameterExpression, ParameterValue, ParameterVector, QiskitError, QuantumCircuit,
+};
+use std::collections::HashMap;
+
+const TOL: f64 = 1e-12;
+
+#[test]
+fn test_expression_arithmetic() {
+    let x = Parameter::new("x");
+    let y = Parameter::new("y");
+    let expr = (2.0 * &x - &y / 4.0).cos() + (&x * &y).exp() - 1.0;
+    assert_eq!(expr.parameters(), &[x.clone(), y.clone()]);
+    assert_eq!(expr.value(), None);
+
+    let partial = expr.bind(&HashMap::from([(x.clone(), 0.3)]));
+    assert_eq!(partial.parameters().len(), 1);
+    assert_eq!(partial.parameters()[0], y);
+
+    let bound = partial.bind(&HashMap::from([(y.clone(), -1.2)]));
+    let expected = (2.0f64 * 0.3 + 1.2 / 4.0).cos() + (0.3f64 * -1.2).exp() - 1.0;
+    assert!((bound.value().unwrap() - expected).abs() < TOL);
+
+    let expr = ParameterExpression::from(&x).pow(2.0).sin().abs() - (-&y).atan();
+    let bound = expr.bind(&HashMap::from([(x, 1.5), (y, 0.5)]));
+    let expected = 2.25f64.sin().abs() - (-0.5f64).atan();
+    assert!((bound.value().unwrap() - expected).abs() < TOL);
+}
+
+#[test]
+fn test_parameter_identity() {
+    let a = Parameter::new("a");
+    assert_eq!(a, a.clone());
+    assert_ne!(a, Parameter::new("a"));
+    let values = HashMap::from([(a.clone(), 1.0)]);
+    assert_eq!(values.get(&a), Some(&1.0));
+    assert_eq!(values.get(&Parameter::new("a")), None);
+}
+
+#[test]
+fn test_parameter_vector_order() {
+    let theta = ParameterVector::new("theta", 12);
+    let alpha = Parameter::new("alpha");
+    let mut qc = QuantumCircuit::new(1, 0);
+    for param in theta.iter().rev() {
+        qc.rz(param, 0);
+    }
+    qc.rx(&alpha, 0);
+
+    let mut expected = vec![alpha];
+    expected.extend(theta.iter().cloned());
+    assert_eq!(qc.parameters(), expected);
+    assert_eq!(qc.num_parameters(), 13);
+}
+
+#[test]
+fn test_assign_parameters() {
+    let theta = Parameter::new("theta");
+    let phi = Parameter::new("phi");
+    let mut qc = QuantumCircuit::new(2, 0);
+    qc.h(0);
+    qc.rx(&theta, 0);
+    qc.crz(&theta * &phi, 0, 1);
+    qc.u(&phi, 0.1, -&theta, 1);
+    qc.rzz(0.4, 0, 1);
+
+    let inst = qc.instructions().nth(2).unwrap();
+    assert!(inst.params[0].is_nan());
+    assert_eq!(
+        inst.param_values(),
+        [ParameterValue::Expression(&theta * &phi)]
+    );
+
+    let values = HashMap::from([(theta.clone(), 0.7), (phi.clone(), -0.2)]);
+    let bound = qc.assign_parameters(&values).unwrap();
+    assert!(bound.parameters().is_empty());
+
+    let mut expected = QuantumCircuit::new(2, 0);
+    expected.h(0);
+    expected.rx(0.7, 0);
+    expected.crz(0.7 * -0.2, 0, 1);
+    expected.u(-0.2, 0.1, -0.7, 1);
+    expected.rzz(0.4, 0, 1);
+    assert_close(&unitary(&bound).data, &unitary(&expected).data);
+
+    let partial = qc
+        .assign_parameters(&HashMap::from([(phi.clone(), -0.2)]))
+        .unwrap();
+    assert_eq!(partial.parameters(), vec![theta.clone()]);
+    let bound = partial
+        .assign_parameters(&HashMap::from([(theta, 0.7)]))
+        .unwrap();
+    assert_close(&unitary(&bound).data, &unitary(&expected).data);
+
+    assert_eq!(
+        qc.assign_parameters(&HashMap::from([(Parameter::new("other"), 1.0)]))
+            .err(),
+        Some(QiskitError::CInputError)
+    );
+}
+
+#[test]
+fn test_transform_parameterized_circuit() {
+    let theta = ParameterVector::new("theta", 3);
+    let mut qc = QuantumCircuit::new(2, 0);
+    qc.ry(&theta[0], 0);
+    qc.cp(&theta[1], 0, 1);
+    qc.r(&theta[2], 0.3, 1);
+    qc.global_phase(&theta[0] / 2.0);
+
+    let values: HashMap<Parameter, f64> = theta.iter().cloned().zip([0.4, -1.1, 2.3]).collect();
+    let bound = qc.assign_parameters(&values).unwrap();
+
+    let inverse = qc.inverse().unwrap();
+    assert_eq!(inverse.num_parameters(), 3);
+    assert_close(
+        &unitary(&inverse.assign_parameters(&values).unwrap()).data,
+        &unitary(&bound).adjoint().data,
+    );
+
+    let controlled = qc.control(2).unwrap();
+    assert_eq!(controlled.parameters(), qc.parameters());
+    assert_close(
+        &unitary(&controlled.assign_parameters(&values).unwrap()).data,
+        &unitary(&bound).controlled(2).data,
+    );
+
+    let repeated = qc.repeat(2).unwrap();
+    let single = unitary(&bound);
+    assert_close(
+        &unitary(&repeated.assign_parameters(&values).unwrap()).data,
+        &single.matmul(&single).data,
+    );
+}
@@ -0,0 +1,427 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+#![cfg(feature = "python")]
+
+use qiskit_rs::pyo3::prelude::*;
+use qiskit_rs::pyo3::types::{IntoPyDict, PyDict};
+use qiskit_rs::pyo3::{self, wrap_pyfunction};
+use qiskit_rs::{
+    ClassicalRegister, Complex64, Parameter, ParameterExpression, ParameterVector, QuantumCircuit,
+    QuantumRegister, SparseObservable,
+};
+use std::collections::HashMap;
+
+fn with_python<F: for<'py> FnOnce(Python<'py>) -> PyResult<()>>(f: F) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| f(py).unwrap_or_else(|e| panic!("{e}")));
+}
+
+#[test]
+fn test_circuit_round_trip() {
+    let theta = Parameter::new("theta");
+    let mut qc = QuantumCircuit::new(2, 2);
+    qc.h(0);
+    qc.cx(0, 1);
+    qc.rz(&theta, 1);
+    qc.u(0.1, &theta, -0.3, 0);
+    qc.barrier(&[0, 1]);
+    qc.reset(1);
+    qc.measure(0, 1);
+    qc.global_phase(0.25);
+
+    with_python(|py| {
+        let py_qc = (&qc).into_pyobject(py)?;
+        assert_eq!(py_qc.getattr("num_qubits")?.extract::<u32>()?, 2);
+        assert_eq!(py_qc.getattr("num_clbits")?.extract::<u32>()?, 2);
+        assert_eq!(py_qc.call_method0("size")?.extract::<usize>()?, 8);
+        let parameters = py_qc.getattr("parameters")?;
+        assert_eq!(parameters.len()?, 1);
+        let name: String = parameters.get_item(0)?.getattr("name")?.extract()?;
+        assert_eq!(name, "theta");
+
+        let back: QuantumCircuit = py_qc.extract()?;
+        assert_eq!(back.num_qubits(), 2);
+        assert_eq!(back.num_clbits(), 2);
+        for (expected, inst) in qc.instructions().zip(back.instructions()) {
+            assert_eq!(expected.name, inst.name);
+            assert_eq!(expected.qubits, inst.qubits);
+            assert_eq!(expected.clbits, inst.clbits);
+            assert_eq!(
+                expected
+                    .param_values()
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>(),
+                inst.param_values()
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>(),
+            );
+        }
+        assert_eq!(qc.num_instructions(), back.num_instructions());
+        assert_eq!(back.parameters().len(), 1);
+        assert_eq!(back.parameters()[0].name(), "theta");
+        Ok(())
+    });
+}
+
+#[test]
+fn test_parameter_expression_into_python() {
+    let x = Parameter::new("x");
+    let y = Parameter::new("y");
+    let mut qc = QuantumCircuit::new(1, 0);
+    qc.rz((2.0 * &x - &y).cos() + (&x * &y).exp(), 0);
+
+    with_python(|py| {
+        let py_qc = (&qc).into_pyobject(py)?;
+        let params = py_qc.getattr("parameters")?;
+        let values = PyDict::new(py);
+        for param in params.try_iter()? {
+            let param = param?;
+            let name: String = param.getattr("name")?.extract()?;
+            values.set_item(param, if name == "x" { 0.3 } else { -1.2 })?;
+        }
+        let angle = py_qc
+            .getattr("data")?
+            .get_item(0)?
+            .getattr("operation")?
+            .getattr("params")?
+            .get_item(0)?;
+        let value: f64 = angle.call_method1("bind", (values,))?.extract()?;
+        let expected = (2.0f64 * 0.3 + 1.2).cos() + (0.3f64 * -1.2).exp();
+        assert!((value - expected).abs() < 1e-12);
+        Ok(())
+    });
+}
+
+#[test]
+fn test_parameter_vector_into_python() {
+    let theta = ParameterVector::new("θ", 3);
+    let mut qc = QuantumCircuit::new(1, 0);
+    qc.rx(&theta[2], 0);
+    qc.rz(ParameterExpression::from(&theta[0]).pow(2.0) / &theta[2], 0);
+
+    with_python(|py| {
+        let py_qc = (&qc).into_pyobject(py)?;
+        let element = py
+            .import("qiskit.circuit.parametervector")?
+            .getattr("ParameterVectorElement")?;
+        let params = py_qc.getattr("parameters")?;
+        assert_eq!(params.len()?, 2);
+        for (param, index) in params.try_iter()?.zip([0, 2]) {
+            let param = param?;
+            assert!(param.is_instance(&element)?);
+            assert_eq!(param.getattr("index")?.extract::<usize>()?, index);
+            let vector = param.getattr("vector")?;
+            assert_eq!(vector.getattr("name")?.extract::<String>()?, "θ");
+            assert_eq!(vector.len()?, 3);
+        }
+        // Both gates share the vector's elements.
+        let vectors = params
+            .try_iter()?
+            .map(|param| param?.getattr("vector"))
+            .collect::<PyResult<Vec<_>>>()?;
+        assert!(vectors[0].is(&vectors[1]));
+        Ok(())
+    });
+}
+
+#[test]
+fn test_circuit_from_python() {
+    with_python(|py| {
+        let module = py.import("qiskit.circuit")?;
+        let theta = module.getattr("Parameter")?.call1(("theta",))?;
+        let py_qc = module.getattr("QuantumCircuit")?.call1((3, 1))?;
+        py_qc.call_method1("h", (2,))?;
+        py_qc.call_method1("cx", (2, 0))?;
+        py_qc.call_method1("rz", (&theta, 0))?;
+        py_qc.call_method1("rz", (&theta, 1))?;
+        py_qc.call_method1("measure", (0, 0))?;
+
+        let qc: QuantumCircuit = py_qc.extract()?;
+        assert_eq!(qc.num_qubits(), 3);
+        assert_eq!(qc.num_clbits(), 1);
+        let insts: Vec<_> = qc.instructions().collect();
+        let names: Vec<&str> = insts.iter().map(|inst| inst.name).collect();
+        assert_eq!(names, ["h", "cx", "rz", "rz", "measure"]);
+        assert_eq!(insts[1].qubits, &[2, 0]);
+        assert_eq!(insts[4].clbits, &[0]);
+        // Both rz gates share one parameter.
+        assert_eq!(qc.parameters().len(), 1);
+        Ok(())
+    });
+}
+
+#[test]
+fn test_parameter_expression_round_trip() {
+    let x = Parameter::new("x");
+    let theta = ParameterVector::new("θ", 2);
+    let mut qc = QuantumCircuit::new(1, 0);
+    qc.rz((2.0 * &x - &theta[1]).cos() / (&x * &theta[0]).exp(), 0);
+    qc.rx(
+        ParameterExpression::from(&theta[1]).pow(&x).asin().abs() - 1.0,
+        0,
+    );
+    qc.ry(-(&x / 4.0).log(), 0);
+
+    let bind = |qc: &QuantumCircuit| {
+        let values = qc
+            .parameters()
+            .into_iter()
+            .zip([0.3, -0.7, 0.5])
+            .collect::<HashMap<_, _>>();
+        let bound = qc.assign_parameters(&values).unwrap();
+        bound
+            .instructions()
+            .map(|inst| inst.param_values()[0].as_float().unwrap())
+            .collect::<Vec<_>>()
+    };
+
+    with_python(|py| {
+        let py_qc = (&qc).into_pyobject(py)?;
+        let back: QuantumCircuit = py_qc.extract()?;
+        let names: Vec<String> = back
+            .parameters()
+            .iter()
+            .map(|param| param.name().to_string())
+            .collect();
+        assert_eq!(names, ["x", "θ[0]", "θ[1]"]);
+        for (expected, value) in bind(&qc).into_iter().zip(bind(&back)) {
+            assert!((expected - value).abs() < 1e-12);
+        }
+
+        // The vector elements are converted back to elements of one vector.
+        let element = py
+            .import("qiskit.circuit.parametervector")?
+            .getattr("ParameterVectorElement")?;
+        let params = (&back).into_pyobject(py)?.getattr("parameters")?;
+        let elements = params.try_iter()?.skip(1).collect::<PyResult<Vec<_>>>()?;
+        assert!(
+            elements
+                .iter()
+                .all(|param| param.is_instance(&element).unwrap())
+        );
+        assert!(
+            elements[0]
+                .getattr("vector")?
+                .is(&elements[1].getattr("vector")?)
+        );
+        Ok(())
+    });
+}
+
+fn register_sizes(py_qc: &Bound<'_, PyAny>, attr: &str) -> PyResult<Vec<(String, usize)>> {
+    py_qc
+        .getattr(attr)?
+        .try_iter()?
+        .map(|register| {
+            let register = register?;
+            Ok((register.getattr("name")?.extract()?, register.len()?))
+        })
+        .collect()
+}
+
+#[test]
+fn test_registers_round_trip() {
+    let mut qc = QuantumCircuit::new(1, 0);
+    qc.add_quantum_register(QuantumRegister::new(2, "data"));
+    qc.add_quantum_register(QuantumRegister::new(1, "ancilla"));
+    qc.add_classical_register(ClassicalRegister::new(2, "meas"));
+    qc.measure(3, 1);
+
+    with_python(|py| {
+        let py_qc = (&qc).into_pyobject(py)?;
+        assert_eq!(py_qc.getattr("num_qubits")?.extract::<u32>()?, 4);
+        assert_eq!(
+            register_sizes(&py_qc, "qregs")?,
+            [("data".to_string(), 2), ("ancilla".to_string(), 1)]
+        );
+        assert_eq!(register_sizes(&py_qc, "cregs")?, [("meas".to_string(), 2)]);
+        // The first qubit isn't in a register.
+        let ancilla = py_qc.getattr("qregs")?.get_item(1)?.get_item(0)?;
+        let index: u32 = py_qc
+            .call_method1("find_bit", (ancilla,))?
+            .getattr("index")?
+            .extract()?;
+        assert_eq!(index, 3);
+
+        let back: QuantumCircuit = py_qc.extract()?;
+        assert_eq!(back.num_qubits(), 4);
+        assert_eq!(back.num_clbits(), 2);
+        let py_back = (&back).into_pyobject(py)?;
+        assert_eq!(
+            register_sizes(&py_back, "qregs")?,
+            register_sizes(&py_qc, "qregs")?
+        );
+        assert_eq!(
+            register_sizes(&py_back, "cregs")?,
+            register_sizes(&py_qc, "cregs")?
+        );
+        Ok(())
+    });
+}
+
+#[test]
+fn test_loose_bits_after_register_from_python() {
+    with_python(|py| {
+        let module = py.import("qiskit.circuit")?;
+        let qreg = module.getattr("QuantumRegister")?.call1((2, "a"))?;
+        let loose = vec![module.getattr("Qubit")?.call0()?];
+        let py_qc = module.getattr("QuantumCircuit")?.call1((qreg, loose))?;
+        let err = py_qc.extract::<QuantumCircuit>().unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        Ok(())
+    });
+}
+
+#[test]
+fn test_parameters_by_identity() {
+    let first = ParameterVector::new("v", 1);
+    let second = ParameterVector::new("v", 1);
+    let mut qc = QuantumCircuit::new(1, 0);
+    qc.rz(&first[0], 0);
+    qc.rx(&second[0], 0);
+
+    with_python(|py| {
+        // Two Rust vectors with the same name stay two Python vectors.
+        let params = (&qc).into_pyobject(py)?.getattr("parameters")?;
+        assert_eq!(params.len()?, 2);
+        let vectors = params
+            .try_iter()?
+            .map(|param| param?.getattr("vector"))
+            .collect::<PyResult<Vec<_>>>()?;
+        assert!(!vectors[0].is(&vectors[1]));
+
+        // Two Python parameters with the same name stay two Rust parameters.
+        let module = py.import("qiskit.circuit")?;
+        let x = module.getattr("Parameter")?.call1(("x",))?;
+        let other_x = module.getattr("Parameter")?.call1(("x",))?;
+        let py_qc = module.getattr("QuantumCircuit")?.call1((1,))?;
+        py_qc.call_method1("rz", (&x, 0))?;
+        py_qc.call_method1("rz", (&other_x, 0))?;
+        py_qc.call_method1("rz", (&x, 0))?;
+        let back: QuantumCircuit = py_qc.extract()?;
+        let values: Vec<_> = back
+            .instructions()
+            .map(|inst| inst.param_values()[0].clone())
+            .collect();
+        assert_ne!(values[0], values[1]);
+        assert_eq!(values[0], values[2]);
+        Ok(())
+    });
+}
+
+#[test]
+fn test_custom_gate_from_python() {
+    with_python(|py| {
+        let module = py.import("qiskit.circuit")?;
+        let circuit = module.getattr("QuantumCircuit")?;
+        let kwargs = [("name", "bell")].into_py_dict(py)?;
+        let inner = circuit.call((2,), Some(&kwargs))?;
+        inner.call_method1("h", (0,))?;
+        inner.call_method1("cx", (0, 1))?;
+        inner.setattr("global_phase", 0.5)?;
+        // A custom gate named like a standard gate is still replaced by its definition.
+        let kwargs = [("name", "x")].into_py_dict(py)?;
+        let fake_x = circuit.call((1,), Some(&kwargs))?;
+        fake_x.call_method1("h", (0,))?;
+
+        let py_qc = circuit.call1((3,))?;
+        py_qc.call_method1("append", (inner.call_method0("to_gate")?, (2, 0)))?;
+        py_qc.call_method1("append", (fake_x.call_method0("to_gate")?, (1,)))?;
+
+        let qc: QuantumCircuit = py_qc.extract()?;
+        let insts: Vec<_> = qc.instructions().collect();
+        let names: Vec<&str> = insts.iter().map(|inst| inst.name).collect();
+        assert_eq!(names, ["h", "cx", "global_phase", "h"]);
+        assert_eq!(insts[0].qubits, &[2]);
+        assert_eq!(insts[1].qubits, &[2, 0]);
+        assert_eq!(insts[3].qubits, &[1]);
+
+        let opaque = module
+            .getattr("Gate")?
+            .call1(("opaque", 1, Vec::<f64>::new()))?;
+        py_qc.call_method1("append", (opaque, (0,)))?;
+        let err = py_qc.extract::<QuantumCircuit>().unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        Ok(())
+    });
+}
+
+#[test]
+fn test_unsupported_operations() {
+    let mut qc = QuantumCircuit::new(1, 0);
+    qc.for_loop(0..3, |body, _| {
+        body.h(0);
+    });
+
+    with_python(|py| {
+        let err = (&qc).into_pyobject(py).unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        Ok(())
+    });
+}
+
+#[test]
+fn test_observable_round_trip() {
+    let obs = SparseObservable::from_sparse_list(
+        &[
+            ("XZ", &[0, 2], Complex64::new(0.5, 0.0)),
+            ("+r", &[1, 3], Complex64::new(0.0, -1.5)),
+            ("", &[], Complex64::new(2.0, 0.0)),
+        ],
+        4,
+    )
+    .unwrap();
+
+    with_python(|py| {
+        let py_obs = (&obs).into_pyobject(py)?;
+        assert_eq!(py_obs.getattr("num_qubits")?.extract::<u32>()?, 4);
+        let back: SparseObservable = py_obs.extract()?;
+        assert_eq!(back.num_qubits(), 4);
+        assert_eq!(
+            back.terms().collect::<Vec<_>>(),
+            obs.terms().collect::<Vec<_>>()
+        );
+        Ok(())
+    });
+}
+
+#[pyfunction]
+#[pyo3(crate = "qiskit_rs::pyo3")]
+fn bell(num_clbits: u32) -> QuantumCircuit {
+    let mut qc = QuantumCircuit::new(2, num_clbits);
+    qc.h(0);
+    qc.cx(0, 1);
+    qc
+}
+
+#[pyfunction]
+#[pyo3(crate = "qiskit_rs::pyo3")]
+fn count_gates(qc: QuantumCircuit) -> usize {
+    qc.num_instructions()
+}
+
+#[test]
+fn test_pyfunction() {
+    with_python(|py| {
+        let module = PyModule::new(py, "rust_circuits")?;
+        module.add_function(wrap_pyfunction!(bell, &module)?)?;
+        module.add_function(wrap_pyfunction!(count_gates, &module)?)?;
+        let qc = module.getattr("bell")?.call1((2,))?;
+        assert_eq!(qc.getattr("num_clbits")?.extract::<u32>()?, 2);
+        let count: usize = module.getattr("count_gates")?.call1((qc,))?.extract()?;
+        assert_eq!(count, 2);
+        Ok(())
+    });
+}
@@ -0,0 +1,30 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+#![cfg(feature = "runtime-linking")]
+
+use qiskit_rs::{LoadError, QuantumCircuit};
+
+// Everything is in one test because the library can only be loaded once per process.
+#[test]
+fn test_init() {
+    // SAFETY: the library doesn't exist, so nothing is loaded.
+    let missing = unsafe { qiskit_rs::init("/nonexistent/libqiskit.so") };
+    assert!(matches!(missing, Err(LoadError::Library(_))));
+
+    // Using the bindings loads the default library, after which init fails.
+    let qc = QuantumCircuit::new(2, 1);
+    assert_eq!(qc.num_qubits(), 2);
+    // SAFETY: a library is already loaded, so nothing is loaded.
+    let again = unsafe { qiskit_rs::init("/nonexistent/libqiskit.so") };
+    assert!(matches!(again, Err(LoadError::AlreadyLoaded)));
+}
@@ -0,0 +1,276 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+mod common;
+
+use common::{Matrix, TOL, assert_close, c, commute, observable_matrix};
+use qiskit_rs::{BitTerm, Complex64, QiskitError, SparseObservable, SparseTerm};
+
+const BIT_TERMS: [BitTerm; 9] = [
+    BitTerm::X,
+    BitTerm::Plus,
+    BitTerm::Minus,
+    BitTerm::Y,
+    BitTerm::Right,
+    BitTerm::Left,
+    BitTerm::Z,
+    BitTerm::Zero,
+    BitTerm::One,
+];
+
+fn single(term: &SparseTerm, num_qubits: u32) -> SparseObservable {
+    let mut obs = SparseObservable::zero(num_qubits);
+    assert_eq!(obs.add_term(term), QiskitError::Success);
+    obs
+}
+
+// Every term on two qubits, including the identity on either.
+fn two_qubit_terms() -> Vec<SparseTerm> {
+    let options: Vec<Option<BitTerm>> = std::iter::once(None)
+        .chain(BIT_TERMS.into_iter().map(Some))
+        .collect();
+    let mut terms = Vec::new();
+    for a in &options {
+        for b in &options {
+            let (indices, bit_terms) = [(0, a), (1, b)]
+                .into_iter()
+                .filter_map(|(q, bit_term)| bit_term.map(|bit_term| (q, bit_term)))
+                .unzip();
+            terms.push(SparseTerm {
+                coeff: Complex64::ONE,
+                bit_terms,
+                indices,
+            });
+        }
+    }
+    terms
+}
+
+#[test]
+fn test_commutes_with() {
+    let terms = two_qubit_terms();
+    let matrices: Vec<Matrix> = terms
+        .iter()
+        .map(|term| observable_matrix(&single(term, 2)))
+        .collect();
+    for (a, a_matrix) in terms.iter().zip(&matrices) {
+        for (b, b_matrix) in terms.iter().zip(&matrices) {
+            assert_eq!(
+                a.commutes_with(b),
+                commute(a_matrix, b_matrix),
+                "{a:?} and {b:?}"
+            );
+            if a.qubit_wise_commutes(b) {
+                assert!(a.commutes_with(b), "{a:?} and {b:?}");
+            }
+        }
+    }
+
+    let term = |label: &str, indices: &[u32]| SparseTerm {
+        coeff: Complex64::ONE,
+        bit_terms: label
+            .chars()
+            .map(|c| BitTerm::from_label(c).unwrap())
+            .collect(),
+        indices: indices.to_vec(),
+    };
+    // Indices need not be sorted or overlap.
+    assert!(term("XY", &[3, 1]).commutes_with(&term("YX", &[3, 1])));
+    assert!(!term("XY", &[3, 1]).qubit_wise_commutes(&term("YX", &[3, 1])));
+    assert!(term("Z+", &[0, 2]).qubit_wise_commutes(&term("0X", &[0, 5])));
+    assert!(!term("Z", &[0]).commutes_with(&term("X1", &[0, 1])));
+
+    // Projectors of other bases on more qubits, against the matrices.
+    let labels = ["+ZY", "0XY", "1YX", "rl-", "XZ0", "-r1", "ZZZ", "l+X"];
+    for a in labels {
+        for b in labels {
+            let (a, b) = (term(a, &[0, 1, 2]), term(b, &[2, 0, 1]));
+            let a_matrix = observable_matrix(&single(&a, 3));
+            let b_matrix = observable_matrix(&single(&b, 3));
+            assert_eq!(
+                a.commutes_with(&b),
+                commute(&a_matrix, &b_matrix),
+                "{a:?} and {b:?}"
+            );
+        }
+    }
+}
+
+fn check_groups(obs: &SparseObservable, groups: &[SparseObservable], qubit_wise: bool) {
+    let mut all = Vec::new();
+    for group in groups {
+        let terms: Vec<SparseTerm> = group.terms().collect();
+        assert!(!terms.is_empty());
+        for a in &terms {
+            for b in &terms {
+                if qubit_wise {
+                    assert!(a.qubit_wise_commutes(b));
+                } else {
+                    assert!(a.commutes_with(b));
+                }
+            }
+        }
+        all.extend(terms);
+    }
+    assert_eq!(all.len(), obs.num_terms());
+    for term in obs.terms() {
+        assert!(all.contains(&term));
+    }
+}
+
+#[test]
+fn test_group_commuting() {
+    // The Heisenberg model on a line of four qubits.
+    let mut heisenberg = SparseObservable::zero(4);
+    for q in 0..3 {
+        for label in ["XX", "YY", "ZZ"] {
+            let term = SparseObservable::from_sparse_list(&[(label, &[q, q + 1], c(1.0))], 4)
+                .unwrap()
+                .terms()
+                .next()
+                .unwrap();
+            assert_eq!(heisenberg.add_term(&term), QiskitError::Success);
+        }
+    }
+    let groups = heisenberg.group_commuting(true);
+    check_groups(&heisenberg, &groups, true);
+    assert_eq!(groups.len(), 3);
+    for group in &groups {
+        let bases: Vec<BitTerm> = group.terms().map(|term| term.bit_terms[0]).collect();
+        assert!(bases.iter().all(|b| *b == bases[0]));
+    }
+    let groups = heisenberg.group_commuting(false);
+    check_groups(&heisenberg, &groups, false);
+    assert!(groups.len() <= 3);
+
+    // The transverse-field Ising model, whose graph is bipartite.
+    let ising = SparseObservable::from_sparse_list(
+        &[
+            ("ZZ", &[0, 1], c(1.0)),
+            ("ZZ", &[1, 2], c(1.0)),
+            ("X", &[0], c(0.5)),
+            ("X", &[1], c(0.5)),
+            ("X", &[2], c(0.5)),
+            ("", &[], c(2.0)),
+        ],
+        3,
+    )
+    .unwrap();
+    for qubit_wise in [true, false] {
+        let groups = ising.group_commuting(qubit_wise);
+        check_groups(&ising, &groups, qubit_wise);
+        assert_eq!(groups.len(), 2);
+    }
+
+    // XX, YY and ZZ commute, but not qubit-wise.
+    let bell = SparseObservable::from_sparse_list(
+        &[
+            ("XX", &[0, 1], c(1.0)),
+            ("YY", &[0, 1], c(-1.0)),
+            ("ZZ", &[0, 1], c(1.0)),
+        ],
+        2,
+    )
+    .unwrap();
+    assert_eq!(bell.group_commuting(true).len(), 3);
+    assert_eq!(bell.group_commuting(false), std::slice::from_ref(&bell));
+
+    assert!(SparseObservable::zero(3).group_commuting(true).is_empty());
+}
+
+#[test]
+fn test_adjoint() {
+    let obs = SparseObservable::from_sparse_list(
+        &[
+            ("X+", &[0, 2], Complex64::new(1.0, 2.0)),
+            ("rZ", &[1, 2], Complex64::new(-0.5, 0.25)),
+            ("", &[], Complex64::I),
+        ],
+        3,
+    )
+    .unwrap();
+    let adjoint = obs.adjoint();
+    assert_eq!(adjoint.num_terms(), 3);
+    assert_close(
+        &observable_matrix(&adjoint).data,
+        &observable_matrix(&obs).adjoint().data,
+    );
+    assert_eq!(adjoint.adjoint(), obs);
+}
+
+#[test]
+fn test_simplify() {
+    let obs = SparseObservable::from_sparse_list(
+        &[
+            ("XZ", &[0, 1], c(1.0)),
+            ("Y", &[2], c(0.5)),
+            ("ZX", &[1, 0], c(0.25)),
+            ("XZ", &[0, 1], c(-1.0)),
+            ("", &[], c(1e-12)),
+            ("Y", &[2], Complex64::new(0.0, 1.0)),
+            ("0", &[1], c(0.1)),
+        ],
+        3,
+    )
+    .unwrap();
+    let expected = SparseObservable::from_sparse_list(
+        &[
+            ("XZ", &[0, 1], c(0.25)),
+            ("Y", &[2], Complex64::new(0.5, 1.0)),
+            ("0", &[1], c(0.1)),
+        ],
+        3,
+    )
+    .unwrap();
+    assert_eq!(obs.simplify(1e-9), expected);
+    assert_close(
+        &observable_matrix(&obs.simplify(1e-9)).data,
+        &observable_matrix(&obs).data,
+    );
+    assert_eq!(obs.simplify(0.2).num_terms(), 2);
+    assert_eq!(obs.simplify(0.0).num_terms(), 4);
+}
+
+#[test]
+fn test_as_paulis() {
+    let obs = SparseObservable::from_sparse_list(
+        &[
+            ("+X-", &[0, 1, 2], c(1.0)),
+            ("rl", &[1, 3], Complex64::new(0.5, -0.5)),
+            ("01Z", &[0, 2, 3], c(-2.0)),
+            ("Y", &[1], c(0.3)),
+        ],
+        4,
+    )
+    .unwrap();
+    let paulis = obs.as_paulis();
+    assert_eq!(paulis.num_terms(), 4 + 4 + 4 + 1);
+    assert!(
+        paulis
+            .terms()
+            .all(|term| term.bit_terms.iter().all(|b| b.is_pauli()))
+    );
+    assert_close(
+        &observable_matrix(&paulis).data,
+        &observable_matrix(&obs).data,
+    );
+
+    let zero = SparseObservable::from_sparse_list(&[("0", &[0], c(1.0))], 1).unwrap();
+    let expected =
+        SparseObservable::from_sparse_list(&[("", &[], c(0.5)), ("Z", &[0], c(0.5))], 1).unwrap();
+    assert_eq!(zero.as_paulis(), expected);
+    let one = SparseObservable::from_sparse_list(&[("1", &[0], c(1.0))], 1).unwrap();
+    let sum =
+        SparseObservable::from_sparse_list(&[("0", &[0], c(1.0)), ("1", &[0], c(1.0))], 1).unwrap();
+    assert_eq!(sum.as_paulis().simplify(TOL), SparseObservable::identity(1));
+    assert_eq!(one.as_paulis().num_terms(), 2);
+}
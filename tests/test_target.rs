@@ -0,0 +1,130 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+use qiskit_rs::target::{InstructionProperties, Target, TargetOperation};
+use qiskit_rs::{QiskitError, StandardGate};
+
+#[test]
+fn test_add_instructions() {
+    let mut target = Target::new(3);
+    assert_eq!(target.num_qubits(), 3);
+    assert_eq!(target.dt(), None);
+    assert_eq!(target.set_dt(2.2e-10), QiskitError::Success);
+    assert_eq!(target.dt(), Some(2.2e-10));
+
+    let cx = InstructionProperties::new(5e-7, 1e-2);
+    assert_eq!(
+        target.add_instruction(StandardGate::CX, [([0, 1], cx), ([1, 2], cx)]),
+        QiskitError::Success
+    );
+    assert_eq!(
+        target.add_global_instruction(StandardGate::SX),
+        QiskitError::Success
+    );
+    let readout = InstructionProperties {
+        duration: None,
+        error: Some(3e-2),
+    };
+    assert_eq!(
+        target.add_instruction(TargetOperation::Measure, (0..3).map(|q| (vec![q], readout))),
+        QiskitError::Success
+    );
+    assert_eq!(target.num_instructions(), 3);
+    assert_eq!(target.operation_names(), ["cx", "sx", "measure"]);
+
+    assert_eq!(
+        target.qargs(StandardGate::CX),
+        Some(vec![&[0, 1][..], &[1, 2]])
+    );
+    assert_eq!(target.qargs(StandardGate::SX), Some(vec![]));
+    assert_eq!(target.qargs(StandardGate::H), None);
+    assert_eq!(
+        target.instruction_properties(StandardGate::CX, &[1, 2]),
+        Some(cx)
+    );
+    assert_eq!(
+        target.instruction_properties(TargetOperation::Measure, &[2]),
+        Some(readout)
+    );
+    assert_eq!(
+        target.instruction_properties(StandardGate::CX, &[2, 1]),
+        None
+    );
+
+    assert!(target.instruction_supported(StandardGate::CX, &[0, 1]));
+    assert!(!target.instruction_supported(StandardGate::CX, &[0, 2]));
+    assert!(target.instruction_supported(StandardGate::SX, &[2]));
+    assert!(!target.instruction_supported(StandardGate::SX, &[3]));
+    assert!(!target.instruction_supported(StandardGate::SX, &[0, 1]));
+    assert!(!target.instruction_supported(TargetOperation::Reset, &[0]));
+}
+
+#[test]
+fn test_errors() {
+    let mut target = Target::new(2);
+    let props = InstructionProperties::default();
+    assert_eq!(
+        target.add_instruction(StandardGate::CZ, [([0, 1], props)]),
+        QiskitError::Success
+    );
+    assert_eq!(
+        target.add_global_instruction(StandardGate::CZ),
+        QiskitError::TargetInstAlreadyExists
+    );
+    assert_eq!(
+        target.add_instruction(StandardGate::ECR, [([0], props)]),
+        QiskitError::TargetQargMismatch
+    );
+    assert_eq!(target.operation_names(), ["cz"]);
+
+    assert_eq!(
+        target.update_instruction_properties(StandardGate::X, &[0], props),
+        QiskitError::TargetInvalidInstKey
+    );
+    assert_eq!(
+        target.update_instruction_properties(StandardGate::CZ, &[1, 0], props),
+        QiskitError::TargetInvalidQargsKey
+    );
+}
+
+#[test]
+fn test_update_and_clone() {
+    let mut target = Target::new(2);
+    target.add_instruction(
+        StandardGate::X,
+        [([0], InstructionProperties::new(3.5e-8, 1e-4))],
+    );
+    let copy = target.clone();
+
+    let updated = InstructionProperties::new(4e-8, 2e-4);
+    assert_eq!(
+        target.update_instruction_properties(StandardGate::X, &[0], updated),
+        QiskitError::Success
+    );
+    assert_eq!(
+        target.instruction_properties(StandardGate::X, &[0]),
+        Some(updated)
+    );
+    assert_eq!(
+        copy.instruction_properties(StandardGate::X, &[0]),
+        Some(InstructionProperties::new(3.5e-8, 1e-4))
+    );
+    assert_eq!(
+        TargetOperation::from_name("x"),
+        Some(TargetOperation::Gate(StandardGate::X))
+    );
+    assert_eq!(
+        TargetOperation::from_name("measure"),
+        Some(TargetOperation::Measure)
+    );
+    assert_eq!(TargetOperation::from_name("delay"), None);
+}
@@ -0,0 +1,185 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+#![cfg(feature = "json")]
+
+use qiskit_rs::target::{BackendJsonError, InstructionProperties, TargetOperation};
+use qiskit_rs::{CouplingMap, StandardGate, Target, fake_backends};
+
+const CONFIG: &str = include_str!("fixtures/backend_config.json");
+const PROPERTIES: &str = include_str!("fixtures/backend_properties.json");
+
+fn assert_same_target(actual: &Target, expected: &Target) {
+    assert_eq!(actual.num_qubits(), expected.num_qubits());
+    assert_eq!(actual.operation_names(), expected.operation_names());
+    match (actual.dt(), expected.dt()) {
+        (Some(a), Some(b)) => assert!((a - b).abs() < 1e-20),
+        (a, b) => assert_eq!(a, b),
+    }
+    for operation in expected.operations() {
+        let qargs = expected.qargs(operation).unwrap();
+        assert_eq!(actual.qargs(operation).unwrap(), qargs);
+        for qargs in qargs {
+            let a = actual.instruction_properties(operation, qargs).unwrap();
+            let b = expected.instruction_properties(operation, qargs).unwrap();
+            for (a, b) in [(a.duration, b.duration), (a.error, b.error)] {
+                match (a, b) {
+                    (Some(a), Some(b)) => assert!((a - b).abs() <= 1e-12 * b.abs()),
+                    (a, b) => assert_eq!(a, b),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_load_fixture() {
+    let target = Target::from_backend_json(CONFIG, Some(PROPERTIES)).unwrap();
+    assert_eq!(target.num_qubits(), 5);
+    assert!((target.dt().unwrap() - 2.0 / 9.0 * 1e-9).abs() < 1e-20);
+    assert_eq!(
+        target.operation_names(),
+        ["id", "rz", "sx", "x", "cx", "measure", "reset"]
+    );
+    let mut edges = CouplingMap::from(&target).edges().to_vec();
+    edges.sort();
+    assert_eq!(
+        edges,
+        [
+            [0, 1],
+            [1, 0],
+            [1, 2],
+            [1, 3],
+            [2, 1],
+            [3, 1],
+            [3, 4],
+            [4, 3]
+        ]
+    );
+
+    let cx = target
+        .instruction_properties(StandardGate::CX, &[0, 1])
+        .unwrap();
+    assert_eq!(cx.error, Some(0.01315));
+    assert!((cx.duration.unwrap() - 380.2222222e-9).abs() < 1e-18);
+    assert_eq!(
+        target.instruction_properties(StandardGate::RZ, &[2]),
+        Some(InstructionProperties::new(0.0, 0.0))
+    );
+    let measure = target
+        .instruction_properties(TargetOperation::Measure, &[4])
+        .unwrap();
+    assert!((measure.duration.unwrap() - 5351.111111111111e-9).abs() < 1e-18);
+    assert!((0.01..0.05).contains(&measure.error.unwrap()));
+    let reset = target
+        .instruction_properties(TargetOperation::Reset, &[0])
+        .unwrap();
+    assert_eq!(reset.error, None);
+    assert!((reset.duration.unwrap() - 5.696e-6).abs() < 1e-18);
+}
+
+#[test]
+fn test_load_configuration_only() {
+    let target = Target::from_backend_json(CONFIG, None).unwrap();
+    assert_eq!(
+        target.operation_names(),
+        ["id", "rz", "sx", "x", "cx", "measure", "reset"]
+    );
+    assert!(target.instruction_supported(StandardGate::CX, &[3, 4]));
+    assert!(!target.instruction_supported(StandardGate::CX, &[0, 2]));
+    assert_eq!(
+        target.instruction_properties(StandardGate::SX, &[1]),
+        Some(InstructionProperties::default())
+    );
+    // Measurements and resets have no couplings in the configuration.
+    assert_eq!(target.qargs(TargetOperation::Measure), Some(vec![]));
+}
+
+#[test]
+fn test_unknown_gates() {
+    let config = r#"{"n_qubits": 2, "basis_gates": ["rz", "sx", "ecr", "ms", "gpi"]}"#;
+    match Target::from_backend_json(config, None) {
+        Err(BackendJsonError::UnknownGates(names)) => assert_eq!(names, ["ms", "gpi"]),
+        other => panic!("expected unknown gates, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_unlisted_gates() {
+    let config = r#"{
+        "n_qubits": 2,
+        "basis_gates": ["rz", "sx"],
+        "gates": [{"name": "cx", "coupling_map": [[0, 1]]}]
+    }"#;
+    match Target::from_backend_json(config, None) {
+        Err(BackendJsonError::UnlistedGates(names)) => assert_eq!(names, ["cx"]),
+        other => panic!("expected unlisted gates, got {other:?}"),
+    }
+    // A properties entry for a gate the configuration doesn't list.
+    let properties = PROPERTIES.replacen(r#""gate": "sx""#, r#""gate": "y""#, 1);
+    assert!(matches!(
+        Target::from_backend_json(CONFIG, Some(&properties)),
+        Err(BackendJsonError::UnlistedGates(names)) if names == ["y"]
+    ));
+}
+
+#[test]
+fn test_invalid_json() {
+    assert!(matches!(
+        Target::from_backend_json("{", None),
+        Err(BackendJsonError::Json(_))
+    ));
+    assert!(matches!(
+        Target::from_backend_json(r#"{"basis_gates": []}"#, None),
+        Err(BackendJsonError::InvalidField(field)) if field == "n_qubits"
+    ));
+    let err = Target::from_backend_json(CONFIG, Some(r#"{"qubits": []}"#)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "missing or invalid field \"gates\" in backend json"
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    let target = Target::from_backend_json(CONFIG, Some(PROPERTIES)).unwrap();
+    let json = target.to_json();
+    let loaded = Target::from_backend_json(&json.configuration, Some(&json.properties)).unwrap();
+    assert_same_target(&loaded, &target);
+
+    for target in [
+        fake_backends::heavy_hex_27q(3),
+        fake_backends::ion_trap(4, 3),
+    ] {
+        let json = target.to_json();
+        let loaded =
+            Target::from_backend_json(&json.configuration, Some(&json.properties)).unwrap();
+        assert_same_target(&loaded, &target);
+    }
+
+    let mut ideal = Target::new(3);
+    ideal.add_global_instruction(StandardGate::H);
+    ideal.add_instruction(
+        StandardGate::CZ,
+        [([0, 2], InstructionProperties::default())],
+    );
+    ideal.add_global_instruction(TargetOperation::Measure);
+    let json = ideal.to_json();
+    assert_same_target(
+        &Target::from_backend_json(&json.configuration, None).unwrap(),
+        &ideal,
+    );
+    assert_same_target(
+        &Target::from_backend_json(&json.configuration, Some(&json.properties)).unwrap(),
+        &ideal,
+    );
+}
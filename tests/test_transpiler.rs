@@ -0,0 +1,534 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+mod common;
+
+use common::{assert_close_up_to_phase, unitary};
+use qiskit_rs::target::InstructionProperties;
+use qiskit_rs::transpiler::{
+    AnalysisPass, BasisTranslator, CheckGateDirection, CommutativeCancellation, ConsolidateBlocks,
+    ElidePermutations, GateDirection, Optimize1qGatesDecomposition, PassManager, PropertySet,
+    RemoveIdentityEquivalent, TranspilerPass, Vf2Layout, Vf2LayoutResult,
+};
+use qiskit_rs::{Parameter, QiskitError, QuantumCircuit, StandardGate, Target};
+use std::collections::BTreeMap;
+
+fn ideal_target(num_qubits: u32, gates: &[StandardGate]) -> Target {
+    let mut target = Target::new(num_qubits);
+    for gate in gates {
+        target.add_global_instruction(*gate);
+    }
+    target
+}
+
+// A line of qubits with cx between neighbours, in the given directions.
+fn line_target(num_qubits: u32, edges: &[[u32; 2]]) -> Target {
+    let mut target = Target::new(num_qubits);
+    for gate in [StandardGate::RZ, StandardGate::SX, StandardGate::X] {
+        target.add_global_instruction(gate);
+    }
+    target.add_instruction(
+        StandardGate::CX,
+        edges
+            .iter()
+            .map(|edge| (*edge, InstructionProperties::new(3e-7, 1e-2))),
+    );
+    target
+}
+
+fn names(qc: &QuantumCircuit) -> Vec<String> {
+    qc.instructions()
+        .map(|inst| inst.name.to_string())
+        .collect()
+}
+
+fn assert_same_unitary(actual: &QuantumCircuit, expected: &QuantumCircuit) {
+    assert_close_up_to_phase(&unitary(actual).data, &unitary(expected).data);
+}
+
+#[test]
+fn test_basis_translator() {
+    let mut qc = QuantumCircuit::new(3, 0);
+    qc.h(0);
+    qc.cz(0, 1);
+    qc.ccx(0, 1, 2);
+    qc.t(2);
+    let expected = qc.clone();
+
+    let target = ideal_target(3, &[StandardGate::RZ, StandardGate::SX, StandardGate::CX]);
+    BasisTranslator::default()
+        .run(&mut qc, &target, &mut PropertySet::new())
+        .unwrap();
+    assert!(
+        qc.instructions()
+            .all(|inst| ["rz", "sx", "cx"].contains(&inst.name))
+    );
+    assert_same_unitary(&qc, &expected);
+}
+
+#[test]
+fn test_optimize_1q_gates() {
+    let mut qc = QuantumCircuit::new(2, 0);
+    qc.h(0);
+    qc.h(0);
+    qc.rz(0.25, 1);
+    qc.rz(0.5, 1);
+    qc.cx(0, 1);
+    qc.rx(0.3, 0);
+    qc.ry(-1.2, 0);
+    qc.rz(0.7, 0);
+    let expected = qc.clone();
+
+    let target = ideal_target(2, &[StandardGate::U, StandardGate::CX]);
+    Optimize1qGatesDecomposition
+        .run(&mut qc, &target, &mut PropertySet::new())
+        .unwrap();
+    assert_eq!(names(&qc), ["u", "cx", "u"]);
+    assert_same_unitary(&qc, &expected);
+}
+
+#[test]
+fn test_remove_identity_equivalent() {
+    let mut qc = QuantumCircuit::new(2, 0);
+    qc.id(0);
+    qc.rz(0.0, 0);
+    qc.rx(0.3, 1);
+    qc.rzz(0.0, 0, 1);
+    qc.cx(0, 1);
+    let target = ideal_target(2, &[StandardGate::RZ, StandardGate::RX, StandardGate::CX]);
+    RemoveIdentityEquivalent::default()
+        .run(&mut qc, &target, &mut PropertySet::new())
+        .unwrap();
+    assert_eq!(names(&qc), ["rx", "cx"]);
+}
+
+#[test]
+fn test_commutative_cancellation() {
+    let mut qc = QuantumCircuit::new(2, 0);
+    qc.h(0);
+    qc.h(0);
+    qc.cx(0, 1);
+    qc.cx(0, 1);
+    qc.rz(0.25, 1);
+    qc.rz(0.5, 1);
+    let expected = qc.clone();
+    let target = ideal_target(2, &[StandardGate::H, StandardGate::RZ, StandardGate::CX]);
+    CommutativeCancellation::default()
+        .run(&mut qc, &target, &mut PropertySet::new())
+        .unwrap();
+    assert_eq!(names(&qc), ["rz"]);
+    assert_same_unitary(&qc, &expected);
+}
+
+#[test]
+fn test_gate_direction() {
+    let target = line_target(2, &[[0, 1]]);
+    let mut qc = QuantumCircuit::new(2, 0);
+    qc.cx(1, 0);
+    qc.cx(0, 1);
+    let expected = qc.clone();
+    assert!(!GateDirection::check(&qc, &target).unwrap());
+
+    GateDirection
+        .run(&mut qc, &target, &mut PropertySet::new())
+        .unwrap();
+    assert!(GateDirection::check(&qc, &target).unwrap());
+    assert!(
+        qc.instructions()
+            .filter(|inst| inst.name == "cx")
+            .all(|inst| inst.qubits == [0, 1])
+    );
+    assert_same_unitary(&qc, &expected);
+}
+
+#[test]
+fn test_elide_permutations() {
+    let mut qc = QuantumCircuit::new(3, 0);
+    qc.h(0);
+    qc.swap(0, 2);
+    qc.cx(2, 1);
+    let target = ideal_target(3, &[StandardGate::H, StandardGate::CX]);
+    ElidePermutations
+        .run(&mut qc, &target, &mut PropertySet::new())
+        .unwrap();
+    assert_eq!(names(&qc), ["h", "cx"]);
+    let insts: Vec<_> = qc.instructions().collect();
+    assert_eq!(insts[1].qubits, [0, 1]);
+}
+
+#[test]
+fn test_vf2_layout() {
+    let target = line_target(
+        5,
+        &[
+            [0, 1],
+            [1, 0],
+            [1, 2],
+            [2, 1],
+            [2, 3],
+            [3, 2],
+            [3, 4],
+            [4, 3],
+        ],
+    );
+    let mut qc = QuantumCircuit::new(3, 3);
+    qc.h(0);
+    qc.cx(0, 2);
+    qc.cx(2, 1);
+    for q in 0..3 {
+        qc.measure(q, q);
+    }
+    Vf2Layout::default()
+        .run(&mut qc, &target, &mut PropertySet::new())
+        .unwrap();
+    assert_eq!(qc.num_qubits(), 5);
+    assert_eq!(qc.num_clbits(), 3);
+    assert_eq!(qc.num_instructions(), 6);
+    for inst in qc.instructions().filter(|inst| inst.name == "cx") {
+        assert!(target.instruction_supported(StandardGate::CX, inst.qubits));
+    }
+
+    // A triangle of interactions doesn't fit on a line.
+    let mut triangle = QuantumCircuit::new(3, 0);
+    triangle.cx(0, 1);
+    triangle.cx(1, 2);
+    triangle.cx(2, 0);
+    Vf2Layout::default()
+        .run(&mut triangle, &target, &mut PropertySet::new())
+        .unwrap();
+    assert_eq!(triangle.num_qubits(), 3);
+}
+
+#[test]
+fn test_vf2_layout_result() {
+    let target = line_target(4, &[[0, 1], [1, 2], [2, 3]]);
+    let mut qc = QuantumCircuit::new(3, 1);
+    qc.cx(2, 0);
+    qc.cx(0, 1);
+    qc.measure(1, 0);
+    let expected = qc.clone();
+
+    let vf2 = Vf2Layout {
+        strict_direction: true,
+        max_trials: Some(1),
+        ..Vf2Layout::default()
+    };
+    let result: Vf2LayoutResult = vf2.search(&qc, &target).unwrap();
+    assert!(result.found());
+    assert_eq!(result.num_virtual_qubits(), 3);
+    assert_eq!(result.num_physical_qubits(), 4);
+    let mapping = result.mapping();
+    assert_eq!(mapping.len(), 3);
+    for (virt, phys) in &mapping {
+        assert_eq!(result.physical_qubit(*virt), Some(*phys));
+    }
+    let physical = |virt: usize| mapping[virt].1;
+    assert!(target.instruction_supported(StandardGate::CX, &[physical(2), physical(0)]));
+    assert!(target.instruction_supported(StandardGate::CX, &[physical(0), physical(1)]));
+    assert_eq!(result.physical_qubit(3), None);
+
+    assert!(result.apply(&mut qc).unwrap());
+    assert_eq!(qc.num_qubits(), 4);
+    assert_eq!(qc.num_clbits(), 1);
+    assert!(GateDirection::check(&qc, &target).unwrap());
+    assert_eq!(qc.instructions().nth(2).unwrap().qubits, [physical(1)]);
+    let mut mapped = QuantumCircuit::new(4, 1);
+    let layout = [physical(0), physical(1), physical(2)];
+    assert_eq!(
+        mapped.compose(&expected, &layout, &[0]),
+        QiskitError::Success
+    );
+    assert_eq!(names(&qc), names(&mapped));
+    assert_eq!(
+        result.apply(&mut qc).unwrap_err(),
+        QiskitError::MismatchedQubits
+    );
+
+    // Reversing a gate only fits when the direction doesn't matter.
+    let mut reversed = QuantumCircuit::new(2, 0);
+    reversed.cx(1, 0);
+    reversed.cx(0, 1);
+    let result = vf2.search(&reversed, &target).unwrap();
+    assert!(!result.found());
+    assert!(result.mapping().is_empty());
+    assert_eq!(result.physical_qubit(0), None);
+    assert!(!result.apply(&mut reversed).unwrap());
+    assert_eq!(reversed.num_qubits(), 2);
+    assert!(
+        Vf2Layout::default()
+            .search(&reversed, &target)
+            .unwrap()
+            .found()
+    );
+}
+
+#[test]
+fn test_consolidate_blocks() {
+    let mut qc = QuantumCircuit::new(3, 0);
+    qc.cx(0, 1);
+    qc.rz(0.5, 1);
+    qc.cx(0, 1);
+    qc.h(2);
+    let target = ideal_target(3, &[StandardGate::RZ, StandardGate::SX, StandardGate::CX]);
+    let mut pass = ConsolidateBlocks {
+        force_consolidate: true,
+        ..ConsolidateBlocks::default()
+    };
+    pass.run(&mut qc, &target, &mut PropertySet::new()).unwrap();
+    let insts: Vec<_> = qc.instructions().collect();
+    assert_eq!(insts.len(), 2);
+    assert_eq!(insts[0].name, "unitary");
+    assert_eq!(insts[0].qubits, [0, 1]);
+}
+
+#[test]
+fn test_pass_manager() {
+    let target = line_target(3, &[[0, 1], [1, 2]]);
+    let mut qc = QuantumCircuit::new(3, 0);
+    qc.h(0);
+    qc.cx(1, 0);
+    qc.rz(0.0, 2);
+    qc.swap(1, 2);
+    qc.cx(1, 2);
+    qc.cx(1, 2);
+
+    let mut pm = PassManager::new();
+    pm.append(ElidePermutations)
+        .append(RemoveIdentityEquivalent::default())
+        .append(CommutativeCancellation::default())
+        .append(BasisTranslator::default())
+        .append(GateDirection)
+        .append(Optimize1qGatesDecomposition);
+    assert_eq!(pm.len(), 6);
+    let report = pm.run(&mut qc, &target).unwrap();
+
+    let names: Vec<&str> = report
+        .passes
+        .iter()
+        .map(|pass| pass.name.as_str())
+        .collect();
+    assert_eq!(names, pm.pass_names());
+    assert_eq!(report.passes[0].num_instructions_before, 6);
+    assert_eq!(report.passes[0].num_instructions_after, 5);
+    assert_eq!(report.passes[1].num_instructions_after, 4);
+    assert_eq!(report.passes[2].num_two_qubit_gates_after, 1);
+    for pair in report.passes.windows(2) {
+        assert_eq!(
+            pair[0].num_instructions_after,
+            pair[1].num_instructions_before
+        );
+    }
+    assert_eq!(
+        report.total_duration(),
+        report.passes.iter().map(|pass| pass.duration).sum()
+    );
+    assert!(GateDirection::check(&qc, &target).unwrap());
+    assert!(qc.instructions().all(|inst| target.instruction_supported(
+        qiskit_rs::target::TargetOperation::from_name(inst.name).unwrap(),
+        inst.qubits
+    )));
+
+    let table = report.to_string();
+    assert_eq!(table.lines().count(), 8);
+    assert!(
+        table
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with("ElidePermutations")
+    );
+    assert!(table.lines().last().unwrap().starts_with("total"));
+}
+
+// Cancel pairs of x gates that follow each other on a qubit.
+struct CancelXPairs;
+
+impl TranspilerPass for CancelXPairs {
+    fn name(&self) -> &str {
+        "CancelXPairs"
+    }
+
+    fn run(
+        &mut self,
+        circuit: &mut QuantumCircuit,
+        _target: &Target,
+        _property_set: &mut PropertySet,
+    ) -> Result<(), QiskitError> {
+        let mut gates: Vec<(StandardGate, Vec<u32>, Vec<f64>)> = Vec::new();
+        for inst in circuit.instructions() {
+            let gate =
+                StandardGate::from_name(inst.name).ok_or(QiskitError::UnsupportedInstruction)?;
+            let last = gates
+                .iter()
+                .rposition(|(_, qubits, _)| qubits.iter().any(|q| inst.qubits.contains(q)));
+            match last {
+                Some(last)
+                    if gate == StandardGate::X
+                        && gates[last] == (gate, inst.qubits.to_vec(), vec![]) =>
+                {
+                    gates.remove(last);
+                }
+                _ => gates.push((gate, inst.qubits.to_vec(), inst.params.to_vec())),
+            }
+        }
+        let mut rebuilt = QuantumCircuit::new(circuit.num_qubits(), circuit.num_clbits());
+        for (gate, qubits, params) in gates {
+            match rebuilt.append_gate(gate, &qubits, &params) {
+                QiskitError::Success => {}
+                err => return Err(err),
+            }
+        }
+        *circuit = rebuilt;
+        Ok(())
+    }
+}
+
+// Count the operations of the circuit by name.
+struct CountOps;
+
+impl AnalysisPass for CountOps {
+    fn name(&self) -> &str {
+        "CountOps"
+    }
+
+    fn analyze(
+        &mut self,
+        circuit: &QuantumCircuit,
+        _target: &Target,
+        property_set: &mut PropertySet,
+    ) -> Result<(), QiskitError> {
+        let mut counts = BTreeMap::<String, usize>::new();
+        for inst in circuit.instructions() {
+            *counts.entry(inst.name.to_string()).or_default() += 1;
+        }
+        property_set.insert("count_ops", counts);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_rust_passes() {
+    let target = line_target(3, &[[0, 1], [1, 2]]);
+    let mut qc = QuantumCircuit::new(2, 0);
+    qc.x(0);
+    qc.x(0);
+    qc.h(1);
+    qc.x(1);
+    qc.cx(1, 0);
+    qc.x(1);
+    let expected = qc.clone();
+
+    let mut pm = PassManager::new();
+    pm.append(CancelXPairs)
+        .append(BasisTranslator::default())
+        .append(CountOps)
+        .append(Vf2Layout {
+            strict_direction: true,
+            ..Vf2Layout::default()
+        })
+        .append(CheckGateDirection)
+        .append(ElidePermutations);
+    assert_eq!(
+        pm.pass_names(),
+        [
+            "CancelXPairs",
+            "BasisTranslator",
+            "CountOps",
+            "VF2Layout",
+            "CheckGateDirection",
+            "ElidePermutations"
+        ]
+    );
+    let mut property_set = PropertySet::new();
+    let report = pm
+        .run_with_properties(&mut qc, &target, &mut property_set)
+        .unwrap();
+    assert_eq!(report.passes[0].num_instructions_after, 4);
+    assert_eq!(
+        report.passes[2].num_instructions_before,
+        report.passes[2].num_instructions_after
+    );
+
+    assert_eq!(
+        property_set.keys().collect::<Vec<_>>(),
+        [
+            "count_ops",
+            "is_direction_mapped",
+            "layout",
+            "virtual_permutation_layout"
+        ]
+    );
+    let counts = property_set
+        .get::<BTreeMap<String, usize>>("count_ops")
+        .unwrap();
+    assert_eq!(counts["cx"], 1);
+    assert!(
+        counts
+            .keys()
+            .all(|name| ["rz", "sx", "x", "cx"].contains(&name.as_str()))
+    );
+    let layout = property_set.get::<Vec<u32>>("layout").unwrap().clone();
+    assert_eq!(layout.len(), 2);
+    assert!(target.instruction_supported(StandardGate::CX, &[layout[1], layout[0]]));
+    assert_eq!(property_set.get::<bool>("is_direction_mapped"), Some(&true));
+    assert_eq!(
+        property_set.get::<Vec<u32>>("virtual_permutation_layout"),
+        Some(&vec![0, 1, 2])
+    );
+
+    let mut mapped = QuantumCircuit::new(3, 0);
+    assert_eq!(
+        mapped.compose(&expected, &layout, &[]),
+        QiskitError::Success
+    );
+    assert_same_unitary(&qc, &mapped);
+}
+
+#[test]
+fn test_property_set() {
+    let mut property_set = PropertySet::new();
+    assert!(property_set.is_empty());
+    property_set.insert("layout", vec![2u32, 0, 1]);
+    property_set.insert("depth", 3usize);
+    assert_eq!(property_set.len(), 2);
+    assert!(property_set.contains_key("depth"));
+    assert_eq!(property_set.get::<u32>("depth"), None);
+    *property_set.get_mut::<usize>("depth").unwrap() += 1;
+    assert_eq!(property_set.get::<usize>("depth"), Some(&4));
+    assert!(property_set.remove("depth"));
+    assert!(!property_set.remove("depth"));
+    assert_eq!(format!("{property_set:?}"), r#"{"layout"}"#);
+}
+
+#[test]
+fn test_unsupported_circuits() {
+    let target = ideal_target(1, &[StandardGate::RZ]);
+    let mut pm = PassManager::new();
+    pm.append(Optimize1qGatesDecomposition);
+
+    let mut qc = QuantumCircuit::new(1, 0);
+    qc.rz(Parameter::new("theta"), 0);
+    assert_eq!(
+        pm.run(&mut qc, &target).unwrap_err(),
+        QiskitError::UnsupportedInstruction
+    );
+
+    let mut qc = QuantumCircuit::new(1, 0);
+    qc.for_loop(0..2, |body, _| {
+        body.x(0);
+    });
+    assert_eq!(
+        pm.run(&mut qc, &target).unwrap_err(),
+        QiskitError::UnsupportedInstruction
+    );
+    assert!(PassManager::new().is_empty());
+}
@@ -0,0 +1,29 @@
+// This code is part of Qiskit Rust bindings.
+//
+// (C) Copyright IBM 2025
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+use qiskit_rs::{HEADER_VERSION, Version};
+
+#[test]
+fn test_version() {
+    let version = qiskit_rs::version();
+    assert_eq!((version.major, version.minor), (2, 2));
+    assert_eq!((HEADER_VERSION.major, HEADER_VERSION.minor), (2, 2));
+    assert!(version.is_compatible_with(&HEADER_VERSION));
+}
+
+#[test]
+fn test_version_display() {
+    let version = Version::new(2, 2, 3);
+    assert_eq!(version.to_string(), "2.2.3");
+    assert!(Version::new(2, 1, 0) < version);
+    assert!(!Version::new(2, 1, 0).is_compatible_with(&version));
+}